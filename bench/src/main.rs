@@ -4,6 +4,7 @@ use std::str::FromStr;
 
 use csv::Reader as CsvReader;
 use eigen_trust_circuit::{
+	eddsa::native::SecretKey,
 	halo2wrong::curves::bn256::Bn256,
 	params::poseidon_bn254_5x5::Params,
 	utils::{keygen, random_circuit, read_params},
@@ -59,7 +60,7 @@ async fn main() {
 		bootstrap_nodes.push((peer_id, local_address));
 	}
 
-	let params = read_params::<Bn256>(&format!("{}/params-9.bin", path_to_data));
+	let params = read_params::<Bn256>(&format!("{}/params-9.bin", path_to_data)).unwrap();
 	let rng = &mut thread_rng();
 	let random_circuit =
 		random_circuit::<Bn256, _, MAX_NEIGHBORS, NUM_BOOTSTRAP_PEERS, Params>(rng);
@@ -76,7 +77,8 @@ async fn main() {
 
 		let join_handle = tokio::spawn(async move {
 			let neighbor_addr = la_clone.clone();
-			let mut peer = Peer::new(local_key.clone(), params, pk).unwrap();
+			let signer = SecretKey::random(&mut thread_rng());
+			let mut peer = Peer::new(local_key.clone(), signer, params, pk).unwrap();
 			for (peer_id, ..) in bootstrap_nodes {
 				let random_score: u32 = rand::thread_rng().gen_range(0..100);
 				peer.set_score(peer_id, random_score);
@@ -0,0 +1,474 @@
+//! Aggregation of many proofs into a single one. The aggregation circuit
+//! verifies the inner proofs succinctly, leaving the final pairing check of
+//! each of them to an accumulator, and folds the accumulators into one, which
+//! is exposed as the public input of the outer proof.
+
+use crate::{
+	error::Error,
+	utils::{prove_evm, verify_evm},
+};
+use halo2wrong::{
+	curves::{
+		bn256::{Bn256, Fq, Fr, G1Affine},
+		group::ff::{Field, PrimeField},
+		pairing::Engine,
+		CurveAffine,
+	},
+	halo2::{
+		circuit::{Layouter, SimpleFloorPlanner, Value},
+		plonk::{self, create_proof, Circuit, ConstraintSystem, ProvingKey, VerifyingKey},
+		poly::{
+			commitment::ParamsProver,
+			kzg::{
+				commitment::{KZGCommitmentScheme, ParamsKZG},
+				multiopen::ProverSHPLONK,
+			},
+		},
+		transcript::TranscriptWriterBuffer,
+	},
+};
+use rand::Rng;
+use snark_verifier::{
+	loader::{
+		self,
+		halo2::halo2_wrong_ecc::{
+			self,
+			integer::rns::Rns,
+			maingate::{
+				MainGate, MainGateConfig, MainGateInstructions, RangeChip, RangeConfig,
+				RangeInstructions, RegionCtx,
+			},
+			EccConfig,
+		},
+		native::NativeLoader,
+	},
+	pcs::{
+		kzg::{Bdfg21, Kzg, KzgAccumulator, KzgAs, KzgSuccinctVerifyingKey, LimbsEncoding},
+		AccumulationScheme, AccumulationSchemeProver,
+	},
+	system::halo2::{compile, transcript::halo2 as halo2_transcript, Config},
+	util::arithmetic::fe_to_limbs,
+	verifier::{self, PlonkVerifier},
+	Protocol,
+};
+use std::rc::Rc;
+
+/// Number of limbs of the accumulator coordinates.
+const LIMBS: usize = 4;
+/// Number of bits of each limb.
+const BITS: usize = 68;
+/// Width of the Poseidon transcript.
+const T: usize = 5;
+/// Rate of the Poseidon transcript.
+const RATE: usize = 4;
+/// Number of full rounds of the Poseidon transcript.
+const R_F: usize = 8;
+/// Number of partial rounds of the Poseidon transcript.
+const R_P: usize = 60;
+
+/// KZG with the SHPLONK (BDFG21) multiopen scheme.
+type Pcs = Kzg<Bn256, Bdfg21>;
+/// Succinct verifying key of the KZG.
+type Svk = KzgSuccinctVerifyingKey<G1Affine>;
+/// Accumulation scheme folding the KZG accumulators.
+type As = KzgAs<Pcs>;
+/// Plonk verifier, with the accumulators encoded as limbs of the instances.
+type Plonk = verifier::Plonk<Pcs, LimbsEncoding<LIMBS, BITS>>;
+/// ECC chip over the base field of Bn256.
+type BaseFieldEccChip = halo2_wrong_ecc::BaseFieldEccChip<G1Affine, LIMBS, BITS>;
+/// Loader for verifying the inner proofs inside the circuit.
+type Halo2Loader<'a> = loader::halo2::Halo2Loader<'a, G1Affine, BaseFieldEccChip>;
+/// Poseidon transcript, which is cheap to verify inside a circuit.
+type PoseidonTranscript<L, S> =
+	halo2_transcript::PoseidonTranscript<G1Affine, L, S, T, RATE, R_F, R_P>;
+
+/// An inner proof, together with the protocol of its circuit and its public
+/// inputs.
+#[derive(Clone)]
+pub struct Snark {
+	protocol: Protocol<G1Affine>,
+	instances: Vec<Vec<Fr>>,
+	proof: Vec<u8>,
+}
+
+impl Snark {
+	/// Make a proof of `circuit` to be aggregated. The proof is created with
+	/// the Poseidon transcript, so it can be verified by the aggregation
+	/// circuit.
+	pub fn new<C: Circuit<Fr>, R: Rng + Clone>(
+		params: &ParamsKZG<Bn256>, circuit: C, instances: Vec<Vec<Fr>>, pk: &ProvingKey<G1Affine>,
+		rng: &mut R,
+	) -> Result<Self, Error> {
+		let protocol = compile(
+			params,
+			pk.get_vk(),
+			Config::kzg().with_num_instance(instances.iter().map(Vec::len).collect()),
+		);
+
+		let instances_slice: Vec<&[Fr]> = instances.iter().map(Vec::as_slice).collect();
+		let mut transcript = PoseidonTranscript::<NativeLoader, _>::init(Vec::new());
+		create_proof::<KZGCommitmentScheme<Bn256>, ProverSHPLONK<_>, _, _, _, _>(
+			params,
+			pk,
+			&[circuit],
+			&[&instances_slice],
+			rng.clone(),
+			&mut transcript,
+		)
+		.map_err(|e| Error::from_plonk(e, Error::Proving))?;
+
+		Ok(Self { protocol, instances, proof: transcript.finalize() })
+	}
+}
+
+/// The witnesses of an inner proof, as seen by the aggregation circuit.
+#[derive(Clone)]
+struct SnarkWitness {
+	protocol: Protocol<G1Affine>,
+	instances: Vec<Vec<Value<Fr>>>,
+	proof: Value<Vec<u8>>,
+}
+
+impl SnarkWitness {
+	/// The same inner proof, with the witnesses left unknown.
+	fn without_witnesses(&self) -> Self {
+		SnarkWitness {
+			protocol: self.protocol.clone(),
+			instances: self
+				.instances
+				.iter()
+				.map(|instances| vec![Value::unknown(); instances.len()])
+				.collect(),
+			proof: Value::unknown(),
+		}
+	}
+
+	/// The bytes of the proof.
+	fn proof(&self) -> Value<&[u8]> {
+		self.proof.as_ref().map(Vec::as_slice)
+	}
+}
+
+impl From<Snark> for SnarkWitness {
+	fn from(snark: Snark) -> Self {
+		Self {
+			protocol: snark.protocol,
+			instances: snark
+				.instances
+				.into_iter()
+				.map(|instances| instances.into_iter().map(Value::known).collect())
+				.collect(),
+			proof: Value::known(snark.proof),
+		}
+	}
+}
+
+/// Verify the inner proofs succinctly inside the circuit, and fold their
+/// accumulators into one.
+fn aggregate_in_circuit<'a>(
+	svk: &Svk, loader: &Rc<Halo2Loader<'a>>, snarks: &[SnarkWitness], as_proof: Value<&'_ [u8]>,
+) -> Result<KzgAccumulator<G1Affine, Rc<Halo2Loader<'a>>>, plonk::Error> {
+	let mut accumulators = Vec::new();
+	for snark in snarks {
+		let protocol = snark.protocol.loaded(loader);
+		let instances: Vec<Vec<_>> = snark
+			.instances
+			.iter()
+			.map(|instances| instances.iter().map(|x| loader.assign_scalar(*x)).collect())
+			.collect();
+		let mut transcript = PoseidonTranscript::<Rc<Halo2Loader>, _>::new(loader, snark.proof());
+		let proof = Plonk::read_proof(svk, &protocol, &instances, &mut transcript)
+			.map_err(|_| plonk::Error::Synthesis)?;
+		let succinct = Plonk::succinct_verify(svk, &protocol, &instances, &proof)
+			.map_err(|_| plonk::Error::Synthesis)?;
+		accumulators.extend(succinct);
+	}
+
+	let mut transcript = PoseidonTranscript::<Rc<Halo2Loader>, _>::new(loader, as_proof);
+	let proof = As::read_proof(&Default::default(), &accumulators, &mut transcript)
+		.map_err(|_| plonk::Error::Synthesis)?;
+	As::verify(&Default::default(), &accumulators, &proof).map_err(|_| plonk::Error::Synthesis)
+}
+
+/// Configuration of the aggregation circuit.
+#[derive(Clone)]
+pub struct AggregationConfig {
+	main_gate_config: MainGateConfig,
+	range_config: RangeConfig,
+}
+
+impl AggregationConfig {
+	/// Configure the main gate and the range chip of the ECC chip.
+	fn configure(meta: &mut ConstraintSystem<Fr>) -> Self {
+		let main_gate_config = MainGate::<Fr>::configure(meta);
+		let range_config = RangeChip::<Fr>::configure(
+			meta,
+			&main_gate_config,
+			vec![BITS / LIMBS],
+			Rns::<Fq, Fr, LIMBS, BITS>::construct().overflow_lengths(),
+		);
+		Self { main_gate_config, range_config }
+	}
+
+	/// The ECC chip over the base field.
+	fn ecc_chip(&self) -> BaseFieldEccChip {
+		BaseFieldEccChip::new(EccConfig::new(
+			self.range_config.clone(),
+			self.main_gate_config.clone(),
+		))
+	}
+}
+
+/// Circuit verifying the inner proofs, and exposing the limbs of their folded
+/// accumulator as the public inputs.
+#[derive(Clone)]
+pub struct AggregationCircuit {
+	svk: Svk,
+	snarks: Vec<SnarkWitness>,
+	instances: Vec<Fr>,
+	as_proof: Value<Vec<u8>>,
+}
+
+impl AggregationCircuit {
+	/// Make the circuit aggregating `snarks`, by verifying them succinctly
+	/// and folding their accumulators natively, blinding the folding with
+	/// randomness from `rng`.
+	pub fn new<R: Rng>(
+		params: &ParamsKZG<Bn256>, snarks: Vec<Snark>, rng: &mut R,
+	) -> Result<Self, Error> {
+		let svk: Svk = params.get_g()[0].into();
+
+		let mut accumulators = Vec::new();
+		for snark in &snarks {
+			let mut transcript = PoseidonTranscript::<NativeLoader, _>::new(snark.proof.as_slice());
+			let proof = Plonk::read_proof(&svk, &snark.protocol, &snark.instances, &mut transcript)
+				.map_err(Error::Aggregation)?;
+			let succinct = Plonk::succinct_verify(&svk, &snark.protocol, &snark.instances, &proof)
+				.map_err(Error::Aggregation)?;
+			accumulators.extend(succinct);
+		}
+
+		let mut transcript = PoseidonTranscript::<NativeLoader, _>::init(Vec::new());
+		let accumulator = As::create_proof(
+			&Default::default(),
+			&accumulators,
+			&mut transcript,
+			&mut *rng,
+		)
+		.map_err(Error::Aggregation)?;
+		let as_proof = transcript.finalize();
+
+		let KzgAccumulator { lhs, rhs } = accumulator;
+		let instances = [lhs.x, lhs.y, rhs.x, rhs.y].map(fe_to_limbs::<_, _, LIMBS, BITS>).concat();
+
+		Ok(Self {
+			svk,
+			snarks: snarks.into_iter().map(SnarkWitness::from).collect(),
+			instances,
+			as_proof: Value::known(as_proof),
+		})
+	}
+
+	/// The positions of the accumulator limbs in the public inputs.
+	pub fn accumulator_indices() -> Vec<(usize, usize)> {
+		(0..4 * LIMBS).map(|idx| (0, idx)).collect()
+	}
+
+	/// The number of public inputs in each instance column.
+	pub fn num_instance() -> Vec<usize> {
+		vec![4 * LIMBS]
+	}
+
+	/// The public inputs: the limbs of the folded accumulator.
+	pub fn instances(&self) -> Vec<Fr> {
+		self.instances.clone()
+	}
+
+	/// The proof of folding the accumulators.
+	fn as_proof(&self) -> Value<&[u8]> {
+		self.as_proof.as_ref().map(Vec::as_slice)
+	}
+}
+
+impl Circuit<Fr> for AggregationCircuit {
+	type Config = AggregationConfig;
+	type FloorPlanner = SimpleFloorPlanner;
+
+	fn without_witnesses(&self) -> Self {
+		Self {
+			svk: self.svk,
+			snarks: self.snarks.iter().map(SnarkWitness::without_witnesses).collect(),
+			instances: Vec::new(),
+			as_proof: Value::unknown(),
+		}
+	}
+
+	fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+		AggregationConfig::configure(meta)
+	}
+
+	fn synthesize(
+		&self, config: Self::Config, mut layouter: impl Layouter<Fr>,
+	) -> Result<(), plonk::Error> {
+		let main_gate = MainGate::<Fr>::new(config.main_gate_config.clone());
+		let range_chip = RangeChip::<Fr>::new(config.range_config.clone());
+		range_chip.load_table(&mut layouter)?;
+
+		let accumulator_limbs = layouter.assign_region(
+			|| "aggregate",
+			|region| {
+				let ctx = RegionCtx::new(region, 0);
+				let loader = Halo2Loader::new(config.ecc_chip(), ctx);
+				let KzgAccumulator { lhs, rhs } =
+					aggregate_in_circuit(&self.svk, &loader, &self.snarks, self.as_proof())?;
+
+				let mut limbs = Vec::new();
+				for point in [lhs, rhs] {
+					limbs.extend(
+						loader
+							.ecc_chip()
+							.assign_ec_point_to_limbs(&mut loader.ctx_mut(), point.assigned())?,
+					);
+				}
+				Ok(limbs)
+			},
+		)?;
+
+		for (row, limb) in accumulator_limbs.into_iter().enumerate() {
+			main_gate.expose_public(layouter.namespace(|| "accumulator"), limb, row)?;
+		}
+		Ok(())
+	}
+}
+
+/// Fold the inner proofs into a single outer proof of the aggregation circuit,
+/// with the proving key `pk`. The `rng` blinds both the folding and the outer
+/// proof. Returns the public inputs and the outer proof, which is made with
+/// the Keccak256 transcript, so it can be verified on chain.
+pub fn aggregate<R: Rng + Clone>(
+	params: &ParamsKZG<Bn256>, pk: &ProvingKey<G1Affine>, snarks: Vec<Snark>, rng: &mut R,
+) -> Result<(Vec<Fr>, Vec<u8>), Error> {
+	let circuit = AggregationCircuit::new(params, snarks, rng)?;
+	let instances = circuit.instances();
+	let proof = prove_evm(params, circuit, &[&instances], pk, rng)?;
+	Ok((instances, proof))
+}
+
+/// Verify the outer proof of the aggregation circuit, together with the
+/// pairing check of the folded accumulator in its public inputs.
+pub fn verify_aggregated(
+	params: &ParamsKZG<Bn256>, instances: &[Fr], proof: &[u8], vk: &VerifyingKey<G1Affine>,
+) -> Result<bool, Error> {
+	if instances.len() != 4 * LIMBS {
+		return Err(Error::InstanceMismatch);
+	}
+	let coordinates = instances.chunks(LIMBS).map(limbs_to_fe).collect::<Result<Vec<_>, _>>()?;
+	let point = |x: Fq, y: Fq| {
+		Option::<G1Affine>::from(G1Affine::from_xy(x, y)).ok_or(Error::InstanceMismatch)
+	};
+	let lhs = point(coordinates[0], coordinates[1])?;
+	let rhs = point(coordinates[2], coordinates[3])?;
+
+	// e(lhs, [1]_2) = e(rhs, [s]_2)
+	let accumulator_valid =
+		Bn256::pairing(&lhs, &params.g2()) == Bn256::pairing(&rhs, &params.s_g2());
+	Ok(accumulator_valid && verify_evm(params, &[instances], proof, vk)?)
+}
+
+/// Recompose a base field element from its limbs of `BITS` bits. Returns
+/// `InstanceMismatch` if a limb has more bits, so every element has a single
+/// encoding.
+fn limbs_to_fe(limbs: &[Fr]) -> Result<Fq, Error> {
+	let shift = Fq::from(2).pow_vartime([BITS as u64]);
+	limbs.iter().rev().try_fold(Fq::from(0), |acc, limb| {
+		let repr = limb.to_repr();
+		let high = &repr.as_ref()[BITS / 8..];
+		if high[0] >> (BITS % 8) != 0 || high[1..].iter().any(|byte| *byte != 0) {
+			return Err(Error::InstanceMismatch);
+		}
+		let limb = Option::<Fq>::from(Fq::from_repr(repr)).ok_or(Error::InstanceMismatch)?;
+		Ok(acc * shift + limb)
+	})
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::utils::{generate_params, keygen, test::valid_circuit};
+	use halo2wrong::halo2::{dev::MockProver, poly::commitment::Params as _};
+	use rand::{rngs::ThreadRng, thread_rng};
+
+	/// Make an inner proof of the EigenTrust circuit with valid inputs.
+	fn eigen_trust_snark(params: &ParamsKZG<Bn256>, rng: &mut ThreadRng) -> Snark {
+		let (circuit, pub_in) = valid_circuit(rng);
+		let pk = keygen(params, &circuit).unwrap();
+		Snark::new(params, circuit, vec![vec![pub_in]], &pk, rng).unwrap()
+	}
+
+	#[test]
+	fn test_native_accumulator() {
+		let rng = &mut thread_rng();
+		let params = generate_params::<Bn256>(9);
+		let snarks = vec![eigen_trust_snark(&params, rng), eigen_trust_snark(&params, rng)];
+
+		let circuit = AggregationCircuit::new(&params, snarks, rng).unwrap();
+		let instances = circuit.instances();
+		let coordinates: Vec<Fq> =
+			instances.chunks(LIMBS).map(|l| limbs_to_fe(l).unwrap()).collect();
+		let lhs = G1Affine::from_xy(coordinates[0], coordinates[1]).unwrap();
+		let rhs = G1Affine::from_xy(coordinates[2], coordinates[3]).unwrap();
+		assert_eq!(
+			Bn256::pairing(&lhs, &params.g2()),
+			Bn256::pairing(&rhs, &params.s_g2())
+		);
+	}
+
+	#[test]
+	fn test_tampered_proof() {
+		let rng = &mut thread_rng();
+		let params = generate_params::<Bn256>(9);
+		let mut snark = eigen_trust_snark(&params, rng);
+		snark.instances[0][0] += Fr::one();
+
+		let res = AggregationCircuit::new(&params, vec![snark], rng).and_then(|circuit| {
+			let instances = circuit.instances();
+			let coordinates =
+				instances.chunks(LIMBS).map(limbs_to_fe).collect::<Result<Vec<_>, _>>()?;
+			let lhs = G1Affine::from_xy(coordinates[0], coordinates[1]).unwrap();
+			let rhs = G1Affine::from_xy(coordinates[2], coordinates[3]).unwrap();
+			Ok(Bn256::pairing(&lhs, &params.g2()) == Bn256::pairing(&rhs, &params.s_g2()))
+		});
+		assert!(!matches!(res, Ok(true)));
+	}
+
+	#[test]
+	fn test_limbs_to_fe() {
+		let x = Fq::from(123456789) - Fq::from(2).pow_vartime([200]);
+		let mut limbs = fe_to_limbs::<_, Fr, LIMBS, BITS>(x);
+		assert_eq!(limbs_to_fe(&limbs).unwrap(), x);
+
+		// A limb of more than `BITS` bits.
+		limbs[0] += Fr::from(2).pow_vartime([BITS as u64]);
+		assert!(matches!(limbs_to_fe(&limbs), Err(Error::InstanceMismatch)));
+	}
+
+	#[test]
+	#[ignore = "needs a large SRS, and takes minutes"]
+	fn test_aggregate() {
+		let k = 21;
+		let rng = &mut thread_rng();
+		let params = generate_params::<Bn256>(k);
+		let mut inner_params = params.clone();
+		inner_params.downsize(9);
+		let snarks =
+			vec![eigen_trust_snark(&inner_params, rng), eigen_trust_snark(&inner_params, rng)];
+
+		let circuit = AggregationCircuit::new(&params, snarks.clone(), rng).unwrap();
+		let prover = MockProver::run(k, &circuit, vec![circuit.instances()]).unwrap();
+		assert_eq!(prover.verify(), Ok(()));
+
+		let pk = keygen(&params, &circuit).unwrap();
+		let (instances, proof) = aggregate(&params, &pk, snarks, rng).unwrap();
+		assert!(verify_aggregated(&params, &instances, &proof, pk.get_vk()).unwrap());
+	}
+}
@@ -0,0 +1,237 @@
+//! Reporting of the cost of a circuit: its shape, the rows it uses, the proof
+//! size and the time it takes to generate the keys, prove and verify.
+
+use crate::{
+	error::Error,
+	utils::{generate_params, keygen, prove, verify},
+};
+use halo2wrong::{
+	curves::bn256::{Bn256, Fr},
+	halo2::{
+		circuit::Value,
+		plonk::{
+			Advice, Any, Assigned, Assignment, Challenge, Circuit, Column, ConstraintSystem,
+			Error as PlonkError, Fixed, FloorPlanner, Instance, Selector,
+		},
+	},
+};
+use rand::thread_rng;
+use std::{
+	fmt,
+	time::{Duration, Instant},
+};
+
+/// The cost of a circuit with `2^k` rows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CircuitCost {
+	/// Number of rows, `log2`.
+	pub k: u32,
+	/// Number of advice columns.
+	pub advice_columns: usize,
+	/// Number of fixed columns, including the ones of the selectors.
+	pub fixed_columns: usize,
+	/// Number of instance columns.
+	pub instance_columns: usize,
+	/// Number of selectors.
+	pub selectors: usize,
+	/// Number of custom gates.
+	pub gates: usize,
+	/// Number of lookup arguments.
+	pub lookups: usize,
+	/// Maximum degree of the constraints.
+	pub degree: usize,
+	/// Number of rows used by the regions of the circuit.
+	pub rows: usize,
+	/// Number of rows available to the circuit, without the blinding rows.
+	pub usable_rows: usize,
+	/// Size of the proof in bytes.
+	pub proof_size: usize,
+	/// Time of generating the proving and the verifying key.
+	pub keygen_time: Duration,
+	/// Time of creating the proof.
+	pub proving_time: Duration,
+	/// Time of verifying the proof.
+	pub verifying_time: Duration,
+}
+
+impl fmt::Display for CircuitCost {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(f, "k: {}", self.k)?;
+		writeln!(
+			f,
+			"columns: {} advice, {} fixed, {} instance",
+			self.advice_columns, self.fixed_columns, self.instance_columns
+		)?;
+		writeln!(
+			f,
+			"constraints: {} selectors, {} gates, {} lookups, degree {}",
+			self.selectors, self.gates, self.lookups, self.degree
+		)?;
+		writeln!(f, "rows: {} / {}", self.rows, self.usable_rows)?;
+		writeln!(f, "proof size: {} bytes", self.proof_size)?;
+		write!(
+			f,
+			"keygen: {:?}, proving: {:?}, verifying: {:?}",
+			self.keygen_time, self.proving_time, self.verifying_time
+		)
+	}
+}
+
+/// Measure the cost of `circuit` with `2^k` rows, proving and verifying it
+/// with the public inputs `pub_inps`.
+pub fn report<C: Circuit<Fr>>(
+	circuit: C, pub_inps: &[&[Fr]], k: u32,
+) -> Result<CircuitCost, Error> {
+	let mut cs = ConstraintSystem::default();
+	let config = C::configure(&mut cs);
+	let mut counter = RowCounter::default();
+	C::FloorPlanner::synthesize(&mut counter, &circuit, config, cs.constants().clone())
+		.map_err(Error::Keygen)?;
+
+	let params = generate_params::<Bn256>(k);
+	let start = Instant::now();
+	let pk = keygen(&params, &circuit)?;
+	let keygen_time = start.elapsed();
+
+	let start = Instant::now();
+	let proof = prove(&params, circuit, pub_inps, &pk, &mut thread_rng())?;
+	let proving_time = start.elapsed();
+
+	let start = Instant::now();
+	let valid = verify(&params, pub_inps, &proof, pk.get_vk())?;
+	let verifying_time = start.elapsed();
+	if !valid {
+		return Err(Error::Verification(PlonkError::ConstraintSystemFailure));
+	}
+
+	Ok(CircuitCost {
+		k,
+		advice_columns: cs.num_advice_columns(),
+		fixed_columns: cs.num_fixed_columns(),
+		instance_columns: cs.num_instance_columns(),
+		selectors: cs.num_selectors(),
+		gates: cs.gates().len(),
+		lookups: cs.lookups().len(),
+		degree: cs.degree(),
+		rows: counter.rows,
+		usable_rows: (1 << k) - (cs.blinding_factors() + 1),
+		proof_size: proof.len(),
+		keygen_time,
+		proving_time,
+		verifying_time,
+	})
+}
+
+/// Assignment that only records the number of rows used.
+#[derive(Default)]
+struct RowCounter {
+	rows: usize,
+}
+
+impl RowCounter {
+	/// Record that the `row` is used.
+	fn use_row(&mut self, row: usize) {
+		self.rows = self.rows.max(row + 1);
+	}
+}
+
+impl Assignment<Fr> for RowCounter {
+	fn enter_region<NR, N>(&mut self, _: N)
+	where
+		NR: Into<String>,
+		N: FnOnce() -> NR,
+	{
+	}
+
+	fn exit_region(&mut self) {}
+
+	fn enable_selector<A, AR>(&mut self, _: A, _: &Selector, row: usize) -> Result<(), PlonkError>
+	where
+		A: FnOnce() -> AR,
+		AR: Into<String>,
+	{
+		self.use_row(row);
+		Ok(())
+	}
+
+	fn query_instance(&self, _: Column<Instance>, _: usize) -> Result<Value<Fr>, PlonkError> {
+		Ok(Value::unknown())
+	}
+
+	fn assign_advice<V, VR, A, AR>(
+		&mut self, _: A, _: Column<Advice>, row: usize, _: V,
+	) -> Result<(), PlonkError>
+	where
+		V: FnOnce() -> Value<VR>,
+		VR: Into<Assigned<Fr>>,
+		A: FnOnce() -> AR,
+		AR: Into<String>,
+	{
+		self.use_row(row);
+		Ok(())
+	}
+
+	fn assign_fixed<V, VR, A, AR>(
+		&mut self, _: A, _: Column<Fixed>, row: usize, _: V,
+	) -> Result<(), PlonkError>
+	where
+		V: FnOnce() -> Value<VR>,
+		VR: Into<Assigned<Fr>>,
+		A: FnOnce() -> AR,
+		AR: Into<String>,
+	{
+		self.use_row(row);
+		Ok(())
+	}
+
+	fn copy(
+		&mut self, _: Column<Any>, _: usize, _: Column<Any>, _: usize,
+	) -> Result<(), PlonkError> {
+		Ok(())
+	}
+
+	fn fill_from_row(
+		&mut self, _: Column<Fixed>, _: usize, _: Value<Assigned<Fr>>,
+	) -> Result<(), PlonkError> {
+		Ok(())
+	}
+
+	fn get_challenge(&self, _: Challenge) -> Value<Fr> {
+		Value::unknown()
+	}
+
+	fn push_namespace<NR, N>(&mut self, _: N)
+	where
+		NR: Into<String>,
+		N: FnOnce() -> NR,
+	{
+	}
+
+	fn pop_namespace(&mut self, _: Option<String>) {}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::gadgets::is_boolean::test::TestCircuit;
+
+	#[test]
+	fn test_report() {
+		let cost = report(TestCircuit::new(Fr::from(1)), &[], 4).unwrap();
+		assert_eq!(cost.advice_columns, 2);
+		assert_eq!(cost.instance_columns, 0);
+		assert_eq!(cost.selectors, 1);
+		assert_eq!(cost.gates, 1);
+		assert_eq!(cost.lookups, 0);
+		assert_eq!(cost.rows, 1);
+		assert!(cost.rows <= cost.usable_rows);
+		assert!(cost.proof_size > 0);
+	}
+
+	#[test]
+	fn test_report_invalid_proof() {
+		// The proof of an unsatisfied circuit doesn't verify.
+		let res = report(TestCircuit::new(Fr::from(2)), &[], 4);
+		assert!(matches!(res, Err(Error::Verification(_))));
+	}
+}
@@ -0,0 +1,165 @@
+//! Helpers for debugging circuits with the `MockProver`.
+
+use halo2wrong::halo2::{
+	arithmetic::FieldExt,
+	dev::{MockProver, VerifyFailure},
+	plonk::Circuit,
+};
+use std::fmt;
+
+/// A reason why the circuit is not satisfied, with the names and the values
+/// rendered for reading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+	/// The circuit couldn't be synthesized, e.g. because `k` is too small or
+	/// the instances don't match the instance columns.
+	Synthesis(String),
+	/// A constraint of a gate is not satisfied.
+	Constraint {
+		/// The constraint, together with the name of its gate.
+		constraint: String,
+		/// The region and the offset where the constraint failed.
+		location: String,
+		/// The cells queried by the constraint, with their values.
+		cell_values: Vec<(String, String)>,
+	},
+	/// A cell queried by an enabled gate is not assigned.
+	CellNotAssigned {
+		/// The gate querying the cell.
+		gate: String,
+		/// The region where the gate is enabled.
+		region: String,
+		/// The column of the cell.
+		column: String,
+		/// The offset of the cell in the region.
+		offset: isize,
+	},
+	/// An input of a lookup is not in the table.
+	Lookup {
+		/// The index of the lookup argument.
+		lookup_index: usize,
+		/// The region and the offset of the input.
+		location: String,
+	},
+	/// A copy constraint is not satisfied.
+	Permutation {
+		/// The column of the cell.
+		column: String,
+		/// The region and the offset of the cell.
+		location: String,
+	},
+	/// Any other failure.
+	Other(String),
+}
+
+impl From<VerifyFailure> for Failure {
+	fn from(failure: VerifyFailure) -> Self {
+		match failure {
+			VerifyFailure::ConstraintNotSatisfied { constraint, location, cell_values } => {
+				Failure::Constraint {
+					constraint: constraint.to_string(),
+					location: location.to_string(),
+					cell_values: cell_values
+						.into_iter()
+						.map(|(cell, value)| (cell.to_string(), value))
+						.collect(),
+				}
+			},
+			VerifyFailure::CellNotAssigned { gate, region, column, offset, .. } => {
+				Failure::CellNotAssigned {
+					gate: gate.to_string(),
+					region: region.to_string(),
+					column: format!("{:?}", column),
+					offset,
+				}
+			},
+			VerifyFailure::Lookup { lookup_index, location, .. } => {
+				Failure::Lookup { lookup_index, location: location.to_string() }
+			},
+			VerifyFailure::Permutation { column, location } => {
+				Failure::Permutation { column: column.to_string(), location: location.to_string() }
+			},
+			failure => Failure::Other(failure.to_string()),
+		}
+	}
+}
+
+impl fmt::Display for Failure {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Failure::Synthesis(err) => write!(f, "Synthesis failed: {}", err),
+			Failure::Constraint { constraint, location, cell_values } => {
+				writeln!(f, "{} is not satisfied {}", constraint, location)?;
+				for (cell, value) in cell_values {
+					writeln!(f, "  {} = {}", cell, value)?;
+				}
+				Ok(())
+			},
+			Failure::CellNotAssigned { gate, region, column, offset } => write!(
+				f,
+				"{} uses the unassigned cell {} at offset {} in {}",
+				gate, column, offset, region
+			),
+			Failure::Lookup { lookup_index, location } => {
+				write!(f, "Lookup {} is not satisfied {}", lookup_index, location)
+			},
+			Failure::Permutation { column, location } => {
+				write!(
+					f,
+					"Copy constraint of {} is not satisfied {}",
+					column, location
+				)
+			},
+			Failure::Other(failure) => write!(f, "{}", failure),
+		}
+	}
+}
+
+/// Run the circuit on the `MockProver` with `2^k` rows, and print the
+/// failures, if any, in a readable form.
+pub fn sanity_check<F: FieldExt, C: Circuit<F>>(
+	circuit: &C, instances: Vec<Vec<F>>, k: u32,
+) -> Result<(), Vec<Failure>> {
+	let prover = MockProver::run(k, circuit, instances)
+		.map_err(|err| vec![Failure::Synthesis(format!("{:?}", err))])?;
+	prover.verify().map_err(|failures| {
+		let failures: Vec<Failure> = failures.into_iter().map(Failure::from).collect();
+		for failure in &failures {
+			eprintln!("{}", failure);
+		}
+		failures
+	})
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::gadgets::is_boolean::test::TestCircuit;
+	use halo2wrong::curves::bn256::Fr;
+
+	#[test]
+	fn test_sanity_check_ok() {
+		let circuit = TestCircuit::new(Fr::from(1));
+		assert_eq!(sanity_check(&circuit, vec![], 4), Ok(()));
+	}
+
+	#[test]
+	fn test_sanity_check_constraint() {
+		let circuit = TestCircuit::new(Fr::from(2));
+		let failures = sanity_check(&circuit, vec![], 4).unwrap_err();
+		assert_eq!(failures.len(), 1);
+		assert!(matches!(
+			&failures[0],
+			Failure::Constraint { constraint, cell_values, .. }
+				if constraint.contains("is_bool") && cell_values.iter().any(|(_, v)| v == "0x2")
+		));
+	}
+
+	#[test]
+	fn test_sanity_check_synthesis() {
+		// The circuit has no instance columns.
+		let circuit = TestCircuit::new(Fr::from(1));
+		let failures = sanity_check(&circuit, vec![vec![Fr::from(1)]], 4).unwrap_err();
+		assert!(matches!(failures[..], [Failure::Synthesis(_)]));
+	}
+}
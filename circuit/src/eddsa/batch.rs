@@ -0,0 +1,754 @@
+use crate::{
+	eddsa::{
+		eddsa_gadgets::{EddsaGadgetsChip, EddsaGadgetsConfig},
+		native::ed_on_bn254::{B8, SUBORDER},
+	},
+	gadgets::{
+		bits2num::value_to_bits,
+		range::{RangeChip, RangeConfig},
+	},
+	params::poseidon_bn254_5x5::Params,
+	poseidon::{
+		native::sponge::{EDDSA_BATCH_DOMAIN, PRF_DOMAIN},
+		sponge::{PoseidonSpongeChip, PoseidonSpongeConfig},
+		PoseidonChip, PoseidonConfig,
+	},
+	utils::to_wide,
+};
+use halo2wrong::{
+	curves::bn256::Fr,
+	halo2::{
+		arithmetic::{Field, FieldExt},
+		circuit::{AssignedCell, Layouter, Region, Value},
+		plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+		poly::Rotation,
+	},
+};
+use num_bigint::{BigInt, BigUint, ToBigInt};
+
+/// Number of bits of the coefficient of each signature.
+pub const COEFFICIENT_BITS: usize = 128;
+/// Number of bits of the limbs of the scalars, in the arithmetic modulo the
+/// suborder.
+const LIMB_BITS: usize = 64;
+/// Number of bits of the limbs of the range checks.
+const RANGE_LIMB_BITS: usize = 8;
+/// Offset added to the carries, so they are range checked as non-negative
+/// numbers of `CARRY_BITS` bits.
+const CARRY_BITS: usize = 68;
+/// Number of rows of the `mul_add` region.
+const MUL_ADD_ROWS: usize = 25;
+/// Number of rows of the `coefficient` region.
+const COEFFICIENT_ROWS: usize = 5;
+
+/// Returns the number as a big integer.
+fn to_big(x: &Fr) -> BigUint {
+	BigUint::from_bytes_le(&x.to_bytes())
+}
+
+/// Returns the big integer, below `2^512`, reduced into the field.
+fn from_big(x: &BigUint) -> Fr {
+	Fr::from_bytes_wide(&to_wide(&x.to_bytes_le()))
+}
+
+/// Returns the first `n` limbs of the number.
+fn limbs(x: &BigUint, n: usize) -> Vec<BigUint> {
+	let mask = (BigUint::from(1u64) << LIMB_BITS) - 1u64;
+	(0..n).map(|i| (x >> (LIMB_BITS * i)) & &mask).collect()
+}
+
+/// Returns `2^(LIMB_BITS * i)`.
+fn limb_shift(i: usize) -> Fr {
+	Fr::from_u128(1 << LIMB_BITS).pow(&[i as u64, 0, 0, 0])
+}
+
+/// Returns the values of the rows of the `mul_add` region, for the
+/// quotient and the remainder `a * b + c = q * SUBORDER + r`.
+fn mul_add_values(a: Fr, b: Fr, c: Fr) -> [Fr; MUL_ADD_ROWS] {
+	let suborder = to_big(&SUBORDER);
+	let sum = to_big(&a) * to_big(&b) + to_big(&c);
+	let q = &sum / &suborder;
+	let r = &sum % &suborder;
+
+	let a_limbs = limbs(&to_big(&a), 2);
+	let b_limbs = limbs(&to_big(&b), 4);
+	let c_limbs = limbs(&to_big(&c), 4);
+	let q_limbs = limbs(&q, 3);
+	let r_limbs = limbs(&r, 4);
+	let l_limbs = limbs(&suborder, 4);
+
+	// The carries of the lowest three columns of a * b + c - q * SUBORDER - r.
+	let shift = BigInt::from(1u64) << LIMB_BITS;
+	let offset = BigInt::from(1u64) << (CARRY_BITS - 1);
+	let mut carry = BigInt::from(0u64);
+	let mut carries = Vec::new();
+	for j in 0..3 {
+		let mut col = c_limbs[j].to_bigint().unwrap() - r_limbs[j].to_bigint().unwrap() + carry;
+		for i in 0..=j {
+			if i < a_limbs.len() {
+				col += (&a_limbs[i] * &b_limbs[j - i]).to_bigint().unwrap();
+			}
+			if i < q_limbs.len() {
+				col -= (&q_limbs[i] * &l_limbs[j - i]).to_bigint().unwrap();
+			}
+		}
+		carry = col / &shift;
+		carries.push((&carry + &offset).to_biguint().unwrap());
+	}
+
+	let values: Vec<BigUint> = [to_big(&a), to_big(&b), to_big(&c), q, r]
+		.into_iter()
+		.chain(a_limbs)
+		.chain(b_limbs)
+		.chain(c_limbs)
+		.chain(q_limbs)
+		.chain(r_limbs)
+		.chain(carries)
+		.collect();
+	let mut rows = [Fr::zero(); MUL_ADD_ROWS];
+	for (row, value) in rows.iter_mut().zip(&values) {
+		*row = from_big(value);
+	}
+	rows
+}
+
+/// Scalar assigned together with its limbs of `LIMB_BITS` bits.
+#[derive(Clone)]
+struct AssignedScalar {
+	/// The scalar.
+	value: AssignedCell<Fr, Fr>,
+	/// The range checked limbs, from the least significant one.
+	limbs: Vec<AssignedCell<Fr, Fr>>,
+}
+
+/// The cells of a signature and the message it signs, verified by the
+/// `BatchEddsaChip`.
+#[derive(Clone)]
+pub struct AssignedSignature {
+	/// Assigns a cell for the big_r_x.
+	pub big_r_x: AssignedCell<Fr, Fr>,
+	/// Assigns a cell for the big_r_y.
+	pub big_r_y: AssignedCell<Fr, Fr>,
+	/// Assigns a cell for the s.
+	pub s: AssignedCell<Fr, Fr>,
+	/// Assigns a cell for the m.
+	pub m: AssignedCell<Fr, Fr>,
+}
+
+#[derive(Clone)]
+/// Configuration elements for the circuit are defined here.
+pub struct BatchEddsaConfig {
+	/// Constructs eddsa gadgets circuit elements.
+	eddsa_gadgets: EddsaGadgetsConfig,
+	/// Constructs poseidon circuit elements, used for the challenges and the
+	/// coefficients.
+	poseidon: PoseidonConfig<5>,
+	/// Constructs sponge circuit elements, used for committing to the batch.
+	sponge: PoseidonSpongeConfig<5>,
+	/// Constructs range circuit elements, used for the limbs.
+	range: RangeConfig,
+	/// Configures a column for the scalars and their limbs.
+	limbs: Column<Advice>,
+	/// Configures a column for the constants.
+	temp: Column<Advice>,
+	/// Configures fixed boolean values for the `coefficient` and the
+	/// `mul_add` regions.
+	selectors: [Selector; 2],
+}
+
+/// Verifies a batch of signatures by the same public key with a single
+/// randomized equation:
+/// `8 * sum(z_i * s_i) * B8 == 8 * (sum(z_i * R_i) + sum(z_i * H_i) * PK)`,
+/// where `H_i = H(R_i || PK || M_i)`. The coefficients `z_i` are the lowest
+/// `COEFFICIENT_BITS` bits of a hash of the whole batch, so a batch with an
+/// invalid signature passes only with a negligible probability. Apart from
+/// the `z_i * R_i`, it takes two scalar multiplications for the whole batch,
+/// instead of two for every signature. The sums of the scalars are computed
+/// modulo the suborder, with limbs checked modulo `2^192` and modulo the
+/// field order. Like `verify_batch`, the low order components of the points
+/// are ignored, and s is only checked to be below `2^252`.
+pub struct BatchEddsaChip {
+	/// Constructs the signatures and their messages.
+	signatures: Vec<AssignedSignature>,
+	/// Assigns a cell for the pk_x.
+	pk_x: AssignedCell<Fr, Fr>,
+	/// Assigns a cell for the pk_y.
+	pk_y: AssignedCell<Fr, Fr>,
+}
+
+impl BatchEddsaChip {
+	/// Create a new chip for the signatures by the public key.
+	pub fn new(
+		signatures: Vec<AssignedSignature>, pk_x: AssignedCell<Fr, Fr>, pk_y: AssignedCell<Fr, Fr>,
+	) -> Self {
+		Self { signatures, pk_x, pk_y }
+	}
+
+	/// Make the circuit config.
+	pub fn configure(meta: &mut ConstraintSystem<Fr>) -> BatchEddsaConfig {
+		let eddsa_gadgets = EddsaGadgetsChip::configure(meta);
+		let poseidon = PoseidonChip::<_, 5, Params>::configure(meta);
+		let sponge = PoseidonSpongeChip::<_, 5, Params>::configure(meta);
+		let range = RangeChip::<_, RANGE_LIMB_BITS>::configure(meta);
+		let limbs = meta.advice_column();
+		let temp = meta.advice_column();
+		let fixed = meta.fixed_column();
+		let selectors = [meta.selector(), meta.selector()];
+
+		meta.enable_equality(limbs);
+		meta.enable_equality(temp);
+		meta.enable_constant(fixed);
+
+		let compose = |limbs: &[Expression<Fr>]| {
+			limbs.iter().enumerate().fold(Expression::Constant(Fr::zero()), |acc, (i, limb)| {
+				acc + limb.clone() * limb_shift(i)
+			})
+		};
+
+		meta.create_gate("coefficient", |v_cells| {
+			let s_exp = v_cells.query_selector(selectors[0]);
+			let mut query = |i: i32| v_cells.query_advice(limbs, Rotation(i));
+			let [challenge, a, a0, a1, t]: [Expression<Fr>; COEFFICIENT_ROWS] =
+				[0, 1, 2, 3, 4].map(&mut query);
+
+			vec![
+				// challenge == a + t * 2^128
+				s_exp.clone() * (challenge - a.clone() - t * limb_shift(2)),
+				// a == a0 + a1 * 2^64
+				s_exp * (a - compose(&[a0, a1])),
+			]
+		});
+
+		let l = limbs_of(&SUBORDER);
+		meta.create_gate("mul_add", |v_cells| {
+			let s_exp = v_cells.query_selector(selectors[1]);
+			let mut query = |i: i32| v_cells.query_advice(limbs, Rotation(i));
+			let [a, b, c, q, r] = [0, 1, 2, 3, 4].map(&mut query);
+			let [a0, a1] = [5, 6].map(&mut query);
+			let [b0, b1, b2, b3] = [7, 8, 9, 10].map(&mut query);
+			let [c0, c1, c2, c3] = [11, 12, 13, 14].map(&mut query);
+			let [q0, q1, q2] = [15, 16, 17].map(&mut query);
+			let [r0, r1, r2, r3] = [18, 19, 20, 21].map(&mut query);
+			let [k0, k1, k2] = [22, 23, 24].map(&mut query);
+
+			let offset = Expression::Constant(Fr::from_u128(1 << (CARRY_BITS - 1)));
+			let [k0, k1, k2] = [k0, k1, k2].map(|k| k - offset.clone());
+			let shift = limb_shift(1);
+
+			// The lowest three columns of a * b + c - q * SUBORDER - r.
+			let col0 = a0.clone() * b0.clone() + c0.clone() - q0.clone() * l[0] - r0.clone();
+			let col1 = a0.clone() * b1.clone() + a1.clone() * b0.clone() + c1.clone()
+				- q0.clone() * l[1]
+				- q1.clone() * l[0]
+				- r1.clone();
+			let col2 = a0.clone() * b2.clone() + a1.clone() * b1.clone() + c2.clone()
+				- q0.clone() * l[2]
+				- q1.clone() * l[1]
+				- q2.clone() * l[0]
+				- r2.clone();
+
+			vec![
+				// The scalars are composed from their limbs.
+				s_exp.clone() * (a.clone() - compose(&[a0, a1])),
+				s_exp.clone() * (b.clone() - compose(&[b0, b1, b2, b3])),
+				s_exp.clone() * (c.clone() - compose(&[c0, c1, c2, c3])),
+				s_exp.clone() * (q.clone() - compose(&[q0, q1, q2])),
+				s_exp.clone() * (r.clone() - compose(&[r0, r1, r2, r3])),
+				// a * b + c == q * SUBORDER + r, modulo the field order.
+				s_exp.clone() * (a * b + c - q * SUBORDER - r),
+				// a * b + c == q * SUBORDER + r, modulo 2^192, with the carries
+				// of the columns of the limbs. Both sides are below 2^389, so
+				// they are also equal as integers.
+				s_exp.clone() * (col0 - k0.clone() * shift),
+				s_exp.clone() * (col1 + k0 - k1.clone() * shift),
+				s_exp * (col2 + k1 - k2 * shift),
+			]
+		});
+
+		BatchEddsaConfig { eddsa_gadgets, poseidon, sponge, range, limbs, temp, selectors }
+	}
+
+	/// Load the table of the range checks. Call it once in the circuit.
+	pub fn load_table(config: &BatchEddsaConfig, layouter: impl Layouter<Fr>) -> Result<(), Error> {
+		RangeChip::<Fr, RANGE_LIMB_BITS>::load_table(&config.range, layouter)
+	}
+
+	/// Synthesize the circuit.
+	pub fn synthesize(
+		&self, config: BatchEddsaConfig, mut layouter: impl Layouter<Fr>,
+	) -> Result<(), Error> {
+		assert!(!self.signatures.is_empty());
+
+		let (b8_x, b8_y, one, zero, prf_domain, indices) = layouter.assign_region(
+			|| "assign_values",
+			|mut region: Region<'_, Fr>| {
+				let mut assign = |offset: usize, name: &str, value: Fr| {
+					region.assign_advice_from_constant(|| name, config.temp, offset, value)
+				};
+				let b8_x = assign(0, "b8_x", B8.x)?;
+				let b8_y = assign(1, "b8_y", B8.y)?;
+				let one = assign(2, "one", Fr::one())?;
+				let zero = assign(3, "zero", Fr::zero())?;
+				let prf_domain = assign(4, "prf_domain", Fr::from(PRF_DOMAIN))?;
+				let indices = (0..self.signatures.len())
+					.map(|i| assign(5 + i, "index", Fr::from(i as u64)))
+					.collect::<Result<Vec<_>, Error>>()?;
+				Ok((b8_x, b8_y, one, zero, prf_domain, indices))
+			},
+		)?;
+
+		// Commit to the whole batch, so no signature can be chosen after the
+		// coefficients.
+		let mut sponge =
+			PoseidonSpongeChip::<_, 5, Params>::new_with_domain(Fr::from(EDDSA_BATCH_DOMAIN));
+		sponge.update(&[self.pk_x.clone(), self.pk_y.clone()]);
+		for sig in &self.signatures {
+			sponge.update(&[
+				sig.big_r_x.clone(),
+				sig.big_r_y.clone(),
+				sig.s.clone(),
+				sig.m.clone(),
+			]);
+		}
+		let batch = sponge.squeeze(&config.sponge, layouter.namespace(|| "batch"))?;
+
+		let zero_scalar = AssignedScalar { value: zero.clone(), limbs: vec![zero.clone(); 4] };
+		let mut s_sum = zero_scalar.clone();
+		let mut h_sum = zero_scalar;
+		let mut r_sum = None;
+		for (sig, index) in self.signatures.iter().zip(indices) {
+			// z_i = PRF(batch, i), taking its lowest bits.
+			let inputs = [prf_domain.clone(), batch.clone(), index, one.clone(), zero.clone()];
+			let hasher = PoseidonChip::<_, 5, Params>::new(inputs);
+			let challenge =
+				hasher.synthesize(config.poseidon.clone(), layouter.namespace(|| "challenge"))?;
+			let z = Self::coefficient(
+				challenge[1].clone(),
+				&config,
+				layouter.namespace(|| "coefficient"),
+			)?;
+
+			// H(R || PK || M)
+			let m_hash_input = [
+				sig.big_r_x.clone(),
+				sig.big_r_y.clone(),
+				self.pk_x.clone(),
+				self.pk_y.clone(),
+				sig.m.clone(),
+			];
+			let hasher = PoseidonChip::<_, 5, Params>::new(m_hash_input);
+			let m_hash =
+				hasher.synthesize(config.poseidon.clone(), layouter.namespace(|| "m_hash"))?;
+
+			s_sum = Self::mul_add(
+				&z,
+				sig.s.clone(),
+				252,
+				&s_sum,
+				&config,
+				layouter.namespace(|| "s_sum"),
+			)?;
+			h_sum = Self::mul_add(
+				&z,
+				m_hash[0].clone(),
+				254,
+				&h_sum,
+				&config,
+				layouter.namespace(|| "h_sum"),
+			)?;
+
+			// z_i * R_i
+			let z_bits = value_to_bits::<_, COEFFICIENT_BITS>(z.value.value());
+			let big_r_z = EddsaGadgetsChip::scalar_mul::<COEFFICIENT_BITS>(
+				sig.big_r_x.clone(),
+				sig.big_r_y.clone(),
+				one.clone(),
+				z.value.clone(),
+				z_bits,
+				config.eddsa_gadgets.clone(),
+				layouter.namespace(|| "big_r * z"),
+			)?;
+			r_sum = Some(match r_sum {
+				None => big_r_z,
+				Some((x, y, z)) => EddsaGadgetsChip::add_point(
+					x,
+					y,
+					z,
+					big_r_z.0,
+					big_r_z.1,
+					big_r_z.2,
+					config.eddsa_gadgets.clone(),
+					layouter.namespace(|| "r_sum"),
+				)?,
+			});
+		}
+
+		// sum(z_i * s_i) * B8
+		let s_bits = value_to_bits::<_, 252>(s_sum.value.value());
+		let lhs = EddsaGadgetsChip::scalar_mul::<252>(
+			b8_x,
+			b8_y,
+			one.clone(),
+			s_sum.value,
+			s_bits,
+			config.eddsa_gadgets.clone(),
+			layouter.namespace(|| "b_8 * s_sum"),
+		)?;
+
+		// sum(z_i * R_i) + sum(z_i * H_i) * PK
+		let h_bits = value_to_bits::<_, 252>(h_sum.value.value());
+		let pk_h = EddsaGadgetsChip::scalar_mul::<252>(
+			self.pk_x.clone(),
+			self.pk_y.clone(),
+			one,
+			h_sum.value,
+			h_bits,
+			config.eddsa_gadgets.clone(),
+			layouter.namespace(|| "pk * h_sum"),
+		)?;
+		let (x, y, z) = r_sum.unwrap();
+		let rhs = EddsaGadgetsChip::add_point(
+			x,
+			y,
+			z,
+			pk_h.0,
+			pk_h.1,
+			pk_h.2,
+			config.eddsa_gadgets.clone(),
+			layouter.namespace(|| "r_sum + pk_h"),
+		)?;
+
+		// Multiply both sides by the cofactor, and compare them.
+		let mut affine = Vec::new();
+		for (mut x, mut y, mut z) in [lhs, rhs] {
+			for _ in 0..3 {
+				(x, y, z) = EddsaGadgetsChip::add_point(
+					x.clone(),
+					y.clone(),
+					z.clone(),
+					x,
+					y,
+					z,
+					config.eddsa_gadgets.clone(),
+					layouter.namespace(|| "double"),
+				)?;
+			}
+			affine.push(EddsaGadgetsChip::into_affine(
+				x,
+				y,
+				z,
+				config.eddsa_gadgets.clone(),
+				layouter.namespace(|| "affine"),
+			)?);
+		}
+		let (lhs, rhs) = (&affine[0], &affine[1]);
+
+		layouter.assign_region(
+			|| "enforce_equal",
+			|mut region: Region<'_, Fr>| {
+				region.constrain_equal(lhs.0.cell(), rhs.0.cell())?;
+				region.constrain_equal(lhs.1.cell(), rhs.1.cell())
+			},
+		)
+	}
+
+	/// Synthesize the coefficient, as the lowest `COEFFICIENT_BITS` bits of
+	/// the challenge. Since `2^254` is above the field order, a challenge
+	/// can have two such splits, which leaves the prover at most one bit of
+	/// choice for each coefficient.
+	fn coefficient(
+		challenge: AssignedCell<Fr, Fr>, config: &BatchEddsaConfig, mut layouter: impl Layouter<Fr>,
+	) -> Result<AssignedScalar, Error> {
+		let (scalar, t) = layouter.assign_region(
+			|| "coefficient",
+			|mut region: Region<'_, Fr>| {
+				config.selectors[0].enable(&mut region, 0)?;
+				challenge.copy_advice(|| "challenge", &mut region, config.limbs, 0)?;
+
+				let values = challenge.value().map(|c| {
+					let c = to_big(c);
+					let a = &c & ((BigUint::from(1u64) << COEFFICIENT_BITS) - 1u64);
+					let t = &c >> COEFFICIENT_BITS;
+					let a_limbs = limbs(&a, 2);
+					[a, a_limbs[0].clone(), a_limbs[1].clone(), t].map(|x| from_big(&x))
+				});
+				let mut cells = (0..4)
+					.map(|i| {
+						region.assign_advice(
+							|| "limb",
+							config.limbs,
+							i + 1,
+							|| values.map(|v| v[i]),
+						)
+					})
+					.collect::<Result<Vec<_>, Error>>()?;
+
+				let t = cells.pop().unwrap();
+				let value = cells.remove(0);
+				Ok((AssignedScalar { value, limbs: cells }, t))
+			},
+		)?;
+
+		for limb in &scalar.limbs {
+			Self::range_check(limb.clone(), LIMB_BITS, config, &mut layouter)?;
+		}
+		Self::range_check(t, 254 - COEFFICIENT_BITS, config, &mut layouter)?;
+		Ok(scalar)
+	}
+
+	/// Synthesize `(a * b + c) mod SUBORDER`, for `a` below `2^128`, `b`
+	/// below `2^b_bits` and `c` below `2^252`. The result is below `2^252`.
+	fn mul_add(
+		a: &AssignedScalar, b: AssignedCell<Fr, Fr>, b_bits: usize, c: &AssignedScalar,
+		config: &BatchEddsaConfig, mut layouter: impl Layouter<Fr>,
+	) -> Result<AssignedScalar, Error> {
+		let (b_limbs, q_limbs, r, carries) = layouter.assign_region(
+			|| "mul_add",
+			|mut region: Region<'_, Fr>| {
+				config.selectors[1].enable(&mut region, 0)?;
+
+				let values = a
+					.value
+					.value()
+					.zip(b.value())
+					.zip(c.value.value())
+					.map(|((a, b), c)| mul_add_values(*a, *b, *c));
+				let mut assign = |row: usize| {
+					region.assign_advice(|| "limb", config.limbs, row, || values.map(|v| v[row]))
+				};
+				assign(3)?;
+				let r = assign(4)?;
+				let b_limbs = (7..11).map(&mut assign).collect::<Result<Vec<_>, Error>>()?;
+				let q_limbs = (15..18).map(&mut assign).collect::<Result<Vec<_>, Error>>()?;
+				let r_limbs = (18..22).map(&mut assign).collect::<Result<Vec<_>, Error>>()?;
+				let carries = (22..25).map(&mut assign).collect::<Result<Vec<_>, Error>>()?;
+
+				a.value.copy_advice(|| "a", &mut region, config.limbs, 0)?;
+				b.copy_advice(|| "b", &mut region, config.limbs, 1)?;
+				c.value.copy_advice(|| "c", &mut region, config.limbs, 2)?;
+				for (i, limb) in a.limbs.iter().enumerate() {
+					limb.copy_advice(|| "a_limb", &mut region, config.limbs, 5 + i)?;
+				}
+				for (i, limb) in c.limbs.iter().enumerate() {
+					limb.copy_advice(|| "c_limb", &mut region, config.limbs, 11 + i)?;
+				}
+
+				Ok((
+					b_limbs,
+					q_limbs,
+					AssignedScalar { value: r, limbs: r_limbs },
+					carries,
+				))
+			},
+		)?;
+
+		let q_bits = [LIMB_BITS, LIMB_BITS, 8];
+		let b_bits = [LIMB_BITS, LIMB_BITS, LIMB_BITS, b_bits - 3 * LIMB_BITS];
+		let r_bits = [LIMB_BITS, LIMB_BITS, LIMB_BITS, 252 - 3 * LIMB_BITS];
+		let checks = b_limbs
+			.into_iter()
+			.zip(b_bits)
+			.chain(q_limbs.into_iter().zip(q_bits))
+			.chain(r.limbs.iter().cloned().zip(r_bits))
+			.chain(carries.into_iter().map(|carry| (carry, CARRY_BITS)));
+		for (limb, num_bits) in checks {
+			Self::range_check(limb, num_bits, config, &mut layouter)?;
+		}
+		Ok(r)
+	}
+
+	/// Synthesize the range check of `x` to `num_bits` bits.
+	fn range_check(
+		x: AssignedCell<Fr, Fr>, num_bits: usize, config: &BatchEddsaConfig,
+		layouter: &mut impl Layouter<Fr>,
+	) -> Result<(), Error> {
+		RangeChip::<Fr, RANGE_LIMB_BITS>::range_check(
+			x,
+			num_bits,
+			&config.range,
+			layouter.namespace(|| "range_check"),
+		)
+	}
+}
+
+/// Returns the limbs of the number, as field elements.
+fn limbs_of(x: &Fr) -> [Fr; 4] {
+	let limbs = limbs(&to_big(x), 4);
+	std::array::from_fn(|i| from_big(&limbs[i]))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		eddsa::native::{sign, verify, PublicKey, SecretKey, Signature},
+		utils::{generate_params, prove_and_verify},
+	};
+	use halo2wrong::{
+		curves::bn256::Bn256,
+		halo2::{
+			circuit::SimpleFloorPlanner,
+			dev::MockProver,
+			plonk::{Circuit, Instance},
+		},
+	};
+	use rand::thread_rng;
+
+	const K: usize = 3;
+
+	#[derive(Clone)]
+	struct TestConfig {
+		batch: BatchEddsaConfig,
+		temp: Column<Advice>,
+		pub_ins: Column<Instance>,
+	}
+
+	#[derive(Clone)]
+	struct TestCircuit {
+		signatures: [Signature; K],
+		messages: [Fr; K],
+	}
+
+	impl Circuit<Fr> for TestCircuit {
+		type Config = TestConfig;
+		type FloorPlanner = SimpleFloorPlanner;
+
+		fn without_witnesses(&self) -> Self {
+			self.clone()
+		}
+
+		fn configure(meta: &mut ConstraintSystem<Fr>) -> TestConfig {
+			let batch = BatchEddsaChip::configure(meta);
+			let temp = meta.advice_column();
+			let pub_ins = meta.instance_column();
+
+			meta.enable_equality(temp);
+			meta.enable_equality(pub_ins);
+
+			TestConfig { batch, temp, pub_ins }
+		}
+
+		fn synthesize(
+			&self, config: TestConfig, mut layouter: impl Layouter<Fr>,
+		) -> Result<(), Error> {
+			let (pk_x, pk_y, signatures) = layouter.assign_region(
+				|| "temp",
+				|mut region: Region<'_, Fr>| {
+					let pk_x = region.assign_advice_from_instance(
+						|| "pk_x",
+						config.pub_ins,
+						0,
+						config.temp,
+						0,
+					)?;
+					let pk_y = region.assign_advice_from_instance(
+						|| "pk_y",
+						config.pub_ins,
+						1,
+						config.temp,
+						1,
+					)?;
+
+					let mut offset = 2;
+					let mut assign = |value: Fr| {
+						let cell = region.assign_advice(
+							|| "sig",
+							config.temp,
+							offset,
+							|| Value::known(value),
+						);
+						offset += 1;
+						cell
+					};
+					let signatures = self
+						.signatures
+						.iter()
+						.zip(self.messages)
+						.map(|(sig, m)| {
+							Ok(AssignedSignature {
+								big_r_x: assign(sig.big_r.x)?,
+								big_r_y: assign(sig.big_r.y)?,
+								s: assign(sig.s)?,
+								m: assign(m)?,
+							})
+						})
+						.collect::<Result<Vec<_>, Error>>()?;
+					Ok((pk_x, pk_y, signatures))
+				},
+			)?;
+
+			BatchEddsaChip::load_table(&config.batch, layouter.namespace(|| "range_table"))?;
+			let batch = BatchEddsaChip::new(signatures, pk_x, pk_y);
+			batch.synthesize(config.batch, layouter.namespace(|| "batch_eddsa"))
+		}
+	}
+
+	fn setup() -> (TestCircuit, PublicKey, Vec<Fr>) {
+		let sk = SecretKey::random(&mut thread_rng());
+		let pk = sk.public();
+		let messages = [1u64, 2, 3].map(Fr::from);
+		let signatures = messages.map(|m| sign(&sk, &pk, m));
+		let pub_ins = vec![pk.0.x, pk.0.y];
+		(TestCircuit { signatures, messages }, pk, pub_ins)
+	}
+
+	#[test]
+	fn test_mul_add_values() {
+		let a = Fr::from_u128(u128::MAX);
+		let b = Fr::zero() - Fr::one();
+		let c = SUBORDER - Fr::one();
+		let rows = mul_add_values(a, b, c);
+
+		let (q, r) = (to_big(&rows[3]), to_big(&rows[4]));
+		let suborder = to_big(&SUBORDER);
+		assert_eq!(q * &suborder + &r, to_big(&a) * to_big(&b) + to_big(&c));
+		assert!(r < suborder);
+		// The carries fit in their range.
+		for carry in &rows[22..] {
+			assert!(to_big(carry) < BigUint::from(1u64) << CARRY_BITS);
+		}
+	}
+
+	#[test]
+	fn test_batch_eddsa() {
+		let (circuit, pk, pub_ins) = setup();
+		for (sig, m) in circuit.signatures.iter().zip(circuit.messages) {
+			assert!(verify(sig, &pk, m));
+		}
+		let prover = MockProver::run(12, &circuit, vec![pub_ins]).unwrap();
+		assert_eq!(prover.verify(), Ok(()));
+	}
+
+	#[test]
+	fn test_batch_eddsa_wrong_s() {
+		let (mut circuit, _, pub_ins) = setup();
+		circuit.signatures[1].s += Fr::one();
+		let prover = MockProver::run(12, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_batch_eddsa_wrong_message() {
+		let (mut circuit, _, pub_ins) = setup();
+		circuit.messages[2] = Fr::from(4);
+		let prover = MockProver::run(12, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_batch_eddsa_wrong_signer() {
+		let (circuit, ..) = setup();
+		let other = SecretKey::random(&mut thread_rng()).public();
+		let prover = MockProver::run(12, &circuit, vec![vec![other.0.x, other.0.y]]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_batch_eddsa_production() {
+		let (circuit, _, pub_ins) = setup();
+		let params = generate_params(12);
+		let res = prove_and_verify::<Bn256, _, _>(params, circuit, &[&pub_ins], &mut thread_rng())
+			.unwrap();
+		assert!(res);
+	}
+}
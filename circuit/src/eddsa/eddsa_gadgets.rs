@@ -1,23 +1,34 @@
 use super::native::ops::{add_exp, add_value, double_exp, double_value};
-use crate::gadgets::bits2num::{Bits2NumChip, Bits2NumConfig};
 use halo2wrong::{
 	curves::bn256::Fr,
 	halo2::{
-		circuit::{AssignedCell, Layouter, Region},
-		plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+		circuit::{AssignedCell, Layouter, Region, Value},
+		plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Selector},
 		poly::Rotation,
 	},
 };
 
+/// Number of bits of the scalar consumed by a row of the scalar
+/// multiplication.
+pub const WINDOW_BITS: usize = 4;
+/// Number of precomputed multiples of the base point, one for each window.
+pub const NUM_MULTIPLES: usize = 1 << WINDOW_BITS;
+
 #[derive(Clone)]
 /// Configuration elements for the circuit are defined here.
 pub struct EddsaGadgetsConfig {
-	/// Constructs bits2num circuit elements.
-	bits2num: Bits2NumConfig,
 	/// Configures columns for the eddsa advice.
 	eddsa_advice: [Column<Advice>; 7],
+	/// Configures columns for the base point of the scalar multiplication.
+	base: [Column<Advice>; 3],
+	/// Configures columns for the doublings of the accumulator in a window.
+	doubled: [[Column<Advice>; 3]; WINDOW_BITS],
+	/// Configures a column for the running sum of the windows of the scalar.
+	acc: Column<Advice>,
+	/// Configures a fixed column for the windows of the multiples table.
+	index: Column<Fixed>,
 	/// Configures fixed boolean values for each row of the circuit.
-	selectors: [Selector; 3],
+	selectors: [Selector; 5],
 }
 
 /// Structure for the eddsa gadgets chip.
@@ -26,7 +37,6 @@ pub struct EddsaGadgetsChip;
 impl EddsaGadgetsChip {
 	/// Make the circuit configs.
 	pub fn configure(meta: &mut ConstraintSystem<Fr>) -> EddsaGadgetsConfig {
-		let bits2num = Bits2NumChip::<_, 256>::configure(meta);
 		let eddsa_advice = [
 			meta.advice_column(),
 			meta.advice_column(),
@@ -36,9 +46,22 @@ impl EddsaGadgetsChip {
 			meta.advice_column(),
 			meta.advice_column(),
 		];
-		let selectors = [meta.selector(), meta.selector(), meta.selector()];
+		let base = [meta.advice_column(), meta.advice_column(), meta.advice_column()];
+		let doubled = [(); WINDOW_BITS]
+			.map(|_| [meta.advice_column(), meta.advice_column(), meta.advice_column()]);
+		let acc = meta.advice_column();
+		let index = meta.fixed_column();
+		let selectors = [
+			meta.selector(),
+			meta.selector(),
+			meta.complex_selector(),
+			meta.complex_selector(),
+			meta.selector(),
+		];
 
 		eddsa_advice.map(|c| meta.enable_equality(c));
+		base.map(|c| meta.enable_equality(c));
+		meta.enable_equality(acc);
 
 		meta.create_gate("point_add", |v_cells| {
 			let s_exp = v_cells.query_selector(selectors[0]);
@@ -97,56 +120,118 @@ impl EddsaGadgetsChip {
 
 		meta.create_gate("scalar_mul", |v_cells| {
 			let s_exp = v_cells.query_selector(selectors[2]);
-			let bit_exp = v_cells.query_advice(eddsa_advice[0], Rotation::cur());
+			let window_exp = v_cells.query_advice(eddsa_advice[0], Rotation::cur());
 
 			let r_x_exp = v_cells.query_advice(eddsa_advice[1], Rotation::cur());
 			let r_y_exp = v_cells.query_advice(eddsa_advice[2], Rotation::cur());
 			let r_z_exp = v_cells.query_advice(eddsa_advice[3], Rotation::cur());
 
-			let e_x_exp = v_cells.query_advice(eddsa_advice[4], Rotation::cur());
-			let e_y_exp = v_cells.query_advice(eddsa_advice[5], Rotation::cur());
-			let e_z_exp = v_cells.query_advice(eddsa_advice[6], Rotation::cur());
+			let t_x_exp = v_cells.query_advice(eddsa_advice[4], Rotation::cur());
+			let t_y_exp = v_cells.query_advice(eddsa_advice[5], Rotation::cur());
+			let t_z_exp = v_cells.query_advice(eddsa_advice[6], Rotation::cur());
 
 			let r_x_next_exp = v_cells.query_advice(eddsa_advice[1], Rotation::next());
 			let r_y_next_exp = v_cells.query_advice(eddsa_advice[2], Rotation::next());
 			let r_z_next_exp = v_cells.query_advice(eddsa_advice[3], Rotation::next());
 
-			let e_x_next_exp = v_cells.query_advice(eddsa_advice[4], Rotation::next());
-			let e_y_next_exp = v_cells.query_advice(eddsa_advice[5], Rotation::next());
-			let e_z_next_exp = v_cells.query_advice(eddsa_advice[6], Rotation::next());
+			let acc_exp = v_cells.query_advice(acc, Rotation::cur());
+			let acc_next_exp = v_cells.query_advice(acc, Rotation::next());
+
+			let mut constraints = Vec::new();
+
+			// Ensure the accumulator is doubled once for every bit of the window.
+			let mut d = (r_x_exp, r_y_exp, r_z_exp);
+			for columns in doubled {
+				let (d_x3, d_y3, d_z3) = double_exp(d.0, d.1, d.2);
+				let d_x_exp = v_cells.query_advice(columns[0], Rotation::cur());
+				let d_y_exp = v_cells.query_advice(columns[1], Rotation::cur());
+				let d_z_exp = v_cells.query_advice(columns[2], Rotation::cur());
+				constraints.push(s_exp.clone() * (d_x_exp.clone() - d_x3));
+				constraints.push(s_exp.clone() * (d_y_exp.clone() - d_y3));
+				constraints.push(s_exp.clone() * (d_z_exp.clone() - d_z3));
+				d = (d_x_exp, d_y_exp, d_z_exp);
+			}
 
-			let (r_x3, r_y3, r_z3) = add_exp(
-				r_x_exp.clone(),
-				r_y_exp.clone(),
-				r_z_exp.clone(),
-				e_x_exp.clone(),
-				e_y_exp.clone(),
-				e_z_exp.clone(),
-			);
+			// Ensure the multiple of the window is added to the doubled accumulator.
+			let (r_x3, r_y3, r_z3) = add_exp(d.0, d.1, d.2, t_x_exp, t_y_exp, t_z_exp);
+			constraints.push(s_exp.clone() * (r_x_next_exp - r_x3));
+			constraints.push(s_exp.clone() * (r_y_next_exp - r_y3));
+			constraints.push(s_exp.clone() * (r_z_next_exp - r_z3));
+
+			// Ensure the windows add up to the scalar, most significant first.
+			// This is the running sum of the `RangeChip`, in the other direction.
+			// The lookup of the multiples already keeps each window below
+			// `NUM_MULTIPLES`, so a range chip table would only repeat it.
+			let shift = Expression::Constant(Fr::from(NUM_MULTIPLES as u64));
+			constraints.push(s_exp.clone() * (acc_next_exp - (acc_exp * shift + window_exp)));
+
+			// Ensure the base point is the same in every row.
+			for column in base {
+				let base_exp = v_cells.query_advice(column, Rotation::cur());
+				let base_next_exp = v_cells.query_advice(column, Rotation::next());
+				constraints.push(s_exp.clone() * (base_next_exp - base_exp));
+			}
 
-			let (e_x3, e_y3, e_z3) = double_exp(e_x_exp, e_y_exp, e_z_exp);
+			constraints
+		});
 
-			// Select the next value based on a `bit` -- see `select` gadget.
-			let selected_r_x =
-				bit_exp.clone() * (r_x3 - r_x_exp.clone()) - (r_x_next_exp - r_x_exp);
-			let selected_r_y =
-				bit_exp.clone() * (r_y3 - r_y_exp.clone()) - (r_y_next_exp - r_y_exp);
-			let selected_r_z =
-				bit_exp.clone() * (r_z3 - r_z_exp.clone()) - (r_z_next_exp - r_z_exp);
+		meta.create_gate("multiples", |v_cells| {
+			let s_exp = v_cells.query_selector(selectors[4]);
 
-			vec![
-				// Ensure the point addition of `r` and `e` is properly calculated.
-				s_exp.clone() * selected_r_x,
-				s_exp.clone() * selected_r_y,
-				s_exp.clone() * selected_r_z,
-				// Ensure the `e` doubling is properly calculated.
-				s_exp.clone() * (e_x_next_exp - e_x3),
-				s_exp.clone() * (e_y_next_exp - e_y3),
-				s_exp * (e_z_next_exp - e_z3),
-			]
+			let t_x_prev_exp = v_cells.query_advice(eddsa_advice[4], Rotation::prev());
+			let t_y_prev_exp = v_cells.query_advice(eddsa_advice[5], Rotation::prev());
+			let t_z_prev_exp = v_cells.query_advice(eddsa_advice[6], Rotation::prev());
+
+			let t_x_exp = v_cells.query_advice(eddsa_advice[4], Rotation::cur());
+			let t_y_exp = v_cells.query_advice(eddsa_advice[5], Rotation::cur());
+			let t_z_exp = v_cells.query_advice(eddsa_advice[6], Rotation::cur());
+
+			let base_prev_exp = base.map(|c| v_cells.query_advice(c, Rotation::prev()));
+			let base_exp = base.map(|c| v_cells.query_advice(c, Rotation::cur()));
+
+			let (t_x3, t_y3, t_z3) = add_exp(
+				t_x_prev_exp,
+				t_y_prev_exp,
+				t_z_prev_exp,
+				base_prev_exp[0].clone(),
+				base_prev_exp[1].clone(),
+				base_prev_exp[2].clone(),
+			);
+
+			let mut constraints = vec![
+				// Ensure every multiple is the previous one plus the base point.
+				s_exp.clone() * (t_x_exp - t_x3),
+				s_exp.clone() * (t_y_exp - t_y3),
+				s_exp.clone() * (t_z_exp - t_z3),
+			];
+			for (base_exp, base_prev_exp) in base_exp.into_iter().zip(base_prev_exp) {
+				constraints.push(s_exp.clone() * (base_exp - base_prev_exp));
+			}
+			constraints
 		});
 
-		EddsaGadgetsConfig { bits2num, eddsa_advice, selectors }
+		// The multiple used by a window must be in the table of the same base
+		// point, at the position of the window. Since the positions are the
+		// fixed 0..NUM_MULTIPLES, this is also the range check of the window.
+		meta.lookup_any(|v_cells| {
+			let s_exp = v_cells.query_selector(selectors[2]);
+			let table_exp = v_cells.query_selector(selectors[3]);
+
+			let window_exp = v_cells.query_advice(eddsa_advice[0], Rotation::cur());
+			let index_exp = v_cells.query_fixed(index, Rotation::cur());
+
+			let mut lookups = vec![
+				(s_exp.clone(), table_exp.clone()),
+				(s_exp.clone() * window_exp, table_exp.clone() * index_exp),
+			];
+			for column in base.into_iter().chain(eddsa_advice[4..].iter().copied()) {
+				let exp = v_cells.query_advice(column, Rotation::cur());
+				lookups.push((s_exp.clone() * exp.clone(), table_exp.clone() * exp));
+			}
+			lookups
+		});
+
+		EddsaGadgetsConfig { eddsa_advice, base, doubled, acc, index, selectors }
 	}
 
 	/// Synthesize the add_point circuit.
@@ -271,7 +356,16 @@ impl EddsaGadgetsChip {
 		)
 	}
 
-	/// Synthesize the scalar_mul circuit.
+	/// Synthesize the scalar_mul circuit, multiplying the point `e` by the
+	/// scalar `value` with the bits `value_bits`, one window of `WINDOW_BITS`
+	/// bits per row.
+	///
+	/// The windows are not taken from a `RangeChip` decomposition: its running
+	/// sum takes the limbs least significant first, while the accumulator
+	/// needs them most significant first, and its limbs are not assigned cells
+	/// the rows could copy. Instead the windows are summed in the same rows
+	/// that add their multiples, and the lookup of the multiples bounds them,
+	/// so the decomposition costs no extra rows or table.
 	pub fn scalar_mul<const B: usize>(
 		// Assigns a cell for the e_x.
 		e_x: AssignedCell<Fr, Fr>,
@@ -293,14 +387,88 @@ impl EddsaGadgetsChip {
 		),
 		Error,
 	> {
-		let bits2num = Bits2NumChip::new(value.clone(), value_bits);
-		let bits = bits2num.synthesize(config.bits2num, layouter.namespace(|| "bits2num"))?;
+		let e = [e_x, e_y, e_z];
+		let multiples = layouter.assign_region(
+			|| "multiples",
+			|mut region: Region<'_, Fr>| {
+				let mut base = e
+					.iter()
+					.zip(config.base)
+					.map(|(e, column)| e.copy_advice(|| "base", &mut region, column, 0))
+					.collect::<Result<Vec<_>, Error>>()?;
+				let identity = [Fr::zero(), Fr::one(), Fr::one()];
+				let t = identity
+					.iter()
+					.zip(&config.eddsa_advice[4..])
+					.map(|(coord, column)| {
+						region.assign_advice_from_constant(|| "t", *column, 0, *coord)
+					})
+					.collect::<Result<Vec<_>, Error>>()?;
+
+				// The i-th multiple is `i * e`.
+				let mut multiples =
+					vec![[t[0].value_field(), t[1].value_field(), t[2].value_field()]];
+				for i in 0..NUM_MULTIPLES {
+					config.selectors[3].enable(&mut region, i)?;
+					region.assign_fixed(
+						|| "index",
+						config.index,
+						i,
+						|| Value::known(Fr::from(i as u64)),
+					)?;
+					if i == 0 {
+						continue;
+					}
+					config.selectors[4].enable(&mut region, i)?;
+
+					let prev = multiples[i - 1];
+					let (t_x3, t_y3, t_z3) = add_value(
+						prev[0],
+						prev[1],
+						prev[2],
+						base[0].value_field(),
+						base[1].value_field(),
+						base[2].value_field(),
+					);
+					for (j, coord) in [t_x3, t_y3, t_z3].into_iter().enumerate() {
+						region.assign_advice(
+							|| "t",
+							config.eddsa_advice[4 + j],
+							i,
+							|| coord.evaluate(),
+						)?;
+					}
+					base = base
+						.iter()
+						.zip(config.base)
+						.map(|(b, column)| {
+							region.assign_advice(|| "base", column, i, || b.value().copied())
+						})
+						.collect::<Result<_, Error>>()?;
+					multiples.push([t_x3, t_y3, t_z3]);
+				}
+				Ok(multiples)
+			},
+		)?;
+
+		// The windows of the scalar, most significant first.
+		let num_windows = (B + WINDOW_BITS - 1) / WINDOW_BITS;
+		let windows: Vec<usize> = (0..num_windows)
+			.rev()
+			.map(|i| {
+				(0..WINDOW_BITS)
+					.filter(|j| i * WINDOW_BITS + j < B)
+					.filter(|j| value_bits[i * WINDOW_BITS + j] == Fr::one())
+					.map(|j| 1 << j)
+					.sum()
+			})
+			.collect();
 
 		layouter.assign_region(
 			|| "scalar_mul",
 			|mut region: Region<'_, Fr>| {
-				for i in 0..bits.len() {
-					bits[i].copy_advice(|| "bit", &mut region, config.eddsa_advice[0], i)?;
+				for (e, column) in e.iter().zip(config.base) {
+					e.copy_advice(|| "base", &mut region, column, 0)?;
 				}
 
 				let mut r_x = region.assign_advice_from_constant(
@@ -321,74 +489,69 @@ impl EddsaGadgetsChip {
 					0,
 					Fr::one(),
 				)?;
+				let mut acc =
+					region.assign_advice_from_constant(|| "acc_0", config.acc, 0, Fr::zero())?;
 
-				let mut e_x = e_x.copy_advice(|| "e_x", &mut region, config.eddsa_advice[4], 0)?;
-				let mut e_y = e_y.copy_advice(|| "e_y", &mut region, config.eddsa_advice[5], 0)?;
-				let mut e_z = e_z.copy_advice(|| "e_z", &mut region, config.eddsa_advice[6], 0)?;
-
-				// Double and add operation.
-				for i in 0..value_bits.len() {
+				// Double `WINDOW_BITS` times and add the multiple of the window.
+				for (i, window) in windows.iter().enumerate() {
 					config.selectors[2].enable(&mut region, i)?;
 
-					// Add `r` and `e`.
-					let (r_x3, r_y3, r_z3) = add_value(
-						r_x.value_field(),
-						r_y.value_field(),
-						r_z.value_field(),
-						e_x.value_field(),
-						e_y.value_field(),
-						e_z.value_field(),
-					);
-
-					// Double `e`.
-					let (e_x3, e_y3, e_z3) =
-						double_value(e_x.value_field(), e_y.value_field(), e_z.value_field());
+					let window_value = Fr::from(*window as u64);
+					region.assign_advice(
+						|| "window",
+						config.eddsa_advice[0],
+						i,
+						|| Value::known(window_value),
+					)?;
+					let t = multiples[*window];
+					for (j, coord) in t.iter().enumerate() {
+						region.assign_advice(
+							|| "t",
+							config.eddsa_advice[4 + j],
+							i,
+							|| coord.evaluate(),
+						)?;
+					}
 
-					let (r_x_next, r_y_next, r_z_next) = if value_bits[i] == Fr::one() {
-						(r_x3, r_y3, r_z3)
-					} else {
-						(r_x.value_field(), r_y.value_field(), r_z.value_field())
-					};
+					let mut d = (r_x.value_field(), r_y.value_field(), r_z.value_field());
+					for columns in config.doubled {
+						d = double_value(d.0, d.1, d.2);
+						region.assign_advice(|| "d_x", columns[0], i, || d.0.evaluate())?;
+						region.assign_advice(|| "d_y", columns[1], i, || d.1.evaluate())?;
+						region.assign_advice(|| "d_z", columns[2], i, || d.2.evaluate())?;
+					}
+					let (r_x3, r_y3, r_z3) = add_value(d.0, d.1, d.2, t[0], t[1], t[2]);
 
 					r_x = region.assign_advice(
 						|| "r_x",
 						config.eddsa_advice[1],
 						i + 1,
-						|| r_x_next.evaluate(),
+						|| r_x3.evaluate(),
 					)?;
 					r_y = region.assign_advice(
 						|| "r_y",
 						config.eddsa_advice[2],
 						i + 1,
-						|| r_y_next.evaluate(),
+						|| r_y3.evaluate(),
 					)?;
 					r_z = region.assign_advice(
 						|| "r_z",
 						config.eddsa_advice[3],
 						i + 1,
-						|| r_z_next.evaluate(),
+						|| r_z3.evaluate(),
 					)?;
 
-					e_x = region.assign_advice(
-						|| "e_x",
-						config.eddsa_advice[4],
-						i + 1,
-						|| e_x3.evaluate(),
-					)?;
-					e_y = region.assign_advice(
-						|| "e_y",
-						config.eddsa_advice[5],
-						i + 1,
-						|| e_y3.evaluate(),
-					)?;
-					e_z = region.assign_advice(
-						|| "e_z",
-						config.eddsa_advice[6],
-						i + 1,
-						|| e_z3.evaluate(),
-					)?;
+					let acc_next =
+						acc.value().map(|acc| *acc * Fr::from(NUM_MULTIPLES as u64) + window_value);
+					acc = region.assign_advice(|| "acc", config.acc, i + 1, || acc_next)?;
+					for (e, column) in e.iter().zip(config.base) {
+						region.assign_advice(|| "base", column, i + 1, || e.value().copied())?;
+					}
 				}
 
+				// The windows must add up to the scalar.
+				region.constrain_equal(acc.cell(), value.cell())?;
+
 				Ok((r_x, r_y, r_z))
 			},
 		)
@@ -407,9 +570,12 @@ mod test {
 		utils::{generate_params, prove_and_verify},
 	};
 	use halo2wrong::{
-		curves::bn256::{Bn256, Fr},
+		curves::{
+			bn256::{Bn256, Fr},
+			group::ff::Field,
+		},
 		halo2::{
-			circuit::{SimpleFloorPlanner, Value},
+			circuit::SimpleFloorPlanner,
 			dev::MockProver,
 			plonk::{Circuit, Instance},
 		},
@@ -513,12 +679,19 @@ mod test {
 						items[2].clone(),
 						items[3].clone(),
 						value_bits,
-						config.eddsa_gadgets,
+						config.eddsa_gadgets.clone(),
 						layouter.namespace(|| "scalar_mul"),
 					)?;
+					// The projective coordinates depend on the order of the additions.
+					let (x, y) = EddsaGadgetsChip::into_affine(
+						x,
+						y,
+						z,
+						config.eddsa_gadgets,
+						layouter.namespace(|| "into_affine"),
+					)?;
 					layouter.constrain_instance(x.cell(), config.pub_ins, 0)?;
 					layouter.constrain_instance(y.cell(), config.pub_ins, 1)?;
-					layouter.constrain_instance(z.cell(), config.pub_ins, 2)?;
 				},
 			}
 			Ok(())
@@ -591,11 +764,11 @@ mod test {
 		// Testing scalar as value 8.
 		let scalar = Fr::from(8);
 		let r = B8.projective();
-		let res = B8.mul_scalar(&scalar.to_bytes());
+		let res = B8.mul_scalar(&scalar.to_bytes()).affine();
 		let circuit = TestCircuit::new([r.x, r.y, r.z, scalar], Gadgets::ScalarMul);
 
-		let k = 9;
-		let pub_ins = vec![res.x, res.y, res.z];
+		let k = 8;
+		let pub_ins = vec![res.x, res.y];
 		let prover = MockProver::run(k, &circuit, vec![pub_ins]).unwrap();
 		assert_eq!(prover.verify(), Ok(()));
 	}
@@ -605,11 +778,11 @@ mod test {
 		// Testing scalar as value 0.
 		let scalar = Fr::from(0);
 		let r = B8.projective();
-		let res = B8.mul_scalar(&scalar.to_bytes());
+		let res = B8.mul_scalar(&scalar.to_bytes()).affine();
 		let circuit = TestCircuit::new([r.x, r.y, r.z, scalar], Gadgets::ScalarMul);
 
-		let k = 9;
-		let pub_ins = vec![res.x, res.y, res.z];
+		let k = 8;
+		let pub_ins = vec![res.x, res.y];
 		let prover = MockProver::run(k, &circuit, vec![pub_ins]).unwrap();
 		assert_eq!(prover.verify(), Ok(()));
 	}
@@ -619,26 +792,52 @@ mod test {
 		// Testing scalar as value 1.
 		let scalar = Fr::from(1);
 		let r = B8.projective();
-		let res = B8.mul_scalar(&scalar.to_bytes());
+		let res = B8.mul_scalar(&scalar.to_bytes()).affine();
 		let circuit = TestCircuit::new([r.x, r.y, r.z, scalar], Gadgets::ScalarMul);
 
-		let k = 9;
-		let pub_ins = vec![res.x, res.y, res.z];
+		let k = 8;
+		let pub_ins = vec![res.x, res.y];
+		let prover = MockProver::run(k, &circuit, vec![pub_ins]).unwrap();
+		assert_eq!(prover.verify(), Ok(()));
+	}
+
+	#[test]
+	fn should_mul_point_with_random_scalar() {
+		let scalar = Fr::random(rand::thread_rng());
+		let r = B8.projective();
+		let res = B8.mul_scalar(&scalar.to_bytes()).affine();
+		let circuit = TestCircuit::new([r.x, r.y, r.z, scalar], Gadgets::ScalarMul);
+
+		let k = 8;
+		let pub_ins = vec![res.x, res.y];
 		let prover = MockProver::run(k, &circuit, vec![pub_ins]).unwrap();
 		assert_eq!(prover.verify(), Ok(()));
 	}
 
+	#[test]
+	fn test_scalar_mul_wrong_result() {
+		let scalar = Fr::from(8);
+		let r = B8.projective();
+		let res = B8.mul_scalar(&Fr::from(9).to_bytes()).affine();
+		let circuit = TestCircuit::new([r.x, r.y, r.z, scalar], Gadgets::ScalarMul);
+
+		let k = 8;
+		let pub_ins = vec![res.x, res.y];
+		let prover = MockProver::run(k, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
 	#[test]
 	fn should_mul_point_with_scalar_production() {
 		let scalar = Fr::from(8);
 		let r = B8.projective();
-		let res = B8.mul_scalar(&scalar.to_bytes());
+		let res = B8.mul_scalar(&scalar.to_bytes()).affine();
 		let circuit = TestCircuit::new([r.x, r.y, r.z, scalar], Gadgets::ScalarMul);
 
 		let k = 9;
 		let rng = &mut rand::thread_rng();
 		let params = generate_params(k);
-		let pub_ins = [res.x, res.y, res.z];
+		let pub_ins = [res.x, res.y];
 		let res = prove_and_verify::<Bn256, _, _>(params, circuit, &[&pub_ins], rng).unwrap();
 
 		assert!(res);
@@ -1,3 +1,5 @@
+/// Batch verification of signatures under a single public key
+pub mod batch;
 /// Implementation for the EDDSA circuit gadgets.
 pub mod eddsa_gadgets;
 /// Native implementation of EDDSA signature scheme
@@ -6,11 +8,12 @@ pub mod native;
 use crate::{
 	eddsa::eddsa_gadgets::{EddsaGadgetsChip, EddsaGadgetsConfig},
 	gadgets::{
+		bits2num::to_bits,
 		common::{CommonChip, CommonConfig},
-		lt_eq::{LessEqualChip, LessEqualConfig},
+		lt_eq::{LessEqualChip, LessEqualConfig, N_SHIFTED},
 	},
 	params::poseidon_bn254_5x5::Params,
-	poseidon::{PoseidonChip, PoseidonConfig},
+	poseidon::{native::Poseidon, PoseidonChip, PoseidonConfig},
 };
 use halo2wrong::{
 	curves::bn256::Fr,
@@ -20,11 +23,40 @@ use halo2wrong::{
 		poly::Rotation,
 	},
 };
-use native::ed_on_bn254::{B8, SUBORDER};
+use native::{
+	ed_on_bn254::{B8, SUBORDER},
+	PublicKey, Signature,
+};
+
+/// Bits of the scalars, that the `EddsaChip` needs to verify a signature.
+pub struct SignatureBits {
+	/// Bits of the s.
+	pub s_bits: [Fr; 252],
+	/// Bits of the suborder.
+	pub suborder_bits: [Fr; 252],
+	/// Bits of the shifted difference between s and the suborder.
+	pub s_suborder_diff_bits: [Fr; 253],
+	/// Bits of the H(R || PK || M).
+	pub m_hash_bits: [Fr; 256],
+}
+
+impl SignatureBits {
+	/// Compute the bits for the signature `sig` of the message `m` by `pk`.
+	pub fn new(sig: &Signature, pk: &PublicKey, m: Fr) -> Self {
+		let s_bits = to_bits(sig.s.to_bytes()).map(Fr::from);
+		let suborder_bits = to_bits(SUBORDER.to_bytes()).map(Fr::from);
+		let diff = sig.s + Fr::from_bytes(&N_SHIFTED).unwrap() - SUBORDER;
+		let s_suborder_diff_bits = to_bits(diff.to_bytes()).map(Fr::from);
+		let h_inputs = [sig.big_r.x, sig.big_r.y, pk.0.x, pk.0.y, m];
+		let m_hash = Poseidon::<_, 5, Params>::new(h_inputs).permute()[0];
+		let m_hash_bits = to_bits(m_hash.to_bytes()).map(Fr::from);
+		Self { s_bits, suborder_bits, s_suborder_diff_bits, m_hash_bits }
+	}
+}
 
 #[derive(Clone)]
 /// Configuration elements for the circuit are defined here.
-struct EddsaConfig {
+pub struct EddsaConfig {
 	/// Constructs eddsa gadgets circuit elements.
 	eddsa_gadgets: EddsaGadgetsConfig,
 	/// Constructs common circuit elements.
@@ -38,7 +70,7 @@ struct EddsaConfig {
 }
 
 /// Constructs individual cells for the configuration elements.
-struct EddsaChip {
+pub struct EddsaChip {
 	/// Assigns a cell for the big_r_x.
 	big_r_x: AssignedCell<Fr, Fr>,
 	/// Assigns a cell for the big_r_y.
@@ -63,7 +95,7 @@ struct EddsaChip {
 
 impl EddsaChip {
 	/// Create a new chip.
-	fn new(
+	pub fn new(
 		big_r_x: AssignedCell<Fr, Fr>, big_r_y: AssignedCell<Fr, Fr>, s: AssignedCell<Fr, Fr>,
 		pk_x: AssignedCell<Fr, Fr>, pk_y: AssignedCell<Fr, Fr>, m: AssignedCell<Fr, Fr>,
 		s_bits: [Fr; 252], suborder_bits: [Fr; 252], s_suborder_diff_bits: [Fr; 253],
@@ -83,6 +115,18 @@ impl EddsaChip {
 		}
 	}
 
+	/// Create a new chip, with the bits computed by `SignatureBits`.
+	pub fn from_bits(
+		big_r_x: AssignedCell<Fr, Fr>, big_r_y: AssignedCell<Fr, Fr>, s: AssignedCell<Fr, Fr>,
+		pk_x: AssignedCell<Fr, Fr>, pk_y: AssignedCell<Fr, Fr>, m: AssignedCell<Fr, Fr>,
+		bits: SignatureBits,
+	) -> Self {
+		Self::new(
+			big_r_x, big_r_y, s, pk_x, pk_y, m, bits.s_bits, bits.suborder_bits,
+			bits.s_suborder_diff_bits, bits.m_hash_bits,
+		)
+	}
+
 	/// Make the circuit config.
 	pub fn configure(meta: &mut ConstraintSystem<Fr>) -> EddsaConfig {
 		let common = CommonChip::configure(meta);
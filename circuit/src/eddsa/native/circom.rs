@@ -0,0 +1,135 @@
+//! EdDSA-Poseidon compatible with circomlib/iden3 (`circomlibjs`). It uses
+//! the same key expansion, nonce derivation and hash ordering, so signatures
+//! produced here are identical to `signPoseidon`, and signatures from
+//! `signPoseidon` verify with `verify`. The packed encodings of iden3
+//! (`packPoint`, `packSignature`) are the same as `PublicKey::to_bytes` and
+//! `Signature::to_bytes`.
+
+use super::{
+	blh,
+	ed_on_bn254::{SecretScalar, B8_TABLE, SUBORDER},
+	PublicKey, Signature,
+};
+use crate::{params::poseidon_bn254_6x5::Params, poseidon::native::Poseidon};
+use halo2wrong::{curves::bn256::Fr, halo2::arithmetic::Field};
+use num_bigint::BigUint;
+use zeroize::Zeroizing;
+
+/// circomlib hashes 5 inputs with a width 6 Poseidon.
+type Hasher = Poseidon<Fr, 6, Params>;
+
+/// Hashes the private key and prunes the first half of the hash, as circomlib
+/// does. The first half is the scalar, and the second half is used for
+/// deriving the nonce.
+fn expand_key(prv: &[u8; 32]) -> Zeroizing<Vec<u8>> {
+	let mut hash = Zeroizing::new(blh(prv));
+	hash[0] &= 0xf8;
+	hash[31] &= 0x7f;
+	hash[31] |= 0x40;
+	hash
+}
+
+/// Returns the scalar `s >> 3` of the expanded key, shifting the bytes
+/// without branching on them.
+fn key_scalar(hash: &[u8]) -> SecretScalar {
+	let mut s = Zeroizing::new([0; 32]);
+	for (i, byte) in s.iter_mut().enumerate() {
+		let next = hash.get(i + 1).copied().unwrap_or(0);
+		*byte = (hash[i] >> 3) | (next << 5);
+	}
+	SecretScalar::from_bytes(s.as_ref())
+}
+
+/// Returns the challenge `H(R || A || M)`.
+fn challenge(big_r_x: Fr, big_r_y: Fr, pk: &PublicKey, m: Fr) -> BigUint {
+	let inputs = [Fr::zero(), big_r_x, big_r_y, pk.0.x, pk.0.y, m];
+	let m_hash = Hasher::new(inputs).permute()[0];
+	BigUint::from_bytes_le(&m_hash.to_bytes())
+}
+
+/// Returns the public key `B8 * (s >> 3)` of the private key.
+pub fn public_key(prv: &[u8; 32]) -> PublicKey {
+	let hash = expand_key(prv);
+	PublicKey(B8_TABLE.mul_scalar_ct(&key_scalar(&hash[..32]).to_bytes()).affine())
+}
+
+/// Signs the message the same way as circomlib's `signPoseidon`.
+pub fn sign(prv: &[u8; 32], m: Fr) -> Signature {
+	let hash = expand_key(prv);
+	let pk = public_key(prv);
+
+	// r = H(hash[32..] || M)   (mod n)
+	let mut r_input = Zeroizing::new(hash[32..].to_vec());
+	r_input.extend_from_slice(&m.to_bytes());
+	let r = SecretScalar::from_bytes(&Zeroizing::new(blh(&r_input)));
+	let big_r = B8_TABLE.mul_scalar_ct(&r.to_bytes()).affine();
+
+	// S = r + H(R || A || M) * s   (mod n)
+	let m_hash = challenge(big_r.x, big_r.y, &pk, m);
+	let m_hash = SecretScalar::from_bytes(&m_hash.to_bytes_le());
+	let s = SecretScalar::from_bytes(&hash[..32]).mul_add(&m_hash, &r).to_fr();
+
+	Signature { big_r, s }
+}
+
+/// Checks the signature the same way as circomlib's `verifyPoseidon`:
+/// `S * B8 == R + 8 * H(R || A || M) * A`.
+pub fn verify(sig: &Signature, pk: &PublicKey, m: Fr) -> bool {
+	if sig.s >= SUBORDER || !sig.big_r.is_on_curve() || !pk.0.is_on_curve() {
+		return false;
+	}
+	let m_hash = challenge(sig.big_r.x, sig.big_r.y, pk, m) * BigUint::from(8u64);
+
+	let cl = B8_TABLE.mul_scalar(&sig.s.to_bytes());
+	let cr = sig.big_r.projective().add(&pk.0.mul_scalar(&m_hash.to_bytes_le()));
+	cl.affine().equals(cr.affine())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::eddsa::native;
+	use halo2wrong::curves::group::ff::PrimeField;
+
+	#[test]
+	fn should_match_circomlibjs_vector() {
+		// From the `signPoseidon` test of circomlibjs.
+		let prv = hex::decode("0001020304050607080900010203040506070809000102030405060708090001")
+			.unwrap()
+			.try_into()
+			.unwrap();
+		let mut m_bytes = [0; 32];
+		m_bytes[..10].copy_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+		let m = Fr::from_bytes(&m_bytes).unwrap();
+		let fr = |x: &str| Fr::from_str_vartime(x).unwrap();
+
+		let pk = public_key(&prv);
+		assert_eq!(
+			pk.0.x,
+			fr("13277427435165878497778222415993513565335242147425444199013288855685581939618")
+		);
+		assert_eq!(
+			pk.0.y,
+			fr("13622229784656158136036771217484571176836296686641868549125388198837476602820")
+		);
+
+		let sig = sign(&prv, m);
+		assert_eq!(
+			sig.big_r.x,
+			fr("11384336176656855268977457483345535180380036354188103142384839473266348197733")
+		);
+		assert_eq!(
+			sig.big_r.y,
+			fr("15383486972088797283337779941324724402501462225528836549661220478783371668959")
+		);
+		assert_eq!(
+			sig.s,
+			fr("1672775540645840396591609181675628451599263765380031905495115170613215233181")
+		);
+
+		assert!(verify(&sig, &pk, m));
+		assert!(!verify(&sig, &pk, m + Fr::one()));
+		// Not interchangeable with the native signatures.
+		assert!(!native::verify(&sig, &pk, m));
+	}
+}
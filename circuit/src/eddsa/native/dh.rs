@@ -0,0 +1,105 @@
+use super::{hash_bytes, PublicKey, SecretKey};
+use crate::{params::poseidon_bn254_5x5::Params, poseidon::native::prf::kdf};
+use halo2wrong::curves::bn256::Fr;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Domain separation tag for hashing the KDF info into a field element.
+const INFO_DOMAIN: &[u8] = b"eigen-trust/dh/info";
+
+/// Returns the field element the KDF takes for the `info` of `derive_key`,
+/// e.g. to derive the same key in a circuit.
+pub fn kdf_info(info: &[u8]) -> Fr {
+	hash_bytes(INFO_DOMAIN, info)
+}
+
+/// Shared secret point agreed between two peers, kept as the little-endian
+/// bytes of its coordinates, so it is wiped from memory when dropped.
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct SharedSecret {
+	/// x coordinate of the shared point.
+	x: [u8; 32],
+	/// y coordinate of the shared point.
+	y: [u8; 32],
+}
+
+impl SharedSecret {
+	/// Derives a key for the purpose described by `info`, with the Poseidon
+	/// KDF over the shared point. Different `info` give independent keys.
+	pub fn derive_key(&self, info: &[u8]) -> Fr {
+		// Always canonical, since they come from a point.
+		let x = Fr::from_bytes(&self.x).unwrap();
+		let y = Fr::from_bytes(&self.y).unwrap();
+		let info = kdf_info(info);
+		kdf::<_, 5, Params>(&[x, y], &[info], 1)[0]
+	}
+}
+
+impl SecretKey {
+	/// Computes the shared secret `sk * PK` with the other peer's public key.
+	/// Both peers get the same secret from their own secret key and the
+	/// other's public key. Returns `None` if the public key is not on the
+	/// curve, not in the prime order subgroup, i.e. `SUBORDER * PK` is not the
+	/// identity, or is the identity itself, so a malicious key can't leak
+	/// bits of the secret key through a low order component.
+	pub fn diffie_hellman(&self, pk: &PublicKey) -> Option<SharedSecret> {
+		if !pk.0.is_in_subgroup() || pk.0.is_identity() {
+			return None;
+		}
+		let shared = pk.0.mul_scalar_ct(&self.sk0().to_bytes()).affine();
+		Some(SharedSecret { x: shared.x.to_bytes(), y: shared.y.to_bytes() })
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::eddsa::native::ed_on_bn254::Point;
+	use halo2wrong::halo2::arithmetic::Field;
+	use rand::thread_rng;
+
+	#[test]
+	fn should_agree_on_shared_secret() {
+		let mut rng = thread_rng();
+		let sk_a = SecretKey::random(&mut rng);
+		let sk_b = SecretKey::random(&mut rng);
+		let sk_c = SecretKey::random(&mut rng);
+
+		let ab = sk_a.diffie_hellman(&sk_b.public()).unwrap();
+		let ba = sk_b.diffie_hellman(&sk_a.public()).unwrap();
+		let ac = sk_a.diffie_hellman(&sk_c.public()).unwrap();
+		assert_eq!(
+			ab.derive_key(b"trust-vector"),
+			ba.derive_key(b"trust-vector")
+		);
+		assert_ne!(
+			ab.derive_key(b"trust-vector"),
+			ac.derive_key(b"trust-vector")
+		);
+		assert_ne!(ab.derive_key(b"trust-vector"), ab.derive_key(b"opinion"));
+	}
+
+	#[test]
+	fn should_reject_low_order_keys() {
+		let sk = SecretKey::random(&mut thread_rng());
+		assert!(sk.diffie_hellman(&PublicKey(Point::IDENTITY)).is_none());
+		// (0, -1) has order 2.
+		let order_2 = Point { x: Fr::zero(), y: -Fr::one() };
+		assert!(sk.diffie_hellman(&PublicKey(order_2)).is_none());
+	}
+
+	#[test]
+	fn should_reject_keys_outside_subgroup() {
+		let mut rng = thread_rng();
+		let sk = SecretKey::random(&mut rng);
+		let pk = SecretKey::random(&mut rng).public();
+		assert!(sk.diffie_hellman(&pk).is_some());
+
+		// On the curve, with a component of order 2.
+		let mixed = pk.0.add(&Point { x: Fr::zero(), y: -Fr::one() }).affine();
+		assert!(mixed.is_on_curve());
+		assert!(sk.diffie_hellman(&PublicKey(mixed)).is_none());
+		// Not on the curve.
+		let off_curve = Point { x: Fr::one(), y: Fr::one() };
+		assert!(sk.diffie_hellman(&PublicKey(off_curve)).is_none());
+	}
+}
@@ -1,5 +1,19 @@
 use super::ops::{add, double};
-use halo2wrong::curves::{bn256::Fr, group::ff::Field};
+#[cfg(feature = "serde")]
+use super::{deserialize_bytes, serialize_bytes};
+use crate::{
+	params::poseidon_bn254_5x5::Params,
+	poseidon::native::sponge::{PoseidonSponge, HASH_TO_CURVE_DOMAIN},
+	utils::bytes_to_fields,
+};
+use halo2wrong::curves::{bn256::Fr, group::ff::Field, FieldExt};
+use num_bigint::BigUint;
+use once_cell::sync::Lazy;
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use std::hash::{Hash, Hasher};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
 /// D = 168696
 pub const D: Fr = Fr::from_raw([0x292F8, 0x00, 0x00, 0x00]);
@@ -12,6 +26,10 @@ pub const A: Fr = Fr::from_raw([0x292FC, 0x00, 0x00, 0x00]);
 pub const SUBORDER: Fr =
 	Fr::from_raw([0x677297DC392126F1, 0xAB3EEDB83920EE0A, 0x370A08B6D0302B0B, 0x60C89CE5C263405]);
 
+/// HALF_MODULUS = (p - 1) / 2, used to determine the sign of the x coordinate
+pub const HALF_MODULUS: Fr =
+	Fr::from_raw([0xA1F0FAC9F8000000, 0x9419F4243CDCB848, 0xDC2822DB40C0AC2E, 0x183227397098D014]);
+
 /// B8_X = 5299619240641551281634865583518297030282874472190772894086521144482721001553
 pub const B8_X: Fr =
 	Fr::from_raw([0x2893F3F6BB957051, 0x2AB8D8010534E0B6, 0x4EACB2E09D6277C1, 0xBB77A6AD63E739B]);
@@ -23,6 +41,10 @@ pub const B8_Y: Fr =
 /// B8 Point
 pub const B8: Point = Point { x: B8_X, y: B8_Y };
 
+/// Lazily initialized fixed-base table of B8, shared by every
+/// multiplication of B8.
+pub static B8_TABLE: Lazy<FixedBaseTable> = Lazy::new(|| FixedBaseTable::new(&B8));
+
 /// G_X = 995203441582195749578291179787384436505546430278305826713579947235728471134
 pub const G_X: Fr =
 	Fr::from_raw([0x40F41A59F4D4B45E, 0xB494B1255B1162BB, 0x38BCBA38F25645AD, 0x23343E3445B673D]);
@@ -34,6 +56,11 @@ pub const G_Y: Fr =
 /// G point
 pub const G: Point = Point { x: G_X, y: G_Y };
 
+/// Field element zero, usable in constants.
+const ZERO: Fr = Fr::from_raw([0x00, 0x00, 0x00, 0x00]);
+/// Field element one, usable in constants.
+const ONE: Fr = Fr::from_raw([0x01, 0x00, 0x00, 0x00]);
+
 #[derive(Clone, Copy, Debug)]
 /// Constructs PointProjective objects.
 pub struct PointProjective {
@@ -46,6 +73,9 @@ pub struct PointProjective {
 }
 
 impl PointProjective {
+	/// The identity element (0, 1, 1).
+	pub const IDENTITY: PointProjective = PointProjective { x: ZERO, y: ONE, z: ONE };
+
 	/// Returns affine representation from the given projective space
 	/// representation.
 	pub fn affine(&self) -> Point {
@@ -75,9 +105,50 @@ impl PointProjective {
 
 		PointProjective { x: x3, y: y3, z: z3 }
 	}
+
+	/// Returns the negation of the point: (-x, y, z).
+	pub fn neg(&self) -> Self {
+		PointProjective { x: -self.x, y: self.y, z: self.z }
+	}
+
+	/// SUB operation between points `self` and `q`
+	pub fn sub(&self, q: &Self) -> Self {
+		self.add(&q.neg())
+	}
+
+	/// Returns true if the point is the identity element.
+	pub fn is_identity(&self) -> bool {
+		self.x == Fr::zero() && self.y == self.z && self.z != Fr::zero()
+	}
+}
+
+impl PartialEq for PointProjective {
+	fn eq(&self, other: &Self) -> bool {
+		// Compare the affine coordinates without inverting z.
+		self.x * other.z == other.x * self.z && self.y * other.z == other.y * self.z
+	}
+}
+
+impl Eq for PointProjective {}
+
+impl Hash for PointProjective {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		// Hash the affine point, so equal points hash the same.
+		self.affine().hash(state);
+	}
+}
+
+impl ConditionallySelectable for PointProjective {
+	fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+		PointProjective {
+			x: Fr::conditional_select(&a.x, &b.x, choice),
+			y: Fr::conditional_select(&a.y, &b.y, choice),
+			z: Fr::conditional_select(&a.z, &b.z, choice),
+		}
+	}
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 /// Configures Point objects.
 pub struct Point {
 	/// Constructs a field element for the x.
@@ -87,15 +158,40 @@ pub struct Point {
 }
 
 impl Point {
+	/// The generator of the prime order subgroup, B8.
+	pub const GENERATOR: Point = B8;
+	/// The identity element (0, 1).
+	pub const IDENTITY: Point = Point { x: ZERO, y: ONE };
+
 	/// Returns projective space representation from the given affine
 	/// representation.
 	pub fn projective(&self) -> PointProjective {
 		PointProjective { x: self.x, y: self.y, z: Fr::one() }
 	}
 
+	/// Returns the negation of the point: (-x, y).
+	pub fn neg(&self) -> Self {
+		Point { x: -self.x, y: self.y }
+	}
+
+	/// ADD operation between points `self` and `q`
+	pub fn add(&self, q: &Self) -> PointProjective {
+		self.projective().add(&q.projective())
+	}
+
+	/// SUB operation between points `self` and `q`
+	pub fn sub(&self, q: &Self) -> PointProjective {
+		self.projective().sub(&q.projective())
+	}
+
+	/// DOUBLE operation of point `self`
+	pub fn double(&self) -> PointProjective {
+		self.projective().double()
+	}
+
 	/// Returns scalar multiplication of the element.
 	pub fn mul_scalar(&self, b: &[u8]) -> PointProjective {
-		let mut r: PointProjective = PointProjective { x: Fr::zero(), y: Fr::one(), z: Fr::one() };
+		let mut r = PointProjective::IDENTITY;
 		let mut exp: PointProjective = self.projective();
 		// Double and add operation.
 		for i in 0..b.len() * 8 {
@@ -107,10 +203,314 @@ impl Point {
 		r
 	}
 
+	/// Returns scalar multiplication of the element in constant time, using a
+	/// Montgomery ladder over every bit of the scalar. Use it for secret
+	/// scalars.
+	pub fn mul_scalar_ct(&self, b: &[u8]) -> PointProjective {
+		let mut r0 = PointProjective::IDENTITY;
+		let mut r1 = self.projective();
+		// Invariant: r1 = r0 + self.
+		for i in (0..b.len() * 8).rev() {
+			let bit = Choice::from((b[i / 8] >> (i % 8)) & 1);
+			PointProjective::conditional_swap(&mut r0, &mut r1, bit);
+			r1 = r0.add(&r1);
+			r0 = r0.double();
+			PointProjective::conditional_swap(&mut r0, &mut r1, bit);
+		}
+		r0
+	}
+
 	/// Returns true if the given point is equal to the element. Else, false.
 	pub fn equals(&self, p: Point) -> bool {
 		self.x == p.x && self.y == p.y
 	}
+
+	/// Returns true if the point is the identity element (0, 1).
+	pub fn is_identity(&self) -> bool {
+		self.x == Fr::zero() && self.y == Fr::one()
+	}
+
+	/// Returns true if the point is in the prime order subgroup, i.e.
+	/// multiplying it by the SUBORDER gives the identity.
+	pub fn is_in_subgroup(&self) -> bool {
+		self.is_on_curve() && self.mul_scalar(&SUBORDER.to_bytes()).affine().is_identity()
+	}
+
+	/// Deterministically maps arbitrary data to a point in the prime order
+	/// subgroup, using try-and-increment: the y coordinate is the Poseidon
+	/// sponge over `bytes_to_fields(data) || counter` with the
+	/// `HASH_TO_CURVE_DOMAIN` tag, for the first counter from zero that gives
+	/// a point on the curve, taken with the non-negative x, and multiplied by
+	/// the cofactor of 8.
+	pub fn hash_to_curve(data: &[u8]) -> Point {
+		let fields = bytes_to_fields(data);
+		let mut counter: u64 = 0;
+		loop {
+			let mut sponge =
+				PoseidonSponge::<Fr, 5, Params>::new_with_domain(Fr::from(HASH_TO_CURVE_DOMAIN));
+			sponge.update(&fields);
+			sponge.update(&[Fr::from(counter)]);
+			let y = sponge.squeeze();
+
+			if let Some(p) = Point::decompress_unchecked(&y.to_bytes()) {
+				// Clear the cofactor of 8 to land in the prime order subgroup.
+				let p = p.mul_scalar(&[8]).affine();
+				if !p.is_identity() {
+					return p;
+				}
+			}
+			counter += 1;
+		}
+	}
+
+	/// Returns true if the point satisfies the curve equation
+	/// `A * x^2 + y^2 = 1 + D * x^2 * y^2`.
+	pub fn is_on_curve(&self) -> bool {
+		let x2 = self.x.square();
+		let y2 = self.y.square();
+		A * x2 + y2 == Fr::one() + D * x2 * y2
+	}
+
+	/// Compresses the point into 32 bytes: the little-endian y coordinate with
+	/// the sign of x stored in the most significant bit.
+	pub fn compress(&self) -> [u8; 32] {
+		let mut bytes = self.y.to_bytes();
+		if is_negative(&self.x) {
+			bytes[31] |= 0x80;
+		}
+		bytes
+	}
+
+	/// Decompresses the point from its 32 byte encoding. Returns `None` if the
+	/// bytes don't encode a point on the curve, or the point is not in the
+	/// prime order subgroup.
+	pub fn decompress(bytes: &[u8; 32]) -> Option<Self> {
+		let p = Self::decompress_unchecked(bytes)?;
+		if !p.is_in_subgroup() {
+			return None;
+		}
+		Some(p)
+	}
+
+	/// Decompresses the point from its 32 byte encoding, without checking that
+	/// it is in the prime order subgroup. Returns `None` if the bytes don't
+	/// encode a point on the curve.
+	pub fn decompress_unchecked(bytes: &[u8; 32]) -> Option<Self> {
+		let mut y_bytes = *bytes;
+		let sign = y_bytes[31] & 0x80 != 0;
+		y_bytes[31] &= 0x7f;
+		let y: Fr = Option::from(Fr::from_bytes(&y_bytes))?;
+
+		// x^2 = (1 - y^2) / (A - D * y^2)
+		let y2 = y.square();
+		let den: Fr = Option::from((A - D * y2).invert())?;
+		let mut x: Fr = Option::from(((Fr::one() - y2) * den).sqrt())?;
+		if bool::from(x.is_zero()) && sign {
+			return None;
+		}
+		if is_negative(&x) != sign {
+			x = -x;
+		}
+
+		Some(Point { x, y })
+	}
+
+	/// Returns the compact 32 byte encoding of the point, same as `compress`.
+	pub fn to_bytes(&self) -> [u8; 32] {
+		self.compress()
+	}
+
+	/// Reads the point from its compact 32 byte encoding, same as
+	/// `decompress`, so it must be in the prime order subgroup.
+	pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+		Self::decompress(bytes)
+	}
+}
+
+impl Hash for Point {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.x.to_bytes().hash(state);
+		self.y.to_bytes().hash(state);
+	}
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Point {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_bytes(&self.to_bytes(), serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Point {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let bytes = deserialize_bytes(deserializer)?;
+		Point::from_bytes(&bytes).ok_or_else(|| D::Error::custom("invalid point encoding"))
+	}
+}
+
+/// Number of bits in each window of a fixed-base table.
+const WINDOW_BITS: usize = 4;
+/// Number of windows needed to cover a 32 byte scalar.
+const NUM_WINDOWS: usize = 256 / WINDOW_BITS;
+
+/// Precomputed windowed table of multiples of a fixed base point, so a
+/// multiplication takes 64 additions and no doublings.
+pub struct FixedBaseTable {
+	/// `table[i][j] = j * 16^i * base`.
+	table: Vec<[PointProjective; 1 << WINDOW_BITS]>,
+}
+
+impl FixedBaseTable {
+	/// Precompute the table of multiples of the base point.
+	pub fn new(base: &Point) -> Self {
+		let mut base = base.projective();
+		let table = (0..NUM_WINDOWS)
+			.map(|_| {
+				let mut row = [PointProjective::IDENTITY; 1 << WINDOW_BITS];
+				for j in 1..row.len() {
+					row[j] = row[j - 1].add(&base);
+				}
+				base = row[row.len() - 1].add(&base);
+				row
+			})
+			.collect();
+
+		Self { table }
+	}
+
+	/// Returns the multiplication of the base point by the little-endian
+	/// scalar.
+	pub fn mul_scalar(&self, scalar: &[u8; 32]) -> PointProjective {
+		let mut r = self.table[0][0];
+		for (i, byte) in scalar.iter().enumerate() {
+			r = r.add(&self.table[2 * i][usize::from(byte & 0x0f)]);
+			r = r.add(&self.table[2 * i + 1][usize::from(byte >> 4)]);
+		}
+		r
+	}
+
+	/// Returns the multiplication of the base point by the little-endian
+	/// scalar in constant time: every entry of a row is read, and the one for
+	/// the window is selected without branching. Use it for secret scalars.
+	pub fn mul_scalar_ct(&self, scalar: &[u8; 32]) -> PointProjective {
+		let mut r = self.table[0][0];
+		for (i, byte) in scalar.iter().enumerate() {
+			r = r.add(&self.select(2 * i, byte & 0x0f));
+			r = r.add(&self.select(2 * i + 1, byte >> 4));
+		}
+		r
+	}
+
+	/// Selects the entry `window` of the row `i` in constant time.
+	fn select(&self, i: usize, window: u8) -> PointProjective {
+		let mut entry = self.table[i][0];
+		for (j, candidate) in (0u8..).zip(self.table[i].iter()) {
+			entry.conditional_assign(candidate, j.ct_eq(&window));
+		}
+		entry
+	}
+}
+
+/// Reduces the little-endian scalar modulo SUBORDER, so scalar multiplication
+/// on the prime order subgroup doesn't depend on the scalar being in range.
+/// It is not constant time; secret scalars are reduced with `SecretScalar`.
+pub fn reduce_scalar(bytes: &[u8]) -> [u8; 32] {
+	let suborder = BigUint::from_bytes_le(&SUBORDER.to_bytes());
+	let reduced = (BigUint::from_bytes_le(bytes) % suborder).to_bytes_le();
+	let mut res = [0; 32];
+	res[..reduced.len()].copy_from_slice(&reduced);
+	res
+}
+
+/// Little-endian limbs of the SUBORDER.
+const SUBORDER_LIMBS: [u64; 4] =
+	[0x677297DC392126F1, 0xAB3EEDB83920EE0A, 0x370A08B6D0302B0B, 0x60C89CE5C263405];
+
+/// Scalar modulo SUBORDER, kept as little-endian limbs. Unlike `BigUint` and
+/// `reduce_scalar`, its arithmetic takes the same time for every value, so it
+/// is used for secret scalars. It is wiped from memory when dropped.
+#[derive(Default, Zeroize, ZeroizeOnDrop)]
+pub struct SecretScalar([u64; 4]);
+
+impl SecretScalar {
+	/// Reduces the little-endian bytes modulo SUBORDER, one bit at a time from
+	/// the most significant one. The time only depends on the length.
+	pub fn from_bytes(bytes: &[u8]) -> Self {
+		let mut acc = Self::default();
+		for i in (0..bytes.len() * 8).rev() {
+			let bit = Self([u64::from((bytes[i / 8] >> (i % 8)) & 1), 0, 0, 0]);
+			acc = acc.add(&acc).add(&bit);
+		}
+		acc
+	}
+
+	/// Returns the 32 little-endian bytes of the scalar.
+	pub fn to_bytes(&self) -> Zeroizing<[u8; 32]> {
+		let mut bytes = Zeroizing::new([0; 32]);
+		for (chunk, limb) in bytes.chunks_mut(8).zip(self.0) {
+			chunk.copy_from_slice(&limb.to_le_bytes());
+		}
+		bytes
+	}
+
+	/// Returns the scalar as a field element. It is as secret as the scalar.
+	pub fn to_fr(&self) -> Fr {
+		// Always canonical, since the SUBORDER is less than the modulus.
+		Fr::from_bytes(&self.to_bytes()).unwrap()
+	}
+
+	/// Returns `self + other` modulo SUBORDER.
+	pub fn add(&self, other: &Self) -> Self {
+		// Both are less than 2^252, so the sum doesn't overflow the limbs.
+		let mut sum = Self::default();
+		let mut carry = false;
+		for ((s, a), b) in sum.0.iter_mut().zip(self.0).zip(other.0) {
+			let (t, c1) = a.overflowing_add(b);
+			let (t, c2) = t.overflowing_add(u64::from(carry));
+			*s = t;
+			carry = c1 | c2;
+		}
+		sum.sub_suborder();
+		sum
+	}
+
+	/// Returns `self * other + addend` modulo SUBORDER, by doubling and
+	/// conditionally adding `self` for each bit of `other`.
+	pub fn mul_add(&self, other: &Self, addend: &Self) -> Self {
+		let mut acc = Self::default();
+		for i in (0..256).rev() {
+			let bit = Choice::from(((other.0[i / 64] >> (i % 64)) & 1) as u8);
+			let mut term = Self::default();
+			for (t, a) in term.0.iter_mut().zip(self.0) {
+				t.conditional_assign(&a, bit);
+			}
+			acc = acc.add(&acc).add(&term);
+		}
+		acc.add(addend)
+	}
+
+	/// Subtracts the SUBORDER if the scalar is not less than it.
+	fn sub_suborder(&mut self) {
+		let mut diff = Self::default();
+		let mut borrow = false;
+		for ((d, a), n) in diff.0.iter_mut().zip(self.0).zip(SUBORDER_LIMBS) {
+			let (t, b1) = a.overflowing_sub(n);
+			let (t, b2) = t.overflowing_sub(u64::from(borrow));
+			*d = t;
+			borrow = b1 | b2;
+		}
+		// The subtraction underflows when the scalar is already reduced.
+		let reduced = Choice::from(u8::from(borrow));
+		for (a, d) in self.0.iter_mut().zip(diff.0) {
+			a.conditional_assign(&d, !reduced);
+		}
+	}
+}
+
+/// Returns true if the field element is greater than (p - 1) / 2.
+fn is_negative(x: &Fr) -> bool {
+	*x > HALF_MODULUS
 }
 
 /// Performs bitwise AND to test bits.
@@ -122,6 +522,50 @@ pub fn test_bit(b: &[u8], i: usize) -> bool {
 mod tests {
 	use super::*;
 	use halo2wrong::curves::group::ff::PrimeField;
+	use rand::thread_rng;
+
+	#[test]
+	fn should_do_point_arithmetic() {
+		let p = Point::GENERATOR.mul_scalar(&[7]).affine();
+		let q = Point::GENERATOR.mul_scalar(&[3]).affine();
+
+		assert!(p.add(&p.neg()).is_identity());
+		assert_eq!(
+			p.sub(&q).affine(),
+			Point::GENERATOR.mul_scalar(&[4]).affine()
+		);
+		assert_eq!(p.double().affine(), p.add(&p).affine());
+		assert_eq!(p.add(&Point::IDENTITY).affine(), p);
+		assert!(Point::IDENTITY.is_identity());
+		assert!(PointProjective::IDENTITY.is_identity());
+		assert!(p.projective().sub(&p.projective()).is_identity());
+		assert!(!p.projective().is_identity());
+
+		// Equal points with different z are equal, and hash the same.
+		let z = Fr::from(5);
+		let scaled = PointProjective { x: p.x * z, y: p.y * z, z };
+		assert_eq!(scaled, p.projective());
+		assert_ne!(scaled, q.projective());
+		let set: std::collections::HashSet<_> = [scaled, p.projective()].into_iter().collect();
+		assert_eq!(set.len(), 1);
+
+		let set: std::collections::HashSet<_> = [p.clone(), p, q].into_iter().collect();
+		assert_eq!(set.len(), 2);
+	}
+
+	#[test]
+	fn should_match_fixed_base_table() {
+		let scalar = reduce_scalar(&Fr::random(thread_rng()).to_bytes());
+		let expected = B8.mul_scalar(&scalar).affine();
+		assert!(B8_TABLE.mul_scalar(&scalar).affine().equals(expected.clone()));
+		assert!(B8_TABLE.mul_scalar_ct(&scalar).affine().equals(expected));
+
+		let table = FixedBaseTable::new(&G);
+		let expected = G.mul_scalar(&scalar).affine();
+		assert!(table.mul_scalar_ct(&scalar).affine().equals(expected));
+
+		assert!(B8_TABLE.mul_scalar_ct(&[0; 32]).affine().is_identity());
+	}
 
 	#[test]
 	fn test_add_same_point() {
@@ -264,4 +708,153 @@ mod tests {
 			.unwrap(),
 		);
 	}
+
+	#[test]
+	fn should_compress_and_decompress() {
+		let rng = &mut thread_rng();
+		let p = B8.mul_scalar(&Fr::random(rng).to_bytes()).affine();
+		assert!(p.is_on_curve());
+
+		let res = Point::decompress(&p.compress()).unwrap();
+		assert!(res.equals(p));
+	}
+
+	#[test]
+	fn should_not_decompress_invalid_point() {
+		// There is no x for y = 2.
+		let mut bytes = [0; 32];
+		bytes[0] = 2;
+		assert!(Point::decompress(&bytes).is_none());
+
+		// y is not a canonical field element.
+		assert!(Point::decompress(&[0x7f; 32]).is_none());
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn should_serde_point_json() {
+		let rng = &mut thread_rng();
+		let p = B8.mul_scalar(&Fr::random(rng).to_bytes()).affine();
+
+		let json = serde_json::to_string(&p).unwrap();
+		let res: Point = serde_json::from_str(&json).unwrap();
+		assert!(res.equals(p));
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn should_fail_to_deserialize_invalid_point() {
+		let mut bytes = [0; 32];
+		bytes[0] = 2;
+		let json = serde_json::to_string(&hex::encode(bytes)).unwrap();
+		assert!(serde_json::from_str::<Point>(&json).is_err());
+	}
+
+	#[test]
+	fn should_hash_to_curve() {
+		let p = Point::hash_to_curve(b"eigen-trust");
+		assert!(p.is_in_subgroup());
+		assert!(p.equals(Point::hash_to_curve(b"eigen-trust")));
+		assert!(!p.equals(Point::hash_to_curve(b"eigen-trust/")));
+	}
+
+	#[test]
+	fn should_hash_distinct_inputs_to_distinct_points() {
+		let points: Vec<Point> =
+			(0u32..32).map(|i| Point::hash_to_curve(&i.to_le_bytes())).collect();
+		for (i, p) in points.iter().enumerate() {
+			assert!(p.is_in_subgroup());
+			for q in &points[i + 1..] {
+				assert!(!p.equals(q.clone()));
+			}
+		}
+	}
+
+	#[test]
+	fn should_not_be_in_subgroup() {
+		// (0, -1) is a point of order 2.
+		let p = Point { x: Fr::zero(), y: -Fr::one() };
+		assert!(p.is_on_curve());
+		assert!(!p.is_in_subgroup());
+		assert!(B8.is_in_subgroup());
+	}
+
+	#[test]
+	fn should_not_decompress_low_order_point() {
+		// (0, -1) is a point of order 2.
+		let p = Point { x: Fr::zero(), y: -Fr::one() };
+		let bytes = p.compress();
+		assert!(Point::decompress_unchecked(&bytes).unwrap().equals(p));
+		assert!(Point::decompress(&bytes).is_none());
+
+		// A point on the curve that is not in the subgroup.
+		let mut y = Fr::zero();
+		let p = loop {
+			if let Some(p) = Point::decompress_unchecked(&y.to_bytes()) {
+				if !p.is_in_subgroup() {
+					break p;
+				}
+			}
+			y += Fr::one();
+		};
+		assert!(p.is_on_curve());
+		assert!(Point::decompress(&p.compress()).is_none());
+	}
+
+	#[test]
+	fn should_reduce_scalar() {
+		let scalar = SUBORDER + Fr::from(5);
+		let reduced = reduce_scalar(&scalar.to_bytes());
+		assert_eq!(reduced, Fr::from(5).to_bytes());
+
+		let res = B8.mul_scalar(&scalar.to_bytes()).affine();
+		let res_reduced = B8.mul_scalar(&reduced).affine();
+		assert!(res.equals(res_reduced));
+	}
+
+	#[test]
+	fn should_match_big_integer_arithmetic() {
+		let mut rng = thread_rng();
+		let n = BigUint::from_bytes_le(&SUBORDER.to_bytes());
+		for _ in 0..10 {
+			let a = [Fr::random(&mut rng).to_bytes(), Fr::random(&mut rng).to_bytes()].concat();
+			let b = Fr::random(&mut rng).to_bytes();
+			let c = Fr::random(&mut rng).to_bytes();
+			let (a_bn, b_bn, c_bn) = (
+				BigUint::from_bytes_le(&a),
+				BigUint::from_bytes_le(&b),
+				BigUint::from_bytes_le(&c),
+			);
+
+			let a_ct = SecretScalar::from_bytes(&a);
+			let (b_ct, c_ct) = (SecretScalar::from_bytes(&b), SecretScalar::from_bytes(&c));
+			let to_bn = |x: &SecretScalar| BigUint::from_bytes_le(&x.to_bytes()[..]);
+			assert_eq!(to_bn(&a_ct), &a_bn % &n);
+			assert_eq!(to_bn(&a_ct.add(&b_ct)), (&a_bn + &b_bn) % &n);
+			assert_eq!(
+				to_bn(&a_ct.mul_add(&b_ct, &c_ct)),
+				(a_bn * b_bn + c_bn) % &n
+			);
+		}
+
+		let unreduced = (SUBORDER + Fr::from(5)).to_bytes();
+		assert_eq!(
+			*SecretScalar::from_bytes(&unreduced).to_bytes(),
+			reduce_scalar(&unreduced)
+		);
+	}
+
+	#[test]
+	fn should_match_constant_time_mul_scalar() {
+		let rng = &mut thread_rng();
+		for _ in 0..10 {
+			let scalar = Fr::random(&mut *rng).to_bytes();
+			let res = B8.mul_scalar(&scalar).affine();
+			let res_ct = B8.mul_scalar_ct(&scalar).affine();
+			assert!(res.equals(res_ct));
+		}
+
+		let res = B8.mul_scalar_ct(&[0; 32]).affine();
+		assert!(res.is_identity());
+	}
 }
@@ -0,0 +1,176 @@
+use super::{
+	ed_on_bn254::{reduce_scalar, Point, PointProjective, B8_TABLE, SUBORDER},
+	fr_from_bytes, Hasher, PublicKey, Signature, Sponge,
+};
+use crate::utils::to_wide;
+use halo2wrong::{
+	curves::{bn256::Fr, FieldExt},
+	halo2::arithmetic::Field,
+};
+use num_bigint::BigUint;
+
+/// Returns the SUBORDER as a big integer.
+fn suborder() -> BigUint {
+	BigUint::from_bytes_le(&SUBORDER.to_bytes())
+}
+
+/// Half-aggregated signatures: the R of every signature, and a single
+/// combined s. Takes `32 * (n + 1)` bytes instead of `64 * n`.
+#[derive(Clone, Debug)]
+pub struct HalfAggregate {
+	/// R of each signature, in the order they were aggregated.
+	big_rs: Vec<Point>,
+	/// `sum(z_i * s_i)`.
+	s: Fr,
+}
+
+impl HalfAggregate {
+	/// Aggregates the signatures of the messages under the public keys. The
+	/// signatures are not checked, so an invalid one makes the aggregate
+	/// invalid.
+	pub fn new(items: &[(Signature, PublicKey, Fr)]) -> Self {
+		let n = suborder();
+		let big_rs: Vec<Point> = items.iter().map(|(sig, ..)| sig.big_r.clone()).collect();
+		let pks_ms: Vec<(&PublicKey, Fr)> = items.iter().map(|(_, pk, m)| (pk, *m)).collect();
+		let coefficients = coefficients(&big_rs, &pks_ms);
+
+		let s = items.iter().zip(coefficients).fold(BigUint::from(0u64), |acc, ((sig, ..), z)| {
+			(acc + z * BigUint::from_bytes_le(&sig.s.to_bytes())) % &n
+		});
+		let s = Fr::from_bytes_wide(&to_wide(&s.to_bytes_le()));
+
+		HalfAggregate { big_rs, s }
+	}
+
+	/// Checks if the aggregate holds for the public keys and messages, given
+	/// in the same order as they were aggregated:
+	/// `s * B8 == sum(z_i * R_i + z_i * H(R_i || PK_i || M_i) * PK_i)`.
+	/// Like `verify_batch`, every R and PK must be in the prime order
+	/// subgroup, so the aggregate holds exactly when `verify` holds for every
+	/// signature.
+	pub fn verify(&self, pks_ms: &[(PublicKey, Fr)]) -> bool {
+		if self.big_rs.len() != pks_ms.len() || self.s > SUBORDER {
+			return false;
+		}
+		let pks_ms: Vec<(&PublicKey, Fr)> = pks_ms.iter().map(|(pk, m)| (pk, *m)).collect();
+		let n = suborder();
+
+		let coefficients = coefficients(&self.big_rs, &pks_ms);
+		let mut rhs = PointProjective::IDENTITY;
+		for ((big_r, (pk, m)), z) in self.big_rs.iter().zip(pks_ms).zip(coefficients) {
+			if !big_r.is_in_subgroup() || !pk.0.is_in_subgroup() {
+				return false;
+			}
+			// H(R || PK || M)
+			let m_hash = Hasher::new([big_r.x, big_r.y, pk.0.x, pk.0.y, m]).permute()[0];
+			let zh = (&z * BigUint::from_bytes_le(&m_hash.to_bytes())) % &n;
+
+			let big_r_z = big_r.mul_scalar(&z.to_bytes_le());
+			let pk_zh = pk.0.mul_scalar(&zh.to_bytes_le());
+			rhs = rhs.add(&big_r_z).add(&pk_zh);
+		}
+		let lhs = B8_TABLE.mul_scalar(&reduce_scalar(&self.s.to_bytes()));
+		lhs.affine().equals(rhs.affine())
+	}
+
+	/// Returns the encoding of the aggregate: the compressed R of every
+	/// signature followed by the 32 little-endian bytes of s.
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let mut bytes: Vec<u8> = self.big_rs.iter().flat_map(|big_r| big_r.to_bytes()).collect();
+		bytes.extend_from_slice(&self.s.to_bytes());
+		bytes
+	}
+
+	/// Reads the aggregate from its encoding. Returns `None` if the length is
+	/// not a multiple of 32, any R is not a point in the prime order subgroup,
+	/// or s is higher than the SUBORDER.
+	pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+		if bytes.is_empty() || bytes.len() % 32 != 0 {
+			return None;
+		}
+		let (big_rs, s) = bytes.split_at(bytes.len() - 32);
+		let big_rs = big_rs
+			.chunks(32)
+			.map(|chunk| {
+				let mut big_r = [0; 32];
+				big_r.copy_from_slice(chunk);
+				Point::from_bytes(&big_r)
+			})
+			.collect::<Option<Vec<Point>>>()?;
+		let s = fr_from_bytes(s)?;
+		if s > SUBORDER {
+			return None;
+		}
+		Some(HalfAggregate { big_rs, s })
+	}
+}
+
+/// Returns the coefficient `z_i = H(L, i)` of each signature, where `L` is the
+/// hash of all the R, public keys and messages, so no signature can be chosen
+/// after seeing the coefficients.
+fn coefficients(big_rs: &[Point], pks_ms: &[(&PublicKey, Fr)]) -> Vec<BigUint> {
+	let mut sponge = Sponge::new();
+	for (big_r, (pk, m)) in big_rs.iter().zip(pks_ms) {
+		sponge.update(&[big_r.x, big_r.y, pk.0.x, pk.0.y, *m]);
+	}
+	let l = sponge.squeeze();
+
+	(0..big_rs.len() as u64)
+		.map(|i| {
+			let z = Hasher::new([l, Fr::from(i), Fr::zero(), Fr::zero(), Fr::zero()]).permute()[0];
+			BigUint::from_bytes_le(&reduce_scalar(&z.to_bytes()))
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::eddsa::native::{sign, SecretKey};
+	use rand::thread_rng;
+
+	fn signed_items(count: u64) -> Vec<(Signature, PublicKey, Fr)> {
+		let mut rng = thread_rng();
+		(0..count)
+			.map(|i| {
+				let sk = SecretKey::random(&mut rng);
+				let pk = sk.public();
+				let m = Fr::from(i);
+				(sign(&sk, &pk, m), pk, m)
+			})
+			.collect()
+	}
+
+	#[test]
+	fn should_verify_half_aggregate() {
+		let items = signed_items(4);
+		let pks_ms: Vec<(PublicKey, Fr)> =
+			items.iter().map(|(_, pk, m)| (PublicKey(pk.0.clone()), *m)).collect();
+
+		let agg = HalfAggregate::new(&items);
+		assert!(agg.verify(&pks_ms));
+		assert_eq!(agg.to_bytes().len(), 32 * 5);
+
+		let decoded = HalfAggregate::from_bytes(&agg.to_bytes()).unwrap();
+		assert!(decoded.verify(&pks_ms));
+
+		// Wrong message, wrong order, or a missing signature.
+		let mut wrong_m: Vec<(PublicKey, Fr)> =
+			pks_ms.iter().map(|(pk, m)| (PublicKey(pk.0.clone()), *m)).collect();
+		wrong_m[2].1 += Fr::one();
+		assert!(!agg.verify(&wrong_m));
+		wrong_m.swap(0, 1);
+		assert!(!agg.verify(&wrong_m));
+		assert!(!agg.verify(&pks_ms[1..]));
+	}
+
+	#[test]
+	fn should_reject_invalid_signature() {
+		let mut items = signed_items(3);
+		items[1].0.s += Fr::one();
+		let pks_ms: Vec<(PublicKey, Fr)> =
+			items.iter().map(|(_, pk, m)| (PublicKey(pk.0.clone()), *m)).collect();
+
+		assert!(!HalfAggregate::new(&items).verify(&pks_ms));
+	}
+}
@@ -0,0 +1,252 @@
+use super::{
+	ed_on_bn254::Point, sign_with_context, verify_with_context, Hasher, PublicKey, SecretKey,
+	Signature,
+};
+use halo2wrong::{curves::bn256::Fr, halo2::arithmetic::Field};
+use std::{
+	collections::{HashMap, HashSet},
+	hash::Hash,
+};
+
+/// Signing context of rotation records.
+const ROTATION_CONTEXT: &[u8] = b"eigen-trust/keyset/rotation";
+/// Signing context of revocation records.
+const REVOCATION_CONTEXT: &[u8] = b"eigen-trust/keyset/revocation";
+
+/// Returns the message signed to bind the record to the public key.
+fn key_message(pk: &PublicKey) -> Fr {
+	Hasher::new([pk.0.x, pk.0.y, Fr::zero(), Fr::zero(), Fr::zero()]).permute()[0]
+}
+
+/// Record of a peer rotating from the old key to the new key, signed with
+/// the old key.
+pub struct RotationRecord {
+	/// The key being replaced.
+	pub old: PublicKey,
+	/// The key replacing it.
+	pub new: PublicKey,
+	/// Signature of the new key with the old key.
+	pub sig: Signature,
+}
+
+impl RotationRecord {
+	/// Signs the rotation from the key of `old_sk` to `new`.
+	pub fn new(old_sk: &SecretKey, new: PublicKey) -> Self {
+		let old = old_sk.public();
+		let sig = sign_with_context(old_sk, &old, ROTATION_CONTEXT, key_message(&new));
+		RotationRecord { old, new, sig }
+	}
+
+	/// Checks if the record is signed with the old key.
+	pub fn verify(&self) -> bool {
+		verify_with_context(
+			&self.sig,
+			&self.old,
+			ROTATION_CONTEXT,
+			key_message(&self.new),
+		)
+	}
+}
+
+/// Record of a key being revoked, signed with the key itself.
+pub struct RevocationRecord {
+	/// The key being revoked.
+	pub key: PublicKey,
+	/// Signature of the key with itself.
+	pub sig: Signature,
+}
+
+impl RevocationRecord {
+	/// Signs the revocation of the key of `sk`.
+	pub fn new(sk: &SecretKey) -> Self {
+		let key = sk.public();
+		let sig = sign_with_context(sk, &key, REVOCATION_CONTEXT, key_message(&key));
+		RevocationRecord { key, sig }
+	}
+
+	/// Checks if the record is signed with the revoked key.
+	pub fn verify(&self) -> bool {
+		verify_with_context(
+			&self.sig,
+			&self.key,
+			REVOCATION_CONTEXT,
+			key_message(&self.key),
+		)
+	}
+}
+
+/// The reason why a key registry update was rejected.
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeysetError {
+	/// The peer is already registered.
+	AlreadyRegistered,
+	/// The peer is not registered.
+	UnknownPeer,
+	/// The record signature doesn't verify.
+	InvalidSignature,
+	/// The old key of the rotation is not the current key of the peer.
+	NotCurrentKey,
+	/// The key was used before, by this or another peer.
+	KeyReused,
+	/// The key was revoked.
+	KeyRevoked,
+}
+
+/// Registry of the key chains of the peers. Each peer has a chain of keys,
+/// starting from the registered key and extended by rotation records, of
+/// which only the last one is currently valid. A revoked key is never valid
+/// again, and can't be rotated from, so a peer whose current key is revoked
+/// has no valid key.
+pub struct KeyRegistry<I: Eq + Hash> {
+	/// Key chain of each peer, from the oldest to the current key.
+	chains: HashMap<I, Vec<Point>>,
+	/// Every key in any chain, so keys are never reused.
+	used: HashSet<Point>,
+	/// The revoked keys.
+	revoked: HashSet<Point>,
+}
+
+impl<I: Eq + Hash> KeyRegistry<I> {
+	/// Returns an empty registry.
+	pub fn new() -> Self {
+		KeyRegistry { chains: HashMap::new(), used: HashSet::new(), revoked: HashSet::new() }
+	}
+
+	/// Registers the initial key of the peer.
+	pub fn register(&mut self, id: I, pk: &PublicKey) -> Result<(), KeysetError> {
+		if self.chains.contains_key(&id) {
+			return Err(KeysetError::AlreadyRegistered);
+		}
+		if self.used.contains(&pk.0) {
+			return Err(KeysetError::KeyReused);
+		}
+		self.used.insert(pk.0.clone());
+		self.chains.insert(id, vec![pk.0.clone()]);
+		Ok(())
+	}
+
+	/// Extends the key chain of the peer with the rotation record.
+	pub fn rotate(&mut self, id: &I, record: &RotationRecord) -> Result<(), KeysetError> {
+		let chain = self.chains.get_mut(id).ok_or(KeysetError::UnknownPeer)?;
+		if !record.verify() {
+			return Err(KeysetError::InvalidSignature);
+		}
+		if chain.last() != Some(&record.old.0) {
+			return Err(KeysetError::NotCurrentKey);
+		}
+		if self.revoked.contains(&record.old.0) || self.revoked.contains(&record.new.0) {
+			return Err(KeysetError::KeyRevoked);
+		}
+		if self.used.contains(&record.new.0) {
+			return Err(KeysetError::KeyReused);
+		}
+		self.used.insert(record.new.0.clone());
+		chain.push(record.new.0.clone());
+		Ok(())
+	}
+
+	/// Revokes the key of the record.
+	pub fn revoke(&mut self, record: &RevocationRecord) -> Result<(), KeysetError> {
+		if !record.verify() {
+			return Err(KeysetError::InvalidSignature);
+		}
+		self.revoked.insert(record.key.0.clone());
+		Ok(())
+	}
+
+	/// Returns the current key of the peer, if it has a valid one.
+	pub fn current_key(&self, id: &I) -> Option<PublicKey> {
+		let current = self.chains.get(id)?.last()?;
+		if self.revoked.contains(current) {
+			return None;
+		}
+		Some(PublicKey(current.clone()))
+	}
+
+	/// Returns true if the key is currently valid for the peer.
+	pub fn is_valid(&self, id: &I, pk: &PublicKey) -> bool {
+		self.current_key(id).map_or(false, |current| current.0 == pk.0)
+	}
+}
+
+impl<I: Eq + Hash> Default for KeyRegistry<I> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use rand::thread_rng;
+
+	#[test]
+	fn should_follow_rotation_chain() {
+		let mut rng = thread_rng();
+		let sk1 = SecretKey::random(&mut rng);
+		let sk2 = SecretKey::random(&mut rng);
+		let sk3 = SecretKey::random(&mut rng);
+
+		let mut registry = KeyRegistry::new();
+		registry.register("alice", &sk1.public()).unwrap();
+		assert_eq!(
+			registry.register("alice", &sk2.public()),
+			Err(KeysetError::AlreadyRegistered)
+		);
+		assert!(registry.is_valid(&"alice", &sk1.public()));
+
+		registry.rotate(&"alice", &RotationRecord::new(&sk1, sk2.public())).unwrap();
+		assert!(!registry.is_valid(&"alice", &sk1.public()));
+		assert!(registry.is_valid(&"alice", &sk2.public()));
+
+		// Only the current key can rotate.
+		assert_eq!(
+			registry.rotate(&"alice", &RotationRecord::new(&sk1, sk3.public())),
+			Err(KeysetError::NotCurrentKey)
+		);
+		// Keys can't be reused.
+		assert_eq!(
+			registry.rotate(&"alice", &RotationRecord::new(&sk2, sk1.public())),
+			Err(KeysetError::KeyReused)
+		);
+		assert_eq!(
+			registry.register("bob", &sk2.public()),
+			Err(KeysetError::KeyReused)
+		);
+		assert_eq!(
+			registry.rotate(&"bob", &RotationRecord::new(&sk2, sk3.public())),
+			Err(KeysetError::UnknownPeer)
+		);
+
+		let mut forged = RotationRecord::new(&sk2, sk3.public());
+		forged.new = SecretKey::random(&mut rng).public();
+		assert_eq!(
+			registry.rotate(&"alice", &forged),
+			Err(KeysetError::InvalidSignature)
+		);
+
+		registry.rotate(&"alice", &RotationRecord::new(&sk2, sk3.public())).unwrap();
+		assert!(registry.is_valid(&"alice", &sk3.public()));
+	}
+
+	#[test]
+	fn should_revoke_keys() {
+		let mut rng = thread_rng();
+		let sk1 = SecretKey::random(&mut rng);
+		let sk2 = SecretKey::random(&mut rng);
+
+		let mut registry = KeyRegistry::new();
+		registry.register(1u32, &sk1.public()).unwrap();
+		registry.revoke(&RevocationRecord::new(&sk1)).unwrap();
+		assert!(!registry.is_valid(&1, &sk1.public()));
+		assert!(registry.current_key(&1).is_none());
+		assert_eq!(
+			registry.rotate(&1, &RotationRecord::new(&sk1, sk2.public())),
+			Err(KeysetError::KeyRevoked)
+		);
+
+		let mut forged = RevocationRecord::new(&sk2);
+		forged.key = sk1.public();
+		assert_eq!(registry.revoke(&forged), Err(KeysetError::InvalidSignature));
+	}
+}
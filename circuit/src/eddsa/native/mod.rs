@@ -1,18 +1,54 @@
+/// EdDSA-Poseidon compatible with circomlib/iden3
+pub mod circom;
+/// ECDH key agreement between EdDSA identity keys
+pub mod dh;
 /// Implementation of Edwards on Bn254 curve AKA BabyJubJub
 pub mod ed_on_bn254;
+/// Non-interactive half-aggregation of signatures
+pub mod half_agg;
+/// Key rotation and revocation records, and a registry of peer key chains
+pub mod keyset;
+/// MuSig-style multi-signatures, verifiable under an aggregated public key
+pub mod musig;
 /// Helper functions for point arithmetic
 pub mod ops;
-
-use crate::{params::poseidon_bn254_5x5::Params, poseidon::native::Poseidon, utils::to_wide};
-use ed_on_bn254::{Point, B8, SUBORDER};
+/// Shamir based t-of-n threshold signing
+pub mod threshold;
+/// wasm-bindgen wrappers for key generation, signing and verification
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+use crate::{
+	params::poseidon_bn254_5x5::Params,
+	poseidon::native::{sponge::PoseidonSponge, Poseidon},
+	utils::{bytes_to_fields, to_wide},
+};
+use ed_on_bn254::{reduce_scalar, Point, PointProjective, SecretScalar, B8_TABLE, SUBORDER};
 use halo2wrong::{
 	curves::{bn256::Fr, FieldExt},
 	halo2::arithmetic::Field,
 };
 use num_bigint::BigUint;
 use rand::RngCore;
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
 type Hasher = Poseidon<Fr, 5, Params>;
+type Sponge = PoseidonSponge<Fr, 5, Params>;
+
+/// Domain separation tag for hardened key derivation.
+const HARDENED_DOMAIN: &[u8] = b"eigen-trust/eddsa/hardened";
+/// Domain separation tag for non-hardened key derivation.
+const NON_HARDENED_DOMAIN: &[u8] = b"eigen-trust/eddsa/non-hardened";
+
+/// Domain separation tag for deriving the nonce seed of a blinded key.
+const BLINDING_DOMAIN: &[u8] = b"eigen-trust/eddsa/blinding";
+
+/// Domain separation tag for hashing byte messages into a field element.
+const MESSAGE_DOMAIN: &[u8] = b"eigen-trust/eddsa/message";
+/// Domain separation tag for hashing signing contexts into a field element.
+const CONTEXT_DOMAIN: &[u8] = b"eigen-trust/eddsa/context";
 
 /// Hashes the input with using the BLAKE hash function.
 fn blh(b: &[u8]) -> Vec<u8> {
@@ -21,32 +57,198 @@ fn blh(b: &[u8]) -> Vec<u8> {
 	hash.to_vec()
 }
 
-/// Configures a structure for the secret key.
-pub struct SecretKey(BigUint, Fr);
+/// Configures a structure for the secret key. Both values are kept as their
+/// 32 little-endian bytes, so they are wiped from memory when the key is
+/// dropped.
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct SecretKey([u8; 32], [u8; 32]);
 
 impl SecretKey {
 	/// Randomly generates a field element and returns
 	/// two hashed values from it.
 	pub fn random<R: RngCore + Clone>(rng: &mut R) -> Self {
-		let a = Fr::random(rng);
-		let hash: Vec<u8> = blh(&a.to_bytes());
-		let sk0 = BigUint::from_bytes_le(&hash[..32]);
+		let a = Zeroizing::new(Fr::random(rng).to_bytes());
+		Self::from_seed(a.as_ref())
+	}
+
+	/// Deterministically derives the secret key from the seed, by hashing it
+	/// and splitting the hash into two values.
+	pub fn from_seed(seed: &[u8]) -> Self {
+		let hash = Zeroizing::new(blh(seed));
+		let bytes_wide = Zeroizing::new(to_wide(&hash[32..]));
+		let mut sk = SecretKey([0; 32], Fr::from_bytes_wide(&bytes_wide).to_bytes());
+		sk.0.copy_from_slice(&hash[..32]);
+		sk
+	}
+
+	/// Returns the first value, the scalar of the public key, reduced modulo
+	/// the SUBORDER.
+	fn sk0(&self) -> SecretScalar {
+		SecretScalar::from_bytes(&self.0)
+	}
 
-		let bytes_wide = to_wide(&hash[32..]);
-		let sk1 = Fr::from_bytes_wide(&bytes_wide);
-		SecretKey(sk0, sk1)
+	/// Returns the second value, used for deriving the nonce.
+	fn sk1(&self) -> Fr {
+		// Always canonical, since it's checked on construction.
+		Fr::from_bytes(&self.1).unwrap()
+	}
+
+	/// Returns the scalar of the public key, reduced modulo the SUBORDER. It
+	/// is as secret as the key itself.
+	pub fn scalar(&self) -> Fr {
+		self.sk0().to_fr()
 	}
 
 	/// Returns a public key from the secret key.
 	pub fn public(&self) -> PublicKey {
-		let a = B8.mul_scalar(&self.0.to_bytes_le());
+		let a = B8_TABLE.mul_scalar_ct(&self.sk0().to_bytes());
 		PublicKey(a.affine())
 	}
+
+	/// Returns the 64 byte encoding of the secret key: both values as 32
+	/// little-endian bytes. The copy is wiped when it is dropped.
+	pub fn to_bytes(&self) -> Zeroizing<[u8; 64]> {
+		let mut bytes = Zeroizing::new([0; 64]);
+		bytes[..32].copy_from_slice(&self.0);
+		bytes[32..].copy_from_slice(&self.1);
+		bytes
+	}
+
+	/// Reads the secret key from its 64 byte encoding. Returns `None` if the
+	/// second value is not a canonical field element.
+	pub fn from_bytes(bytes: &[u8; 64]) -> Option<Self> {
+		// Check that the second value is a canonical field element.
+		fr_from_bytes(&bytes[32..])?;
+
+		let mut sk = SecretKey([0; 32], [0; 32]);
+		sk.0.copy_from_slice(&bytes[..32]);
+		sk.1.copy_from_slice(&bytes[32..]);
+		Some(sk)
+	}
+
+	/// Derives the child key at `path`, one level per index, by hashing the
+	/// parent secret key with the index. The child public key can't be derived
+	/// from the parent public key.
+	pub fn derive(&self, path: &[u32]) -> SecretKey {
+		let mut sk = SecretKey(self.0, self.1);
+		for index in path {
+			let mut seed = Zeroizing::new(HARDENED_DOMAIN.to_vec());
+			seed.extend_from_slice(&sk.to_bytes()[..]);
+			seed.extend_from_slice(&index.to_le_bytes());
+			sk = SecretKey::from_seed(&seed);
+		}
+		sk
+	}
+
+	/// Derives the child key at `path`, one level per index, by adding a tweak
+	/// of the parent public key and the index to the scalar. The matching child
+	/// public key is given by `PublicKey::derive`.
+	pub fn derive_non_hardened(&self, path: &[u32]) -> SecretKey {
+		let mut sk = SecretKey(self.0, self.1);
+		for index in path {
+			let tweak =
+				SecretScalar::from_bytes(&derivation_tweak(&sk.public(), *index).to_bytes_le());
+			let sk0 = sk.sk0().add(&tweak);
+
+			let mut seed = Zeroizing::new(NON_HARDENED_DOMAIN.to_vec());
+			seed.extend_from_slice(&sk.1);
+			seed.extend_from_slice(&index.to_le_bytes());
+			let sk1 = Fr::from_bytes_wide(&to_wide(&Zeroizing::new(blh(&seed))));
+
+			sk = SecretKey(*sk0.to_bytes(), sk1.to_bytes());
+		}
+		sk
+	}
+
+	/// Blinds the secret key with the factor `r`, by adding it to the scalar.
+	/// The matching public key is given by `PublicKey::blind`.
+	pub fn blind(&self, r: Fr) -> SecretKey {
+		let sk0 = self.sk0().add(&SecretScalar::from_bytes(&r.to_bytes()));
+
+		// Blinded keys get their own nonces, so signatures under different
+		// blinded keys can't be linked through R.
+		let mut seed = Zeroizing::new(BLINDING_DOMAIN.to_vec());
+		seed.extend_from_slice(&self.1);
+		seed.extend_from_slice(&r.to_bytes());
+		let sk1 = Fr::from_bytes_wide(&to_wide(&Zeroizing::new(blh(&seed))));
+
+		SecretKey(*sk0.to_bytes(), sk1.to_bytes())
+	}
+}
+
+/// Source of signatures under a single public key. Implemented by
+/// `SecretKey`, and implementable by signers that keep the key elsewhere, like
+/// hardware or remote signers.
+pub trait Signer {
+	/// Returns a signature of the message, verifiable with `verify` under
+	/// `self.public()`.
+	fn sign(&self, m: Fr) -> Signature;
+
+	/// Returns the public key of the signer.
+	fn public(&self) -> PublicKey;
+}
+
+impl Signer for SecretKey {
+	fn sign(&self, m: Fr) -> Signature {
+		sign(self, &SecretKey::public(self), m)
+	}
+
+	fn public(&self) -> PublicKey {
+		SecretKey::public(self)
+	}
 }
 
 /// Configures a structure for the public key.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PublicKey(pub Point);
 
+impl PublicKey {
+	/// Returns the 32 byte encoding of the public key: the compressed point.
+	pub fn to_bytes(&self) -> [u8; 32] {
+		self.0.to_bytes()
+	}
+
+	/// Reads the public key from its 32 byte encoding. Returns `None` if the
+	/// bytes don't encode a point in the prime order subgroup.
+	pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+		Point::from_bytes(bytes).map(PublicKey)
+	}
+
+	/// Derives the child public key at `path`, matching the secret key from
+	/// `SecretKey::derive_non_hardened`.
+	pub fn derive(&self, path: &[u32]) -> PublicKey {
+		let mut pk = PublicKey(self.0.clone());
+		for index in path {
+			let tweak = derivation_tweak(&pk, *index);
+			let tweak_point = B8_TABLE.mul_scalar(&reduce_scalar(&tweak.to_bytes_le()));
+			pk = PublicKey(pk.0.projective().add(&tweak_point).affine());
+		}
+		pk
+	}
+
+	/// Blinds the public key with the factor `r`: `PK + r * B8`. Keys blinded
+	/// with different random factors can't be linked to each other, or to the
+	/// identity key, without knowing the factors.
+	pub fn blind(&self, r: Fr) -> PublicKey {
+		let r_point = B8_TABLE.mul_scalar(&reduce_scalar(&r.to_bytes()));
+		PublicKey(self.0.projective().add(&r_point).affine())
+	}
+
+	/// Checks if the key is the identity key `pk` blinded with the factor `r`.
+	pub fn is_blinding_of(&self, pk: &PublicKey, r: Fr) -> bool {
+		self.0.equals(pk.blind(r).0)
+	}
+}
+
+/// Returns the scalar added to the key at each level of non-hardened
+/// derivation, by hashing the parent public key with the index.
+fn derivation_tweak(pk: &PublicKey, index: u32) -> BigUint {
+	let mut input = NON_HARDENED_DOMAIN.to_vec();
+	input.extend_from_slice(&pk.to_bytes());
+	input.extend_from_slice(&index.to_le_bytes());
+	BigUint::from_bytes_le(&blh(&input)) % BigUint::from_bytes_le(&SUBORDER.to_bytes())
+}
+
 #[derive(Clone)]
 /// Configures signature objects.
 pub struct Signature {
@@ -56,46 +258,287 @@ pub struct Signature {
 	pub s: Fr,
 }
 
+impl Signature {
+	/// Returns the 64 byte encoding of the signature: the compressed R
+	/// followed by the 32 little-endian bytes of s.
+	pub fn to_bytes(&self) -> [u8; 64] {
+		let mut bytes = [0; 64];
+		bytes[..32].copy_from_slice(&self.big_r.to_bytes());
+		bytes[32..].copy_from_slice(&self.s.to_bytes());
+		bytes
+	}
+
+	/// Reads the signature from its 64 byte encoding. Returns `None` if R is
+	/// not a point in the prime order subgroup or s is higher than the
+	/// SUBORDER.
+	pub fn from_bytes(bytes: &[u8; 64]) -> Option<Self> {
+		let mut big_r = [0; 32];
+		big_r.copy_from_slice(&bytes[..32]);
+		let big_r = Point::from_bytes(&big_r)?;
+		let s = fr_from_bytes(&bytes[32..])?;
+		if s > SUBORDER {
+			return None;
+		}
+		Some(Signature { big_r, s })
+	}
+}
+
+/// Reads a field element from 32 little-endian bytes, if it is canonical.
+fn fr_from_bytes(bytes: &[u8]) -> Option<Fr> {
+	let mut repr = [0; 32];
+	repr.copy_from_slice(bytes);
+	Option::from(Fr::from_bytes(&repr))
+}
+
+/// Serializes the bytes as a hex string for human readable formats, and as
+/// raw bytes otherwise.
+#[cfg(feature = "serde")]
+fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+	if serializer.is_human_readable() {
+		serializer.serialize_str(&hex::encode(bytes))
+	} else {
+		serializer.serialize_bytes(bytes)
+	}
+}
+
+/// Deserializes exactly `N` bytes written by `serialize_bytes`.
+#[cfg(feature = "serde")]
+fn deserialize_bytes<'de, D: Deserializer<'de>, const N: usize>(
+	deserializer: D,
+) -> Result<[u8; N], D::Error> {
+	let bytes = if deserializer.is_human_readable() {
+		let s = String::deserialize(deserializer)?;
+		hex::decode(s).map_err(D::Error::custom)?
+	} else {
+		Vec::<u8>::deserialize(deserializer)?
+	};
+	bytes.try_into().map_err(|_| D::Error::custom("invalid length"))
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for SecretKey {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_bytes(&self.to_bytes()[..], serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for SecretKey {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let bytes: Zeroizing<[u8; 64]> = Zeroizing::new(deserialize_bytes(deserializer)?);
+		SecretKey::from_bytes(&bytes).ok_or_else(|| D::Error::custom("invalid secret key"))
+	}
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for PublicKey {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_bytes(&self.to_bytes(), serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for PublicKey {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let bytes = deserialize_bytes(deserializer)?;
+		PublicKey::from_bytes(&bytes).ok_or_else(|| D::Error::custom("invalid public key"))
+	}
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Signature {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_bytes(&self.to_bytes(), serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Signature {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let bytes = deserialize_bytes(deserializer)?;
+		Signature::from_bytes(&bytes).ok_or_else(|| D::Error::custom("invalid signature"))
+	}
+}
+
 /// Returns a signature from given keys and message.
 pub fn sign(sk: &SecretKey, pk: &PublicKey, m: Fr) -> Signature {
-	let inputs = [Fr::zero(), sk.1, m, Fr::zero(), Fr::zero()];
-	let r = Hasher::new(inputs).permute()[0];
-	let r_bn = BigUint::from_bytes_le(&r.to_bytes());
+	let inputs = [Fr::zero(), sk.sk1(), m, Fr::zero(), Fr::zero()];
+	sign_with_nonce_inputs(sk, pk, m, inputs)
+}
+
+/// Returns a signature from given keys and message, with the nonce hedged
+/// with fresh randomness from `rng`. The nonce is still bound to the key and
+/// the message, so it stays secret even if the randomness is weak, and
+/// repeated signing of the same message uses different nonces. Verifies with
+/// `verify`, like the deterministic signature.
+pub fn sign_with_rng<R: RngCore>(sk: &SecretKey, pk: &PublicKey, m: Fr, rng: &mut R) -> Signature {
+	let inputs = [Fr::zero(), sk.sk1(), m, Fr::random(rng), Fr::zero()];
+	sign_with_nonce_inputs(sk, pk, m, inputs)
+}
+
+/// Returns a signature with the nonce derived by hashing `inputs`.
+fn sign_with_nonce_inputs(sk: &SecretKey, pk: &PublicKey, m: Fr, inputs: [Fr; 5]) -> Signature {
+	// The nonce bytes are wiped once the signature is computed.
+	let r = Zeroizing::new(Hasher::new(inputs).permute()[0].to_bytes());
+	let r = SecretScalar::from_bytes(r.as_ref());
 
 	// R = B8 * r
-	let big_r = B8.mul_scalar(&r.to_bytes()).affine();
+	let big_r = B8_TABLE.mul_scalar_ct(&r.to_bytes()).affine();
 	// H(R || PK || M)
 	let m_hash_input = [big_r.x, big_r.y, pk.0.x, pk.0.y, m];
 	let m_hash = Hasher::new(m_hash_input).permute()[0];
-	let m_hash_bn = BigUint::from_bytes_le(&m_hash.to_bytes());
 	// S = r + H(R || PK || M) * sk0   (mod n)
-	let s = r_bn + &sk.0 * m_hash_bn;
-	let s = s % BigUint::from_bytes_le(&SUBORDER.to_bytes());
-	let s = Fr::from_bytes_wide(&to_wide(&s.to_bytes_le()));
+	let s = sk.sk0().mul_add(&SecretScalar::from_bytes(&m_hash.to_bytes()), &r).to_fr();
 
 	Signature { big_r, s }
 }
 
-/// Checks if the signature holds with the given PK and message.
-pub fn verify(sig: &Signature, pk: &PublicKey, m: Fr) -> bool {
+/// Returns a signature of the message under the key blinded with the factor
+/// `r`, which verifies with `pk.blind(r)`.
+pub fn sign_blinded(sk: &SecretKey, r: Fr, m: Fr) -> Signature {
+	let sk = sk.blind(r);
+	sign(&sk, &sk.public(), m)
+}
+
+/// Hashes the bytes into a field element with the Poseidon sponge. The domain
+/// tag and the length are absorbed first, followed by the bytes in chunks of
+/// 31 bytes.
+fn hash_bytes(domain: &[u8], bytes: &[u8]) -> Fr {
+	let mut sponge = Sponge::new();
+	let domain = Fr::from_bytes_wide(&to_wide(domain));
+	sponge.update(&[domain]);
+	sponge.update(&bytes_to_fields(bytes));
+	sponge.squeeze()
+}
+
+/// Hashes the byte message into a field element with the Poseidon sponge.
+pub fn hash_message(msg: &[u8]) -> Fr {
+	hash_bytes(MESSAGE_DOMAIN, msg)
+}
+
+/// Binds the message to the signing context, so the challenge
+/// `H(R || PK || M)` is computed over `M = H(H(ctx), m)`.
+fn context_message(ctx: &[u8], m: Fr) -> Fr {
+	let ctx = hash_bytes(CONTEXT_DOMAIN, ctx);
+	Hasher::new([ctx, m, Fr::zero(), Fr::zero(), Fr::zero()]).permute()[0]
+}
+
+/// Returns a signature of the message under the signing context `ctx`, which
+/// doesn't verify under any other context, or with plain `verify`.
+pub fn sign_with_context(sk: &SecretKey, pk: &PublicKey, ctx: &[u8], m: Fr) -> Signature {
+	sign(sk, pk, context_message(ctx, m))
+}
+
+/// Checks if the signature of the message under the signing context `ctx`
+/// holds with the given PK.
+pub fn verify_with_context(sig: &Signature, pk: &PublicKey, ctx: &[u8], m: Fr) -> bool {
+	verify(sig, pk, context_message(ctx, m))
+}
+
+/// Returns a signature of the byte message, hashed with `hash_message`.
+pub fn sign_bytes(sk: &SecretKey, pk: &PublicKey, msg: &[u8]) -> Signature {
+	sign(sk, pk, hash_message(msg))
+}
+
+/// Checks if the signature of the byte message holds with the given PK.
+pub fn verify_bytes(sig: &Signature, pk: &PublicKey, msg: &[u8]) -> bool {
+	verify(sig, pk, hash_message(msg))
+}
+
+/// The reason why a signature failed to verify.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VerifyError {
+	/// The s scalar is higher than the SUBORDER.
+	ScalarOutOfRange,
+	/// R is not on the curve.
+	RNotOnCurve,
+	/// The public key is not on the curve.
+	PublicKeyNotOnCurve,
+	/// R is not in the prime order subgroup.
+	RNotInSubgroup,
+	/// The public key is not in the prime order subgroup.
+	PublicKeyNotInSubgroup,
+	/// The verification equation doesn't hold.
+	EquationMismatch,
+}
+
+/// Checks if the signature holds with the given PK and message, returning the
+/// reason of the failure if it doesn't.
+pub fn verify_detailed(sig: &Signature, pk: &PublicKey, m: Fr) -> Result<(), VerifyError> {
 	if sig.s > SUBORDER {
 		// S can't be higher than SUBORDER
-		return false;
+		return Err(VerifyError::ScalarOutOfRange);
+	}
+	if !sig.big_r.is_on_curve() {
+		return Err(VerifyError::RNotOnCurve);
+	}
+	if !pk.0.is_on_curve() {
+		return Err(VerifyError::PublicKeyNotOnCurve);
+	}
+	if !sig.big_r.is_in_subgroup() {
+		return Err(VerifyError::RNotInSubgroup);
+	}
+	if !pk.0.is_in_subgroup() {
+		return Err(VerifyError::PublicKeyNotInSubgroup);
 	}
 	// Cl = s * G
-	let cl = B8.mul_scalar(&sig.s.to_bytes());
+	let cl = B8_TABLE.mul_scalar(&reduce_scalar(&sig.s.to_bytes()));
 	// H(R || PK || M)
 	let m_hash_input = [sig.big_r.x, sig.big_r.y, pk.0.x, pk.0.y, m];
 	let m_hash = Hasher::new(m_hash_input).permute()[0];
-	let pk_h = pk.0.mul_scalar(&m_hash.to_bytes());
+	let pk_h = pk.0.mul_scalar(&reduce_scalar(&m_hash.to_bytes()));
 	// Cr = R + H(R || PK || M) * PK
 	let cr = sig.big_r.projective().add(&pk_h);
-	cr.affine().equals(cl.affine())
+	if !cr.affine().equals(cl.affine()) {
+		return Err(VerifyError::EquationMismatch);
+	}
+
+	Ok(())
+}
+
+/// Checks if the signature holds with the given PK and message.
+pub fn verify(sig: &Signature, pk: &PublicKey, m: Fr) -> bool {
+	verify_detailed(sig, pk, m).is_ok()
+}
+
+/// Checks if all the signatures hold with their PKs and messages, by checking a
+/// random linear combination of the verification equations at once. Like
+/// `verify`, every R and PK must be in the prime order subgroup, and the
+/// equation is not multiplied by the cofactor, so the batch holds exactly when
+/// `verify` holds for every signature, but with a chance of `2^-128`.
+pub fn verify_batch<R: RngCore>(items: &[(Signature, PublicKey, Fr)], rng: &mut R) -> bool {
+	let suborder = BigUint::from_bytes_le(&SUBORDER.to_bytes());
+	let mut s_sum = BigUint::from(0u64);
+	let mut rhs = PointProjective::IDENTITY;
+	for (sig, pk, m) in items {
+		if sig.s > SUBORDER || !sig.big_r.is_in_subgroup() || !pk.0.is_in_subgroup() {
+			return false;
+		}
+		// Random 128 bit coefficient for this equation.
+		let mut z_bytes = [0; 16];
+		rng.fill_bytes(&mut z_bytes);
+		let z = BigUint::from_bytes_le(&z_bytes);
+
+		// H(R || PK || M)
+		let m_hash_input = [sig.big_r.x, sig.big_r.y, pk.0.x, pk.0.y, *m];
+		let m_hash = Hasher::new(m_hash_input).permute()[0];
+		let zh = (&z * BigUint::from_bytes_le(&m_hash.to_bytes())) % &suborder;
+
+		// sum(z * s)
+		s_sum = (s_sum + &z * BigUint::from_bytes_le(&sig.s.to_bytes())) % &suborder;
+		// sum(z * R + z * H(R || PK || M) * PK)
+		let big_r_z = sig.big_r.mul_scalar(&z_bytes);
+		let pk_zh = pk.0.mul_scalar(&zh.to_bytes_le());
+		rhs = rhs.add(&big_r_z).add(&pk_zh);
+	}
+	let lhs = B8_TABLE.mul_scalar(&reduce_scalar(&s_sum.to_bytes_le()));
+	lhs.affine().equals(rhs.affine())
 }
 
 #[cfg(test)]
 mod test {
 	use super::*;
+	use ed_on_bn254::B8;
 	use halo2wrong::curves::group::ff::PrimeField;
 	use rand::thread_rng;
 
@@ -114,6 +557,17 @@ mod test {
 		assert!(res);
 	}
 
+	#[test]
+	fn should_derive_same_key_from_seed() {
+		let seed = b"eigen-trust-seed";
+		let pk1 = SecretKey::from_seed(seed).public();
+		let pk2 = SecretKey::from_seed(seed).public();
+		let pk3 = SecretKey::from_seed(b"another-seed").public();
+
+		assert!(pk1.0.equals(pk2.0));
+		assert!(!pk1.0.equals(pk3.0));
+	}
+
 	#[test]
 	fn test_invalid_big_r() {
 		// Testing invalid R.
@@ -183,4 +637,335 @@ mod test {
 
 		assert_eq!(res, false);
 	}
+
+	#[test]
+	fn test_verify_detailed_errors() {
+		let mut rng = thread_rng();
+
+		let sk = SecretKey::random(&mut rng);
+		let pk = sk.public();
+		let m = Fr::from_str_vartime("123456789012345678901234567890").unwrap();
+		let sig = sign(&sk, &pk, m);
+		assert_eq!(verify_detailed(&sig, &pk, m), Ok(()));
+
+		let mut sig_big_s = sig.clone();
+		sig_big_s.s = SUBORDER + Fr::one();
+		assert_eq!(
+			verify_detailed(&sig_big_s, &pk, m),
+			Err(VerifyError::ScalarOutOfRange)
+		);
+
+		let mut sig_off_curve = sig.clone();
+		sig_off_curve.big_r = Point { x: Fr::one(), y: Fr::one() };
+		assert_eq!(
+			verify_detailed(&sig_off_curve, &pk, m),
+			Err(VerifyError::RNotOnCurve)
+		);
+		let pk_off_curve = PublicKey(Point { x: Fr::one(), y: Fr::one() });
+		assert_eq!(
+			verify_detailed(&sig, &pk_off_curve, m),
+			Err(VerifyError::PublicKeyNotOnCurve)
+		);
+
+		// (0, -1) is a point of order 2.
+		let mut sig_low_order = sig.clone();
+		sig_low_order.big_r = Point { x: Fr::zero(), y: -Fr::one() };
+		assert_eq!(
+			verify_detailed(&sig_low_order, &pk, m),
+			Err(VerifyError::RNotInSubgroup)
+		);
+		let low_order_pk = PublicKey(Point { x: Fr::zero(), y: -Fr::one() });
+		assert_eq!(
+			verify_detailed(&sig, &low_order_pk, m),
+			Err(VerifyError::PublicKeyNotInSubgroup)
+		);
+
+		let m2 = Fr::from_str_vartime("123456789012345678901234567891").unwrap();
+		assert_eq!(
+			verify_detailed(&sig, &pk, m2),
+			Err(VerifyError::EquationMismatch)
+		);
+	}
+
+	// Test vectors of (sk0, sk1, m, pk.x, pk.y, big_r.x, big_r.y, s), generated
+	// with the independent reference implementation. To regenerate them, e.g.
+	// after changing the Poseidon params, run:
+	// python3 scripts/eddsa-test-vectors.py <sk0> <sk1> <m>
+	const TEST_VECTORS: [[&str; 8]; 2] = [
+		[
+			"12345678901234567890123456789012345678901234567890",
+			"98765432109876543210987654321",
+			"123456789012345678901234567890",
+			"16860437127882500233147129339267817607015719480496417942811007360414161663565",
+			"6096217714172487061911224580934571564807345925965232270274450025099885703594",
+			"9655137448124399427270705150100018838865373642123174515178333428065560291336",
+			"1241297936022138898079456620715098995562097981551342474659391608273650509818",
+			"1956967672347855453596110251159708937006773680245035839436644532163754531099",
+		],
+		[
+			"7777777777777777777777777777777777777777777777777777777777777777777777777",
+			"42",
+			"1000",
+			"16957771356133915976003416172014144635080734056662874682561514493988349489005",
+			"5573955710729596433139332660790888161244523325607443857878108950920816263421",
+			"8890524755466950875944477850390015477459638757566167951002328994446067123774",
+			"21369159521336054033258103413828097710478024860746926619360244323189649670895",
+			"2074077200120024325575924664536434481509054419314255593858882434645275265921",
+		],
+	];
+
+	#[test]
+	fn should_match_reference_vectors() {
+		for vector in TEST_VECTORS {
+			let [sk0, sk1, m, pk_x, pk_y, big_r_x, big_r_y, s] = vector;
+			let fr = |x: &str| Fr::from_str_vartime(x).unwrap();
+
+			let mut sk_bytes = [0; 64];
+			let sk0 = BigUint::parse_bytes(sk0.as_bytes(), 10).unwrap().to_bytes_le();
+			sk_bytes[..sk0.len()].copy_from_slice(&sk0);
+			sk_bytes[32..].copy_from_slice(&fr(sk1).to_bytes());
+			let sk = SecretKey::from_bytes(&sk_bytes).unwrap();
+			let pk = sk.public();
+			assert_eq!(pk.0.x, fr(pk_x));
+			assert_eq!(pk.0.y, fr(pk_y));
+
+			let sig = sign(&sk, &pk, fr(m));
+			assert_eq!(sig.big_r.x, fr(big_r_x));
+			assert_eq!(sig.big_r.y, fr(big_r_y));
+			assert_eq!(sig.s, fr(s));
+			assert!(verify(&sig, &pk, fr(m)));
+		}
+	}
+
+	#[test]
+	fn should_verify_batch() {
+		let mut rng = thread_rng();
+
+		let mut items: Vec<(Signature, PublicKey, Fr)> = (0..10)
+			.map(|_| {
+				let sk = SecretKey::random(&mut rng);
+				let pk = sk.public();
+				let m = Fr::random(&mut rng);
+				(sign(&sk, &pk, m), pk, m)
+			})
+			.collect();
+		assert!(verify_batch(&items, &mut rng));
+		assert!(verify_batch(&[], &mut rng));
+
+		items[3].2 += Fr::one();
+		assert!(!verify_batch(&items, &mut rng));
+	}
+
+	/// Returns a signature whose R has a component of order 2, which only
+	/// holds if the low order components are ignored.
+	fn sign_with_torsion(sk: &SecretKey, pk: &PublicKey, m: Fr) -> Signature {
+		let r = SecretScalar::from_bytes(&Fr::from(123456789).to_bytes());
+		let order_2 = Point { x: Fr::zero(), y: -Fr::one() };
+		let big_r = B8.mul_scalar(&r.to_bytes()).add(&order_2.projective()).affine();
+		let m_hash = Hasher::new([big_r.x, big_r.y, pk.0.x, pk.0.y, m]).permute()[0];
+		let s = sk.sk0().mul_add(&SecretScalar::from_bytes(&m_hash.to_bytes()), &r);
+		Signature { big_r, s: s.to_fr() }
+	}
+
+	#[test]
+	fn should_agree_on_small_order_components() {
+		let mut rng = thread_rng();
+
+		let sk = SecretKey::random(&mut rng);
+		let pk = sk.public();
+		let m = Fr::random(&mut rng);
+		let sig = sign_with_torsion(&sk, &pk, m);
+		assert_eq!(
+			verify_detailed(&sig, &pk, m),
+			Err(VerifyError::RNotInSubgroup)
+		);
+
+		// Rejected by the batch and the half-aggregation too, even though
+		// the equation multiplied by the cofactor holds.
+		let big_r_8 = sig.big_r.mul_scalar(&[8]);
+		let m_hash = Hasher::new([sig.big_r.x, sig.big_r.y, pk.0.x, pk.0.y, m]).permute()[0];
+		let pk_h_8 = pk.0.mul_scalar(&reduce_scalar(&m_hash.to_bytes())).affine().mul_scalar(&[8]);
+		let s_b8_8 = B8.mul_scalar(&sig.s.to_bytes()).affine().mul_scalar(&[8]);
+		assert!(s_b8_8.affine().equals(big_r_8.add(&pk_h_8).affine()));
+
+		let items = vec![(sig, pk.clone(), m)];
+		assert!(!verify_batch(&items, &mut rng));
+		assert!(!half_agg::HalfAggregate::new(&items).verify(&[(pk, m)]));
+	}
+
+	#[test]
+	fn should_encode_and_decode_bytes() {
+		let mut rng = thread_rng();
+
+		let sk = SecretKey::random(&mut rng);
+		let pk = sk.public();
+		let m = Fr::random(&mut rng);
+		let sig = sign(&sk, &pk, m);
+
+		let sk = SecretKey::from_bytes(&sk.to_bytes()).unwrap();
+		let pk = PublicKey::from_bytes(&pk.to_bytes()).unwrap();
+		let sig = Signature::from_bytes(&sig.to_bytes()).unwrap();
+		assert!(verify(&sig, &pk, m));
+		assert!(sk.public().0.equals(pk.0));
+
+		// s higher than the SUBORDER.
+		let mut bytes = sig.to_bytes();
+		bytes[32..].copy_from_slice(&(SUBORDER + Fr::one()).to_bytes());
+		assert!(Signature::from_bytes(&bytes).is_none());
+
+		// sk1 not a canonical field element.
+		let mut bytes = sk.to_bytes();
+		bytes[32..].copy_from_slice(&[0xff; 32]);
+		assert!(SecretKey::from_bytes(&bytes).is_none());
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn should_serde_keys_and_signature_json() {
+		let mut rng = thread_rng();
+
+		let sk = SecretKey::random(&mut rng);
+		let pk = sk.public();
+		let m = Fr::random(&mut rng);
+		let sig = sign(&sk, &pk, m);
+
+		let sk: SecretKey = serde_json::from_str(&serde_json::to_string(&sk).unwrap()).unwrap();
+		let pk: PublicKey = serde_json::from_str(&serde_json::to_string(&pk).unwrap()).unwrap();
+		let sig: Signature = serde_json::from_str(&serde_json::to_string(&sig).unwrap()).unwrap();
+		assert!(verify(&sig, &pk, m));
+		assert!(sk.public().0.equals(pk.0));
+
+		let json = serde_json::to_string(&hex::encode([0xff; 64])).unwrap();
+		assert!(serde_json::from_str::<Signature>(&json).is_err());
+	}
+
+	#[test]
+	fn should_zeroize_secret_key() {
+		let mut rng = thread_rng();
+
+		let mut sk = SecretKey::random(&mut rng);
+		assert_ne!(*sk.to_bytes(), [0; 64]);
+
+		sk.zeroize();
+		assert_eq!(*sk.to_bytes(), [0; 64]);
+	}
+
+	#[test]
+	fn should_sign_and_verify_bytes() {
+		let mut rng = thread_rng();
+
+		let sk = SecretKey::random(&mut rng);
+		let pk = sk.public();
+
+		let msg = b"peer 1 trusts peer 2 with a score of 0.75, in epoch 42";
+		let sig = sign_bytes(&sk, &pk, msg);
+		assert!(verify_bytes(&sig, &pk, msg));
+		assert!(!verify_bytes(
+			&sig,
+			&pk,
+			b"peer 1 trusts peer 2 with a score of 0.76, in epoch 42"
+		));
+
+		// Trailing zeros are not ignored.
+		assert_ne!(hash_message(b"a"), hash_message(b"a\0"));
+		assert_ne!(hash_message(b""), hash_message(&[0; 31]));
+	}
+
+	#[test]
+	fn should_derive_child_keys() {
+		let sk = SecretKey::from_seed(b"eigen-trust-seed");
+		let pk = sk.public();
+
+		let child = sk.derive(&[0, 42]);
+		assert_eq!(child.to_bytes(), sk.derive(&[0, 42]).to_bytes());
+		assert_eq!(child.to_bytes(), sk.derive(&[0]).derive(&[42]).to_bytes());
+		assert_ne!(child.to_bytes(), sk.derive(&[0, 43]).to_bytes());
+		assert_eq!(sk.derive(&[]).to_bytes(), sk.to_bytes());
+
+		let child_nh = sk.derive_non_hardened(&[0, 42]);
+		assert_ne!(child.to_bytes(), child_nh.to_bytes());
+		assert!(child_nh.public().0.equals(pk.derive(&[0, 42]).0));
+		assert!(!child_nh.public().0.equals(pk.derive(&[0, 43]).0));
+
+		let m = Fr::from(123456789);
+		let sig = sign(&child_nh, &pk.derive(&[0, 42]), m);
+		assert!(verify(&sig, &pk.derive(&[0, 42]), m));
+	}
+
+	#[test]
+	fn should_sign_and_verify_with_context() {
+		let mut rng = thread_rng();
+
+		let sk = SecretKey::random(&mut rng);
+		let pk = sk.public();
+		let m = Fr::from(123456789);
+
+		let sig = sign_with_context(&sk, &pk, b"opinion", m);
+		assert!(verify_with_context(&sig, &pk, b"opinion", m));
+		assert!(!verify_with_context(&sig, &pk, b"epoch-commit", m));
+		assert!(!verify(&sig, &pk, m));
+
+		let sig = sign(&sk, &pk, m);
+		assert!(!verify_with_context(&sig, &pk, b"opinion", m));
+	}
+
+	#[test]
+	fn should_sign_and_verify_blinded() {
+		let mut rng = thread_rng();
+
+		let sk = SecretKey::random(&mut rng);
+		let pk = sk.public();
+		let m = Fr::from(123456789);
+
+		let r1 = Fr::random(&mut rng);
+		let r2 = Fr::random(&mut rng);
+		let pk1 = pk.blind(r1);
+		let pk2 = pk.blind(r2);
+		assert!(!pk1.0.equals(pk.0.clone()));
+		assert!(!pk1.0.equals(pk2.0.clone()));
+		assert!(sk.blind(r1).public().0.equals(pk1.0.clone()));
+		assert!(pk1.is_blinding_of(&pk, r1));
+		assert!(!pk1.is_blinding_of(&pk, r2));
+
+		let sig1 = sign_blinded(&sk, r1, m);
+		let sig2 = sign_blinded(&sk, r2, m);
+		assert!(verify(&sig1, &pk1, m));
+		assert!(!verify(&sig1, &pk2, m));
+		assert!(!verify(&sig1, &pk, m));
+		// Different nonces, so R doesn't link the signatures.
+		assert!(!sig1.big_r.equals(sig2.big_r));
+
+		// Blinding with zero gives back the identity key.
+		assert!(pk.blind(Fr::zero()).0.equals(pk.0));
+	}
+
+	#[test]
+	fn should_sign_and_verify_with_rng() {
+		let mut rng = thread_rng();
+
+		let sk = SecretKey::random(&mut rng);
+		let pk = sk.public();
+		let m = Fr::from(123456789);
+
+		let sig1 = sign_with_rng(&sk, &pk, m, &mut rng);
+		let sig2 = sign_with_rng(&sk, &pk, m, &mut rng);
+		assert!(verify(&sig1, &pk, m));
+		assert!(verify(&sig2, &pk, m));
+		assert!(!verify(&sig1, &pk, m + Fr::one()));
+		// Fresh nonce every time, unlike the deterministic signature.
+		assert!(!sig1.big_r.equals(sig2.big_r));
+		assert!(!sig1.big_r.equals(sign(&sk, &pk, m).big_r));
+	}
+
+	#[test]
+	fn should_sign_with_signer() {
+		fn sign_and_verify<S: Signer>(signer: &S, m: Fr) -> bool {
+			verify(&signer.sign(m), &signer.public(), m)
+		}
+
+		let sk = SecretKey::random(&mut thread_rng());
+		let m = Fr::from(123456789);
+		assert!(sign_and_verify(&sk, m));
+		assert!(Signer::public(&sk).0.equals(sk.public().0));
+	}
 }
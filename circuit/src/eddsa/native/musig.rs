@@ -0,0 +1,214 @@
+use super::{
+	ed_on_bn254::{Point, PointProjective, SecretScalar, B8_TABLE, SUBORDER},
+	Hasher, PublicKey, SecretKey, Signature, Sponge,
+};
+use crate::utils::to_wide;
+use halo2wrong::{
+	curves::{bn256::Fr, FieldExt},
+	halo2::arithmetic::Field,
+};
+use num_bigint::BigUint;
+use rand::RngCore;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+/// Returns the SUBORDER as a big integer.
+fn suborder() -> BigUint {
+	BigUint::from_bytes_le(&SUBORDER.to_bytes())
+}
+
+/// The reason why a step of the signing session failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MuSigError {
+	/// The index of the co-signer is not one of the aggregated keys.
+	UnknownSigner,
+	/// The number of public nonces doesn't match the number of commitments.
+	MissingNonce,
+	/// A public nonce doesn't match the commitment sent before it.
+	NonceMismatch,
+}
+
+/// Aggregated public key of the co-signers, together with the coefficient of
+/// each of their keys.
+pub struct KeyAggregation {
+	/// Coefficient of each public key, in the order they were given.
+	coefficients: Vec<BigUint>,
+	/// The aggregated public key.
+	agg_pk: PublicKey,
+}
+
+impl KeyAggregation {
+	/// Aggregates the public keys into `sum(a_i * pk_i)`, where
+	/// `a_i = H(L, pk_i)` and `L` is the hash of all the public keys.
+	pub fn new(pks: &[PublicKey]) -> Self {
+		let mut sponge = Sponge::new();
+		for pk in pks {
+			sponge.update(&[pk.0.x, pk.0.y]);
+		}
+		let l = sponge.squeeze();
+
+		let mut agg = PointProjective::IDENTITY;
+		let mut coefficients = Vec::new();
+		for pk in pks {
+			let a = Hasher::new([l, pk.0.x, pk.0.y, Fr::zero(), Fr::zero()]).permute()[0];
+			let a = BigUint::from_bytes_le(&a.to_bytes()) % suborder();
+			agg = agg.add(&pk.0.mul_scalar(&a.to_bytes_le()));
+			coefficients.push(a);
+		}
+
+		Self { coefficients, agg_pk: PublicKey(agg.affine()) }
+	}
+
+	/// Returns the aggregated public key, which verifies the aggregated
+	/// signature with the regular `verify`.
+	pub fn public_key(&self) -> &PublicKey {
+		&self.agg_pk
+	}
+}
+
+/// Secret nonce of a co-signer, used for a single partial signature.
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct SecretNonce(pub(super) [u8; 32]);
+
+impl SecretNonce {
+	/// Generates a random nonce, returning it with its public nonce
+	/// `R_i = r_i * B8`. The co-signers first share the commitments
+	/// `commit_nonce(R_i)`, and only reveal the public nonces once they
+	/// received all the commitments, so no one can pick its nonce after
+	/// seeing the others.
+	pub fn random<R: RngCore>(rng: &mut R) -> (Self, Point) {
+		let r = SecretScalar::from_bytes(&Zeroizing::new(Fr::random(rng).to_bytes())[..]);
+		let big_r = B8_TABLE.mul_scalar_ct(&r.to_bytes()).affine();
+		(SecretNonce(*r.to_bytes()), big_r)
+	}
+}
+
+/// Returns the commitment `H(R_i)` to the public nonce of a co-signer, shared
+/// in the first round of the signing session.
+pub fn commit_nonce(big_r: &Point) -> Fr {
+	Hasher::new([big_r.x, big_r.y, Fr::zero(), Fr::zero(), Fr::zero()]).permute()[0]
+}
+
+/// Aggregates the public nonces of all the co-signers into `R = sum(R_i)`,
+/// after checking them against the `commitments` received before them, in
+/// the same order.
+pub fn aggregate_nonces(commitments: &[Fr], nonces: &[Point]) -> Result<Point, MuSigError> {
+	if commitments.len() != nonces.len() {
+		return Err(MuSigError::MissingNonce);
+	}
+	if commitments.iter().zip(nonces).any(|(c, big_r)| *c != commit_nonce(big_r)) {
+		return Err(MuSigError::NonceMismatch);
+	}
+	let agg_nonce = nonces
+		.iter()
+		.fold(PointProjective::IDENTITY, |acc, big_r| {
+			acc.add(&big_r.projective())
+		})
+		.affine();
+	Ok(agg_nonce)
+}
+
+/// Returns the partial signature `s_i = r_i + H(R || PK || M) * a_i * sk_i` of
+/// the co-signer at `index`. The nonce is consumed, so it can't be reused.
+pub fn partial_sign(
+	sk: &SecretKey, nonce: SecretNonce, key_agg: &KeyAggregation, index: usize, agg_nonce: &Point,
+	m: Fr,
+) -> Result<Fr, MuSigError> {
+	let a = key_agg.coefficients.get(index).ok_or(MuSigError::UnknownSigner)?;
+	let agg_pk = &key_agg.agg_pk.0;
+	// H(R || PK || M)
+	let m_hash_input = [agg_nonce.x, agg_nonce.y, agg_pk.x, agg_pk.y, m];
+	let m_hash = Hasher::new(m_hash_input).permute()[0];
+	// H(R || PK || M) * a_i is public, only the key and the nonce are secret.
+	let m_hash_a = BigUint::from_bytes_le(&m_hash.to_bytes()) * a % suborder();
+	let m_hash_a = SecretScalar::from_bytes(&m_hash_a.to_bytes_le());
+
+	let r = SecretScalar::from_bytes(&nonce.0);
+	Ok(sk.sk0().mul_add(&m_hash_a, &r).to_fr())
+}
+
+/// Combines the partial signatures into a signature under the aggregated
+/// public key.
+pub fn aggregate_signatures(agg_nonce: Point, partial_sigs: &[Fr]) -> Signature {
+	let s = partial_sigs.iter().fold(BigUint::from(0u64), |acc, s| {
+		(acc + BigUint::from_bytes_le(&s.to_bytes())) % suborder()
+	});
+	let s = Fr::from_bytes_wide(&to_wide(&s.to_bytes_le()));
+	Signature { big_r: agg_nonce, s }
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::eddsa::native::verify;
+	use rand::thread_rng;
+
+	#[test]
+	fn should_aggregate_signatures() {
+		let mut rng = thread_rng();
+
+		let sks: Vec<SecretKey> = (0..4).map(|_| SecretKey::random(&mut rng)).collect();
+		let pks: Vec<PublicKey> = sks.iter().map(|sk| sk.public()).collect();
+		let key_agg = KeyAggregation::new(&pks);
+
+		let (nonces, big_rs): (Vec<_>, Vec<_>) =
+			sks.iter().map(|_| SecretNonce::random(&mut rng)).unzip();
+		let commitments: Vec<Fr> = big_rs.iter().map(commit_nonce).collect();
+		let agg_nonce = aggregate_nonces(&commitments, &big_rs).unwrap();
+
+		let m = Fr::from(123456789);
+		let partial_sigs: Vec<Fr> = sks
+			.iter()
+			.zip(nonces)
+			.enumerate()
+			.map(|(i, (sk, nonce))| partial_sign(sk, nonce, &key_agg, i, &agg_nonce, m).unwrap())
+			.collect();
+		let sig = aggregate_signatures(agg_nonce.clone(), &partial_sigs);
+
+		assert!(verify(&sig, key_agg.public_key(), m));
+		assert!(!verify(&sig, key_agg.public_key(), Fr::from(987654321)));
+
+		// Missing a co-signer.
+		let sig = aggregate_signatures(agg_nonce, &partial_sigs[..3]);
+		assert!(!verify(&sig, key_agg.public_key(), m));
+	}
+
+	#[test]
+	fn should_check_nonce_commitments() {
+		let mut rng = thread_rng();
+
+		let big_rs: Vec<Point> = (0..3).map(|_| SecretNonce::random(&mut rng).1).collect();
+		let commitments: Vec<Fr> = big_rs.iter().map(commit_nonce).collect();
+		assert!(aggregate_nonces(&commitments, &big_rs).is_ok());
+
+		// A co-signer changing its nonce after seeing the others.
+		let (_, other) = SecretNonce::random(&mut rng);
+		let changed = [big_rs[0].clone(), big_rs[1].clone(), other];
+		let res = aggregate_nonces(&commitments, &changed);
+		assert_eq!(res.unwrap_err(), MuSigError::NonceMismatch);
+		let res = aggregate_nonces(&commitments, &big_rs[..2]);
+		assert_eq!(res.unwrap_err(), MuSigError::MissingNonce);
+	}
+
+	#[test]
+	fn should_reject_unknown_signer() {
+		let mut rng = thread_rng();
+
+		let sk = SecretKey::random(&mut rng);
+		let key_agg = KeyAggregation::new(&[sk.public()]);
+		let (nonce, big_r) = SecretNonce::random(&mut rng);
+		let res = partial_sign(&sk, nonce, &key_agg, 1, &big_r, Fr::one());
+		assert_eq!(res.unwrap_err(), MuSigError::UnknownSigner);
+	}
+
+	#[test]
+	fn should_depend_on_key_order() {
+		let mut rng = thread_rng();
+
+		let pks: Vec<PublicKey> = (0..3).map(|_| SecretKey::random(&mut rng).public()).collect();
+		let key_agg = KeyAggregation::new(&pks);
+
+		let reversed: Vec<PublicKey> = pks.iter().rev().map(|pk| PublicKey(pk.0.clone())).collect();
+		let key_agg_rev = KeyAggregation::new(&reversed);
+		assert!(!key_agg.public_key().0.equals(key_agg_rev.public_key().0.clone()));
+	}
+}
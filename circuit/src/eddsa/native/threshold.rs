@@ -0,0 +1,206 @@
+use super::{
+	ed_on_bn254::{Point, SecretScalar, SUBORDER},
+	musig::SecretNonce,
+	Hasher, PublicKey, SecretKey,
+};
+use halo2wrong::{curves::bn256::Fr, halo2::arithmetic::Field};
+use num_bigint::BigUint;
+use rand::RngCore;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+/// Returns the SUBORDER as a big integer.
+fn suborder() -> BigUint {
+	BigUint::from_bytes_le(&SUBORDER.to_bytes())
+}
+
+/// The reason why a step of the threshold signing failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThresholdError {
+	/// The threshold is zero, or above the number of shares.
+	InvalidThreshold,
+	/// The index of the share holder is not one of the signers.
+	UnknownSigner,
+	/// An index appears more than once among the signers.
+	DuplicateSigner,
+	/// An index of the signers is zero, which no share holder has.
+	ZeroIndex,
+}
+
+/// Shamir share of a secret key, held by the participant at `index`.
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct KeyShare {
+	/// Index of the participant, starting from 1.
+	index: u64,
+	/// Value of the sharing polynomial at `index`, in little-endian bytes.
+	share: [u8; 32],
+}
+
+impl KeyShare {
+	/// Returns the index of the participant holding the share.
+	pub fn index(&self) -> u64 {
+		self.index
+	}
+}
+
+/// Splits the secret key into `num_shares` shares, any `threshold` of which
+/// can produce a signature verifiable under the original public key.
+pub fn split_key<R: RngCore>(
+	sk: &SecretKey, threshold: usize, num_shares: usize, rng: &mut R,
+) -> Result<Vec<KeyShare>, ThresholdError> {
+	if threshold == 0 || threshold > num_shares {
+		return Err(ThresholdError::InvalidThreshold);
+	}
+	// f(x) = sk + c_1 * x + ... + c_{t-1} * x^{t-1}
+	let mut coeffs = vec![sk.sk0()];
+	for _ in 1..threshold {
+		let c = Zeroizing::new(Fr::random(&mut *rng).to_bytes());
+		coeffs.push(SecretScalar::from_bytes(c.as_ref()));
+	}
+
+	let shares = (1..=num_shares as u64)
+		.map(|index| {
+			let x = SecretScalar::from_bytes(&index.to_le_bytes());
+			let y = coeffs.iter().rev().fold(SecretScalar::default(), |acc, c| acc.mul_add(&x, c));
+			KeyShare { index, share: *y.to_bytes() }
+		})
+		.collect();
+	Ok(shares)
+}
+
+/// Returns the Lagrange coefficient of `index` at zero, for the set of
+/// `signers`. The indices must be distinct and non-zero, or a denominator
+/// would be zero, and `index` must be one of them.
+fn lagrange_coefficient(index: u64, signers: &[u64]) -> Result<BigUint, ThresholdError> {
+	if signers.contains(&0) {
+		return Err(ThresholdError::ZeroIndex);
+	}
+	let mut sorted = signers.to_vec();
+	sorted.sort_unstable();
+	if sorted.windows(2).any(|pair| pair[0] == pair[1]) {
+		return Err(ThresholdError::DuplicateSigner);
+	}
+	if !signers.contains(&index) {
+		return Err(ThresholdError::UnknownSigner);
+	}
+
+	let n = suborder();
+	let mut num = BigUint::from(1u64);
+	let mut den = BigUint::from(1u64);
+	for j in signers.iter().filter(|j| **j != index) {
+		num = num * BigUint::from(*j) % &n;
+		den = den * ((BigUint::from(*j) + &n - BigUint::from(index)) % &n) % &n;
+	}
+	// The SUBORDER is prime, so den^(n - 2) is the inverse of den.
+	let den_inv = den.modpow(&(&n - BigUint::from(2u64)), &n);
+	Ok(num * den_inv % &n)
+}
+
+/// Returns the partial signature `s_i = r_i + H(R || PK || M) * l_i * x_i` of
+/// the share holder, where `l_i` is its Lagrange coefficient among the
+/// `signers`, and `R` is the aggregation of the public nonces of all the
+/// signers. The partial signatures are combined with
+/// `musig::aggregate_signatures`.
+pub fn partial_sign(
+	share: &KeyShare, nonce: SecretNonce, signers: &[u64], pk: &PublicKey, agg_nonce: &Point, m: Fr,
+) -> Result<Fr, ThresholdError> {
+	let lambda = lagrange_coefficient(share.index, signers)?;
+	// H(R || PK || M)
+	let m_hash_input = [agg_nonce.x, agg_nonce.y, pk.0.x, pk.0.y, m];
+	let m_hash = Hasher::new(m_hash_input).permute()[0];
+
+	// H(R || PK || M) * l_i is public, only the share and the nonce are secret.
+	let m_hash_lambda = BigUint::from_bytes_le(&m_hash.to_bytes()) * lambda % suborder();
+	let m_hash_lambda = SecretScalar::from_bytes(&m_hash_lambda.to_bytes_le());
+
+	let r = SecretScalar::from_bytes(&nonce.0);
+	let x = SecretScalar::from_bytes(&share.share);
+	Ok(x.mul_add(&m_hash_lambda, &r).to_fr())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::eddsa::native::{
+		musig::{aggregate_nonces, aggregate_signatures, commit_nonce},
+		verify, Signature,
+	};
+	use rand::thread_rng;
+
+	/// Signs the message with the given shares and returns the signature.
+	fn threshold_sign(shares: &[&KeyShare], pk: &PublicKey, m: Fr) -> Signature {
+		let mut rng = thread_rng();
+		let signers: Vec<u64> = shares.iter().map(|share| share.index()).collect();
+		let (nonces, big_rs): (Vec<_>, Vec<_>) =
+			shares.iter().map(|_| SecretNonce::random(&mut rng)).unzip();
+		let commitments: Vec<Fr> = big_rs.iter().map(commit_nonce).collect();
+		let agg_nonce = aggregate_nonces(&commitments, &big_rs).unwrap();
+
+		let partial_sigs: Vec<Fr> = shares
+			.iter()
+			.zip(nonces)
+			.map(|(share, nonce)| partial_sign(share, nonce, &signers, pk, &agg_nonce, m).unwrap())
+			.collect();
+		aggregate_signatures(agg_nonce, &partial_sigs)
+	}
+
+	#[test]
+	fn should_sign_with_threshold_of_shares() {
+		let mut rng = thread_rng();
+
+		let sk = SecretKey::random(&mut rng);
+		let pk = sk.public();
+		let shares = split_key(&sk, 3, 5, &mut rng).unwrap();
+		let m = Fr::from(123456789);
+
+		let sig = threshold_sign(&[&shares[0], &shares[2], &shares[4]], &pk, m);
+		assert!(verify(&sig, &pk, m));
+
+		let sig = threshold_sign(&[&shares[1], &shares[2], &shares[3], &shares[4]], &pk, m);
+		assert!(verify(&sig, &pk, m));
+	}
+
+	#[test]
+	fn should_not_sign_below_threshold() {
+		let mut rng = thread_rng();
+
+		let sk = SecretKey::random(&mut rng);
+		let pk = sk.public();
+		let shares = split_key(&sk, 3, 5, &mut rng).unwrap();
+		let m = Fr::from(123456789);
+
+		let sig = threshold_sign(&[&shares[0], &shares[1]], &pk, m);
+		assert!(!verify(&sig, &pk, m));
+	}
+
+	#[test]
+	fn should_reject_invalid_threshold() {
+		let mut rng = thread_rng();
+
+		let sk = SecretKey::random(&mut rng);
+		let res = split_key(&sk, 0, 5, &mut rng);
+		assert_eq!(res.err(), Some(ThresholdError::InvalidThreshold));
+		let res = split_key(&sk, 6, 5, &mut rng);
+		assert_eq!(res.err(), Some(ThresholdError::InvalidThreshold));
+	}
+
+	#[test]
+	fn should_reject_invalid_signers() {
+		let mut rng = thread_rng();
+
+		let sk = SecretKey::random(&mut rng);
+		let pk = sk.public();
+		let shares = split_key(&sk, 2, 3, &mut rng).unwrap();
+		let (_, big_r) = SecretNonce::random(&mut rng);
+		let m = Fr::from(123456789);
+
+		let sign = |signers: &[u64]| {
+			let (nonce, _) = SecretNonce::random(&mut thread_rng());
+			partial_sign(&shares[0], nonce, signers, &pk, &big_r, m)
+		};
+		assert_eq!(sign(&[1, 2, 2]), Err(ThresholdError::DuplicateSigner));
+		assert_eq!(sign(&[1, 1]), Err(ThresholdError::DuplicateSigner));
+		assert_eq!(sign(&[0, 1]), Err(ThresholdError::ZeroIndex));
+		assert_eq!(sign(&[2, 3]), Err(ThresholdError::UnknownSigner));
+		assert!(sign(&[1, 3]).is_ok());
+	}
+}
@@ -0,0 +1,109 @@
+//! Bindings exposing the native EDDSA scheme to JavaScript. All of the values
+//! cross the boundary as hex strings:
+//! - secret key: `SecretKey::to_bytes`, 64 bytes
+//! - public key: `PublicKey::to_bytes`, 32 bytes
+//! - message: field element in little-endian, 32 bytes
+//! - signature: `Signature::to_bytes`, 64 bytes
+
+use super::{sign, verify, PublicKey, SecretKey, Signature};
+use halo2wrong::curves::bn256::Fr;
+use js_sys::{Object, Reflect};
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroizing;
+
+/// Decodes a hex string into exactly `N` bytes.
+fn decode_hex<const N: usize>(s: &str) -> Result<[u8; N], JsValue> {
+	let bytes = hex::decode(s).map_err(|e| JsValue::from_str(&e.to_string()))?;
+	bytes.try_into().map_err(|_| JsValue::from_str("Invalid length"))
+}
+
+/// Decodes a field element from its 32 byte little-endian representation.
+fn decode_fr(bytes: &[u8; 32]) -> Result<Fr, JsValue> {
+	Option::from(Fr::from_bytes(bytes)).ok_or_else(|| JsValue::from_str("Invalid field element"))
+}
+
+fn decode_sk(sk_hex: &str) -> Result<SecretKey, JsValue> {
+	let bytes = Zeroizing::new(decode_hex::<64>(sk_hex)?);
+	SecretKey::from_bytes(&bytes).ok_or_else(|| JsValue::from_str("Invalid secret key"))
+}
+
+fn decode_pk(pk_hex: &str) -> Result<PublicKey, JsValue> {
+	let bytes = decode_hex::<32>(pk_hex)?;
+	PublicKey::from_bytes(&bytes).ok_or_else(|| JsValue::from_str("Invalid public key"))
+}
+
+fn decode_sig(sig_hex: &str) -> Result<Signature, JsValue> {
+	let bytes = decode_hex::<64>(sig_hex)?;
+	Signature::from_bytes(&bytes).ok_or_else(|| JsValue::from_str("Invalid signature"))
+}
+
+/// Derives a key pair from the seed and returns it as an object with the
+/// `secret_key` and `public_key` hex fields.
+#[wasm_bindgen]
+pub fn wasm_keygen(seed: &[u8]) -> Result<JsValue, JsValue> {
+	let sk = SecretKey::from_seed(seed);
+	let pk = sk.public();
+
+	let keys = Object::new();
+	Reflect::set(
+		&keys,
+		&"secret_key".into(),
+		&hex::encode(sk.to_bytes()).into(),
+	)?;
+	Reflect::set(
+		&keys,
+		&"public_key".into(),
+		&hex::encode(pk.to_bytes()).into(),
+	)?;
+	Ok(keys.into())
+}
+
+/// Signs the message with the secret key and returns the hex encoded
+/// signature.
+#[wasm_bindgen]
+pub fn wasm_sign(sk_hex: &str, msg_hex: &str) -> Result<String, JsValue> {
+	let sk = decode_sk(sk_hex)?;
+	let pk = sk.public();
+	let m = decode_fr(&decode_hex::<32>(msg_hex)?)?;
+	let sig = sign(&sk, &pk, m);
+	Ok(hex::encode(sig.to_bytes()))
+}
+
+/// Verifies the signature of the message. Malformed inputs are reported as
+/// invalid signatures.
+#[wasm_bindgen]
+pub fn wasm_verify(pk_hex: &str, msg_hex: &str, sig_hex: &str) -> bool {
+	let inputs = decode_pk(pk_hex).and_then(|pk| {
+		let m = decode_fr(&decode_hex::<32>(msg_hex)?)?;
+		let sig = decode_sig(sig_hex)?;
+		Ok((pk, m, sig))
+	});
+	match inputs {
+		Ok((pk, m, sig)) => verify(&sig, &pk, m),
+		Err(_) => false,
+	}
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod test {
+	use super::*;
+	use wasm_bindgen_test::wasm_bindgen_test;
+
+	fn get_field(keys: &JsValue, name: &str) -> String {
+		Reflect::get(keys, &name.into()).unwrap().as_string().unwrap()
+	}
+
+	#[wasm_bindgen_test]
+	fn should_sign_and_verify() {
+		let keys = wasm_keygen(b"eigen-trust-seed").unwrap();
+		let sk_hex = get_field(&keys, "secret_key");
+		let pk_hex = get_field(&keys, "public_key");
+
+		let msg_hex = hex::encode(Fr::from(123456789).to_bytes());
+		let sig_hex = wasm_sign(&sk_hex, &msg_hex).unwrap();
+		assert!(wasm_verify(&pk_hex, &msg_hex, &sig_hex));
+
+		let other_msg_hex = hex::encode(Fr::from(987654321).to_bytes());
+		assert!(!wasm_verify(&pk_hex, &other_msg_hex, &sig_hex));
+	}
+}
@@ -0,0 +1,208 @@
+//! Self-describing envelope of the proofs, which identifies the circuit, `k`,
+//! the transcript and the public inputs a proof was made for, so a proof for
+//! another circuit version is rejected before the verification.
+
+use crate::{
+	error::Error, params::poseidon_bn254_5x5::Params, poseidon::native::sponge::hash_instances,
+	utils::vk_hash,
+};
+use halo2wrong::{
+	curves::{
+		bn256::{Fr, G1Affine},
+		group::ff::PrimeField,
+	},
+	halo2::plonk::VerifyingKey,
+};
+
+/// Magic bytes at the start of the envelope.
+const MAGIC: [u8; 4] = *b"ETPF";
+/// Version of the envelope format.
+pub const ENVELOPE_VERSION: u8 = 1;
+/// Size of the envelope without the proof.
+const HEADER_SIZE: usize = 4 + 1 + 1 + 4 + 32 + 32 + 4;
+
+/// The transcript a proof was made with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TranscriptKind {
+	/// Blake2b, used by `prove`.
+	Blake2b = 0,
+	/// Keccak256, used by `prove_evm`.
+	Keccak256 = 1,
+	/// Poseidon, used by the proofs to aggregate.
+	Poseidon = 2,
+}
+
+impl TranscriptKind {
+	/// Parses the transcript kind from its byte.
+	fn from_byte(byte: u8) -> Result<Self, Error> {
+		match byte {
+			0 => Ok(TranscriptKind::Blake2b),
+			1 => Ok(TranscriptKind::Keccak256),
+			2 => Ok(TranscriptKind::Poseidon),
+			_ => Err(Error::MalformedEnvelope),
+		}
+	}
+}
+
+/// A proof together with what it was made for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofEnvelope {
+	/// Hash of the verifying key, identifying the circuit, see [`vk_hash`].
+	pub vk_hash: [u8; 32],
+	/// The circuit has `2^k` rows.
+	pub k: u32,
+	/// The transcript of the proof.
+	pub transcript: TranscriptKind,
+	/// Commitment to the public inputs.
+	pub instances_hash: [u8; 32],
+	/// The raw proof.
+	pub proof: Vec<u8>,
+}
+
+impl ProofEnvelope {
+	/// Wrap the `proof` of the circuit with the verifying key `vk`, made
+	/// with `transcript` for the public inputs `pub_inps`.
+	pub fn new(
+		vk: &VerifyingKey<G1Affine>, transcript: TranscriptKind, pub_inps: &[&[Fr]], proof: Vec<u8>,
+	) -> Self {
+		Self {
+			vk_hash: vk_hash(vk),
+			k: vk.get_domain().k(),
+			transcript,
+			instances_hash: hash_instances::<_, Params>(pub_inps).to_repr(),
+			proof,
+		}
+	}
+
+	/// Encode the envelope: the magic bytes, the version, the transcript,
+	/// `k`, the hashes of the verifying key and the public inputs, and the
+	/// length prefixed proof.
+	pub fn encode(&self) -> Vec<u8> {
+		let mut bytes = Vec::with_capacity(HEADER_SIZE + self.proof.len());
+		bytes.extend_from_slice(&MAGIC);
+		bytes.push(ENVELOPE_VERSION);
+		bytes.push(self.transcript as u8);
+		bytes.extend_from_slice(&self.k.to_le_bytes());
+		bytes.extend_from_slice(&self.vk_hash);
+		bytes.extend_from_slice(&self.instances_hash);
+		bytes.extend_from_slice(&(self.proof.len() as u32).to_le_bytes());
+		bytes.extend_from_slice(&self.proof);
+		bytes
+	}
+
+	/// Decode the envelope, checking its magic bytes and version.
+	pub fn decode(bytes: &[u8]) -> Result<Self, Error> {
+		if bytes.len() < HEADER_SIZE || bytes[..4] != MAGIC || bytes[4] != ENVELOPE_VERSION {
+			return Err(Error::MalformedEnvelope);
+		}
+		let transcript = TranscriptKind::from_byte(bytes[5])?;
+		let k = u32::from_le_bytes(bytes[6..10].try_into().unwrap());
+		let vk_hash = bytes[10..42].try_into().unwrap();
+		let instances_hash = bytes[42..74].try_into().unwrap();
+		let proof_len = u32::from_le_bytes(bytes[74..78].try_into().unwrap()) as usize;
+		if bytes.len() - HEADER_SIZE != proof_len {
+			return Err(Error::MalformedEnvelope);
+		}
+		let proof = bytes[HEADER_SIZE..].to_vec();
+
+		Ok(Self { vk_hash, k, transcript, instances_hash, proof })
+	}
+
+	/// Check that the proof was made for the circuit with the verifying key
+	/// `vk`, with `transcript`, for the public inputs `pub_inps`.
+	pub fn check(
+		&self, vk: &VerifyingKey<G1Affine>, transcript: TranscriptKind, pub_inps: &[&[Fr]],
+	) -> Result<(), Error> {
+		let expected = Self::new(vk, transcript, pub_inps, Vec::new());
+		if self.vk_hash != expected.vk_hash
+			|| self.k != expected.k
+			|| self.transcript != expected.transcript
+			|| self.instances_hash != expected.instances_hash
+		{
+			return Err(Error::EnvelopeMismatch);
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::utils::{generate_params, keygen, prove, random_circuit, verify};
+	use halo2wrong::curves::bn256::Bn256;
+	use rand::thread_rng;
+
+	fn envelope() -> ProofEnvelope {
+		ProofEnvelope {
+			vk_hash: [1; 32],
+			k: 9,
+			transcript: TranscriptKind::Keccak256,
+			instances_hash: [2; 32],
+			proof: vec![3; 100],
+		}
+	}
+
+	#[test]
+	fn test_encode_decode() {
+		let envelope = envelope();
+		let bytes = envelope.encode();
+		assert_eq!(bytes.len(), HEADER_SIZE + 100);
+		assert_eq!(ProofEnvelope::decode(&bytes).unwrap(), envelope);
+	}
+
+	#[test]
+	fn test_decode_malformed() {
+		let bytes = envelope().encode();
+		let decode = |bytes: &[u8]| ProofEnvelope::decode(bytes);
+		assert!(matches!(
+			decode(&bytes[..HEADER_SIZE - 1]),
+			Err(Error::MalformedEnvelope)
+		));
+		assert!(matches!(
+			decode(&bytes[..bytes.len() - 1]),
+			Err(Error::MalformedEnvelope)
+		));
+
+		let mut wrong_version = bytes.clone();
+		wrong_version[4] = ENVELOPE_VERSION + 1;
+		assert!(matches!(
+			decode(&wrong_version),
+			Err(Error::MalformedEnvelope)
+		));
+
+		let mut wrong_transcript = bytes;
+		wrong_transcript[5] = 7;
+		assert!(matches!(
+			decode(&wrong_transcript),
+			Err(Error::MalformedEnvelope)
+		));
+	}
+
+	#[test]
+	fn test_check() {
+		const SIZE: usize = 256;
+		const NUM_BOOTSTRAP: usize = 12;
+		let rng = &mut thread_rng();
+		let params = generate_params::<Bn256>(9);
+		let circuit = random_circuit::<Bn256, _, SIZE, NUM_BOOTSTRAP, Params>(rng);
+		let pk = keygen(&params, &circuit).unwrap();
+		let pub_inps = [Fr::from(5)];
+		let proof = prove(&params, circuit, &[&pub_inps], &pk, rng).unwrap();
+
+		let bytes =
+			ProofEnvelope::new(pk.get_vk(), TranscriptKind::Blake2b, &[&pub_inps], proof).encode();
+		let envelope = ProofEnvelope::decode(&bytes).unwrap();
+		envelope.check(pk.get_vk(), TranscriptKind::Blake2b, &[&pub_inps]).unwrap();
+		// The proof of the random circuit doesn't verify, but it is checked
+		// against the right circuit.
+		assert!(!matches!(
+			verify(&params, &[&pub_inps], &envelope.proof, pk.get_vk()),
+			Ok(true)
+		));
+
+		let res = envelope.check(pk.get_vk(), TranscriptKind::Keccak256, &[&pub_inps]);
+		assert!(matches!(res, Err(Error::EnvelopeMismatch)));
+		let res = envelope.check(pk.get_vk(), TranscriptKind::Blake2b, &[&[Fr::from(6)]]);
+		assert!(matches!(res, Err(Error::EnvelopeMismatch)));
+	}
+}
@@ -0,0 +1,92 @@
+use halo2wrong::halo2::plonk;
+use std::{fmt, io};
+
+/// The reason why loading the parameters, or proving and verifying failed.
+#[derive(Debug)]
+pub enum Error {
+	/// Reading or writing a file failed.
+	Io(io::Error),
+	/// The parameters couldn't be decoded, e.g. the SRS file is corrupt.
+	ParamsDecode(io::Error),
+	/// The parameters have fewer rows than requested.
+	ParamsTooSmall,
+	/// The proving or verifying key couldn't be decoded.
+	KeyDecode(io::Error),
+	/// The key file is for another circuit, `k` or format version.
+	KeyMismatch,
+	/// There is no proving key for the circuit.
+	KeyNotFound,
+	/// Generating the proving or verifying key failed.
+	Keygen(plonk::Error),
+	/// Creating the proof failed.
+	Proving(plonk::Error),
+	/// The proof couldn't be verified.
+	Verification(plonk::Error),
+	/// The public inputs don't match the instance columns of the circuit.
+	InstanceMismatch,
+	/// The proof envelope is malformed.
+	MalformedEnvelope,
+	/// The proof envelope is for another circuit, `k`, transcript or public
+	/// inputs.
+	EnvelopeMismatch,
+	/// A public input was not set when building the instances.
+	MissingPublicInput(&'static str),
+	/// Generating the EVM verifier failed.
+	EvmVerifier(snark_verifier::Error),
+	/// The EVM verifier wouldn't be bound to the vk hash of the circuit.
+	VkHashMismatch,
+	/// Verifying the inner proofs for the aggregation failed.
+	Aggregation(snark_verifier::Error),
+	/// The network snapshot is malformed or of another format version.
+	MalformedNetwork,
+	/// The scores don't match the peers of the network, or don't sum to one.
+	InvalidScores,
+	/// A score or an opinion doesn't fit in the `SCORE_BITS` bits of the
+	/// fixed-point scores.
+	OutOfRange,
+}
+
+impl Error {
+	/// Wraps the error of the proving system with the given variant, unless
+	/// it is about the public inputs.
+	pub(crate) fn from_plonk(err: plonk::Error, variant: fn(plonk::Error) -> Self) -> Self {
+		match err {
+			plonk::Error::InvalidInstances => Error::InstanceMismatch,
+			err => variant(err),
+		}
+	}
+}
+
+impl From<io::Error> for Error {
+	fn from(err: io::Error) -> Self {
+		Error::Io(err)
+	}
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Error::Io(err) => write!(f, "IO error: {}", err),
+			Error::ParamsDecode(err) => write!(f, "Failed to decode the params: {}", err),
+			Error::ParamsTooSmall => write!(f, "The params have too few rows"),
+			Error::KeyDecode(err) => write!(f, "Failed to decode the key: {}", err),
+			Error::KeyMismatch => write!(f, "The key file doesn't match the circuit"),
+			Error::KeyNotFound => write!(f, "No proving key for the circuit"),
+			Error::Keygen(err) => write!(f, "Key generation failed: {}", err),
+			Error::Proving(err) => write!(f, "Proving failed: {}", err),
+			Error::Verification(err) => write!(f, "Verification failed: {}", err),
+			Error::InstanceMismatch => write!(f, "Public inputs don't match the circuit"),
+			Error::MalformedEnvelope => write!(f, "Malformed proof envelope"),
+			Error::EnvelopeMismatch => write!(f, "The proof envelope doesn't match the circuit"),
+			Error::MissingPublicInput(name) => write!(f, "Missing public input: {}", name),
+			Error::EvmVerifier(err) => write!(f, "EVM verifier generation failed: {:?}", err),
+			Error::VkHashMismatch => write!(f, "The EVM verifier doesn't match the vk hash"),
+			Error::Aggregation(err) => write!(f, "Aggregation failed: {:?}", err),
+			Error::MalformedNetwork => write!(f, "Malformed network snapshot"),
+			Error::InvalidScores => write!(f, "The scores aren't a distribution over the peers"),
+			Error::OutOfRange => write!(f, "A score or an opinion is out of range"),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,343 @@
+use halo2wrong::halo2::{
+	arithmetic::FieldExt,
+	circuit::{AssignedCell, Layouter, Region, Value},
+	plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+	poly::Rotation,
+};
+
+/// The order of a list of bits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+	/// The least significant bit comes first.
+	Little,
+	/// The most significant bit comes first.
+	Big,
+}
+
+/// Returns the lowest `num_bits` bits of the value, in the given order. The
+/// bits above the 256th are zero.
+pub fn num_to_bits<F: FieldExt>(value: F, num_bits: usize, endianness: Endianness) -> Vec<bool> {
+	let repr = value.to_repr();
+	let bytes = repr.as_ref();
+	let mut bits: Vec<bool> = (0..num_bits)
+		.map(|i| bytes.get(i / 8).map_or(false, |byte| byte & (1 << (i % 8)) != 0))
+		.collect();
+	if endianness == Endianness::Big {
+		bits.reverse();
+	}
+	bits
+}
+
+/// Returns the number with the bits, given in the order of `endianness`,
+/// reduced modulo the field order.
+pub fn bits_to_num<F: FieldExt>(bits: &[bool], endianness: Endianness) -> F {
+	let fold = |acc: F, bit: &bool| acc.double() + F::from(*bit);
+	match endianness {
+		Endianness::Little => bits.iter().rev().fold(F::zero(), fold),
+		Endianness::Big => bits.iter().fold(F::zero(), fold),
+	}
+}
+
+/// Configuration elements for the circuit defined here.
+#[derive(Clone, Debug)]
+pub struct BitsConfig {
+	/// Configures a column for the bits.
+	bits: Column<Advice>,
+	/// Configures a column for the number composed from the bits so far.
+	acc: Column<Advice>,
+	/// Configures a fixed boolean value for each row of the circuit.
+	selector: Selector,
+}
+
+impl BitsConfig {
+	/// The column of the bits, e.g. to copy them into another chip. This was
+	/// the public `bits` field of the old `Bits2NumConfig`.
+	pub fn bits(&self) -> Column<Advice> {
+		self.bits
+	}
+}
+
+/// Decomposes numbers into bits and composes them back, for any number of
+/// bits and in either order.
+pub struct BitsChip;
+
+impl BitsChip {
+	/// Make the circuit config.
+	pub fn configure<F: FieldExt>(meta: &mut ConstraintSystem<F>) -> BitsConfig {
+		let bits = meta.advice_column();
+		let acc = meta.advice_column();
+		let fixed = meta.fixed_column();
+		let s = meta.selector();
+
+		meta.enable_equality(bits);
+		meta.enable_equality(acc);
+		meta.enable_constant(fixed);
+
+		meta.create_gate("bits", |v_cells| {
+			let one_exp = Expression::Constant(F::one());
+			let bit_exp = v_cells.query_advice(bits, Rotation::cur());
+			let acc_exp = v_cells.query_advice(acc, Rotation::cur());
+			let acc_next_exp = v_cells.query_advice(acc, Rotation::next());
+			let s_exp = v_cells.query_selector(s);
+
+			vec![
+				// bit * (1 - bit) == 0
+				s_exp.clone() * (bit_exp.clone() * (one_exp - bit_exp.clone())),
+				// acc + acc + bit == acc_next
+				// The bits are added from the most significant one.
+				s_exp * (acc_exp.clone() + acc_exp + bit_exp - acc_next_exp),
+			]
+		});
+
+		BitsConfig { bits, acc, selector: s }
+	}
+
+	/// Decompose the value into `num_bits` bits, in the given order. With 254
+	/// or more bits, the bits may also compose to the value plus the field
+	/// order, so a unique decomposition needs an extra comparison.
+	pub fn to_bits<F: FieldExt>(
+		value: AssignedCell<F, F>, num_bits: usize, endianness: Endianness, config: BitsConfig,
+		layouter: impl Layouter<F>,
+	) -> Result<Vec<AssignedCell<F, F>>, Error> {
+		let native_bits = value.value().map(|v| num_to_bits(*v, num_bits, endianness));
+		let bits = (0..num_bits)
+			.map(|i| native_bits.as_ref().map(|bits| F::from(bits[i])))
+			.collect::<Vec<_>>();
+		Self::decompose(value, &bits, endianness, config, layouter)
+	}
+
+	/// Constrain the bits, given by the prover in the order of `endianness`,
+	/// to compose to the value.
+	pub fn decompose<F: FieldExt>(
+		value: AssignedCell<F, F>, bits: &[Value<F>], endianness: Endianness, config: BitsConfig,
+		mut layouter: impl Layouter<F>,
+	) -> Result<Vec<AssignedCell<F, F>>, Error> {
+		layouter.assign_region(
+			|| "decompose",
+			|mut region: Region<'_, F>| {
+				let mut assigned = Vec::new();
+				let acc = Self::compose(&mut region, bits.len(), &config, |i, column, region| {
+					let bit = bits[Self::index(i, bits.len(), endianness)];
+					let bit = region.assign_advice(|| "bit", column, i, || bit)?;
+					assigned.push(bit.clone());
+					Ok(bit)
+				})?;
+				region.constrain_equal(value.cell(), acc.cell())?;
+
+				if endianness == Endianness::Little {
+					assigned.reverse();
+				}
+				Ok(assigned)
+			},
+		)
+	}
+
+	/// Compose the bits, given in the order of `endianness`, into a number.
+	pub fn from_bits<F: FieldExt>(
+		bits: &[AssignedCell<F, F>], endianness: Endianness, config: BitsConfig,
+		mut layouter: impl Layouter<F>,
+	) -> Result<AssignedCell<F, F>, Error> {
+		layouter.assign_region(
+			|| "compose",
+			|mut region: Region<'_, F>| {
+				Self::compose(&mut region, bits.len(), &config, |i, column, region| {
+					let bit = &bits[Self::index(i, bits.len(), endianness)];
+					bit.copy_advice(|| "bit", region, column, i)
+				})
+			},
+		)
+	}
+
+	/// Assign the running sum of `num_bits` bits, placing the i-th most
+	/// significant bit with `assign_bit`. Returns the composed number.
+	fn compose<F: FieldExt>(
+		region: &mut Region<'_, F>, num_bits: usize, config: &BitsConfig,
+		mut assign_bit: impl FnMut(
+			usize,
+			Column<Advice>,
+			&mut Region<'_, F>,
+		) -> Result<AssignedCell<F, F>, Error>,
+	) -> Result<AssignedCell<F, F>, Error> {
+		let mut acc = region.assign_advice_from_constant(|| "acc_0", config.acc, 0, F::zero())?;
+		for i in 0..num_bits {
+			config.selector.enable(region, i)?;
+			let bit = assign_bit(i, config.bits, region)?;
+			let next_acc = acc.value().copied() + acc.value() + bit.value();
+			acc = region.assign_advice(|| "acc", config.acc, i + 1, || next_acc)?;
+		}
+		Ok(acc)
+	}
+
+	/// Position of the i-th most significant bit in a list of `num_bits` bits.
+	fn index(i: usize, num_bits: usize, endianness: Endianness) -> usize {
+		match endianness {
+			Endianness::Little => num_bits - 1 - i,
+			Endianness::Big => i,
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::utils::{generate_params, prove_and_verify};
+	use halo2wrong::{
+		curves::bn256::{Bn256, Fr},
+		halo2::{
+			circuit::SimpleFloorPlanner,
+			dev::MockProver,
+			plonk::{Circuit, Instance},
+		},
+	};
+
+	#[derive(Clone)]
+	struct TestConfig {
+		bits: BitsConfig,
+		temp: Column<Advice>,
+		pub_ins: Column<Instance>,
+	}
+
+	#[derive(Clone)]
+	struct TestCircuit {
+		value: Fr,
+		num_bits: usize,
+		endianness: Endianness,
+	}
+
+	impl TestCircuit {
+		fn new(value: Fr, num_bits: usize, endianness: Endianness) -> Self {
+			Self { value, num_bits, endianness }
+		}
+	}
+
+	impl Circuit<Fr> for TestCircuit {
+		type Config = TestConfig;
+		type FloorPlanner = SimpleFloorPlanner;
+
+		fn without_witnesses(&self) -> Self {
+			self.clone()
+		}
+
+		fn configure(meta: &mut ConstraintSystem<Fr>) -> TestConfig {
+			let bits = BitsChip::configure(meta);
+			let temp = meta.advice_column();
+			let pub_ins = meta.instance_column();
+
+			meta.enable_equality(temp);
+			meta.enable_equality(pub_ins);
+
+			TestConfig { bits, temp, pub_ins }
+		}
+
+		fn synthesize(
+			&self, config: TestConfig, mut layouter: impl Layouter<Fr>,
+		) -> Result<(), Error> {
+			let value = layouter.assign_region(
+				|| "temp",
+				|mut region: Region<'_, Fr>| {
+					region.assign_advice(|| "value", config.temp, 0, || Value::known(self.value))
+				},
+			)?;
+			let bits = BitsChip::to_bits(
+				value,
+				self.num_bits,
+				self.endianness,
+				config.bits.clone(),
+				layouter.namespace(|| "to_bits"),
+			)?;
+			for (i, bit) in bits.iter().enumerate() {
+				layouter.constrain_instance(bit.cell(), config.pub_ins, i)?;
+			}
+
+			let num = BitsChip::from_bits(
+				&bits,
+				self.endianness,
+				config.bits,
+				layouter.namespace(|| "from_bits"),
+			)?;
+			layouter.constrain_instance(num.cell(), config.pub_ins, bits.len())?;
+			Ok(())
+		}
+	}
+
+	fn pub_ins(value: Fr, num_bits: usize, endianness: Endianness) -> Vec<Fr> {
+		let bits = num_to_bits(value, num_bits, endianness);
+		let mut pub_ins: Vec<Fr> = bits.iter().map(|b| Fr::from(*b)).collect();
+		pub_ins.push(bits_to_num(&bits, endianness));
+		pub_ins
+	}
+
+	#[test]
+	fn test_native_bits() {
+		let bits = num_to_bits(Fr::from(6), 4, Endianness::Little);
+		assert_eq!(bits, vec![false, true, true, false]);
+		let bits = num_to_bits(Fr::from(6), 4, Endianness::Big);
+		assert_eq!(bits, vec![false, true, true, false]);
+		let bits = num_to_bits(Fr::from(1), 3, Endianness::Big);
+		assert_eq!(bits, vec![false, false, true]);
+
+		let value = Fr::from(1311768467294899695u64);
+		for endianness in [Endianness::Little, Endianness::Big] {
+			let bits = num_to_bits(value, 254, endianness);
+			assert_eq!(bits_to_num::<Fr>(&bits, endianness), value);
+		}
+	}
+
+	#[test]
+	fn test_bits_little_endian() {
+		let value = Fr::from(1311768467294899695u64);
+		let circuit = TestCircuit::new(value, 64, Endianness::Little);
+		let k = 8;
+		let pub_ins = pub_ins(value, 64, Endianness::Little);
+		let prover = MockProver::run(k, &circuit, vec![pub_ins]).unwrap();
+
+		assert_eq!(prover.verify(), Ok(()));
+	}
+
+	#[test]
+	fn test_bits_big_endian() {
+		let value = Fr::from(1311768467294899695u64);
+		let circuit = TestCircuit::new(value, 64, Endianness::Big);
+		let k = 8;
+		let pub_ins = pub_ins(value, 64, Endianness::Big);
+		let prover = MockProver::run(k, &circuit, vec![pub_ins]).unwrap();
+
+		assert_eq!(prover.verify(), Ok(()));
+	}
+
+	#[test]
+	fn test_bits_full_width() {
+		let value = Fr::zero() - Fr::one();
+		let circuit = TestCircuit::new(value, 254, Endianness::Little);
+		let k = 10;
+		let pub_ins = pub_ins(value, 254, Endianness::Little);
+		let prover = MockProver::run(k, &circuit, vec![pub_ins]).unwrap();
+
+		assert_eq!(prover.verify(), Ok(()));
+	}
+
+	#[test]
+	fn test_bits_too_few() {
+		// 256 doesn't fit in 8 bits.
+		let value = Fr::from(256);
+		let circuit = TestCircuit::new(value, 8, Endianness::Little);
+		let k = 8;
+		let pub_ins = pub_ins(value, 8, Endianness::Little);
+		let prover = MockProver::run(k, &circuit, vec![pub_ins]).unwrap();
+
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_bits_production() {
+		let value = Fr::from(1311768467294899695u64);
+		let circuit = TestCircuit::new(value, 64, Endianness::Big);
+		let k = 8;
+		let rng = &mut rand::thread_rng();
+		let params = generate_params(k);
+		let pub_ins = pub_ins(value, 64, Endianness::Big);
+		let res = prove_and_verify::<Bn256, _, _>(params, circuit, &[&pub_ins], rng).unwrap();
+
+		assert!(res);
+	}
+}
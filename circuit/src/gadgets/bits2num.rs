@@ -1,8 +1,8 @@
+use super::bits::{BitsChip, BitsConfig, Endianness};
 use halo2wrong::halo2::{
 	arithmetic::FieldExt,
-	circuit::{AssignedCell, Layouter, Region, Value},
-	plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
-	poly::Rotation,
+	circuit::{AssignedCell, Layouter, Value},
+	plonk::{ConstraintSystem, Error},
 };
 
 /// Converts given bytes to the bits.
@@ -14,19 +14,21 @@ pub fn to_bits<const B: usize>(num: [u8; 32]) -> [bool; B] {
 	bits
 }
 
-/// Configuration elements for the circuit defined here.
-#[derive(Clone)]
-pub struct Bits2NumConfig {
-	/// Configures a column for the bits.
-	pub bits: Column<Advice>,
-	/// Configures a column for the lc1.
-	lc1: Column<Advice>,
-	/// Configures a column for the e2.
-	e2: Column<Advice>,
-	/// Configures a fixed boolean value for each row of the circuit.
-	selector: Selector,
+/// Converts the value to the bits as field elements, or zeros if the value is
+/// unknown, e.g. during the key generation.
+pub fn value_to_bits<F: FieldExt, const B: usize>(value: Value<&F>) -> [F; B] {
+	let mut bits = [F::zero(); B];
+	value.map(|value| {
+		let mut bytes = [0; 32];
+		bytes.copy_from_slice(value.to_repr().as_ref());
+		bits = to_bits::<B>(bytes).map(|b| F::from(b));
+	});
+	bits
 }
 
+/// Configuration elements for the circuit defined here.
+pub type Bits2NumConfig = BitsConfig;
+
 /// Constructs a cell and a variable for the circuit.
 #[derive(Clone)]
 pub struct Bits2NumChip<F: FieldExt, const B: usize> {
@@ -44,86 +46,22 @@ impl<F: FieldExt, const B: usize> Bits2NumChip<F, B> {
 
 	/// Make the circuit config.
 	pub fn configure(meta: &mut ConstraintSystem<F>) -> Bits2NumConfig {
-		let bits = meta.advice_column();
-		let lc1 = meta.advice_column();
-		let e2 = meta.advice_column();
-		let fixed = meta.fixed_column();
-		let s = meta.selector();
-
-		meta.enable_equality(bits);
-		meta.enable_equality(lc1);
-		meta.enable_equality(e2);
-		meta.enable_constant(fixed);
-
-		meta.create_gate("bits2num", |v_cells| {
-			let one_exp = Expression::Constant(F::one());
-			let bit_exp = v_cells.query_advice(bits, Rotation::cur());
-
-			let e2_exp = v_cells.query_advice(e2, Rotation::cur());
-			let e2_next_exp = v_cells.query_advice(e2, Rotation::next());
-
-			let lc1_exp = v_cells.query_advice(lc1, Rotation::cur());
-			let lc1_next_exp = v_cells.query_advice(lc1, Rotation::next());
-
-			let s_exp = v_cells.query_selector(s);
-
-			vec![
-				// bit * (1 - bit) == 0
-				// Constraining bit to be a boolean.
-				s_exp.clone() * (bit_exp.clone() * (one_exp - bit_exp.clone())),
-				// e2 + e2 == e2_next
-				// Starting from 1, doubling.
-				s_exp.clone() * ((e2_exp.clone() + e2_exp.clone()) - e2_next_exp),
-				// lc1 + bit * e2 == lc1_next
-				// If the bit is equal to 1, e2 will be added to the sum.
-				// Example:
-				// bit = 1
-				// e2 = 1 (first rotation)
-				// lc1 = 0
-				// If the bit == 1, double the e2.
-				// This will be used in the next rotation, if bit == 1 again. (e2_next = 1 + 1 = 2)
-				//
-				// Check the constraint => (1 * 1 + 0)
-				// lc1_next = 1
-				s_exp * ((bit_exp * e2_exp + lc1_exp) - lc1_next_exp),
-			]
-		});
-
-		Bits2NumConfig { bits, lc1, e2, selector: s }
+		BitsChip::configure(meta)
 	}
 
-	/// Synthesize the circuit.
+	/// Synthesize the circuit, returning the bits from the least significant
+	/// one.
 	pub fn synthesize(
-		&self, config: Bits2NumConfig, mut layouter: impl Layouter<F>,
+		&self, config: Bits2NumConfig, layouter: impl Layouter<F>,
 	) -> Result<[AssignedCell<F, F>; B], Error> {
-		layouter.assign_region(
-			|| "bits2num",
-			|mut region: Region<'_, F>| {
-				let mut lc1 =
-					region.assign_advice_from_constant(|| "lc1_0", config.lc1, 0, F::zero())?;
-				let mut e2 =
-					region.assign_advice_from_constant(|| "e2_0", config.e2, 0, F::one())?;
-
-				let mut bits: [Option<AssignedCell<F, F>>; B] = [(); B].map(|_| None);
-				for i in 0..self.bits.len() {
-					config.selector.enable(&mut region, i)?;
-
-					let bit = region.assign_advice(|| "bits", config.bits, i, || self.bits[i])?;
-					bits[i] = Some(bit.clone());
-
-					let next_lc1 =
-						lc1.value().cloned() + bit.value().cloned() * e2.value().cloned();
-					let next_e2 = e2.value().cloned() + e2.value();
-
-					lc1 = region.assign_advice(|| "lc1", config.lc1, i + 1, || next_lc1)?;
-					e2 = region.assign_advice(|| "e2", config.e2, i + 1, || next_e2)?;
-				}
-
-				region.constrain_equal(self.value.cell(), lc1.cell())?;
-
-				Ok(bits.map(|b| b.unwrap()))
-			},
-		)
+		let bits = BitsChip::decompose(
+			self.value.clone(),
+			&self.bits,
+			Endianness::Little,
+			config,
+			layouter,
+		)?;
+		Ok(bits.try_into().unwrap())
 	}
 }
 
@@ -133,7 +71,11 @@ mod test {
 	use crate::utils::{generate_params, prove_and_verify};
 	use halo2wrong::{
 		curves::bn256::{Bn256, Fr},
-		halo2::{circuit::SimpleFloorPlanner, dev::MockProver, plonk::Circuit},
+		halo2::{
+			circuit::{Region, SimpleFloorPlanner},
+			dev::MockProver,
+			plonk::{Advice, Circuit, Column},
+		},
 	};
 
 	#[derive(Clone)]
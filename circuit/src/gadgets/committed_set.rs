@@ -0,0 +1,269 @@
+use super::common::{CommonChip, CommonConfig};
+use crate::{
+	merkle::{native::PoseidonMerkleTree, MerkleChip, MerkleConfig},
+	params::poseidon_bn254_5x5::Params,
+	poseidon::{native::Poseidon, PoseidonChip, PoseidonConfig},
+};
+use halo2wrong::{
+	curves::bn256::Fr,
+	halo2::{
+		arithmetic::Field,
+		circuit::{AssignedCell, Layouter, Region},
+		plonk::{Advice, Column, ConstraintSystem, Error},
+	},
+};
+
+/// Returns the leaf of the set tree holding the `member`. The members are
+/// hashed, so the zero leaves padding the tree are not members.
+pub fn member_leaf(member: Fr) -> Fr {
+	Poseidon::<_, 5, Params>::new([member, Fr::zero(), Fr::zero(), Fr::zero(), Fr::zero()])
+		.permute()[0]
+}
+
+/// Builds the tree committing to the set of `members`, e.g. the bootstrap
+/// peers. Its root is the commitment to the set.
+pub fn commit_set(members: &[Fr]) -> PoseidonMerkleTree {
+	PoseidonMerkleTree::new(members.iter().map(|member| member_leaf(*member)).collect())
+}
+
+#[derive(Clone, Debug)]
+/// Configuration elements for the circuit are defined here.
+pub struct CommittedSetConfig {
+	/// Constructs the Merkle circuit elements.
+	merkle: MerkleConfig,
+	/// Constructs the Poseidon circuit elements, used for hashing the member.
+	poseidon: PoseidonConfig<5>,
+	/// Constructs the common circuit elements, used for comparing the roots.
+	common: CommonConfig,
+	/// Configures a column for the constants.
+	temp: Column<Advice>,
+}
+
+/// Constructs a chip checking that the member is in the set committed to by
+/// the root of `commit_set`. The set has at most `2^DEPTH` members.
+pub struct CommittedSetChip<const DEPTH: usize> {
+	/// Assigns a cell for the member.
+	member: AssignedCell<Fr, Fr>,
+	/// Assigns a cell for the root of the set.
+	root: AssignedCell<Fr, Fr>,
+	/// Assigns cells for the bits of the index of the member.
+	path_bits: [AssignedCell<Fr, Fr>; DEPTH],
+	/// Assigns cells for the siblings on the path from the member to the root.
+	siblings: [AssignedCell<Fr, Fr>; DEPTH],
+}
+
+impl<const DEPTH: usize> CommittedSetChip<DEPTH> {
+	/// Create a new chip.
+	pub fn new(
+		member: AssignedCell<Fr, Fr>, root: AssignedCell<Fr, Fr>,
+		path_bits: [AssignedCell<Fr, Fr>; DEPTH], siblings: [AssignedCell<Fr, Fr>; DEPTH],
+	) -> Self {
+		CommittedSetChip { member, root, path_bits, siblings }
+	}
+
+	/// Make the circuit config.
+	pub fn configure(meta: &mut ConstraintSystem<Fr>) -> CommittedSetConfig {
+		let merkle = MerkleChip::<DEPTH>::configure(meta);
+		let poseidon = PoseidonChip::<_, 5, Params>::configure(meta);
+		let common = CommonChip::configure(meta);
+		let temp = meta.advice_column();
+		let fixed = meta.fixed_column();
+
+		meta.enable_equality(temp);
+		meta.enable_constant(fixed);
+
+		CommittedSetConfig { merkle, poseidon, common, temp }
+	}
+
+	/// Synthesize the circuit, returning 1 if the path leads from the member
+	/// to the root, and 0 otherwise. Constrain it to 1 to prove the membership.
+	pub fn synthesize(
+		&self, config: CommittedSetConfig, mut layouter: impl Layouter<Fr>,
+	) -> Result<AssignedCell<Fr, Fr>, Error> {
+		let zero = layouter.assign_region(
+			|| "assign_zero",
+			|mut region: Region<'_, Fr>| {
+				region.assign_advice_from_constant(|| "zero", config.temp, 0, Fr::zero())
+			},
+		)?;
+
+		let inputs = [self.member.clone(), zero.clone(), zero.clone(), zero.clone(), zero];
+		let hasher = PoseidonChip::<_, 5, Params>::new(inputs);
+		let leaf = hasher.synthesize(config.poseidon, layouter.namespace(|| "leaf"))?[0].clone();
+
+		let merkle = MerkleChip::new(leaf, self.path_bits.clone(), self.siblings.clone());
+		let root = merkle.synthesize(config.merkle, layouter.namespace(|| "merkle"))?;
+
+		CommonChip::is_equal(
+			root,
+			self.root.clone(),
+			config.common,
+			layouter.namespace(|| "is_eq"),
+		)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::utils::{generate_params, prove_and_verify};
+	use halo2wrong::{
+		curves::bn256::Bn256,
+		halo2::{
+			circuit::{SimpleFloorPlanner, Value},
+			dev::MockProver,
+			plonk::{Circuit, Instance},
+		},
+	};
+	use rand::thread_rng;
+
+	const DEPTH: usize = 3;
+
+	#[derive(Clone)]
+	struct TestConfig {
+		set: CommittedSetConfig,
+		temp: Column<Advice>,
+		pub_ins: Column<Instance>,
+	}
+
+	#[derive(Clone)]
+	struct TestCircuit {
+		member: Fr,
+		path_bits: [Fr; DEPTH],
+		siblings: [Fr; DEPTH],
+	}
+
+	impl TestCircuit {
+		fn new(member: Fr, index: usize, siblings: &[Fr]) -> Self {
+			let path_bits = [0, 1, 2].map(|i| Fr::from(((index >> i) & 1) as u64));
+			Self { member, path_bits, siblings: siblings.try_into().unwrap() }
+		}
+	}
+
+	impl Circuit<Fr> for TestCircuit {
+		type Config = TestConfig;
+		type FloorPlanner = SimpleFloorPlanner;
+
+		fn without_witnesses(&self) -> Self {
+			self.clone()
+		}
+
+		fn configure(meta: &mut ConstraintSystem<Fr>) -> TestConfig {
+			let set = CommittedSetChip::<DEPTH>::configure(meta);
+			let temp = meta.advice_column();
+			let pub_ins = meta.instance_column();
+
+			meta.enable_equality(temp);
+			meta.enable_equality(pub_ins);
+
+			TestConfig { set, temp, pub_ins }
+		}
+
+		fn synthesize(
+			&self, config: TestConfig, mut layouter: impl Layouter<Fr>,
+		) -> Result<(), Error> {
+			let (member, root, path_bits, siblings) = layouter.assign_region(
+				|| "temp",
+				|mut region: Region<'_, Fr>| {
+					let member = region.assign_advice(
+						|| "member",
+						config.temp,
+						0,
+						|| Value::known(self.member),
+					)?;
+					let root = region.assign_advice_from_instance(
+						|| "root",
+						config.pub_ins,
+						0,
+						config.temp,
+						1,
+					)?;
+					let mut path_bits = Vec::new();
+					let mut siblings = Vec::new();
+					for i in 0..DEPTH {
+						path_bits.push(region.assign_advice(
+							|| "path_bit",
+							config.temp,
+							2 + i,
+							|| Value::known(self.path_bits[i]),
+						)?);
+						siblings.push(region.assign_advice(
+							|| "sibling",
+							config.temp,
+							2 + DEPTH + i,
+							|| Value::known(self.siblings[i]),
+						)?);
+					}
+					Ok((member, root, path_bits, siblings))
+				},
+			)?;
+
+			let set = CommittedSetChip::<DEPTH>::new(
+				member,
+				root,
+				path_bits.try_into().unwrap(),
+				siblings.try_into().unwrap(),
+			);
+			let is_member = set.synthesize(config.set, layouter.namespace(|| "set"))?;
+			layouter.constrain_instance(is_member.cell(), config.pub_ins, 1)?;
+			Ok(())
+		}
+	}
+
+	fn members() -> Vec<Fr> {
+		let rng = &mut thread_rng();
+		(0..6).map(|_| Fr::random(&mut *rng)).collect()
+	}
+
+	#[test]
+	fn test_committed_set_member() {
+		let members = members();
+		let tree = commit_set(&members);
+		for index in [0, 3, 5] {
+			let proof = tree.proof(index);
+			let circuit = TestCircuit::new(members[index], index, &proof.siblings);
+			let pub_ins = vec![tree.root(), Fr::one()];
+			let prover = MockProver::run(11, &circuit, vec![pub_ins]).unwrap();
+			assert_eq!(prover.verify(), Ok(()));
+		}
+	}
+
+	#[test]
+	fn test_committed_set_not_member() {
+		let members = members();
+		let tree = commit_set(&members);
+		let proof = tree.proof(2);
+		let circuit = TestCircuit::new(Fr::random(thread_rng()), 2, &proof.siblings);
+
+		let prover = MockProver::run(11, &circuit, vec![vec![tree.root(), Fr::zero()]]).unwrap();
+		assert_eq!(prover.verify(), Ok(()));
+		let prover = MockProver::run(11, &circuit, vec![vec![tree.root(), Fr::one()]]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_committed_set_padding() {
+		// The padding leaves of the tree are not members, even for zero.
+		let members = members();
+		let tree = commit_set(&members);
+		let proof = tree.proof(7);
+		let circuit = TestCircuit::new(Fr::zero(), 7, &proof.siblings);
+
+		let prover = MockProver::run(11, &circuit, vec![vec![tree.root(), Fr::one()]]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_committed_set_production() {
+		let members = members();
+		let tree = commit_set(&members);
+		let proof = tree.proof(4);
+		let circuit = TestCircuit::new(members[4], 4, &proof.siblings);
+
+		let params = generate_params(11);
+		let pub_ins = [tree.root(), Fr::one()];
+		let res = prove_and_verify::<Bn256, _, _>(params, circuit, &[&pub_ins], &mut thread_rng())
+			.unwrap();
+		assert!(res);
+	}
+}
@@ -0,0 +1,659 @@
+use super::range::{RangeChip, RangeConfig};
+use halo2wrong::halo2::{
+	arithmetic::FieldExt,
+	circuit::{AssignedCell, Layouter, Region, Value},
+	plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+	poly::Rotation,
+};
+use std::{
+	marker::PhantomData,
+	ops::{Add, Div, Mul, Sub},
+};
+
+/// Number of bits of the fixed-point numbers, which are non-negative and
+/// below `2^NUM_BITS`.
+pub const NUM_BITS: usize = 64;
+/// Number of bits of the limbs of the range checks.
+const LIMB_BITS: usize = 8;
+
+/// Converts a non-negative number to a fixed-point number with `FRAC_BITS`
+/// fractional bits, rounding it to the closest one.
+pub fn to_fixed<F: FieldExt, const FRAC_BITS: usize>(x: f64) -> F {
+	let scaled = (x * (1u64 << FRAC_BITS) as f64).round();
+	F::from(scaled as u64)
+}
+
+/// Converts a fixed-point number with `FRAC_BITS` fractional bits back to a
+/// float. Only the lowest 128 bits of the number are read.
+pub fn from_fixed<F: FieldExt, const FRAC_BITS: usize>(x: F) -> f64 {
+	let mut bytes = [0; 16];
+	bytes.copy_from_slice(&x.to_repr().as_ref()[..16]);
+	u128::from_le_bytes(bytes) as f64 / (1u64 << FRAC_BITS) as f64
+}
+
+/// Divides the fixed-point numbers with `FRAC_BITS` fractional bits,
+/// rounding the quotient down like the `div` of the chip. Returns zero if `y`
+/// is zero.
+pub fn div_fixed<F: FieldExt, const FRAC_BITS: usize>(x: F, y: F) -> F {
+	div_rem(x * F::from_u128(1 << FRAC_BITS), y).0
+}
+
+/// A non-negative fixed-point number with `FRAC_BITS` fractional bits, for
+/// native computations matching the chip bit for bit: the products and
+/// quotients are rounded down like the `mul` and `div` of the chip. The
+/// operators panic on an overflow or a negative difference, like the integer
+/// ones in debug builds, but in every build; the `checked_*` methods return
+/// `None` instead. The chip only holds numbers below `2^NUM_BITS`, far below
+/// the overflow of the products.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<const FRAC_BITS: usize>(u128);
+
+impl<const FRAC_BITS: usize> Fixed<FRAC_BITS> {
+	/// The fixed-point number one.
+	pub const ONE: Self = Self(1 << FRAC_BITS);
+	/// The fixed-point number zero.
+	pub const ZERO: Self = Self(0);
+
+	/// Create a fixed-point number from its integer representation, i.e. the
+	/// number times `2^FRAC_BITS`.
+	pub fn from_raw(raw: u128) -> Self {
+		Self(raw)
+	}
+
+	/// Returns the integer representation of the number.
+	pub fn raw(&self) -> u128 {
+		self.0
+	}
+
+	/// Converts a non-negative float, rounding it to the closest fixed-point
+	/// number, like `to_fixed`.
+	pub fn from_f64(x: f64) -> Self {
+		Self((x * (1u64 << FRAC_BITS) as f64).round() as u128)
+	}
+
+	/// Converts the number back to a float, like `from_fixed`.
+	pub fn to_f64(&self) -> f64 {
+		self.0 as f64 / (1u64 << FRAC_BITS) as f64
+	}
+
+	/// Reads the number from a field element. Only the lowest 128 bits of the
+	/// element are read.
+	pub fn from_field<F: FieldExt>(x: F) -> Self {
+		let mut bytes = [0; 16];
+		bytes.copy_from_slice(&x.to_repr().as_ref()[..16]);
+		Self(u128::from_le_bytes(bytes))
+	}
+
+	/// Returns the number as a field element, as assigned in the chip.
+	pub fn to_field<F: FieldExt>(&self) -> F {
+		F::from_u128(self.0)
+	}
+
+	/// Returns the absolute difference of the numbers.
+	pub fn abs_diff(self, other: Self) -> Self {
+		Self(self.0.abs_diff(other.0))
+	}
+
+	/// Adds the numbers, or returns `None` on an overflow.
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		self.0.checked_add(rhs.0).map(Self)
+	}
+
+	/// Subtracts the numbers, or returns `None` if the difference is
+	/// negative.
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		self.0.checked_sub(rhs.0).map(Self)
+	}
+
+	/// Multiplies the numbers, rounding the product down, or returns `None`
+	/// on an overflow.
+	pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+		self.0.checked_mul(rhs.0).map(|product| Self(product >> FRAC_BITS))
+	}
+
+	/// Divides the numbers, rounding the quotient down, or returns `None` if
+	/// `rhs` is zero or on an overflow.
+	pub fn checked_div(self, rhs: Self) -> Option<Self> {
+		self.0.checked_mul(1 << FRAC_BITS).and_then(|x| x.checked_div(rhs.0)).map(Self)
+	}
+}
+
+impl<const FRAC_BITS: usize> Add for Fixed<FRAC_BITS> {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self {
+		self.checked_add(rhs).expect("fixed-point addition overflowed")
+	}
+}
+
+impl<const FRAC_BITS: usize> Sub for Fixed<FRAC_BITS> {
+	type Output = Self;
+
+	fn sub(self, rhs: Self) -> Self {
+		self.checked_sub(rhs).expect("negative fixed-point difference")
+	}
+}
+
+impl<const FRAC_BITS: usize> Mul for Fixed<FRAC_BITS> {
+	type Output = Self;
+
+	/// Multiplies the numbers, rounding the product down.
+	fn mul(self, rhs: Self) -> Self {
+		self.checked_mul(rhs).expect("fixed-point multiplication overflowed")
+	}
+}
+
+impl<const FRAC_BITS: usize> Div for Fixed<FRAC_BITS> {
+	type Output = Self;
+
+	/// Divides the numbers, rounding the quotient down. Returns zero if `rhs`
+	/// is zero, like `div_fixed`.
+	fn div(self, rhs: Self) -> Self {
+		let x = self.0.checked_mul(1 << FRAC_BITS).expect("fixed-point division overflowed");
+		Self(x.checked_div(rhs.0).unwrap_or(0))
+	}
+}
+
+/// Returns the quotient and the remainder of the division of the integers.
+fn div_rem<F: FieldExt>(x: F, y: F) -> (F, F) {
+	let to_u128 = |x: F| {
+		let mut bytes = [0; 16];
+		bytes.copy_from_slice(&x.to_repr().as_ref()[..16]);
+		u128::from_le_bytes(bytes)
+	};
+	let (x, y) = (to_u128(x), to_u128(y));
+	if y == 0 {
+		return (F::zero(), F::zero());
+	}
+	(F::from_u128(x / y), F::from_u128(x % y))
+}
+
+#[derive(Clone)]
+/// Configuration elements for the circuit are defined here.
+pub struct FixedPointConfig {
+	/// Configures the range chip.
+	range: RangeConfig,
+	/// Configures columns for the advice.
+	advice: [Column<Advice>; 4],
+	/// Configures fixed boolean values for each row of the circuit.
+	selectors: [Selector; 4],
+}
+
+/// Structure for the fixed-point chip, working with non-negative numbers
+/// with `FRAC_BITS` fractional bits. The results are range checked to
+/// `NUM_BITS` bits, so the inputs are expected to be range checked too.
+pub struct FixedPointChip<F: FieldExt, const FRAC_BITS: usize> {
+	/// Constructs a phantom data for the FieldExt.
+	_phantom: PhantomData<F>,
+}
+
+impl<F: FieldExt, const FRAC_BITS: usize> FixedPointChip<F, FRAC_BITS> {
+	/// Make the circuit configs.
+	pub fn configure(meta: &mut ConstraintSystem<F>) -> FixedPointConfig {
+		let range = RangeChip::<_, LIMB_BITS>::configure(meta);
+		let advice = [
+			meta.advice_column(),
+			meta.advice_column(),
+			meta.advice_column(),
+			meta.advice_column(),
+		];
+		let selectors = [meta.selector(), meta.selector(), meta.selector(), meta.selector()];
+
+		advice.map(|c| meta.enable_equality(c));
+
+		let scale = F::from_u128(1 << FRAC_BITS);
+		let shift = F::from_u128(1 << NUM_BITS);
+
+		// Gate for the add circuit.
+		meta.create_gate("fixed_add", |v_cells| {
+			let x_exp = v_cells.query_advice(advice[0], Rotation::cur());
+			let y_exp = v_cells.query_advice(advice[1], Rotation::cur());
+			let z_exp = v_cells.query_advice(advice[2], Rotation::cur());
+			let s_exp = v_cells.query_selector(selectors[0]);
+
+			vec![
+				// x + y - z == 0
+				s_exp * (x_exp + y_exp - z_exp),
+			]
+		});
+
+		// Gate for the mul circuit.
+		meta.create_gate("fixed_mul", |v_cells| {
+			let scale_exp = Expression::Constant(scale);
+			let x_exp = v_cells.query_advice(advice[0], Rotation::cur());
+			let y_exp = v_cells.query_advice(advice[1], Rotation::cur());
+			let q_exp = v_cells.query_advice(advice[2], Rotation::cur());
+			let r_exp = v_cells.query_advice(advice[3], Rotation::cur());
+			let s_exp = v_cells.query_selector(selectors[1]);
+
+			vec![
+				// x * y - (q * scale + r) == 0
+				// With r < scale, q is the product rounded down.
+				s_exp * (x_exp * y_exp - (q_exp * scale_exp + r_exp)),
+			]
+		});
+
+		// Gate for the div circuit.
+		meta.create_gate("fixed_div", |v_cells| {
+			let one = Expression::Constant(F::one());
+			let scale_exp = Expression::Constant(scale);
+			let x_exp = v_cells.query_advice(advice[0], Rotation::cur());
+			let y_exp = v_cells.query_advice(advice[1], Rotation::cur());
+			let q_exp = v_cells.query_advice(advice[2], Rotation::cur());
+			let r_exp = v_cells.query_advice(advice[3], Rotation::cur());
+			let diff_exp = v_cells.query_advice(advice[0], Rotation::next());
+			let s_exp = v_cells.query_selector(selectors[2]);
+
+			vec![
+				// x * scale - (q * y + r) == 0
+				s_exp.clone() * (x_exp * scale_exp - (q_exp * y_exp.clone() + r_exp.clone())),
+				// y - r - 1 - diff == 0
+				// With diff range checked, r < y, so q is the quotient rounded down.
+				s_exp * (y_exp - r_exp - one - diff_exp),
+			]
+		});
+
+		// Gate for the less_eq circuit.
+		meta.create_gate("fixed_less_eq", |v_cells| {
+			let shift_exp = Expression::Constant(shift);
+			let x_exp = v_cells.query_advice(advice[0], Rotation::cur());
+			let y_exp = v_cells.query_advice(advice[1], Rotation::cur());
+			let bit_exp = v_cells.query_advice(advice[2], Rotation::cur());
+			let low_exp = v_cells.query_advice(advice[3], Rotation::cur());
+			let s_exp = v_cells.query_selector(selectors[3]);
+
+			vec![
+				// y - x + 2^NUM_BITS - (bit * 2^NUM_BITS + low) == 0
+				// With low range checked, bit is set if and only if x <= y.
+				s_exp.clone()
+					* (y_exp - x_exp + shift_exp.clone() - (bit_exp.clone() * shift_exp + low_exp)),
+				// (1 - bit) * bit == 0
+				s_exp * ((Expression::Constant(F::one()) - bit_exp.clone()) * bit_exp),
+			]
+		});
+
+		FixedPointConfig { range, advice, selectors }
+	}
+
+	/// Load the table of the range checks. Call it once in the circuit.
+	pub fn load_table(config: &FixedPointConfig, layouter: impl Layouter<F>) -> Result<(), Error> {
+		RangeChip::<F, LIMB_BITS>::load_table(&config.range, layouter)
+	}
+
+	/// Range check the `x` to `num_bits` bits.
+	fn range_check(
+		x: &AssignedCell<F, F>, num_bits: usize, config: &FixedPointConfig,
+		layouter: impl Layouter<F>,
+	) -> Result<(), Error> {
+		RangeChip::<F, LIMB_BITS>::range_check(x.clone(), num_bits, &config.range, layouter)
+	}
+
+	/// Synthesize the range check of `x` to `NUM_BITS` bits, for the inputs
+	/// that are not results of the chip.
+	pub fn check_range(
+		x: AssignedCell<F, F>, config: FixedPointConfig, layouter: impl Layouter<F>,
+	) -> Result<(), Error> {
+		Self::range_check(&x, NUM_BITS, &config, layouter)
+	}
+
+	/// Synthesize the add circuit.
+	pub fn add(
+		// Assigns a cell for the x.
+		x: AssignedCell<F, F>,
+		// Assigns a cell for the y.
+		y: AssignedCell<F, F>,
+		config: FixedPointConfig,
+		mut layouter: impl Layouter<F>,
+	) -> Result<AssignedCell<F, F>, Error> {
+		let z = layouter.assign_region(
+			|| "fixed_add",
+			|mut region: Region<'_, F>| {
+				config.selectors[0].enable(&mut region, 0)?;
+				let assigned_x = x.copy_advice(|| "x", &mut region, config.advice[0], 0)?;
+				let assigned_y = y.copy_advice(|| "y", &mut region, config.advice[1], 0)?;
+
+				let z = assigned_x.value().cloned() + assigned_y.value();
+				region.assign_advice(|| "z", config.advice[2], 0, || z)
+			},
+		)?;
+
+		Self::range_check(&z, NUM_BITS, &config, layouter.namespace(|| "z_range"))?;
+		Ok(z)
+	}
+
+	/// Synthesize the mul circuit, rounding the product down.
+	pub fn mul(
+		// Assigns a cell for the x.
+		x: AssignedCell<F, F>,
+		// Assigns a cell for the y.
+		y: AssignedCell<F, F>,
+		config: FixedPointConfig,
+		mut layouter: impl Layouter<F>,
+	) -> Result<AssignedCell<F, F>, Error> {
+		let (q, r) = layouter.assign_region(
+			|| "fixed_mul",
+			|mut region: Region<'_, F>| {
+				config.selectors[1].enable(&mut region, 0)?;
+				let assigned_x = x.copy_advice(|| "x", &mut region, config.advice[0], 0)?;
+				let assigned_y = y.copy_advice(|| "y", &mut region, config.advice[1], 0)?;
+
+				let scale = F::from_u128(1 << FRAC_BITS);
+				let qr =
+					assigned_x.value().zip(assigned_y.value()).map(|(x, y)| div_rem(*x * y, scale));
+				let q = region.assign_advice(|| "q", config.advice[2], 0, || qr.map(|qr| qr.0))?;
+				let r = region.assign_advice(|| "r", config.advice[3], 0, || qr.map(|qr| qr.1))?;
+				Ok((q, r))
+			},
+		)?;
+
+		Self::range_check(&q, NUM_BITS, &config, layouter.namespace(|| "q_range"))?;
+		Self::range_check(&r, FRAC_BITS, &config, layouter.namespace(|| "r_range"))?;
+		Ok(q)
+	}
+
+	/// Synthesize the div circuit, rounding the quotient down. The proof
+	/// can't be made if `y` is zero.
+	pub fn div(
+		// Assigns a cell for the x.
+		x: AssignedCell<F, F>,
+		// Assigns a cell for the y.
+		y: AssignedCell<F, F>,
+		config: FixedPointConfig,
+		layouter: impl Layouter<F>,
+	) -> Result<AssignedCell<F, F>, Error> {
+		let scale = F::from_u128(1 << FRAC_BITS);
+		Self::div_with(x, y, |x, y| div_rem(x * scale, y), config, layouter)
+	}
+
+	/// Synthesize the div circuit with the quotient and the remainder of
+	/// `x * 2^FRAC_BITS` by `y` computed by `witness`, so the tests can forge
+	/// them.
+	fn div_with(
+		x: AssignedCell<F, F>, y: AssignedCell<F, F>, witness: impl Fn(F, F) -> (F, F),
+		config: FixedPointConfig, mut layouter: impl Layouter<F>,
+	) -> Result<AssignedCell<F, F>, Error> {
+		let (q, r, diff) = layouter.assign_region(
+			|| "fixed_div",
+			|mut region: Region<'_, F>| {
+				config.selectors[2].enable(&mut region, 0)?;
+				let assigned_x = x.copy_advice(|| "x", &mut region, config.advice[0], 0)?;
+				let assigned_y = y.copy_advice(|| "y", &mut region, config.advice[1], 0)?;
+
+				let qr = assigned_x.value().zip(assigned_y.value()).map(|(x, y)| witness(*x, *y));
+				let q = region.assign_advice(|| "q", config.advice[2], 0, || qr.map(|qr| qr.0))?;
+				let r = region.assign_advice(|| "r", config.advice[3], 0, || qr.map(|qr| qr.1))?;
+
+				let diff = assigned_y.value().cloned() - r.value() - Value::known(F::one());
+				let diff = region.assign_advice(|| "y - r - 1", config.advice[0], 1, || diff)?;
+				Ok((q, r, diff))
+			},
+		)?;
+
+		Self::range_check(&q, NUM_BITS, &config, layouter.namespace(|| "q_range"))?;
+		// Without it, r could wrap around the field, e.g. to r - y for q + 1.
+		Self::range_check(&r, NUM_BITS, &config, layouter.namespace(|| "r_range"))?;
+		Self::range_check(
+			&diff,
+			NUM_BITS,
+			&config,
+			layouter.namespace(|| "diff_range"),
+		)?;
+		Ok(q)
+	}
+
+	/// Synthesize the less_eq circuit, returning 1 if `x <= y` and 0
+	/// otherwise.
+	pub fn less_eq(
+		// Assigns a cell for the x.
+		x: AssignedCell<F, F>,
+		// Assigns a cell for the y.
+		y: AssignedCell<F, F>,
+		config: FixedPointConfig,
+		mut layouter: impl Layouter<F>,
+	) -> Result<AssignedCell<F, F>, Error> {
+		let (bit, low) = layouter.assign_region(
+			|| "fixed_less_eq",
+			|mut region: Region<'_, F>| {
+				config.selectors[3].enable(&mut region, 0)?;
+				let assigned_x = x.copy_advice(|| "x", &mut region, config.advice[0], 0)?;
+				let assigned_y = y.copy_advice(|| "y", &mut region, config.advice[1], 0)?;
+
+				let shift = F::from_u128(1 << NUM_BITS);
+				let diff = assigned_y.value().cloned() - assigned_x.value() + Value::known(shift);
+				let bit_low = diff.map(|diff| div_rem(diff, shift));
+				let bit = region.assign_advice(
+					|| "bit",
+					config.advice[2],
+					0,
+					|| bit_low.map(|bl| bl.0),
+				)?;
+				let low = region.assign_advice(
+					|| "low",
+					config.advice[3],
+					0,
+					|| bit_low.map(|bl| bl.1),
+				)?;
+				Ok((bit, low))
+			},
+		)?;
+
+		Self::range_check(&low, NUM_BITS, &config, layouter.namespace(|| "low_range"))?;
+		Ok(bit)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::utils::{generate_params, prove_and_verify};
+	use halo2wrong::{
+		curves::bn256::{Bn256, Fr},
+		halo2::{
+			circuit::SimpleFloorPlanner,
+			dev::MockProver,
+			plonk::{Circuit, Instance},
+		},
+	};
+
+	const FRAC_BITS: usize = 16;
+
+	#[derive(Clone)]
+	enum Gadgets {
+		Add,
+		Mul,
+		Div,
+		DivForged,
+		LessEq,
+	}
+
+	#[derive(Clone)]
+	struct TestConfig {
+		fixed_point: FixedPointConfig,
+		temp: Column<Advice>,
+		pub_ins: Column<Instance>,
+	}
+
+	#[derive(Clone)]
+	struct TestCircuit {
+		x: Fr,
+		y: Fr,
+		gadget: Gadgets,
+	}
+
+	impl TestCircuit {
+		fn new(x: f64, y: f64, gadget: Gadgets) -> Self {
+			Self { x: to_fixed::<_, FRAC_BITS>(x), y: to_fixed::<_, FRAC_BITS>(y), gadget }
+		}
+	}
+
+	impl Circuit<Fr> for TestCircuit {
+		type Config = TestConfig;
+		type FloorPlanner = SimpleFloorPlanner;
+
+		fn without_witnesses(&self) -> Self {
+			self.clone()
+		}
+
+		fn configure(meta: &mut ConstraintSystem<Fr>) -> TestConfig {
+			let fixed_point = FixedPointChip::<_, FRAC_BITS>::configure(meta);
+			let temp = meta.advice_column();
+			let pub_ins = meta.instance_column();
+			meta.enable_equality(temp);
+			meta.enable_equality(pub_ins);
+
+			TestConfig { fixed_point, temp, pub_ins }
+		}
+
+		fn synthesize(
+			&self, config: TestConfig, mut layouter: impl Layouter<Fr>,
+		) -> Result<(), Error> {
+			let (x, y) = layouter.assign_region(
+				|| "temp",
+				|mut region: Region<'_, Fr>| {
+					let x =
+						region.assign_advice(|| "x", config.temp, 0, || Value::known(self.x))?;
+					let y =
+						region.assign_advice(|| "y", config.temp, 1, || Value::known(self.y))?;
+					Ok((x, y))
+				},
+			)?;
+
+			type Chip = FixedPointChip<Fr, FRAC_BITS>;
+			Chip::load_table(&config.fixed_point, layouter.namespace(|| "table"))?;
+			let res = match self.gadget {
+				Gadgets::Add => Chip::add(x, y, config.fixed_point, layouter.namespace(|| "add"))?,
+				Gadgets::Mul => Chip::mul(x, y, config.fixed_point, layouter.namespace(|| "mul"))?,
+				Gadgets::Div => Chip::div(x, y, config.fixed_point, layouter.namespace(|| "div"))?,
+				Gadgets::DivForged => {
+					// The quotient rounded up, with the remainder wrapped around the field.
+					let scale = Fr::from_u128(1 << FRAC_BITS);
+					let forge = |x: Fr, y: Fr| {
+						let (q, r) = div_rem(x * scale, y);
+						(q + Fr::one(), r - y)
+					};
+					let layouter = layouter.namespace(|| "div_forged");
+					Chip::div_with(x, y, forge, config.fixed_point, layouter)?
+				},
+				Gadgets::LessEq => {
+					Chip::less_eq(x, y, config.fixed_point, layouter.namespace(|| "less_eq"))?
+				},
+			};
+			layouter.constrain_instance(res.cell(), config.pub_ins, 0)?;
+
+			Ok(())
+		}
+	}
+
+	fn run(circuit: TestCircuit, res: Fr) -> bool {
+		let prover = MockProver::run(9, &circuit, vec![vec![res]]).unwrap();
+		prover.verify().is_ok()
+	}
+
+	#[test]
+	fn test_to_from_fixed() {
+		let x = to_fixed::<Fr, FRAC_BITS>(0.375);
+		assert_eq!(x, Fr::from(24576));
+		assert_eq!(from_fixed::<_, FRAC_BITS>(x), 0.375);
+		assert_eq!(
+			from_fixed::<_, FRAC_BITS>(to_fixed::<Fr, FRAC_BITS>(12.5)),
+			12.5
+		);
+	}
+
+	#[test]
+	fn test_fixed_add() {
+		let circuit = TestCircuit::new(1.5, 2.25, Gadgets::Add);
+		assert!(run(circuit.clone(), to_fixed::<_, FRAC_BITS>(3.75)));
+		assert!(!run(circuit, to_fixed::<_, FRAC_BITS>(3.5)));
+	}
+
+	#[test]
+	fn test_fixed_mul() {
+		let circuit = TestCircuit::new(1.5, 2.25, Gadgets::Mul);
+		assert!(run(circuit.clone(), to_fixed::<_, FRAC_BITS>(3.375)));
+		// The product is rounded down.
+		let circuit = TestCircuit { x: Fr::from(3), y: Fr::from(1 << 15), gadget: Gadgets::Mul };
+		assert!(run(circuit.clone(), Fr::from(1)));
+		assert!(!run(circuit, Fr::from(2)));
+	}
+
+	#[test]
+	fn test_fixed_div() {
+		let circuit = TestCircuit::new(3.375, 1.5, Gadgets::Div);
+		assert!(run(circuit.clone(), to_fixed::<_, FRAC_BITS>(2.25)));
+		assert!(!run(circuit, to_fixed::<_, FRAC_BITS>(2.5)));
+		// 1 / 3 rounded down.
+		let circuit = TestCircuit::new(1.0, 3.0, Gadgets::Div);
+		assert!(run(circuit, Fr::from(21845)));
+	}
+
+	#[test]
+	fn test_fixed_div_forged_remainder() {
+		// q * y + r still equals x * scale, and y - r - 1 is in range.
+		let circuit = TestCircuit::new(1.0, 3.0, Gadgets::DivForged);
+		assert!(!run(circuit, Fr::from(21846)));
+	}
+
+	#[test]
+	fn test_fixed_native() {
+		type Num = Fixed<FRAC_BITS>;
+		let (x, y) = (Num::from_f64(3.375), Num::from_f64(1.5));
+		assert_eq!(x.to_field::<Fr>(), to_fixed::<Fr, FRAC_BITS>(3.375));
+		assert_eq!(Num::from_field(x.to_field::<Fr>()), x);
+		assert_eq!((x + y).to_f64(), 4.875);
+		assert_eq!((x - y).to_f64(), 1.875);
+		assert_eq!(y.abs_diff(x), x - y);
+		assert_eq!(Num::ONE / Num::ZERO, Num::ZERO);
+
+		// The native results are the ones of the chip, with the rounding.
+		let (x, y) = (Num::from_raw(3), Num::from_raw(1 << 15));
+		let circuit = TestCircuit { x: x.to_field(), y: y.to_field(), gadget: Gadgets::Mul };
+		assert!(run(circuit, (x * y).to_field()));
+		let (x, y) = (Num::ONE, Num::from_f64(3.0));
+		let circuit = TestCircuit { x: x.to_field(), y: y.to_field(), gadget: Gadgets::Div };
+		assert!(run(circuit, (x / y).to_field()));
+		assert_eq!(x / y, Num::from_raw(21845));
+	}
+
+	#[test]
+	fn test_fixed_native_overflow() {
+		type Num = Fixed<FRAC_BITS>;
+		let max = Num::from_raw(u128::MAX);
+		assert_eq!(max.checked_add(Num::from_raw(1)), None);
+		assert_eq!(Num::ZERO.checked_sub(Num::ONE), None);
+		assert_eq!(max.checked_mul(Num::from_raw(2)), None);
+		assert_eq!(max.checked_div(Num::ONE), None);
+		assert_eq!(Num::ONE.checked_div(Num::ZERO), None);
+		assert_eq!(Num::ONE.checked_sub(Num::ONE), Some(Num::ZERO));
+	}
+
+	#[test]
+	fn test_fixed_div_by_zero() {
+		let circuit = TestCircuit::new(1.0, 0.0, Gadgets::Div);
+		assert!(!run(circuit, Fr::zero()));
+	}
+
+	#[test]
+	fn test_fixed_less_eq() {
+		assert!(run(TestCircuit::new(1.5, 2.25, Gadgets::LessEq), Fr::one()));
+		assert!(run(
+			TestCircuit::new(2.25, 2.25, Gadgets::LessEq),
+			Fr::one()
+		));
+		assert!(run(
+			TestCircuit::new(2.5, 2.25, Gadgets::LessEq),
+			Fr::zero()
+		));
+	}
+
+	#[test]
+	fn test_fixed_point_production() {
+		let circuit = TestCircuit::new(3.375, 1.5, Gadgets::Div);
+		let res = to_fixed::<_, FRAC_BITS>(2.25);
+		let params = generate_params(9);
+		let res =
+			prove_and_verify::<Bn256, _, _>(params, circuit, &[&[res]], &mut rand::thread_rng())
+				.unwrap();
+		assert!(res);
+	}
+}
@@ -0,0 +1,212 @@
+use crate::{
+	params::RoundParams,
+	poseidon::{
+		native::sponge::{hash_instances, INSTANCES_DOMAIN},
+		sponge::{PoseidonSpongeChip, PoseidonSpongeConfig},
+	},
+};
+use halo2wrong::halo2::{
+	arithmetic::FieldExt,
+	circuit::{AssignedCell, Layouter, Region},
+	plonk::{Advice, Column, ConstraintSystem, Error, Instance},
+};
+use std::marker::PhantomData;
+
+/// Returns the commitment to the instance vector, exposed as the only public
+/// input of a circuit opening it with `InstancesChip`. It's the same as the
+/// `hash_instances` of a single column holding the vector.
+pub fn commit_instances<F: FieldExt, P: RoundParams<F, 5>>(instances: &[F]) -> F {
+	hash_instances::<F, P>(&[instances])
+}
+
+#[derive(Clone)]
+/// Configuration elements for the circuit are defined here.
+pub struct InstancesConfig {
+	/// Constructs the sponge circuit elements, used for the commitment.
+	sponge: PoseidonSpongeConfig<5>,
+	/// Configures a column for the lengths.
+	temp: Column<Advice>,
+}
+
+/// Compresses the instance vector of a circuit into a single public input.
+/// The vector is assigned as advice, and only its Poseidon commitment is
+/// public, so the cost of verifying the proof, natively, on chain or in an
+/// aggregation circuit, doesn't grow with the length of the vector.
+pub struct InstancesChip<F: FieldExt, P: RoundParams<F, 5>> {
+	_params: PhantomData<(F, P)>,
+}
+
+impl<F: FieldExt, P: RoundParams<F, 5>> InstancesChip<F, P> {
+	/// Make the circuit config.
+	pub fn configure(meta: &mut ConstraintSystem<F>) -> InstancesConfig {
+		let sponge = PoseidonSpongeChip::<_, 5, P>::configure(meta);
+		let temp = meta.advice_column();
+		let fixed = meta.fixed_column();
+
+		meta.enable_equality(temp);
+		meta.enable_constant(fixed);
+
+		InstancesConfig { sponge, temp }
+	}
+
+	/// Synthesize the commitment to the instance vector. See
+	/// `commit_instances`.
+	pub fn commit(
+		instances: &[AssignedCell<F, F>], config: &InstancesConfig, mut layouter: impl Layouter<F>,
+	) -> Result<AssignedCell<F, F>, Error> {
+		let lengths = layouter.assign_region(
+			|| "lengths",
+			|mut region: Region<'_, F>| {
+				let num_columns =
+					region.assign_advice_from_constant(|| "columns", config.temp, 0, F::one())?;
+				let len = F::from(instances.len() as u64);
+				let len = region.assign_advice_from_constant(|| "len", config.temp, 1, len)?;
+				Ok([num_columns, len])
+			},
+		)?;
+
+		let domain = F::from(INSTANCES_DOMAIN);
+		let mut sponge = PoseidonSpongeChip::<_, 5, P>::new_with_domain(domain);
+		sponge.update(&lengths);
+		sponge.update(instances);
+		sponge.squeeze(&config.sponge, layouter.namespace(|| "commitment"))
+	}
+
+	/// Constrain the commitment to the instance vector to be the public input
+	/// in the `row` of the `pub_ins` column.
+	pub fn expose(
+		instances: &[AssignedCell<F, F>], pub_ins: Column<Instance>, row: usize,
+		config: &InstancesConfig, mut layouter: impl Layouter<F>,
+	) -> Result<(), Error> {
+		let commitment = Self::commit(instances, config, layouter.namespace(|| "commit"))?;
+		layouter.constrain_instance(commitment.cell(), pub_ins, row)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		params::poseidon_bn254_5x5::Params,
+		utils::{generate_params, prove_and_verify},
+	};
+	use halo2wrong::{
+		curves::bn256::{Bn256, Fr},
+		halo2::{
+			arithmetic::Field,
+			circuit::{SimpleFloorPlanner, Value},
+			dev::MockProver,
+			plonk::Circuit,
+		},
+	};
+	use rand::thread_rng;
+
+	type Chip = InstancesChip<Fr, Params>;
+
+	fn commit(instances: &[Fr]) -> Fr {
+		commit_instances::<_, Params>(instances)
+	}
+
+	#[derive(Clone)]
+	struct TestConfig {
+		instances: InstancesConfig,
+		temp: Column<Advice>,
+		pub_ins: Column<Instance>,
+	}
+
+	#[derive(Clone)]
+	struct TestCircuit {
+		instances: Vec<Fr>,
+	}
+
+	impl Circuit<Fr> for TestCircuit {
+		type Config = TestConfig;
+		type FloorPlanner = SimpleFloorPlanner;
+
+		fn without_witnesses(&self) -> Self {
+			self.clone()
+		}
+
+		fn configure(meta: &mut ConstraintSystem<Fr>) -> TestConfig {
+			let instances = Chip::configure(meta);
+			let temp = meta.advice_column();
+			let pub_ins = meta.instance_column();
+
+			meta.enable_equality(temp);
+			meta.enable_equality(pub_ins);
+
+			TestConfig { instances, temp, pub_ins }
+		}
+
+		fn synthesize(
+			&self, config: TestConfig, mut layouter: impl Layouter<Fr>,
+		) -> Result<(), Error> {
+			let instances = layouter.assign_region(
+				|| "temp",
+				|mut region: Region<'_, Fr>| {
+					self.instances
+						.iter()
+						.enumerate()
+						.map(|(i, x)| {
+							region.assign_advice(|| "instance", config.temp, i, || Value::known(*x))
+						})
+						.collect::<Result<Vec<_>, Error>>()
+				},
+			)?;
+
+			Chip::expose(
+				&instances,
+				config.pub_ins,
+				0,
+				&config.instances,
+				layouter.namespace(|| "instances"),
+			)
+		}
+	}
+
+	fn setup(len: usize) -> (TestCircuit, Vec<Fr>) {
+		let rng = &mut thread_rng();
+		let instances: Vec<Fr> = (0..len).map(|_| Fr::random(&mut *rng)).collect();
+		let commitment = commit(&instances);
+		(TestCircuit { instances }, vec![commitment])
+	}
+
+	#[test]
+	fn test_native_commit_instances() {
+		let instances = [1u64, 2, 3].map(Fr::from);
+		let commitment = commit(&instances);
+		assert_ne!(commitment, commit(&instances[..2]));
+		assert_ne!(commitment, commit(&[Fr::from(1), Fr::from(3), Fr::from(2)]));
+		// A trailing zero isn't confused with the padding.
+		assert_ne!(
+			commitment,
+			commit(&[instances.as_slice(), &[Fr::zero()]].concat())
+		);
+	}
+
+	#[test]
+	fn test_instances() {
+		for len in [1, 3, 4, 100] {
+			let (circuit, pub_ins) = setup(len);
+			let prover = MockProver::run(12, &circuit, vec![pub_ins]).unwrap();
+			assert_eq!(prover.verify(), Ok(()));
+		}
+	}
+
+	#[test]
+	fn test_instances_wrong_commitment() {
+		let (mut circuit, pub_ins) = setup(10);
+		circuit.instances[3] += Fr::one();
+		let prover = MockProver::run(12, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_instances_production() {
+		let (circuit, pub_ins) = setup(10);
+		let params = generate_params(12);
+		let res = prove_and_verify::<Bn256, _, _>(params, circuit, &[&pub_ins], &mut thread_rng())
+			.unwrap();
+		assert!(res);
+	}
+}
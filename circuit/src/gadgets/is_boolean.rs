@@ -72,7 +72,7 @@ impl<F: FieldExt> IsBooleanChip<F> {
 }
 
 #[cfg(test)]
-mod test {
+pub(crate) mod test {
 	use super::*;
 	use crate::utils::{generate_params, prove_and_verify};
 	use halo2wrong::{
@@ -85,18 +85,20 @@ mod test {
 	};
 
 	#[derive(Clone)]
-	struct TestConfig {
+	pub(crate) struct TestConfig {
 		is_bool: IsBooleanConfig,
 		temp: Column<Advice>,
 	}
 
+	/// Circuit checking that a single value is a boolean, also used by the
+	/// tests of the development tools.
 	#[derive(Clone)]
-	struct TestCircuit<F: FieldExt> {
+	pub(crate) struct TestCircuit<F: FieldExt> {
 		numba: F,
 	}
 
 	impl<F: FieldExt> TestCircuit<F> {
-		fn new(x: F) -> Self {
+		pub(crate) fn new(x: F) -> Self {
 			Self { numba: x }
 		}
 	}
@@ -1,9 +1,17 @@
 /// Bitwise AND operation
 pub mod and;
+/// Decompose numbers into bits and compose them back
+pub mod bits;
 /// Convert bits to number
 pub mod bits2num;
+/// Membership in a set committed to by a Merkle root
+pub mod committed_set;
 /// Common gadget for the optimization
 pub mod common;
+/// Arithmetic and comparison of fixed-point numbers
+pub mod fixed_point;
+/// Commitments compressing the public inputs of a circuit
+pub mod instances;
 /// Check if a number is a boolean
 pub mod is_boolean;
 /// Check equality between two numbers
@@ -14,6 +22,10 @@ pub mod is_zero;
 pub mod lt_eq;
 /// Multiply two numbers
 pub mod mul;
+/// Nullifiers of the opinions of a peer
+pub mod nullifier;
+/// Range checks with lookups of limbs
+pub mod range;
 /// Conditionally select between two numbers based on a value of a bit
 pub mod select;
 /// Set membership gadget
@@ -0,0 +1,369 @@
+use super::range::{RangeChip, RangeConfig};
+use crate::{
+	eddsa::{
+		eddsa_gadgets::{EddsaGadgetsChip, EddsaGadgetsConfig},
+		native::{
+			ed_on_bn254::{B8, SUBORDER},
+			SecretKey,
+		},
+	},
+	params::poseidon_bn254_5x5::Params,
+	poseidon::{
+		native::sponge::{PoseidonSponge, NULLIFIER_DOMAIN},
+		PoseidonChip, PoseidonConfig,
+	},
+};
+use halo2wrong::{
+	curves::bn256::Fr,
+	halo2::{
+		arithmetic::Field,
+		circuit::{AssignedCell, Layouter, Region},
+		plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+		poly::Rotation,
+	},
+};
+
+/// Number of bits of the scalar of a secret key.
+pub const SCALAR_BITS: usize = 252;
+/// Number of bits of the limbs of the range check of the scalar.
+const LIMB_BITS: usize = 8;
+
+/// Returns the nullifier of the opinion of the holder of `sk` about the
+/// `neighbor` in the `epoch`. A peer has a single nullifier for each neighbor
+/// and epoch, so repeated or conflicting opinions can be told apart.
+pub fn nullifier(sk: &SecretKey, epoch: Fr, neighbor: Fr) -> Fr {
+	let mut sponge = PoseidonSponge::<Fr, 5, Params>::new_with_domain(Fr::from(NULLIFIER_DOMAIN));
+	sponge.update(&[sk.scalar(), epoch, neighbor]);
+	sponge.squeeze()
+}
+
+#[derive(Clone)]
+/// Configuration elements for the circuit are defined here.
+pub struct NullifierConfig {
+	/// Constructs the eddsa gadgets, used for deriving the public key.
+	eddsa_gadgets: EddsaGadgetsConfig,
+	/// Constructs the Poseidon circuit elements, used for the nullifier.
+	poseidon: PoseidonConfig<5>,
+	/// Configures the range chip, used for checking the scalar is reduced.
+	range: RangeConfig,
+	/// Configures a column for the constants.
+	temp: Column<Advice>,
+	/// Configures a column for `SUBORDER - 1 - sk`.
+	diff: Column<Advice>,
+	/// Configures a selector for the `sk + diff == SUBORDER - 1` gate.
+	canonical: Selector,
+}
+
+/// Derives nullifiers from the scalar of a secret key, and binds the scalar
+/// to the public key.
+pub struct NullifierChip;
+
+impl NullifierChip {
+	/// Make the circuit config.
+	pub fn configure(meta: &mut ConstraintSystem<Fr>) -> NullifierConfig {
+		let eddsa_gadgets = EddsaGadgetsChip::configure(meta);
+		let poseidon = PoseidonChip::<_, 5, Params>::configure(meta);
+		let range = RangeChip::<_, LIMB_BITS>::configure(meta);
+		let temp = meta.advice_column();
+		let diff = meta.advice_column();
+		let fixed = meta.fixed_column();
+		let canonical = meta.selector();
+
+		meta.enable_equality(temp);
+		meta.enable_equality(diff);
+		meta.enable_constant(fixed);
+
+		meta.create_gate("sk + diff == SUBORDER - 1", |v_cells| {
+			let sk_exp = v_cells.query_advice(temp, Rotation::cur());
+			let diff_exp = v_cells.query_advice(diff, Rotation::cur());
+			let s_exp = v_cells.query_selector(canonical);
+			let max = Expression::Constant(SUBORDER - Fr::one());
+
+			vec![s_exp * (sk_exp + diff_exp - max)]
+		});
+
+		NullifierConfig { eddsa_gadgets, poseidon, range, temp, diff, canonical }
+	}
+
+	/// Load the table of the range check of the scalar. Call it once in the
+	/// circuit.
+	pub fn load_table(config: &NullifierConfig, layouter: impl Layouter<Fr>) -> Result<(), Error> {
+		RangeChip::<Fr, LIMB_BITS>::load_table(&config.range, layouter)
+	}
+
+	/// Constrain the public key to be the base point multiplied by the scalar
+	/// `sk`, with the bits `sk_bits`, and the scalar to be below the
+	/// `SUBORDER`. Otherwise `sk + SUBORDER`, which gives the same public key,
+	/// would give other nullifiers.
+	pub fn check_key(
+		sk: AssignedCell<Fr, Fr>, sk_bits: [Fr; SCALAR_BITS], pk_x: AssignedCell<Fr, Fr>,
+		pk_y: AssignedCell<Fr, Fr>, config: &NullifierConfig, mut layouter: impl Layouter<Fr>,
+	) -> Result<(), Error> {
+		let (b8_x, b8_y, one) = layouter.assign_region(
+			|| "base_point",
+			|mut region: Region<'_, Fr>| {
+				let b8_x = region.assign_advice_from_constant(|| "b8_x", config.temp, 0, B8.x)?;
+				let b8_y = region.assign_advice_from_constant(|| "b8_y", config.temp, 1, B8.y)?;
+				let one =
+					region.assign_advice_from_constant(|| "one", config.temp, 2, Fr::one())?;
+				Ok((b8_x, b8_y, one))
+			},
+		)?;
+
+		let (x, y, z) = EddsaGadgetsChip::scalar_mul(
+			b8_x,
+			b8_y,
+			one,
+			sk.clone(),
+			sk_bits,
+			config.eddsa_gadgets.clone(),
+			layouter.namespace(|| "b_8 * sk"),
+		)?;
+		let (x, y) = EddsaGadgetsChip::into_affine(
+			x,
+			y,
+			z,
+			config.eddsa_gadgets.clone(),
+			layouter.namespace(|| "pk_affine"),
+		)?;
+
+		layouter.assign_region(
+			|| "pk_equal",
+			|mut region: Region<'_, Fr>| {
+				region.constrain_equal(x.cell(), pk_x.cell())?;
+				region.constrain_equal(y.cell(), pk_y.cell())
+			},
+		)?;
+
+		// The scalar multiplication bounds `sk` below `2^SCALAR_BITS`, so
+		// `SUBORDER - 1 - sk` fits in `SCALAR_BITS` bits only if `sk` is below
+		// the `SUBORDER`. Otherwise it wraps around the field modulus.
+		let diff = layouter.assign_region(
+			|| "canonical",
+			|mut region: Region<'_, Fr>| {
+				config.canonical.enable(&mut region, 0)?;
+				let sk = sk.copy_advice(|| "sk", &mut region, config.temp, 0)?;
+				let diff = sk.value().map(|sk| SUBORDER - Fr::one() - sk);
+				region.assign_advice(|| "diff", config.diff, 0, || diff)
+			},
+		)?;
+		RangeChip::<_, LIMB_BITS>::range_check(
+			diff,
+			SCALAR_BITS,
+			&config.range,
+			layouter.namespace(|| "diff_range"),
+		)
+	}
+
+	/// Synthesize the nullifier of the opinion about the `neighbor` in the
+	/// `epoch`, for the scalar `sk`. See `nullifier`.
+	pub fn nullifier(
+		sk: AssignedCell<Fr, Fr>, epoch: AssignedCell<Fr, Fr>, neighbor: AssignedCell<Fr, Fr>,
+		config: &NullifierConfig, mut layouter: impl Layouter<Fr>,
+	) -> Result<AssignedCell<Fr, Fr>, Error> {
+		let (domain, one) = layouter.assign_region(
+			|| "nullifier_constants",
+			|mut region: Region<'_, Fr>| {
+				let domain = Fr::from(NULLIFIER_DOMAIN);
+				let domain =
+					region.assign_advice_from_constant(|| "domain", config.temp, 0, domain)?;
+				let one =
+					region.assign_advice_from_constant(|| "one", config.temp, 1, Fr::one())?;
+				Ok((domain, one))
+			},
+		)?;
+
+		// The capacity element holds the domain, followed by the padded inputs.
+		let hasher = PoseidonChip::<_, 5, Params>::new([domain, sk, epoch, neighbor, one]);
+		let res = hasher.synthesize(config.poseidon.clone(), layouter.namespace(|| "nullifier"))?;
+		Ok(res[1].clone())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		gadgets::bits2num::to_bits,
+		utils::{generate_params, prove_and_verify},
+	};
+	use halo2wrong::{
+		curves::bn256::Bn256,
+		halo2::{
+			circuit::{SimpleFloorPlanner, Value},
+			dev::MockProver,
+			plonk::{Circuit, Instance},
+		},
+	};
+	use rand::thread_rng;
+
+	#[derive(Clone)]
+	struct TestConfig {
+		nullifier: NullifierConfig,
+		temp: Column<Advice>,
+		pub_ins: Column<Instance>,
+	}
+
+	#[derive(Clone)]
+	struct TestCircuit {
+		sk: Fr,
+		epoch: Fr,
+		neighbor: Fr,
+	}
+
+	impl Circuit<Fr> for TestCircuit {
+		type Config = TestConfig;
+		type FloorPlanner = SimpleFloorPlanner;
+
+		fn without_witnesses(&self) -> Self {
+			self.clone()
+		}
+
+		fn configure(meta: &mut ConstraintSystem<Fr>) -> TestConfig {
+			let nullifier = NullifierChip::configure(meta);
+			let temp = meta.advice_column();
+			let pub_ins = meta.instance_column();
+
+			meta.enable_equality(temp);
+			meta.enable_equality(pub_ins);
+
+			TestConfig { nullifier, temp, pub_ins }
+		}
+
+		fn synthesize(
+			&self, config: TestConfig, mut layouter: impl Layouter<Fr>,
+		) -> Result<(), Error> {
+			let (sk, pk_x, pk_y, epoch, neighbor) = layouter.assign_region(
+				|| "temp",
+				|mut region: Region<'_, Fr>| {
+					let sk =
+						region.assign_advice(|| "sk", config.temp, 0, || Value::known(self.sk))?;
+					let pk_x = region.assign_advice_from_instance(
+						|| "pk_x",
+						config.pub_ins,
+						0,
+						config.temp,
+						1,
+					)?;
+					let pk_y = region.assign_advice_from_instance(
+						|| "pk_y",
+						config.pub_ins,
+						1,
+						config.temp,
+						2,
+					)?;
+					let epoch = region.assign_advice(
+						|| "epoch",
+						config.temp,
+						3,
+						|| Value::known(self.epoch),
+					)?;
+					let neighbor = region.assign_advice(
+						|| "neighbor",
+						config.temp,
+						4,
+						|| Value::known(self.neighbor),
+					)?;
+					Ok((sk, pk_x, pk_y, epoch, neighbor))
+				},
+			)?;
+
+			NullifierChip::load_table(&config.nullifier, layouter.namespace(|| "table"))?;
+			let sk_bits = to_bits(self.sk.to_bytes()).map(Fr::from);
+			NullifierChip::check_key(
+				sk.clone(),
+				sk_bits,
+				pk_x,
+				pk_y,
+				&config.nullifier,
+				layouter.namespace(|| "check_key"),
+			)?;
+			let nullifier = NullifierChip::nullifier(
+				sk,
+				epoch,
+				neighbor,
+				&config.nullifier,
+				layouter.namespace(|| "nullifier"),
+			)?;
+			layouter.constrain_instance(nullifier.cell(), config.pub_ins, 2)?;
+			Ok(())
+		}
+	}
+
+	fn setup() -> (SecretKey, TestCircuit, Vec<Fr>) {
+		let rng = &mut thread_rng();
+		let sk = SecretKey::random(rng);
+		let pk = sk.public();
+		let epoch = Fr::from(3);
+		let neighbor = Fr::random(rng);
+
+		let circuit = TestCircuit { sk: sk.scalar(), epoch, neighbor };
+		let pub_ins = vec![pk.0.x, pk.0.y, nullifier(&sk, epoch, neighbor)];
+		(sk, circuit, pub_ins)
+	}
+
+	#[test]
+	fn test_native_nullifier() {
+		let sk = SecretKey::random(&mut thread_rng());
+		let neighbor = Fr::from(7);
+		let n = nullifier(&sk, Fr::one(), neighbor);
+		assert_eq!(n, nullifier(&sk, Fr::one(), neighbor));
+		assert_ne!(n, nullifier(&sk, Fr::from(2), neighbor));
+		assert_ne!(n, nullifier(&sk, Fr::one(), Fr::from(8)));
+		assert_eq!(
+			B8.mul_scalar(&sk.scalar().to_bytes()).affine(),
+			sk.public().0
+		);
+	}
+
+	#[test]
+	fn test_nullifier() {
+		let (_, circuit, pub_ins) = setup();
+		let prover = MockProver::run(10, &circuit, vec![pub_ins]).unwrap();
+		assert_eq!(prover.verify(), Ok(()));
+	}
+
+	#[test]
+	fn test_nullifier_wrong_key() {
+		let (_, circuit, mut pub_ins) = setup();
+		let other = SecretKey::random(&mut thread_rng()).public();
+		pub_ins[0] = other.0.x;
+		pub_ins[1] = other.0.y;
+		let prover = MockProver::run(10, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_nullifier_wrong_epoch() {
+		let (sk, circuit, mut pub_ins) = setup();
+		pub_ins[2] = nullifier(&sk, Fr::from(4), circuit.neighbor);
+		let prover = MockProver::run(10, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_nullifier_unreduced_key() {
+		// `sk + SUBORDER` has the same public key, but another nullifier.
+		let (sk, circuit, _) = setup();
+		let unreduced = sk.scalar() + SUBORDER;
+		assert!(to_bits(unreduced.to_bytes())[SCALAR_BITS..].iter().all(|bit| !bit));
+		let mut sponge =
+			PoseidonSponge::<Fr, 5, Params>::new_with_domain(Fr::from(NULLIFIER_DOMAIN));
+		sponge.update(&[unreduced, circuit.epoch, circuit.neighbor]);
+		let pk = sk.public();
+
+		let circuit = TestCircuit { sk: unreduced, ..circuit };
+		let pub_ins = vec![pk.0.x, pk.0.y, sponge.squeeze()];
+		let prover = MockProver::run(10, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_nullifier_production() {
+		let (_, circuit, pub_ins) = setup();
+		let params = generate_params(10);
+		let res = prove_and_verify::<Bn256, _, _>(params, circuit, &[&pub_ins], &mut thread_rng())
+			.unwrap();
+		assert!(res);
+	}
+}
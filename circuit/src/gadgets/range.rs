@@ -0,0 +1,229 @@
+use halo2wrong::halo2::{
+	arithmetic::FieldExt,
+	circuit::{AssignedCell, Layouter, Region, Value},
+	plonk::{
+		Advice, Column, ConstraintSystem, Error, Expression, Fixed, Selector, TableColumn,
+		VirtualCells,
+	},
+	poly::Rotation,
+};
+use std::marker::PhantomData;
+
+/// Returns the lowest `bits` bits of the value, for `bits` up to 32.
+fn low_bits<F: FieldExt>(value: &F, bits: usize) -> F {
+	let mut bytes = [0; 4];
+	bytes.copy_from_slice(&value.to_repr().as_ref()[..4]);
+	let mask = (1u64 << bits) - 1;
+	F::from(u64::from(u32::from_le_bytes(bytes)) & mask)
+}
+
+#[derive(Clone, Debug)]
+/// Configuration elements for the circuit are defined here.
+pub struct RangeConfig {
+	/// Configures a column for the running sum.
+	z: Column<Advice>,
+	/// Configures a fixed column for the shift of the last, shorter limb.
+	shift: Column<Fixed>,
+	/// Configures a table column for the values of a limb.
+	table: TableColumn,
+	/// Configures a fixed boolean value for each row of the circuit.
+	selector: Selector,
+}
+
+/// Structure for the range chip, constraining values to a number of bits by
+/// splitting them into limbs of `LIMB_BITS` bits, looked up in a table.
+/// The table has `2^LIMB_BITS` rows, so 8 or 16 bits are the usual choices.
+pub struct RangeChip<F: FieldExt, const LIMB_BITS: usize> {
+	/// Constructs a phantom data for the FieldExt.
+	_phantom: PhantomData<F>,
+}
+
+impl<F: FieldExt, const LIMB_BITS: usize> RangeChip<F, LIMB_BITS> {
+	/// Make the circuit config.
+	pub fn configure(meta: &mut ConstraintSystem<F>) -> RangeConfig {
+		let z = meta.advice_column();
+		let shift = meta.fixed_column();
+		let fixed = meta.fixed_column();
+		let table = meta.lookup_table_column();
+		let selector = meta.complex_selector();
+
+		meta.enable_equality(z);
+		meta.enable_constant(fixed);
+
+		let limb_size = F::from_u128(1 << LIMB_BITS);
+		// limb = z - z_next * 2^LIMB_BITS
+		let limb = |v_cells: &mut VirtualCells<'_, F>| {
+			let z_exp = v_cells.query_advice(z, Rotation::cur());
+			let z_next_exp = v_cells.query_advice(z, Rotation::next());
+			z_exp - z_next_exp * Expression::Constant(limb_size)
+		};
+
+		meta.lookup(|v_cells| {
+			let s_exp = v_cells.query_selector(selector);
+			vec![(s_exp * limb(v_cells), table)]
+		});
+
+		// The last limb, shifted to the top of the table, is also in the table,
+		// when it is shorter than LIMB_BITS.
+		meta.lookup(|v_cells| {
+			let shift_exp = v_cells.query_fixed(shift, Rotation::cur());
+			vec![(shift_exp * limb(v_cells), table)]
+		});
+
+		RangeConfig { z, shift, table, selector }
+	}
+
+	/// Load the table of the limbs. Call it once in the circuit.
+	pub fn load_table(config: &RangeConfig, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+		layouter.assign_table(
+			|| "range_table",
+			|mut table| {
+				for i in 0..1 << LIMB_BITS {
+					table.assign_cell(
+						|| "limb",
+						config.table,
+						i,
+						|| Value::known(F::from(i as u64)),
+					)?;
+				}
+				Ok(())
+			},
+		)
+	}
+
+	/// Synthesize the range check of `x` to `num_bits` bits.
+	pub fn range_check(
+		// Assigns a cell for the x.
+		x: AssignedCell<F, F>,
+		num_bits: usize,
+		config: &RangeConfig,
+		mut layouter: impl Layouter<F>,
+	) -> Result<(), Error> {
+		let num_limbs = (num_bits + LIMB_BITS - 1) / LIMB_BITS;
+		let last_bits = num_bits % LIMB_BITS;
+		let limb_size_inv = F::from_u128(1 << LIMB_BITS).invert().unwrap();
+
+		layouter.assign_region(
+			|| "range_check",
+			|mut region: Region<'_, F>| {
+				let mut z = x.copy_advice(|| "z_0", &mut region, config.z, 0)?;
+				for i in 0..num_limbs {
+					config.selector.enable(&mut region, i)?;
+					if i == num_limbs - 1 && last_bits != 0 {
+						let shift = F::from_u128(1 << (LIMB_BITS - last_bits));
+						region.assign_fixed(|| "shift", config.shift, i, || Value::known(shift))?;
+					}
+
+					let next = z.value().map(|z| (*z - low_bits(z, LIMB_BITS)) * limb_size_inv);
+					z = region.assign_advice(|| "z", config.z, i + 1, || next)?;
+				}
+				// All the limbs are taken out.
+				region.constrain_constant(z.cell(), F::zero())
+			},
+		)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::utils::{generate_params, prove_and_verify};
+	use halo2wrong::{
+		curves::bn256::{Bn256, Fr},
+		halo2::{
+			circuit::SimpleFloorPlanner,
+			dev::MockProver,
+			plonk::{Circuit, Instance},
+		},
+	};
+
+	#[derive(Clone)]
+	struct TestConfig {
+		range: RangeConfig,
+		temp: Column<Advice>,
+		pub_ins: Column<Instance>,
+	}
+
+	#[derive(Clone)]
+	struct TestCircuit {
+		x: Fr,
+		num_bits: usize,
+	}
+
+	impl Circuit<Fr> for TestCircuit {
+		type Config = TestConfig;
+		type FloorPlanner = SimpleFloorPlanner;
+
+		fn without_witnesses(&self) -> Self {
+			self.clone()
+		}
+
+		fn configure(meta: &mut ConstraintSystem<Fr>) -> TestConfig {
+			let range = RangeChip::<_, 8>::configure(meta);
+			let temp = meta.advice_column();
+			let fixed = meta.fixed_column();
+			let pub_ins = meta.instance_column();
+
+			meta.enable_equality(temp);
+			meta.enable_constant(fixed);
+			meta.enable_equality(pub_ins);
+
+			TestConfig { range, temp, pub_ins }
+		}
+
+		fn synthesize(
+			&self, config: TestConfig, mut layouter: impl Layouter<Fr>,
+		) -> Result<(), Error> {
+			RangeChip::<_, 8>::load_table(&config.range, layouter.namespace(|| "table"))?;
+			let x = layouter.assign_region(
+				|| "temp",
+				|mut region: Region<'_, Fr>| {
+					region.assign_advice(|| "x", config.temp, 0, || Value::known(self.x))
+				},
+			)?;
+			layouter.constrain_instance(x.cell(), config.pub_ins, 0)?;
+			RangeChip::<_, 8>::range_check(
+				x,
+				self.num_bits,
+				&config.range,
+				layouter.namespace(|| "range_check"),
+			)
+		}
+	}
+
+	fn run(x: Fr, num_bits: usize) -> bool {
+		let circuit = TestCircuit { x, num_bits };
+		let prover = MockProver::run(9, &circuit, vec![vec![x]]).unwrap();
+		prover.verify().is_ok()
+	}
+
+	#[test]
+	fn test_range_check() {
+		assert!(run(Fr::from(0), 0));
+		assert!(run(Fr::from(0xff), 8));
+		assert!(run(Fr::from(u64::MAX), 64));
+		assert!(run(Fr::from(0x1fff), 13));
+		assert!(run(Fr::from(0x3ff), 10));
+	}
+
+	#[test]
+	fn test_range_check_overflow() {
+		assert!(!run(Fr::from(1), 0));
+		assert!(!run(Fr::from(0x100), 8));
+		assert!(!run(Fr::from(u64::MAX) + Fr::one(), 64));
+		assert!(!run(Fr::from(0x2000), 13));
+		assert!(!run(Fr::from(0x400), 10));
+		assert!(!run(-Fr::one(), 64));
+	}
+
+	#[test]
+	fn test_range_check_production() {
+		let x = Fr::from(0x1fff);
+		let circuit = TestCircuit { x, num_bits: 13 };
+		let params = generate_params(9);
+		let res =
+			prove_and_verify::<Bn256, _, _>(params, circuit, &[&[x]], &mut rand::thread_rng())
+				.unwrap();
+		assert!(res);
+	}
+}
@@ -0,0 +1,560 @@
+/// Native version of the EigenTrust iterations
+pub mod native;
+/// Native EigenTrust network with peers joining and leaving
+pub mod network;
+/// Attack simulations building networks from the strategies of the peers
+pub mod simulation;
+/// Detection of candidate Sybil clusters on the trust graph
+pub mod sybil;
+
+use crate::{
+	gadgets::{
+		fixed_point::Fixed,
+		instances::{InstancesChip, InstancesConfig},
+		range::{RangeChip, RangeConfig},
+	},
+	params::RoundParams,
+};
+use halo2wrong::halo2::{
+	arithmetic::FieldExt,
+	circuit::{AssignedCell, Layouter, Region, SimpleFloorPlanner, Value},
+	plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Expression, Instance, Selector},
+	poly::Rotation,
+};
+use native::div_rem_pow2;
+use std::marker::PhantomData;
+
+/// Number of fractional bits of the fixed-point scores and trust values.
+pub const FRAC_BITS: usize = 16;
+/// Fixed-point number with the precision of the scores, to compute with them
+/// natively like the circuit does.
+pub type Score = Fixed<FRAC_BITS>;
+/// Number of bits of the scores, which bounds them below
+/// `2^(SCORE_BITS - FRAC_BITS)`.
+pub const SCORE_BITS: usize = 64;
+/// Number of bits dropped when rounding a product of three fixed-point
+/// numbers back to a score.
+const REM_BITS: usize = 2 * FRAC_BITS;
+/// Number of bits of the limbs of the range checks.
+const LIMB_BITS: usize = 8;
+
+/// Returns the number of bits of the rounding deficit of a normalized row of
+/// `n` opinions. Rounding every opinion down loses less than one unit each,
+/// so the deficit is below `n`, and below `2^deficit_bits(n)`.
+pub const fn deficit_bits(n: usize) -> usize {
+	(usize::BITS - n.leading_zeros()) as usize
+}
+
+#[derive(Clone)]
+/// Configuration elements for the circuit are defined here.
+pub struct IterationConfig {
+	/// Configures the range chip.
+	range: RangeConfig,
+	/// Configures a column for the x.
+	x: Column<Advice>,
+	/// Configures a column for the y.
+	y: Column<Advice>,
+	/// Configures a column for the accumulator.
+	acc: Column<Advice>,
+	/// Configures a selector for the `acc + x * y` gate.
+	mul_add: Selector,
+	/// Configures a selector for the division with remainder gate.
+	div: Selector,
+}
+
+/// Constructs a chip running one update `t' = (1 - a) * C^T * t + a * p` of
+/// the EigenTrust algorithm over fixed-point numbers with `FRAC_BITS`
+/// fractional bits, rounding the new scores down.
+pub struct IterationChip<F: FieldExt, const N: usize> {
+	/// Assigns cells for the local trust matrix, `local_trust[i][j]` being
+	/// the normalized trust of the peer `i` in the peer `j`.
+	local_trust: [[AssignedCell<F, F>; N]; N],
+	/// Assigns cells for the scores of the previous iteration.
+	scores: [AssignedCell<F, F>; N],
+	/// Assigns cells for the pre-trust scores.
+	pre_trust: [AssignedCell<F, F>; N],
+	/// Weight of the pre-trust scores.
+	alpha: F,
+}
+
+impl<F: FieldExt, const N: usize> IterationChip<F, N> {
+	/// Create a new chip.
+	pub fn new(
+		local_trust: [[AssignedCell<F, F>; N]; N], scores: [AssignedCell<F, F>; N],
+		pre_trust: [AssignedCell<F, F>; N], alpha: F,
+	) -> Self {
+		Self { local_trust, scores, pre_trust, alpha }
+	}
+
+	/// Make the circuit config.
+	pub fn configure(meta: &mut ConstraintSystem<F>) -> IterationConfig {
+		let range = RangeChip::<_, LIMB_BITS>::configure(meta);
+		let x = meta.advice_column();
+		let y = meta.advice_column();
+		let acc = meta.advice_column();
+		let fixed = meta.fixed_column();
+		let mul_add = meta.selector();
+		let div = meta.selector();
+
+		meta.enable_equality(x);
+		meta.enable_equality(y);
+		meta.enable_equality(acc);
+		meta.enable_constant(fixed);
+
+		meta.create_gate("mul_add", |v_cells| {
+			let x_exp = v_cells.query_advice(x, Rotation::cur());
+			let y_exp = v_cells.query_advice(y, Rotation::cur());
+			let acc_exp = v_cells.query_advice(acc, Rotation::cur());
+			let acc_next_exp = v_cells.query_advice(acc, Rotation::next());
+			let s_exp = v_cells.query_selector(mul_add);
+
+			vec![
+				// acc + x * y - acc_next == 0
+				s_exp * (acc_exp + x_exp * y_exp - acc_next_exp),
+			]
+		});
+
+		meta.create_gate("div", |v_cells| {
+			let shift = Expression::Constant(F::from_u128(1 << REM_BITS));
+			let q_exp = v_cells.query_advice(x, Rotation::cur());
+			let r_exp = v_cells.query_advice(y, Rotation::cur());
+			let num_exp = v_cells.query_advice(acc, Rotation::cur());
+			let s_exp = v_cells.query_selector(div);
+
+			vec![
+				// q * 2^REM_BITS + r - num == 0
+				// With q and r range checked, q is the rounded down quotient.
+				s_exp * (q_exp * shift + r_exp - num_exp),
+			]
+		});
+
+		IterationConfig { range, x, y, acc, mul_add, div }
+	}
+
+	/// Load the table of the range checks. Call it once in the circuit.
+	pub fn load_table(config: &IterationConfig, layouter: impl Layouter<F>) -> Result<(), Error> {
+		RangeChip::<F, LIMB_BITS>::load_table(&config.range, layouter)
+	}
+
+	/// Synthesize the check that a row of the local trust matrix is
+	/// normalized: every opinion is range checked to `FRAC_BITS + 1` bits, so
+	/// it is between zero and one, and the opinions either sum to
+	/// `2^FRAC_BITS` minus a deficit below `2^deficit_bits(N)`, left by
+	/// rounding every opinion down like `SparseTrust::normalize_row`, or are
+	/// all zero, for a peer without opinions.
+	pub fn check_row(
+		row: &[AssignedCell<F, F>; N], config: &IterationConfig, mut layouter: impl Layouter<F>,
+	) -> Result<(), Error> {
+		let scale = F::from_u128(1 << FRAC_BITS);
+		let (deficit, empty) = layouter.assign_region(
+			|| "row_sum",
+			|mut region: Region<'_, F>| {
+				// sum_j c_ij * 1
+				let mut acc =
+					region.assign_advice_from_constant(|| "acc_0", config.acc, 0, F::zero())?;
+				for (j, c_ij) in row.iter().enumerate() {
+					config.mul_add.enable(&mut region, j)?;
+					let c = c_ij.copy_advice(|| "c_ij", &mut region, config.x, j)?;
+					region.assign_advice_from_constant(|| "one", config.y, j, F::one())?;
+					let next = acc.value().cloned() + c.value();
+					acc = region.assign_advice(|| "acc", config.acc, j + 1, || next)?;
+				}
+				let sum = acc.value().cloned();
+				let is_empty = sum.map(|sum| sum == F::zero());
+
+				// sum + deficit * 1 + empty * 2^FRAC_BITS == 2^FRAC_BITS
+				// With the deficit range checked, and the opinions too, an empty
+				// row can only be all zeros.
+				config.mul_add.enable(&mut region, N)?;
+				let deficit_value =
+					sum.zip(is_empty).map(
+						|(sum, is_empty)| {
+							if is_empty {
+								F::zero()
+							} else {
+								scale - sum
+							}
+						},
+					);
+				let deficit = region.assign_advice(|| "deficit", config.x, N, || deficit_value)?;
+				region.assign_advice_from_constant(|| "one", config.y, N, F::one())?;
+				let next = acc.value().cloned() + deficit_value;
+				acc = region.assign_advice(|| "acc", config.acc, N + 1, || next)?;
+
+				config.mul_add.enable(&mut region, N + 1)?;
+				let empty_value = is_empty.map(|is_empty| F::from(is_empty as u64));
+				let empty = region.assign_advice(|| "empty", config.x, N + 1, || empty_value)?;
+				region.assign_advice_from_constant(|| "scale", config.y, N + 1, scale)?;
+				let next = acc.value().cloned() + empty_value * Value::known(scale);
+				acc = region.assign_advice(|| "acc", config.acc, N + 2, || next)?;
+				region.constrain_constant(acc.cell(), scale)?;
+
+				Ok((deficit, empty))
+			},
+		)?;
+
+		RangeChip::<_, LIMB_BITS>::range_check(
+			deficit,
+			deficit_bits(N),
+			&config.range,
+			layouter.namespace(|| "deficit_range"),
+		)?;
+		RangeChip::<_, LIMB_BITS>::range_check(
+			empty,
+			1,
+			&config.range,
+			layouter.namespace(|| "empty_range"),
+		)?;
+		for c_ij in row {
+			RangeChip::<_, LIMB_BITS>::range_check(
+				c_ij.clone(),
+				FRAC_BITS + 1,
+				&config.range,
+				layouter.namespace(|| "c_ij_range"),
+			)?;
+		}
+		Ok(())
+	}
+
+	/// Synthesize the circuit, returning the new scores.
+	pub fn synthesize(
+		&self, config: IterationConfig, mut layouter: impl Layouter<F>,
+	) -> Result<[AssignedCell<F, F>; N], Error> {
+		let scale = F::from_u128(1 << FRAC_BITS);
+		let mut new_scores = Vec::with_capacity(N);
+		for i in 0..N {
+			let (q, r) = layouter.assign_region(
+				|| "update",
+				|mut region: Region<'_, F>| {
+					// sum_j c_ji * t_j
+					let mut acc =
+						region.assign_advice_from_constant(|| "acc_0", config.acc, 0, F::zero())?;
+					for (j, (c_j, t_j)) in self.local_trust.iter().zip(&self.scores).enumerate() {
+						config.mul_add.enable(&mut region, j)?;
+						let c = c_j[i].copy_advice(|| "c_ji", &mut region, config.x, j)?;
+						let t = t_j.copy_advice(|| "t_j", &mut region, config.y, j)?;
+						let next = acc.value().cloned() + c.value().cloned() * t.value();
+						acc = region.assign_advice(|| "acc", config.acc, j + 1, || next)?;
+					}
+					let sum = acc;
+
+					// (1 - a) * sum + a * p_i
+					let offset = N + 1;
+					region.assign_advice_from_constant(
+						|| "acc_0",
+						config.acc,
+						offset,
+						F::zero(),
+					)?;
+					config.mul_add.enable(&mut region, offset)?;
+					region.assign_advice_from_constant(
+						|| "1 - a",
+						config.x,
+						offset,
+						scale - self.alpha,
+					)?;
+					let sum = sum.copy_advice(|| "sum", &mut region, config.y, offset)?;
+					let acc_value = sum.value().map(|sum| (scale - self.alpha) * sum);
+					region.assign_advice(|| "acc", config.acc, offset + 1, || acc_value)?;
+
+					config.mul_add.enable(&mut region, offset + 1)?;
+					region.assign_advice_from_constant(
+						|| "a",
+						config.x,
+						offset + 1,
+						self.alpha * scale,
+					)?;
+					let p = self.pre_trust[i].copy_advice(
+						|| "p_i",
+						&mut region,
+						config.y,
+						offset + 1,
+					)?;
+					let num_value = acc_value + p.value().map(|p| self.alpha * scale * p);
+					let num =
+						region.assign_advice(|| "num", config.acc, offset + 2, || num_value)?;
+
+					// num = q * 2^REM_BITS + r
+					config.div.enable(&mut region, offset + 2)?;
+					let qr = num.value().map(|num| div_rem_pow2(*num, REM_BITS));
+					let q =
+						region.assign_advice(|| "q", config.x, offset + 2, || qr.map(|qr| qr.0))?;
+					let r =
+						region.assign_advice(|| "r", config.y, offset + 2, || qr.map(|qr| qr.1))?;
+
+					Ok((q, r))
+				},
+			)?;
+
+			let range = &config.range;
+			RangeChip::<_, LIMB_BITS>::range_check(
+				q.clone(),
+				SCORE_BITS,
+				range,
+				layouter.namespace(|| "q_range"),
+			)?;
+			RangeChip::<_, LIMB_BITS>::range_check(
+				r,
+				REM_BITS,
+				range,
+				layouter.namespace(|| "r_range"),
+			)?;
+
+			new_scores.push(q);
+		}
+
+		new_scores.try_into().map_err(|_| Error::Synthesis)
+	}
+}
+
+/// The halo2 columns config for the iteration circuit.
+#[derive(Clone)]
+pub struct EigenTrustIterationConfig {
+	iteration: IterationConfig,
+	instances: InstancesConfig,
+	temp: Column<Advice>,
+	pub_ins: Column<Instance>,
+}
+
+/// Circuit proving `I` iterations of the EigenTrust algorithm over `N` peers,
+/// starting from the pre-trust scores, with a normalized local trust matrix.
+/// The only public input is the commitment to the pre-trust scores followed
+/// by the resulting global trust scores, all as fixed-point numbers with
+/// `FRAC_BITS` fractional bits, see `gadgets::instances::commit_instances`.
+#[derive(Clone)]
+pub struct EigenTrustIterationCircuit<F: FieldExt, const N: usize, const I: usize, P>
+where
+	P: RoundParams<F, 5>,
+{
+	/// Normalized local trust matrix, each row summing to `2^FRAC_BITS`
+	/// up to the rounding deficit, or empty, see `IterationChip::check_row`.
+	local_trust: [[Value<F>; N]; N],
+	/// Pre-trust scores.
+	pre_trust: [Value<F>; N],
+	/// Weight of the pre-trust scores.
+	alpha: F,
+	_params: PhantomData<P>,
+}
+
+impl<F: FieldExt, const N: usize, const I: usize, P> EigenTrustIterationCircuit<F, N, I, P>
+where
+	P: RoundParams<F, 5>,
+{
+	/// Create a new circuit.
+	pub fn new(local_trust: [[F; N]; N], pre_trust: [F; N], alpha: F) -> Self {
+		Self {
+			local_trust: local_trust.map(|row| row.map(Value::known)),
+			pre_trust: pre_trust.map(Value::known),
+			alpha,
+			_params: PhantomData,
+		}
+	}
+}
+
+impl<F: FieldExt, const N: usize, const I: usize, P> Circuit<F>
+	for EigenTrustIterationCircuit<F, N, I, P>
+where
+	P: RoundParams<F, 5>,
+{
+	type Config = EigenTrustIterationConfig;
+	type FloorPlanner = SimpleFloorPlanner;
+
+	fn without_witnesses(&self) -> Self {
+		Self {
+			local_trust: [[Value::unknown(); N]; N],
+			pre_trust: [Value::unknown(); N],
+			alpha: self.alpha,
+			_params: PhantomData,
+		}
+	}
+
+	fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+		let iteration = IterationChip::<_, N>::configure(meta);
+		let instances = InstancesChip::<_, P>::configure(meta);
+		let temp = meta.advice_column();
+		let pub_ins = meta.instance_column();
+
+		meta.enable_equality(temp);
+		meta.enable_equality(pub_ins);
+
+		EigenTrustIterationConfig { iteration, instances, temp, pub_ins }
+	}
+
+	fn synthesize(
+		&self, config: Self::Config, mut layouter: impl Layouter<F>,
+	) -> Result<(), Error> {
+		let (local_trust, pre_trust) = layouter.assign_region(
+			|| "temp",
+			|mut region: Region<'_, F>| {
+				let mut offset = 0;
+				let local_trust = self.local_trust.try_map(|row| {
+					row.try_map::<_, Result<AssignedCell<F, F>, Error>>(|c| {
+						let cell = region.assign_advice(|| "c_ij", config.temp, offset, || c);
+						offset += 1;
+						cell
+					})
+				})?;
+				let pre_trust =
+					self.pre_trust.try_map::<_, Result<AssignedCell<F, F>, Error>>(|p| {
+						let cell = region.assign_advice(|| "p_i", config.temp, offset, || p);
+						offset += 1;
+						cell
+					})?;
+				Ok((local_trust, pre_trust))
+			},
+		)?;
+
+		IterationChip::<_, N>::load_table(&config.iteration, layouter.namespace(|| "table"))?;
+		for row in &local_trust {
+			IterationChip::check_row(row, &config.iteration, layouter.namespace(|| "row"))?;
+		}
+		let mut scores = pre_trust.clone();
+		for _ in 0..I {
+			let chip =
+				IterationChip::new(local_trust.clone(), scores, pre_trust.clone(), self.alpha);
+			scores =
+				chip.synthesize(config.iteration.clone(), layouter.namespace(|| "iteration"))?;
+		}
+
+		InstancesChip::<_, P>::expose(
+			&[pre_trust.to_vec(), scores.to_vec()].concat(),
+			config.pub_ins,
+			0,
+			&config.instances,
+			layouter.namespace(|| "instances"),
+		)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{
+		native::{iterate, SparseTrustBuilder},
+		network::Network,
+		*,
+	};
+	use crate::{
+		params::poseidon_bn254_5x5::Params,
+		public_inputs::IterationPublicInputs,
+		utils::{generate_params, prove_and_verify},
+	};
+	use halo2wrong::{
+		curves::bn256::{Bn256, Fr},
+		halo2::dev::MockProver,
+	};
+	use rand::thread_rng;
+
+	const N: usize = 3;
+	const I: usize = 2;
+
+	type IterationCircuit = EigenTrustIterationCircuit<Fr, N, I, Params>;
+
+	/// Converts a fraction to a fixed-point number.
+	fn fixed(num: u64, den: u64) -> Fr {
+		Fr::from((num << FRAC_BITS) / den)
+	}
+
+	/// Returns the public input committing to the pre-trust and the global
+	/// trust scores.
+	fn commit(pre_trust: &[Fr; N], scores: &[Fr; N]) -> Vec<Fr> {
+		let instances = IterationPublicInputs::new()
+			.with_pre_trust(*pre_trust)
+			.with_scores(*scores)
+			.build::<Params>()
+			.unwrap();
+		instances.columns()[0].clone()
+	}
+
+	fn setup() -> (IterationCircuit, [Fr; N], [Fr; N]) {
+		let local_trust = [
+			[Fr::zero(), fixed(1, 2), fixed(1, 2)],
+			[fixed(1, 4), Fr::zero(), fixed(3, 4)],
+			[fixed(1, 2), fixed(1, 2), Fr::zero()],
+		];
+		let pre_trust = [fixed(1, 2), fixed(1, 2), Fr::zero()];
+		let alpha = fixed(1, 5);
+
+		let scores = iterate(&local_trust, &pre_trust, alpha, I);
+		let circuit = IterationCircuit::new(local_trust, pre_trust, alpha);
+		(circuit, pre_trust, scores)
+	}
+
+	#[test]
+	fn test_eigen_trust_iteration() {
+		let (circuit, pre_trust, scores) = setup();
+		let prover = MockProver::run(11, &circuit, vec![commit(&pre_trust, &scores)]).unwrap();
+		assert_eq!(prover.verify(), Ok(()));
+	}
+
+	#[test]
+	fn test_eigen_trust_iteration_wrong_scores() {
+		let (circuit, pre_trust, mut scores) = setup();
+		scores[0] += Fr::one();
+		let prover = MockProver::run(11, &circuit, vec![commit(&pre_trust, &scores)]).unwrap();
+		assert!(prover.verify().is_err());
+
+		// Other pre-trust scores than the ones of the witness.
+		let (circuit, mut pre_trust, scores) = setup();
+		pre_trust.swap(1, 2);
+		let prover = MockProver::run(11, &circuit, vec![commit(&pre_trust, &scores)]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_eigen_trust_iteration_not_normalized() {
+		let pre_trust = [fixed(1, 2), fixed(1, 2), Fr::zero()];
+		let alpha = fixed(1, 5);
+		let rows = [
+			// Summing to 3/4.
+			[Fr::zero(), fixed(1, 2), fixed(1, 4)],
+			// Summing to one, with a negative opinion.
+			[-fixed(1, 4), fixed(1, 2), fixed(3, 4)],
+		];
+		for row in rows {
+			let local_trust = [row, [fixed(1, 4), Fr::zero(), fixed(3, 4)], [
+				fixed(1, 2),
+				fixed(1, 2),
+				Fr::zero(),
+			]];
+			let scores = iterate(&local_trust, &pre_trust, alpha, I);
+			let circuit = IterationCircuit::new(local_trust, pre_trust, alpha);
+			let prover = MockProver::run(11, &circuit, vec![commit(&pre_trust, &scores)]).unwrap();
+			assert!(prover.verify().is_err());
+		}
+	}
+
+	#[test]
+	fn test_eigen_trust_iteration_network() {
+		// Thirds round down to a row summing to one unit less than one, and the
+		// last peer has no opinions at all.
+		let mut builder = SparseTrustBuilder::new(N);
+		builder.set(0, 1, Fr::from(1)).set(0, 2, Fr::from(2));
+		builder.set(1, 0, Fr::from(3)).set(1, 2, Fr::from(3));
+		let pre_trust = [fixed(1, 2), fixed(1, 2), Fr::zero()];
+		let alpha = fixed(1, 5);
+		let mut network = Network::new(builder.build(), pre_trust.to_vec(), alpha);
+
+		let mut local_trust = [[Fr::zero(); N]; N];
+		for (i, row) in local_trust.iter_mut().enumerate() {
+			for (j, c_ij) in row.iter_mut().enumerate() {
+				*c_ij = network.local_trust().get(i, j);
+			}
+		}
+		assert_ne!(local_trust[0][1] + local_trust[0][2], fixed(1, 1));
+		let scores = iterate(&local_trust, &pre_trust, alpha, I);
+		assert_eq!(network.converge(I), &scores[..]);
+		let circuit = IterationCircuit::new(local_trust, pre_trust, alpha);
+		let prover = MockProver::run(11, &circuit, vec![commit(&pre_trust, &scores)]).unwrap();
+		assert_eq!(prover.verify(), Ok(()));
+	}
+
+	#[test]
+	fn test_eigen_trust_iteration_production() {
+		let (circuit, pre_trust, scores) = setup();
+		let pub_ins = commit(&pre_trust, &scores);
+		let params = generate_params(11);
+		let res = prove_and_verify::<Bn256, _, _>(params, circuit, &[&pub_ins], &mut thread_rng())
+			.unwrap();
+		assert!(res);
+	}
+}
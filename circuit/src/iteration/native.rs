@@ -0,0 +1,454 @@
+use super::{FRAC_BITS, REM_BITS};
+use crate::gadgets::{bits2num::to_bits, fixed_point::div_fixed};
+use halo2wrong::halo2::arithmetic::FieldExt;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+
+/// Number of rows of the local trust matrix multiplied by each thread.
+#[cfg(feature = "parallel")]
+const ROWS_PER_THREAD: usize = 1024;
+
+/// Splits `value` into the quotient and the remainder of dividing it by
+/// `2^shift`, treating it as an integer.
+pub fn div_rem_pow2<F: FieldExt>(value: F, shift: usize) -> (F, F) {
+	let mut bytes = [0; 32];
+	bytes.copy_from_slice(value.to_repr().as_ref());
+	let bits = to_bits::<256>(bytes);
+
+	let mut q = F::zero();
+	let mut r = F::zero();
+	for (i, bit) in bits.iter().enumerate().rev() {
+		let acc = if i < shift { &mut r } else { &mut q };
+		*acc = acc.double();
+		if *bit {
+			*acc += F::one();
+		}
+	}
+	(q, r)
+}
+
+/// Local trust matrix keeping only the nonzero opinions, in the compressed
+/// sparse row format, so its size grows with the number of opinions rather
+/// than with the square of the number of peers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseTrust<F: FieldExt> {
+	/// Number of peers.
+	size: usize,
+	/// Index of the first opinion of every peer in `neighbors` and `values`,
+	/// followed by the number of opinions.
+	row_starts: Vec<usize>,
+	/// The neighbor of every opinion.
+	neighbors: Vec<usize>,
+	/// The normalized trust of every opinion.
+	values: Vec<F>,
+}
+
+impl<F: FieldExt> SparseTrust<F> {
+	/// Returns the sparse form of the dense `local_trust`.
+	pub fn from_dense<const N: usize>(local_trust: &[[F; N]; N]) -> Self {
+		let mut builder = SparseTrustBuilder::new(N);
+		for (i, row) in local_trust.iter().enumerate() {
+			for (j, c_ij) in row.iter().enumerate() {
+				builder.set(i, j, *c_ij);
+			}
+		}
+		builder.build()
+	}
+
+	/// Returns the number of peers.
+	pub fn size(&self) -> usize {
+		self.size
+	}
+
+	/// Returns the number of nonzero opinions.
+	pub fn num_opinions(&self) -> usize {
+		self.values.len()
+	}
+
+	/// Returns the nonzero opinions of the peer `i`, as the neighbors and the
+	/// trust in them.
+	pub fn row(&self, i: usize) -> impl Iterator<Item = (usize, &F)> {
+		let range = self.row_starts[i]..self.row_starts[i + 1];
+		self.neighbors[range.clone()].iter().copied().zip(&self.values[range])
+	}
+
+	/// Returns the trust of the peer `i` in the peer `j`.
+	pub fn get(&self, i: usize, j: usize) -> F {
+		self.row(i).find(|(neighbor, _)| *neighbor == j).map_or(F::zero(), |(_, c_ij)| *c_ij)
+	}
+
+	/// Sets the trust of the peer `i` in the peer `j`, replacing the previous
+	/// one. A zero trust removes the opinion.
+	pub fn set(&mut self, i: usize, j: usize, c_ij: F) {
+		assert!(i < self.size && j < self.size);
+		let start = self.row_starts[i];
+		let row = &self.neighbors[start..self.row_starts[i + 1]];
+		match (row.binary_search(&j), c_ij == F::zero()) {
+			(Ok(pos), false) => self.values[start + pos] = c_ij,
+			(Ok(pos), true) => {
+				self.neighbors.remove(start + pos);
+				self.values.remove(start + pos);
+				self.row_starts[i + 1..].iter_mut().for_each(|row_start| *row_start -= 1);
+			},
+			(Err(pos), false) => {
+				self.neighbors.insert(start + pos, j);
+				self.values.insert(start + pos, c_ij);
+				self.row_starts[i + 1..].iter_mut().for_each(|row_start| *row_start += 1);
+			},
+			(Err(_), true) => {},
+		}
+	}
+
+	/// Adds a peer without opinions, returning its index.
+	pub fn add_peer(&mut self) -> usize {
+		self.row_starts.push(self.values.len());
+		self.size += 1;
+		self.size - 1
+	}
+
+	/// Removes the peer `index` with its opinions and the opinions about it,
+	/// moving the following peers down by one. Returns the new indices of the
+	/// peers which had an opinion about it.
+	pub fn remove_peer(&mut self, index: usize) -> Vec<usize> {
+		assert!(index < self.size);
+		let shift = |i: usize| if i > index { i - 1 } else { i };
+		let mut builder = SparseTrustBuilder::new(self.size - 1);
+		let mut affected = Vec::new();
+		for i in (0..self.size).filter(|i| *i != index) {
+			for (j, c_ij) in self.row(i) {
+				if j == index {
+					affected.push(shift(i));
+				} else {
+					builder.set(shift(i), shift(j), *c_ij);
+				}
+			}
+		}
+		*self = builder.build();
+		affected
+	}
+
+	/// Scales the opinions of the peer `i` to sum to one, rounding them down
+	/// like the `OpinionCircuit`.
+	pub fn normalize_row(&mut self, i: usize) {
+		let range = self.row_starts[i]..self.row_starts[i + 1];
+		let sum = self.values[range.clone()].iter().fold(F::zero(), |acc, c_ij| acc + c_ij);
+		for c_ij in &mut self.values[range] {
+			*c_ij = div_fixed::<_, FRAC_BITS>(*c_ij, sum);
+		}
+	}
+
+	/// Returns the transposed matrix, whose row `i` holds the opinions about
+	/// the peer `i`.
+	pub fn transpose(&self) -> Self {
+		let mut builder = SparseTrustBuilder::new(self.size);
+		for i in 0..self.size {
+			for (j, c_ij) in self.row(i) {
+				builder.set(j, i, *c_ij);
+			}
+		}
+		builder.build()
+	}
+}
+
+/// Builds a `SparseTrust` from individual opinions.
+#[derive(Clone, Debug)]
+pub struct SparseTrustBuilder<F: FieldExt> {
+	/// Number of peers.
+	size: usize,
+	/// The nonzero opinions, by the peer and the neighbor.
+	opinions: BTreeMap<(usize, usize), F>,
+}
+
+impl<F: FieldExt> SparseTrustBuilder<F> {
+	/// Create a new builder for a network of `size` peers without opinions.
+	pub fn new(size: usize) -> Self {
+		Self { size, opinions: BTreeMap::new() }
+	}
+
+	/// Sets the trust of the peer `i` in the peer `j`, replacing the previous
+	/// one. A zero trust removes the opinion.
+	pub fn set(&mut self, i: usize, j: usize, c_ij: F) -> &mut Self {
+		assert!(i < self.size && j < self.size);
+		if c_ij == F::zero() {
+			self.opinions.remove(&(i, j));
+		} else {
+			self.opinions.insert((i, j), c_ij);
+		}
+		self
+	}
+
+	/// Builds the matrix.
+	pub fn build(&self) -> SparseTrust<F> {
+		let mut row_starts = vec![0; self.size + 1];
+		for (i, _) in self.opinions.keys() {
+			row_starts[i + 1] += 1;
+		}
+		for i in 0..self.size {
+			row_starts[i + 1] += row_starts[i];
+		}
+		// The opinions are sorted by the peer, then by the neighbor.
+		let neighbors = self.opinions.keys().map(|(_, j)| *j).collect();
+		let values = self.opinions.values().copied().collect();
+		SparseTrust { size: self.size, row_starts, neighbors, values }
+	}
+}
+
+/// Returns `C^T * t`, for the sparse local trust matrix `C`.
+fn mul_transposed_serial<F: FieldExt>(local_trust: &SparseTrust<F>, scores: &[F]) -> Vec<F> {
+	let mut sums = vec![F::zero(); local_trust.size()];
+	for (j, t_j) in scores.iter().enumerate() {
+		for (i, c_ji) in local_trust.row(j) {
+			sums[i] += *c_ji * t_j;
+		}
+	}
+	sums
+}
+
+/// Returns `C^T * t`, for the sparse local trust matrix `C`.
+#[cfg(not(feature = "parallel"))]
+fn mul_transposed<F: FieldExt>(local_trust: &SparseTrust<F>, scores: &[F]) -> Vec<F> {
+	mul_transposed_serial(local_trust, scores)
+}
+
+/// Returns `C^T * t`, for the sparse local trust matrix `C`. The rows are
+/// split into chunks of `ROWS_PER_THREAD`, whose sums are accumulated by
+/// separate threads and then added together. Matrices of a single chunk,
+/// like the ones of `update` and `iterate`, are multiplied on the current
+/// thread.
+#[cfg(feature = "parallel")]
+fn mul_transposed<F: FieldExt>(local_trust: &SparseTrust<F>, scores: &[F]) -> Vec<F> {
+	let size = local_trust.size();
+	if size <= ROWS_PER_THREAD {
+		return mul_transposed_serial(local_trust, scores);
+	}
+	scores
+		.par_chunks(ROWS_PER_THREAD)
+		.enumerate()
+		.fold(
+			|| vec![F::zero(); size],
+			|mut sums, (chunk, chunk_scores)| {
+				for (k, t_j) in chunk_scores.iter().enumerate() {
+					for (i, c_ji) in local_trust.row(chunk * ROWS_PER_THREAD + k) {
+						sums[i] += *c_ji * t_j;
+					}
+				}
+				sums
+			},
+		)
+		.reduce(
+			|| vec![F::zero(); size],
+			|mut sums, other| {
+				sums.iter_mut().zip(other).for_each(|(sum, other)| *sum += other);
+				sums
+			},
+		)
+}
+
+/// Returns the new score `((1 - a) * sum + a * p_i)`, for the `sum` of the
+/// trust in the peer weighted by the scores, rounded down.
+fn next_score<F: FieldExt>(sum: F, p_i: &F, alpha: F) -> F {
+	let scale = F::from_u128(1 << FRAC_BITS);
+	let num = (scale - alpha) * sum + alpha * scale * p_i;
+	div_rem_pow2(num, REM_BITS).0
+}
+
+/// Runs one update `t' = (1 - a) * C^T * t + a * p` over the sparse
+/// `local_trust`, rounding the scores down, the same way as the
+/// `IterationChip`. With the `parallel` feature, the work is split among
+/// threads.
+pub fn update_sparse<F: FieldExt>(
+	local_trust: &SparseTrust<F>, scores: &[F], pre_trust: &[F], alpha: F,
+) -> Vec<F> {
+	assert!(scores.len() == local_trust.size() && pre_trust.len() == local_trust.size());
+	let sums = mul_transposed(local_trust, scores);
+	#[cfg(feature = "parallel")]
+	let sums = sums.into_par_iter();
+	#[cfg(not(feature = "parallel"))]
+	let sums = sums.into_iter();
+	sums.zip(pre_trust).map(|(sum, p_i)| next_score(sum, p_i, alpha)).collect()
+}
+
+/// Runs one Gauss-Seidel update of the `scores` in place: the new score of
+/// a peer is computed like in `update_sparse`, but from the new scores of the
+/// peers before it. Takes the transposed local trust matrix, see
+/// `SparseTrust::transpose`.
+pub fn update_gauss_seidel<F: FieldExt>(
+	transposed: &SparseTrust<F>, scores: &mut [F], pre_trust: &[F], alpha: F,
+) {
+	assert!(scores.len() == transposed.size() && pre_trust.len() == transposed.size());
+	for (i, p_i) in pre_trust.iter().enumerate() {
+		let sum = transposed.row(i).fold(F::zero(), |acc, (j, c_ji)| acc + *c_ji * scores[j]);
+		scores[i] = next_score(sum, p_i, alpha);
+	}
+}
+
+/// Runs `iterations` updates over the sparse `local_trust`, starting from the
+/// pre-trust scores. See `iterate`.
+pub fn converge<F: FieldExt>(
+	local_trust: &SparseTrust<F>, pre_trust: &[F], alpha: F, iterations: usize,
+) -> Vec<F> {
+	converge_from(local_trust, pre_trust, pre_trust, alpha, iterations)
+}
+
+/// Runs `iterations` updates over the sparse `local_trust`, starting from the
+/// `scores`, e.g. the scores of a previous run.
+pub fn converge_from<F: FieldExt>(
+	local_trust: &SparseTrust<F>, scores: &[F], pre_trust: &[F], alpha: F, iterations: usize,
+) -> Vec<F> {
+	let mut scores = scores.to_vec();
+	for _ in 0..iterations {
+		scores = update_sparse(local_trust, &scores, pre_trust, alpha);
+	}
+	scores
+}
+
+/// Runs one update `t' = (1 - a) * C^T * t + a * p`, rounding the scores
+/// down, the same way as the `IterationChip`. Converts the dense
+/// `local_trust` on every call, so use `iterate` or `converge` for several
+/// updates.
+pub fn update<F: FieldExt, const N: usize>(
+	local_trust: &[[F; N]; N], scores: &[F; N], pre_trust: &[F; N], alpha: F,
+) -> [F; N] {
+	let local_trust = SparseTrust::from_dense(local_trust);
+	let scores = update_sparse(&local_trust, scores, pre_trust, alpha);
+	scores.try_into().unwrap()
+}
+
+/// Runs `iterations` updates starting from the pre-trust scores, returning
+/// the global trust scores proven by the `EigenTrustIterationCircuit`. The
+/// dense `local_trust` is converted to a `SparseTrust` once, for all the
+/// updates.
+pub fn iterate<F: FieldExt, const N: usize>(
+	local_trust: &[[F; N]; N], pre_trust: &[F; N], alpha: F, iterations: usize,
+) -> [F; N] {
+	let local_trust = SparseTrust::from_dense(local_trust);
+	let scores = converge(&local_trust, pre_trust, alpha, iterations);
+	scores.try_into().unwrap()
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use halo2wrong::curves::bn256::Fr;
+
+	#[test]
+	fn test_div_rem_pow2() {
+		let (q, r) = div_rem_pow2(Fr::from(0x1234_5678_9abc), 32);
+		assert_eq!(q, Fr::from(0x1234));
+		assert_eq!(r, Fr::from(0x5678_9abc));
+	}
+
+	#[test]
+	fn test_iterate_converges() {
+		let half = Fr::from(1 << (FRAC_BITS - 1));
+		// Two peers trusting each other fully, with uneven pre-trust.
+		let local_trust = [[Fr::zero(), half + half], [half + half, Fr::zero()]];
+		let pre_trust = [half + half, Fr::zero()];
+		let alpha = Fr::zero();
+
+		let scores = iterate(&local_trust, &pre_trust, alpha, 1);
+		assert_eq!(scores, [Fr::zero(), half + half]);
+		let scores = iterate(&local_trust, &pre_trust, half, 1);
+		assert_eq!(scores, [half, half]);
+	}
+
+	#[test]
+	fn test_sparse_trust_builder() {
+		let half = Fr::from(1 << (FRAC_BITS - 1));
+		let mut builder = SparseTrustBuilder::new(3);
+		builder.set(2, 0, half).set(0, 1, half).set(0, 2, half).set(1, 2, half);
+		// Replacing and removing opinions.
+		builder.set(0, 2, half + half).set(1, 2, Fr::zero());
+		let local_trust = builder.build();
+
+		assert_eq!(local_trust.size(), 3);
+		assert_eq!(local_trust.num_opinions(), 3);
+		assert_eq!(local_trust.get(0, 2), half + half);
+		assert_eq!(local_trust.get(1, 2), Fr::zero());
+		assert_eq!(local_trust.row(1).count(), 0);
+		assert_eq!(local_trust.row(2).collect::<Vec<_>>(), vec![(0, &half)]);
+
+		let dense =
+			[[Fr::zero(), half, half + half], [Fr::zero(); 3], [half, Fr::zero(), Fr::zero()]];
+		assert_eq!(SparseTrust::from_dense(&dense), local_trust);
+	}
+
+	#[test]
+	fn test_gauss_seidel() {
+		let half = Fr::from(1 << (FRAC_BITS - 1));
+		let local_trust = [[Fr::zero(), half + half], [half + half, Fr::zero()]];
+		let pre_trust = [half + half, Fr::zero()];
+		let transposed = SparseTrust::from_dense(&local_trust).transpose();
+		assert_eq!(transposed.get(1, 0), half + half);
+
+		// The second peer already sees the new score of the first one.
+		let mut scores = pre_trust;
+		update_gauss_seidel(&transposed, &mut scores, &pre_trust, half);
+		assert_eq!(scores, [half, Fr::from(1 << (FRAC_BITS - 2))]);
+	}
+
+	#[test]
+	fn test_converge_large_network() {
+		let one = Fr::from(1 << FRAC_BITS);
+		let half = Fr::from(1 << (FRAC_BITS - 1));
+		// A ring of peers, each trusting the next two equally.
+		let size = 10_000;
+		let mut builder = SparseTrustBuilder::new(size);
+		for i in 0..size {
+			builder.set(i, (i + 1) % size, half).set(i, (i + 2) % size, half);
+		}
+		let local_trust = builder.build();
+		assert_eq!(local_trust.num_opinions(), 2 * size);
+
+		let mut pre_trust = vec![Fr::zero(); size];
+		pre_trust[0] = one;
+		let scores = converge(&local_trust, &pre_trust, Fr::zero(), 2);
+		let quarter = Fr::from(1 << (FRAC_BITS - 2));
+		assert_eq!(scores[1], Fr::zero());
+		assert_eq!(scores[2], quarter);
+		assert_eq!(scores[3], quarter + quarter);
+		assert_eq!(scores[4], quarter);
+		assert_eq!(scores.iter().fold(Fr::zero(), |acc, t| acc + t), one);
+	}
+
+	#[cfg(feature = "parallel")]
+	#[test]
+	fn test_parallel_equals_serial() {
+		use rand::{Rng, SeedableRng};
+		use rand_chacha::ChaCha8Rng;
+
+		// Several chunks, the last one partial.
+		let size = 3 * ROWS_PER_THREAD + 7;
+		let mut rng = ChaCha8Rng::seed_from_u64(568);
+		let mut builder = SparseTrustBuilder::new(size);
+		for i in 0..size {
+			for _ in 0..4 {
+				let c_ij = Fr::from(rng.gen_range(1..1 << FRAC_BITS));
+				builder.set(i, rng.gen_range(0..size), c_ij);
+			}
+		}
+		let local_trust = builder.build();
+		let scores: Vec<Fr> =
+			(0..size).map(|_| Fr::from(rng.gen_range(0..1 << FRAC_BITS))).collect();
+
+		assert_eq!(
+			mul_transposed(&local_trust, &scores),
+			mul_transposed_serial(&local_trust, &scores)
+		);
+
+		// The pre-trust doesn't need to be a distribution here.
+		let pre_trust = &scores;
+		let alpha = Fr::from(1 << (FRAC_BITS - 2));
+		let mut serial = scores.clone();
+		for _ in 0..3 {
+			let sums = mul_transposed_serial(&local_trust, &serial);
+			serial = sums
+				.into_iter()
+				.zip(pre_trust)
+				.map(|(sum, p_i)| next_score(sum, p_i, alpha))
+				.collect();
+		}
+		assert_eq!(converge(&local_trust, pre_trust, alpha, 3), serial);
+	}
+}
@@ -0,0 +1,1459 @@
+use super::{
+	native::{
+		converge_from, div_rem_pow2, update_gauss_seidel, update_sparse, SparseTrust,
+		SparseTrustBuilder,
+	},
+	Score, FRAC_BITS, SCORE_BITS,
+};
+use crate::{error::Error, gadgets::fixed_point::div_fixed};
+use halo2wrong::halo2::arithmetic::FieldExt;
+#[cfg(feature = "serde")]
+use serde::{de::Error as DeError, Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+use std::{
+	collections::{HashMap, VecDeque},
+	time::Duration,
+};
+#[cfg(feature = "fs")]
+use std::{
+	fs::{read, write},
+	path::Path,
+};
+
+/// Version of the format of the network snapshots.
+#[cfg(feature = "serde")]
+const NETWORK_FORMAT_VERSION: u32 = 1;
+
+/// Number of previous scores the new scores of an iteration are compared
+/// with, to find the runs that cycle instead of settling.
+const OSCILLATION_WINDOW: usize = 8;
+
+/// Scales the values to sum to one, rounding them down. Leaves them
+/// unchanged if they are all zero.
+fn normalize<F: FieldExt>(values: &mut [F]) {
+	let sum = values.iter().fold(F::zero(), |acc, value| acc + value);
+	if sum == F::zero() {
+		return;
+	}
+	for value in values.iter_mut() {
+		*value = div_fixed::<_, FRAC_BITS>(*value, sum);
+	}
+}
+
+/// Checks that the fixed-point number fits in `SCORE_BITS` bits, the range of
+/// the scores in the circuit. Anything above, e.g. a negative field element,
+/// would make the scores grow without bounds instead of converging.
+fn in_range<F: FieldExt>(x: &F) -> bool {
+	x.to_repr().as_ref()[SCORE_BITS / 8..].iter().all(|byte| *byte == 0)
+}
+
+/// Checks that the `scores` are a distribution over `size` peers: a score for
+/// every peer, summing to one up to the rounding down of each score.
+fn check_distribution<F: FieldExt>(scores: &[F], size: usize) -> Result<(), Error> {
+	if !scores.iter().all(in_range) {
+		return Err(Error::OutOfRange);
+	}
+	let sum = scores.iter().try_fold(Score::ZERO, |acc, score| {
+		acc.checked_add(Score::from_field(*score))
+	});
+	let max_rounding = Score::from_raw(size as u128);
+	match sum {
+		Some(sum)
+			if scores.len() == size && sum <= Score::ONE && Score::ONE - sum <= max_rounding =>
+		{
+			Ok(())
+		},
+		_ => Err(Error::InvalidScores),
+	}
+}
+
+/// Runs `f`, returning its result and the time it took. The clock is not
+/// available on wasm32-unknown-unknown, where the time is zero.
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+	#[cfg(not(target_arch = "wasm32"))]
+	let start = Instant::now();
+	let res = f();
+	#[cfg(not(target_arch = "wasm32"))]
+	let elapsed = start.elapsed();
+	#[cfg(target_arch = "wasm32")]
+	let elapsed = Duration::ZERO;
+	(res, elapsed)
+}
+
+/// Returns the fixed-point number as an integer. Only the lowest 128 bits
+/// are read, like `Score::from_field`.
+fn to_int<F: FieldExt>(x: F) -> i128 {
+	let mut bytes = [0; 16];
+	bytes.copy_from_slice(&x.to_repr().as_ref()[..16]);
+	i128::from_le_bytes(bytes)
+}
+
+/// Returns the Aitken extrapolation of the limit of the sequence of scores
+/// `t0`, `t1`, `t2`: `t2 - (t2 - t1)^2 / ((t2 - t1) - (t1 - t0))`, rounded
+/// towards zero. Falls back to `t2` where the sequence doesn't shrink
+/// geometrically, or the extrapolation would be negative.
+fn aitken<F: FieldExt>(t0: F, t1: F, t2: F) -> F {
+	let (t0, t1, t2) = (to_int(t0), to_int(t1), to_int(t2));
+	let (d1, d2) = (t1 - t0, t2 - t1);
+	let den = d2 - d1;
+	let limit = d2.checked_mul(d2).and_then(|num| num.checked_div(den)).map(|q| t2 - q);
+	match limit {
+		Some(limit) if den != 0 && limit >= 0 => F::from_u128(limit.unsigned_abs()),
+		_ => F::from_u128(t2.unsigned_abs()),
+	}
+}
+
+/// The method of running the updates of a `Network`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ConvergenceStrategy {
+	/// Computes all the new scores from the scores of the previous
+	/// iteration, like the `EigenTrustIterationCircuit`.
+	#[default]
+	Jacobi,
+	/// Updates the scores in place, so the new score of a peer is computed
+	/// from the new scores of the peers before it. Usually settles in fewer
+	/// iterations, but the scores differ from the ones of the circuit.
+	GaussSeidel,
+	/// Runs two Jacobi updates per iteration, and extrapolates their limit
+	/// with Aitken's delta-squared process. Speeds up the slowly converging
+	/// networks.
+	Aitken,
+}
+
+/// How a `Network` handles the negative opinions, set with
+/// `Network::update_distrust`. Either way, a negative opinion of a peer
+/// replaces its trust in the other peer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DistrustMode {
+	/// Clamps the negative opinions to zero before the normalization, so they
+	/// don't lower any score.
+	#[default]
+	Clamp,
+	/// Runs a separate distrust pass after the trust scores: every peer
+	/// spreads its own trust score over the peers it distrusts, in proportion
+	/// to its normalized negative opinions, and the distrust is subtracted
+	/// from their scores, see `Network::net_scores`. The distrust isn't
+	/// transitive: the peers distrusted by a distrusted peer are unaffected.
+	Propagate,
+}
+
+/// Configuration of a `Network`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NetworkConfig {
+	/// How the negative opinions are handled.
+	pub distrust: DistrustMode,
+}
+
+/// Diagnostics of an iteration of `Network::converge_with_report`.
+#[derive(Clone, Debug)]
+pub struct IterationStats {
+	/// The largest change of a score in the iteration.
+	pub max_change: f64,
+	/// The sum of the changes of the scores in the iteration.
+	pub residual: f64,
+	/// The time the iteration took.
+	pub duration: Duration,
+}
+
+/// How a run of `Network::converge_with_report` ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConvergenceOutcome {
+	/// No score changed by more than `delta` in the last iteration.
+	Converged,
+	/// The scores came back to the scores of one of the previous
+	/// `OSCILLATION_WINDOW` iterations, so they cycle and would never settle:
+	/// more iterations won't help, the matrix is pathological or the `delta`
+	/// is below the rounding.
+	Oscillating,
+	/// The iterations ran out while the scores were still settling.
+	MaxIterationsReached,
+	/// An iteration gave a score above the range of the scores, because of
+	/// unchecked pre-trust or initial scores, so the run stopped before it.
+	OutOfRange,
+}
+
+/// Diagnostics of a run of `Network::converge_with_report`.
+#[derive(Clone, Debug)]
+pub struct ConvergenceReport<F: FieldExt> {
+	/// How the run ended.
+	pub outcome: ConvergenceOutcome,
+	/// The diagnostics of every iteration.
+	pub iterations: Vec<IterationStats>,
+	/// The scores after every iteration, if they were recorded.
+	pub trajectory: Option<Vec<Vec<F>>>,
+}
+
+impl<F: FieldExt> ConvergenceReport<F> {
+	/// Checks if the scores settled within the iterations.
+	pub fn converged(&self) -> bool {
+		self.outcome == ConvergenceOutcome::Converged
+	}
+
+	/// Returns the number of iterations run.
+	pub fn num_iterations(&self) -> usize {
+		self.iterations.len()
+	}
+
+	/// Returns the total time of the iterations.
+	pub fn total_duration(&self) -> Duration {
+		self.iterations.iter().map(|stats| stats.duration).sum()
+	}
+}
+
+/// When a run of the updates stops, see `Network::converge_with_report`.
+#[derive(Clone, Copy, Debug)]
+struct RunLimits {
+	/// Largest change of a score at which the scores settled.
+	delta: f64,
+	/// Maximum number of iterations.
+	max_iterations: usize,
+}
+
+/// Opinions of the peers as they were set, with their normalized matrix. Each
+/// row of the matrix is normalized from the opinions as set, so it doesn't
+/// depend on the order of the updates, and the rounding doesn't build up.
+#[derive(Clone, Debug)]
+struct Opinions<F: FieldExt> {
+	/// The opinions as set.
+	raw: SparseTrust<F>,
+	/// The opinions of every peer, scaled to sum to one.
+	normalized: SparseTrust<F>,
+}
+
+impl<F: FieldExt> Opinions<F> {
+	/// Create the opinions, normalizing every row.
+	fn new(raw: SparseTrust<F>) -> Self {
+		let mut normalized = raw.clone();
+		for i in 0..raw.size() {
+			normalized.normalize_row(i);
+		}
+		Self { raw, normalized }
+	}
+
+	/// Sets the opinion of the peer `i` about the peer `j`, zero removing it,
+	/// and normalizes the row of `i` again.
+	fn set(&mut self, i: usize, j: usize, c_ij: F) {
+		self.raw.set(i, j, c_ij);
+		self.normalize_row(i);
+	}
+
+	/// Replaces the normalized row of the peer `i` with its opinions as set,
+	/// scaled to sum to one.
+	fn normalize_row(&mut self, i: usize) {
+		let removed: Vec<usize> = self
+			.normalized
+			.row(i)
+			.map(|(j, _)| j)
+			.filter(|j| self.raw.get(i, *j) == F::zero())
+			.collect();
+		for j in removed {
+			self.normalized.set(i, j, F::zero());
+		}
+		for (j, c_ij) in self.raw.row(i) {
+			self.normalized.set(i, j, *c_ij);
+		}
+		self.normalized.normalize_row(i);
+	}
+
+	/// Adds a peer without opinions.
+	fn add_peer(&mut self) -> usize {
+		self.raw.add_peer();
+		self.normalized.add_peer()
+	}
+
+	/// Removes the peer `index`, normalizing the rows of the peers which had
+	/// an opinion about it again.
+	fn remove_peer(&mut self, index: usize) {
+		self.raw.remove_peer(index);
+		for i in self.normalized.remove_peer(index) {
+			self.normalize_row(i);
+		}
+	}
+}
+
+/// EigenTrust network keeping the local trust matrix, the pre-trust scores
+/// and the last global trust scores, all as fixed-point numbers with
+/// `FRAC_BITS` fractional bits. Peers can join and leave between the runs,
+/// which continue from the last scores.
+#[derive(Clone, Debug)]
+pub struct Network<F: FieldExt> {
+	/// Local trust, with the normalized matrix.
+	trust: Opinions<F>,
+	/// Negative opinions, as positive values.
+	distrust: Opinions<F>,
+	/// Pre-trust scores, summing to one.
+	pre_trust: Vec<F>,
+	/// Global trust scores of the last run.
+	scores: Vec<F>,
+	/// Weight of the pre-trust scores.
+	alpha: F,
+	/// Whether the scores settled in the last run, see `reconverge`.
+	converged: bool,
+	/// The largest change of a score in the last iteration of the last run,
+	/// see `converged_within`.
+	last_change: Option<Score>,
+	/// The method of running the updates.
+	strategy: ConvergenceStrategy,
+	/// The configuration of the network.
+	config: NetworkConfig,
+}
+
+impl<F: FieldExt> Network<F> {
+	/// Create a new network from the `opinions` of the peers, scaled row by
+	/// row to sum to one, with the pre-trust scores as the initial global
+	/// trust scores. Nothing is checked, see `from_pre_trust`.
+	pub fn new(opinions: SparseTrust<F>, pre_trust: Vec<F>, alpha: F) -> Self {
+		assert_eq!(opinions.size(), pre_trust.len());
+		let scores = pre_trust.clone();
+		let distrust = Opinions::new(SparseTrustBuilder::new(opinions.size()).build());
+		Self {
+			trust: Opinions::new(opinions),
+			distrust,
+			pre_trust,
+			scores,
+			alpha,
+			converged: false,
+			last_change: None,
+			strategy: ConvergenceStrategy::default(),
+			config: NetworkConfig::default(),
+		}
+	}
+
+	/// Create a new network like `new`, the standard EigenTrust cold start,
+	/// after checking that the `pre_trust` scores are a score for every peer,
+	/// summing to one, and that the opinions and the weight `alpha` are in
+	/// the range of the scores.
+	pub fn from_pre_trust(
+		opinions: SparseTrust<F>, pre_trust: Vec<F>, alpha: F,
+	) -> Result<Self, Error> {
+		let opinions_in_range =
+			(0..opinions.size()).all(|i| opinions.row(i).all(|(_, c_ij)| in_range(c_ij)));
+		if !opinions_in_range || Score::from_field(alpha) > Score::ONE || !in_range(&alpha) {
+			return Err(Error::OutOfRange);
+		}
+		check_distribution(&pre_trust, opinions.size())?;
+		Ok(Self::new(opinions, pre_trust, alpha))
+	}
+
+	/// Returns the number of peers.
+	pub fn size(&self) -> usize {
+		self.pre_trust.len()
+	}
+
+	/// Returns the normalized local trust matrix.
+	pub fn local_trust(&self) -> &SparseTrust<F> {
+		&self.trust.normalized
+	}
+
+	/// Returns the opinions of the peers as they were set, before the
+	/// normalization.
+	pub fn opinions(&self) -> &SparseTrust<F> {
+		&self.trust.raw
+	}
+
+	/// Returns the normalized negative opinions, as positive values.
+	pub fn distrust(&self) -> &SparseTrust<F> {
+		&self.distrust.normalized
+	}
+
+	/// Returns the pre-trust scores.
+	pub fn pre_trust(&self) -> &[F] {
+		&self.pre_trust
+	}
+
+	/// Returns the global trust scores of the last run.
+	pub fn scores(&self) -> &[F] {
+		&self.scores
+	}
+
+	/// Returns the dense matrix `M = (1 - a) * C^T + a * p * 1^T` of the
+	/// Jacobi updates `t' = M * t`, for scores `t` summing to one, rounded
+	/// down. The row `i` holds the weights of the scores of the peers in the
+	/// new score of `i`, so the column of a peer with opinions sums to one,
+	/// up to the rounding, and the column of a peer without any to `a`.
+	pub fn effective_transition_matrix(&self) -> Vec<Vec<F>> {
+		let scale = F::from_u128(1 << FRAC_BITS);
+		let transposed = self.local_trust().transpose();
+		self.pre_trust
+			.iter()
+			.enumerate()
+			.map(|(i, p_i)| {
+				let mut row = vec![F::zero(); self.size()];
+				for (j, c_ji) in transposed.row(i) {
+					row[j] = (scale - self.alpha) * c_ji;
+				}
+				let row = row.into_iter().map(|m_ij| m_ij + self.alpha * p_i);
+				row.map(|m_ij| div_rem_pow2(m_ij, FRAC_BITS).0).collect()
+			})
+			.collect()
+	}
+
+	/// Returns the global trust scores as fixed-point numbers. With the
+	/// Jacobi strategy, the scores of `converge(n)` from the pre-trust scores
+	/// are the ones the `EigenTrustIterationCircuit` proves for `n`
+	/// iterations over `local_trust`. The distrust of `net_scores` isn't
+	/// proven. The network itself keeps the scores as field elements, not as
+	/// `Score`.
+	pub fn fixed_scores(&self) -> Vec<Score> {
+		self.scores.iter().map(|score| Score::from_field(*score)).collect()
+	}
+
+	/// Sets the method of running the updates of `reconverge` and
+	/// `converge_with_report`.
+	pub fn set_strategy(&mut self, strategy: ConvergenceStrategy) {
+		self.strategy = strategy;
+	}
+
+	/// Returns the method of running the updates.
+	pub fn strategy(&self) -> ConvergenceStrategy {
+		self.strategy
+	}
+
+	/// Sets the configuration. The negative opinions are kept whatever the
+	/// `DistrustMode`, so changing it applies to the previous ones too.
+	pub fn set_config(&mut self, config: NetworkConfig) {
+		self.config = config;
+	}
+
+	/// Returns the configuration.
+	pub fn config(&self) -> NetworkConfig {
+		self.config
+	}
+
+	/// Checks if the scores settled in the last run of `reconverge`, and the
+	/// network didn't change since.
+	pub fn is_converged(&self) -> bool {
+		self.converged
+	}
+
+	/// Checks if the largest change of a score in the last iteration of the
+	/// last run was at most `tolerance`, e.g. to accept the scores with
+	/// another bound than the `delta` the run stopped at. Like `is_converged`,
+	/// it is false if the network changed since, or didn't run yet.
+	pub fn converged_within(&self, tolerance: f64) -> bool {
+		self.last_change.map_or(false, |change| change <= Score::from_f64(tolerance))
+	}
+
+	/// Runs `iterations` updates of the `strategy`, starting from the scores
+	/// of the last run, and returns the new scores. Stops early if the scores
+	/// stop changing, which also marks them as settled, or cycle.
+	pub fn converge(&mut self, iterations: usize) -> &[F] {
+		self.converge_with_report(0.0, iterations, false);
+		&self.scores
+	}
+
+	/// Runs updates, starting from the scores of the last run, until no score
+	/// changes by more than `delta`, or for at most `max_iterations`. After a
+	/// small change of the network, e.g. with `update_opinion`, it takes far
+	/// fewer iterations than starting from scratch. Returns the number of
+	/// iterations run.
+	pub fn reconverge(&mut self, delta: f64, max_iterations: usize) -> usize {
+		self.converge_with_report(delta, max_iterations, false).num_iterations()
+	}
+
+	/// Runs the updates like `reconverge`, returning the diagnostics of every
+	/// iteration, and the scores after every iteration if
+	/// `record_trajectory` is set. The updates follow the `strategy`.
+	pub fn converge_with_report(
+		&mut self, delta: f64, max_iterations: usize, record_trajectory: bool,
+	) -> ConvergenceReport<F> {
+		let alpha = self.alpha;
+		let limits = RunLimits { delta, max_iterations };
+		self.converge_scheduled(limits, record_trajectory, &|_| alpha)
+	}
+
+	/// Runs the updates like `converge_with_report`, with the weight of the
+	/// pre-trust scores in every iteration, counted from zero, given by the
+	/// `schedule` instead of the weight of the network. E.g. a weight starting
+	/// high and relaxing anchors the first iterations to the pre-trust scores
+	/// on adversarial networks. The constant schedule of the weight of the
+	/// network is `converge_with_report`.
+	pub fn converge_with_schedule(
+		&mut self, delta: f64, max_iterations: usize, schedule: impl Fn(usize) -> F,
+	) -> ConvergenceReport<F> {
+		let limits = RunLimits { delta, max_iterations };
+		self.converge_scheduled(limits, false, &schedule)
+	}
+
+	/// Runs the updates from the scores of the last run, with the weights of
+	/// the `schedule`, and keeps the new scores.
+	fn converge_scheduled(
+		&mut self, limits: RunLimits, record_trajectory: bool, schedule: &dyn Fn(usize) -> F,
+	) -> ConvergenceReport<F> {
+		let transposed = self.transposed();
+		let scores = std::mem::take(&mut self.scores);
+		let (scores, report) = self.run(
+			scores,
+			&self.pre_trust,
+			transposed.as_ref(),
+			limits,
+			record_trajectory,
+			schedule,
+		);
+		self.scores = scores;
+		self.converged = report.converged();
+		self.last_change = match report.outcome {
+			ConvergenceOutcome::OutOfRange => None,
+			_ => report.iterations.last().map(|stats| Score::from_f64(stats.max_change)),
+		};
+		report
+	}
+
+	/// Returns the transposed local trust matrix if the `strategy` needs it,
+	/// since Gauss-Seidel reads the opinions about every peer.
+	fn transposed(&self) -> Option<SparseTrust<F>> {
+		match self.strategy {
+			ConvergenceStrategy::GaussSeidel => Some(self.local_trust().transpose()),
+			_ => None,
+		}
+	}
+
+	/// Runs the updates with the `pre_trust` scores and the weights of the
+	/// `schedule`, starting from the `scores`, see `converge_with_report`.
+	/// Returns the last scores.
+	fn run(
+		&self, mut scores: Vec<F>, pre_trust: &[F], transposed: Option<&SparseTrust<F>>,
+		limits: RunLimits, record_trajectory: bool, schedule: &dyn Fn(usize) -> F,
+	) -> (Vec<F>, ConvergenceReport<F>) {
+		let mut report = ConvergenceReport {
+			outcome: ConvergenceOutcome::MaxIterationsReached,
+			iterations: Vec::new(),
+			trajectory: record_trajectory.then(Vec::new),
+		};
+		// The changes are compared in fixed-point, so whether the scores
+		// settled doesn't depend on the float arithmetic of the platform.
+		let delta = Score::from_f64(limits.delta);
+		// The scores of the previous iterations, the last one at the back.
+		let mut recent = VecDeque::with_capacity(OSCILLATION_WINDOW);
+		for iteration in 0..limits.max_iterations {
+			let alpha = schedule(iteration);
+			let (new_scores, duration) = timed(|| self.step(&scores, pre_trust, transposed, alpha));
+			// Keeps the last scores in range rather than the ones blown up.
+			if !new_scores.iter().all(in_range) {
+				report.outcome = ConvergenceOutcome::OutOfRange;
+				break;
+			}
+			let changes = new_scores
+				.iter()
+				.zip(&scores)
+				.map(|(new, old)| Score::from_field(*new).abs_diff(Score::from_field(*old)));
+			let (max_change, residual) = changes
+				.fold((Score::ZERO, Score::ZERO), |(max, sum), change| {
+					(max.max(change), sum + change)
+				});
+
+			report.iterations.push(IterationStats {
+				max_change: max_change.to_f64(),
+				residual: residual.to_f64(),
+				duration,
+			});
+			if let Some(trajectory) = &mut report.trajectory {
+				trajectory.push(new_scores.clone());
+			}
+			if max_change <= delta {
+				scores = new_scores;
+				report.outcome = ConvergenceOutcome::Converged;
+				break;
+			}
+			// The updates are deterministic, so scores seen before repeat.
+			let cycle = recent.contains(&new_scores);
+			if recent.len() == OSCILLATION_WINDOW {
+				recent.pop_front();
+			}
+			recent.push_back(std::mem::replace(&mut scores, new_scores));
+			if cycle {
+				report.outcome = ConvergenceOutcome::Oscillating;
+				break;
+			}
+		}
+		(scores, report)
+	}
+
+	/// Returns the scores after an iteration of the `strategy` with the
+	/// `pre_trust` scores of weight `alpha`, starting from the `scores`.
+	fn step(
+		&self, scores: &[F], pre_trust: &[F], transposed: Option<&SparseTrust<F>>, alpha: F,
+	) -> Vec<F> {
+		let update = |scores: &[F]| converge_from(self.local_trust(), scores, pre_trust, alpha, 1);
+		match (self.strategy, transposed) {
+			(ConvergenceStrategy::GaussSeidel, Some(transposed)) => {
+				let mut scores = scores.to_vec();
+				update_gauss_seidel(transposed, &mut scores, pre_trust, alpha);
+				scores
+			},
+			(ConvergenceStrategy::Aitken, _) => {
+				let t1 = update(scores);
+				let t2 = update(&t1);
+				scores.iter().zip(t1).zip(t2).map(|((t0, t1), t2)| aitken(*t0, t1, t2)).collect()
+			},
+			_ => update(scores),
+		}
+	}
+
+	/// Resets the global trust scores to the pre-trust scores, so the next
+	/// run starts from scratch.
+	pub fn reset_scores(&mut self) {
+		self.scores = self.pre_trust.clone();
+		self.mark_changed();
+	}
+
+	/// Sets the scores the next run starts from, e.g. to compare warm starts
+	/// on the same network, after checking they are a score for every peer,
+	/// summing to one.
+	pub fn set_initial_scores(&mut self, scores: Vec<F>) -> Result<(), Error> {
+		check_distribution(&scores, self.size())?;
+		self.scores = scores;
+		self.mark_changed();
+		Ok(())
+	}
+
+	/// Forgets whether the scores settled, after a change of the network.
+	fn mark_changed(&mut self) {
+		self.converged = false;
+		self.last_change = None;
+	}
+
+	/// Starts a new epoch with the `opinions` of the peers in it, keeping a
+	/// rolling reputation: the pre-trust scores become `d * p + (1 - d) * t`,
+	/// for the `decay` `d` and the pre-trust `p` and global trust `t` scores
+	/// of the last epoch, so the weight of an epoch shrinks by `d` at every
+	/// new one. The opinions replace the trust opinions, and the negative
+	/// opinions about the same peers, then the scores converge again from the
+	/// last ones, like `converge_with_report`.
+	pub fn apply_epoch(
+		&mut self, opinions: SparseTrust<F>, decay: F, delta: f64, max_iterations: usize,
+	) -> ConvergenceReport<F> {
+		assert_eq!(opinions.size(), self.size());
+		assert!(Score::from_field(decay) <= Score::ONE);
+		let scale = F::from_u128(1 << FRAC_BITS);
+		for (p_i, t_i) in self.pre_trust.iter_mut().zip(&self.scores) {
+			*p_i = div_rem_pow2(decay * *p_i + (scale - decay) * t_i, FRAC_BITS).0;
+		}
+		for i in 0..self.size() {
+			for (j, _) in opinions.row(i) {
+				if self.distrust.raw.get(i, j) != F::zero() {
+					self.distrust.set(i, j, F::zero());
+				}
+			}
+		}
+		self.trust = Opinions::new(opinions);
+		self.mark_changed();
+		self.converge_with_report(delta, max_iterations, false)
+	}
+
+	/// Sets the trust of the peer `i` in the peer `j`, replacing the previous
+	/// opinion, and normalizes the opinions of `i` as set again. A zero trust
+	/// removes the opinion. A non-zero trust replaces the distrust of `i` in
+	/// `j`. The scores of the last run are kept, see `reconverge`.
+	pub fn update_opinion(&mut self, i: usize, j: usize, c_ij: F) {
+		if c_ij != F::zero() && self.distrust.raw.get(i, j) != F::zero() {
+			self.distrust.set(i, j, F::zero());
+		}
+		self.trust.set(i, j, c_ij);
+		self.mark_changed();
+	}
+
+	/// Sets the distrust of the peer `i` in the peer `j`, i.e. the size of a
+	/// negative opinion, removing the trust of `i` in `j`. The negative
+	/// opinions of `i` are normalized like the positive ones. How they affect
+	/// the scores depends on the `DistrustMode`.
+	pub fn update_distrust(&mut self, i: usize, j: usize, d_ij: F) {
+		if self.trust.raw.get(i, j) != F::zero() {
+			self.update_opinion(i, j, F::zero());
+		}
+		self.distrust.set(i, j, d_ij);
+	}
+
+	/// Sets the opinion of the peer `i` about the peer `j`, a signed
+	/// fixed-point number with `FRAC_BITS` fractional bits: a trust if it is
+	/// positive, see `update_opinion`, and a distrust if it is negative, see
+	/// `update_distrust`. Zero removes both.
+	pub fn update_signed_opinion(&mut self, i: usize, j: usize, c_ij: i128) {
+		let value = F::from_u128(c_ij.unsigned_abs());
+		if c_ij < 0 {
+			self.update_distrust(i, j, value);
+		} else {
+			self.update_distrust(i, j, F::zero());
+			self.update_opinion(i, j, value);
+		}
+	}
+
+	/// Returns the distrust in every peer, from the scores of the last run:
+	/// the sum of the scores of the peers distrusting it, weighted by their
+	/// normalized negative opinions. All zero with `DistrustMode::Clamp`.
+	pub fn distrust_scores(&self) -> Vec<F> {
+		let zeros = vec![F::zero(); self.size()];
+		match self.config.distrust {
+			DistrustMode::Clamp => zeros,
+			DistrustMode::Propagate => {
+				update_sparse(self.distrust(), &self.scores, &zeros, F::zero())
+			},
+		}
+	}
+
+	/// Returns the scores of the last run minus the `distrust_scores`,
+	/// clamped to zero.
+	pub fn net_scores(&self) -> Vec<F> {
+		let distrust = self.distrust_scores();
+		let net = |t: &F, d: F| {
+			let (t, d) = (Score::from_field(*t), Score::from_field(d));
+			t.max(d) - d
+		};
+		self.scores.iter().zip(distrust).map(|(t, d)| net(t, d).to_field()).collect()
+	}
+
+	/// Adds a peer without opinions, returning its index. The pre-trust
+	/// scores are scaled to sum to one again with its `pre_trust`, and the
+	/// next run starts from its `initial_score`.
+	pub fn add_peer(&mut self, pre_trust: F, initial_score: F) -> usize {
+		let index = self.trust.add_peer();
+		self.distrust.add_peer();
+		self.pre_trust.push(pre_trust);
+		if pre_trust != F::zero() {
+			normalize(&mut self.pre_trust);
+		}
+		self.scores.push(initial_score);
+		self.mark_changed();
+		index
+	}
+
+	/// Removes the peer `index`, moving the following peers down by one. The
+	/// opinions of the peers which trusted it, and the pre-trust scores if it
+	/// was pre-trusted, are scaled to sum to one again.
+	pub fn remove_peer(&mut self, index: usize) {
+		self.trust.remove_peer(index);
+		self.distrust.remove_peer(index);
+		if self.pre_trust.remove(index) != F::zero() {
+			normalize(&mut self.pre_trust);
+		}
+		self.scores.remove(index);
+		self.mark_changed();
+	}
+
+	/// Serializes the network in the binary format of bincode, see
+	/// `NetworkSnapshot`.
+	#[cfg(feature = "serde")]
+	pub fn to_bytes(&self) -> Vec<u8> {
+		bincode::serialize(self).expect("The snapshot is always serializable")
+	}
+
+	/// Deserializes a network written by `to_bytes`.
+	#[cfg(feature = "serde")]
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+		bincode::deserialize(bytes).map_err(|_| Error::MalformedNetwork)
+	}
+
+	/// Serializes the network as JSON, see `NetworkSnapshot`.
+	#[cfg(feature = "serde")]
+	pub fn to_json(&self) -> String {
+		serde_json::to_string(self).expect("The snapshot is always serializable")
+	}
+
+	/// Deserializes a network written by `to_json`.
+	#[cfg(feature = "serde")]
+	pub fn from_json(json: &str) -> Result<Self, Error> {
+		serde_json::from_str(json).map_err(|_| Error::MalformedNetwork)
+	}
+
+	/// Write the network to a file, see `to_bytes`.
+	#[cfg(feature = "fs")]
+	pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+		write(path, self.to_bytes())?;
+		Ok(())
+	}
+
+	/// Read the network from a file written by `save`.
+	#[cfg(feature = "fs")]
+	pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+		Self::from_bytes(&read(path)?)
+	}
+}
+
+/// Personalized views of the trust in a `Network`, where the pre-trust is
+/// centered on a querying peer instead of the pre-trusted peers. The views
+/// are computed on demand with the strategy of the network, and cached. The
+/// queries share the precomputed transposed matrix of Gauss-Seidel.
+pub struct PersonalizedTrust<'a, F: FieldExt> {
+	/// The network.
+	network: &'a Network<F>,
+	/// The transposed local trust matrix, if the strategy needs it.
+	transposed: Option<SparseTrust<F>>,
+	/// Largest change of a score at which a view settled.
+	delta: f64,
+	/// Maximum number of iterations of a view.
+	max_iterations: usize,
+	/// The computed views, by the querying peer.
+	views: HashMap<usize, Vec<F>>,
+}
+
+impl<'a, F: FieldExt> PersonalizedTrust<'a, F> {
+	/// Create the personalized views of the `network`, computed like
+	/// `Network::reconverge`.
+	pub fn new(network: &'a Network<F>, delta: f64, max_iterations: usize) -> Self {
+		let transposed = network.transposed();
+		Self { network, transposed, delta, max_iterations, views: HashMap::new() }
+	}
+
+	/// Returns the global trust scores from the point of view of the `peer`,
+	/// i.e. with all the pre-trust in it.
+	pub fn scores(&mut self, peer: usize) -> &[F] {
+		assert!(peer < self.network.size());
+		let Self { network, transposed, delta, max_iterations, views } = self;
+		views.entry(peer).or_insert_with(|| {
+			let mut pre_trust = vec![F::zero(); network.size()];
+			pre_trust[peer] = F::from_u128(1 << FRAC_BITS);
+			let scores = pre_trust.clone();
+			let transposed = transposed.as_ref();
+			let limits = RunLimits { delta: *delta, max_iterations: *max_iterations };
+			let alpha = network.alpha;
+			network.run(scores, &pre_trust, transposed, limits, false, &|_| alpha).0
+		})
+	}
+
+	/// Returns the trust of the `peer` in the `other` peer.
+	pub fn trust(&mut self, peer: usize, other: usize) -> F {
+		self.scores(peer)[other]
+	}
+
+	/// Returns the number of cached views.
+	pub fn num_views(&self) -> usize {
+		self.views.len()
+	}
+}
+
+/// Saved state of a network, the same in every serde format: the opinions
+/// and the negative opinions as set, the pre-trust scores, the global trust
+/// scores, the weight of the pre-trust scores, the convergence flag and the
+/// last change, the strategy and the configuration. The field elements are in
+/// hex.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct NetworkSnapshot {
+	/// Version of the format, `NETWORK_FORMAT_VERSION`.
+	version: u32,
+	/// Weight of the pre-trust scores.
+	alpha: String,
+	/// Whether the scores settled in the last run.
+	converged: bool,
+	/// The largest change of a score in the last iteration of the last run.
+	last_change: Option<f64>,
+	/// The method of running the updates.
+	strategy: ConvergenceStrategy,
+	/// The configuration of the network.
+	config: NetworkConfig,
+	/// Pre-trust scores.
+	pre_trust: Vec<String>,
+	/// Global trust scores of the last run.
+	scores: Vec<String>,
+	/// Opinions as set, by the peers giving and getting them.
+	opinions: Vec<(usize, usize, String)>,
+	/// Negative opinions as set, by the peers giving and getting them.
+	distrust: Vec<(usize, usize, String)>,
+}
+
+/// Encodes a field element in hex.
+#[cfg(feature = "serde")]
+fn to_hex<F: FieldExt>(value: &F) -> String {
+	hex::encode(value.to_repr().as_ref())
+}
+
+/// Decodes a canonical field element from hex.
+#[cfg(feature = "serde")]
+fn from_hex<F: FieldExt>(value: &str) -> Result<F, Error> {
+	let bytes = hex::decode(value).map_err(|_| Error::MalformedNetwork)?;
+	let mut repr = F::Repr::default();
+	if bytes.len() != repr.as_ref().len() {
+		return Err(Error::MalformedNetwork);
+	}
+	repr.as_mut().copy_from_slice(&bytes);
+	Option::from(F::from_repr(repr)).ok_or(Error::MalformedNetwork)
+}
+
+#[cfg(feature = "serde")]
+impl<F: FieldExt> From<&Network<F>> for NetworkSnapshot {
+	fn from(network: &Network<F>) -> Self {
+		let opinions = |matrix: &SparseTrust<F>| {
+			(0..network.size())
+				.flat_map(|i| matrix.row(i).map(move |(j, c_ij)| (i, j, to_hex(c_ij))))
+				.collect()
+		};
+		NetworkSnapshot {
+			version: NETWORK_FORMAT_VERSION,
+			alpha: to_hex(&network.alpha),
+			converged: network.converged,
+			last_change: network.last_change.map(|change| change.to_f64()),
+			strategy: network.strategy,
+			config: network.config,
+			pre_trust: network.pre_trust.iter().map(to_hex).collect(),
+			scores: network.scores.iter().map(to_hex).collect(),
+			opinions: opinions(&network.trust.raw),
+			distrust: opinions(&network.distrust.raw),
+		}
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<F: FieldExt> TryFrom<NetworkSnapshot> for Network<F> {
+	type Error = Error;
+
+	fn try_from(snapshot: NetworkSnapshot) -> Result<Self, Error> {
+		let size = snapshot.pre_trust.len();
+		if snapshot.version != NETWORK_FORMAT_VERSION || snapshot.scores.len() != size {
+			return Err(Error::MalformedNetwork);
+		}
+		let matrix = |opinions: &[(usize, usize, String)]| {
+			let mut builder = SparseTrustBuilder::new(size);
+			for (i, j, c_ij) in opinions {
+				if *i >= size || *j >= size {
+					return Err(Error::MalformedNetwork);
+				}
+				builder.set(*i, *j, from_hex(c_ij)?);
+			}
+			Ok(Opinions::new(builder.build()))
+		};
+		let values =
+			|values: &[String]| values.iter().map(|v| from_hex(v)).collect::<Result<Vec<F>, _>>();
+		Ok(Self {
+			trust: matrix(&snapshot.opinions)?,
+			distrust: matrix(&snapshot.distrust)?,
+			pre_trust: values(&snapshot.pre_trust)?,
+			scores: values(&snapshot.scores)?,
+			alpha: from_hex(&snapshot.alpha)?,
+			converged: snapshot.converged,
+			last_change: snapshot.last_change.map(Score::from_f64),
+			strategy: snapshot.strategy,
+			config: snapshot.config,
+		})
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<F: FieldExt> Serialize for Network<F> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		NetworkSnapshot::from(self).serialize(serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de, F: FieldExt> Deserialize<'de> for Network<F> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let snapshot = NetworkSnapshot::deserialize(deserializer)?;
+		Self::try_from(snapshot).map_err(D::Error::custom)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::iteration::native::{converge, SparseTrustBuilder};
+	use halo2wrong::curves::bn256::Fr;
+
+	/// Converts a fraction to a fixed-point number.
+	fn fixed(num: u64, den: u64) -> Fr {
+		Fr::from((num << FRAC_BITS) / den)
+	}
+
+	fn setup() -> Network<Fr> {
+		let mut builder = SparseTrustBuilder::new(3);
+		builder.set(0, 1, fixed(1, 2)).set(0, 2, fixed(1, 2));
+		builder.set(1, 0, fixed(1, 4)).set(1, 2, fixed(3, 4));
+		builder.set(2, 0, fixed(1, 2)).set(2, 1, fixed(1, 2));
+		let pre_trust = vec![fixed(1, 2), fixed(1, 2), Fr::zero()];
+		Network::new(builder.build(), pre_trust, fixed(1, 5))
+	}
+
+	#[test]
+	fn test_network_from_pre_trust() {
+		let mut expected = setup();
+		let opinions = expected.opinions().clone();
+		let pre_trust = expected.pre_trust().to_vec();
+		let mut network =
+			Network::from_pre_trust(opinions.clone(), pre_trust, fixed(1, 5)).unwrap();
+		assert_eq!(network.scores(), network.pre_trust());
+		network.reconverge(1e-3, 100);
+		expected.reconverge(1e-3, 100);
+		assert_eq!(network.scores(), expected.scores());
+
+		// Rounded down, thirds are close enough to one.
+		assert!(
+			Network::from_pre_trust(opinions.clone(), vec![fixed(1, 3); 3], Fr::zero()).is_ok()
+		);
+		let half = vec![fixed(1, 4), fixed(1, 4), Fr::zero()];
+		let res = Network::from_pre_trust(opinions.clone(), half, Fr::zero());
+		assert!(matches!(res, Err(Error::InvalidScores)));
+		let res = Network::from_pre_trust(opinions, vec![fixed(1, 2); 2], Fr::zero());
+		assert!(matches!(res, Err(Error::InvalidScores)));
+	}
+
+	#[test]
+	fn test_network_transition_matrix() {
+		let network = setup();
+		let matrix = network.effective_transition_matrix();
+		// The opinion of the peer 0 about the peer 1, mixed with the
+		// pre-trust of the peer 1: 4/5 * 1/2 + 1/5 * 1/2.
+		assert_eq!(matrix[1][0], fixed(1, 2));
+		assert_eq!(matrix[2][2], Fr::zero());
+		for j in 0..network.size() {
+			let sum = matrix.iter().fold(Score::ZERO, |acc, row| acc + Score::from_field(row[j]));
+			assert!(sum.abs_diff(Score::ONE) <= Score::from_raw(network.size() as u128));
+		}
+	}
+
+	#[test]
+	fn test_network_out_of_range() {
+		let network = setup();
+		let opinions = network.opinions().clone();
+		let pre_trust = network.pre_trust().to_vec();
+		let res = Network::from_pre_trust(
+			opinions.clone(),
+			vec![-Fr::one(), fixed(1, 1), Fr::zero()],
+			fixed(1, 5),
+		);
+		assert!(matches!(res, Err(Error::OutOfRange)));
+		let res = Network::from_pre_trust(opinions.clone(), pre_trust.clone(), fixed(2, 1));
+		assert!(matches!(res, Err(Error::OutOfRange)));
+		let mut negative = opinions.clone();
+		negative.set(0, 1, -Fr::one());
+		let res = Network::from_pre_trust(negative, pre_trust, fixed(1, 5));
+		assert!(matches!(res, Err(Error::OutOfRange)));
+
+		// Unchecked, a huge pre-trust score blows up the scores.
+		let huge = Fr::from_u128(1 << 70);
+		let mut network = Network::new(opinions, vec![huge, Fr::zero(), Fr::zero()], fixed(1, 5));
+		let report = network.converge_with_report(1e-3, 100, false);
+		assert_eq!(report.outcome, ConvergenceOutcome::OutOfRange);
+		assert_eq!(report.num_iterations(), 0);
+		assert_eq!(network.scores(), network.pre_trust());
+		assert!(!network.is_converged() && !network.converged_within(1.0));
+	}
+
+	#[test]
+	fn test_network_warm_start() {
+		let mut network = setup();
+		let expected = converge(network.local_trust(), network.pre_trust(), fixed(1, 5), 4);
+		network.converge(2);
+		assert_eq!(network.converge(2), expected);
+
+		network.reset_scores();
+		assert_eq!(network.scores(), network.pre_trust());
+	}
+
+	#[test]
+	fn test_network_converge_strategy() {
+		let mut network = setup();
+		network.set_strategy(ConvergenceStrategy::GaussSeidel);
+		let mut expected = setup();
+		expected.set_strategy(ConvergenceStrategy::GaussSeidel);
+		expected.converge_with_report(0.0, 3, false);
+		assert_eq!(network.converge(3), expected.scores());
+
+		// Running until the scores stop changing marks them as settled.
+		assert!(!network.is_converged());
+		network.converge(1000);
+		assert!(network.is_converged());
+	}
+
+	#[test]
+	fn test_network_initial_scores() {
+		let mut network = setup();
+		network.reconverge(1e-3, 100);
+		network.set_initial_scores(vec![fixed(1, 3); 3]).unwrap();
+		assert_eq!(network.scores(), vec![fixed(1, 3); 3]);
+		assert!(!network.is_converged());
+		assert!(network.reconverge(1e-4, 200) < 200);
+		let uniform = network.fixed_scores();
+
+		network.set_initial_scores(vec![Fr::zero(), Fr::zero(), fixed(1, 1)]).unwrap();
+		assert!(network.reconverge(1e-4, 200) < 200);
+		for (score, expected) in network.fixed_scores().into_iter().zip(uniform) {
+			assert!(score.abs_diff(expected) < Score::from_f64(1e-2));
+		}
+
+		let res = network.set_initial_scores(vec![fixed(1, 2); 2]);
+		assert!(matches!(res, Err(Error::InvalidScores)));
+		let res = network.set_initial_scores(vec![fixed(1, 2); 3]);
+		assert!(matches!(res, Err(Error::InvalidScores)));
+	}
+
+	#[test]
+	fn test_network_add_peer() {
+		let mut network = setup();
+		network.converge(2);
+		let index = network.add_peer(fixed(1, 2), fixed(1, 4));
+
+		assert_eq!(index, 3);
+		assert_eq!(network.size(), 4);
+		assert_eq!(network.local_trust().row(3).count(), 0);
+		assert_eq!(network.pre_trust(), [
+			fixed(1, 3),
+			fixed(1, 3),
+			Fr::zero(),
+			fixed(1, 3)
+		]);
+		assert_eq!(network.scores()[3], fixed(1, 4));
+		assert_eq!(network.converge(1).len(), 4);
+	}
+
+	#[test]
+	fn test_network_remove_peer() {
+		let mut network = setup();
+		network.converge(2);
+		let scores = network.scores().to_vec();
+		network.remove_peer(0);
+
+		assert_eq!(network.size(), 2);
+		assert_eq!(network.pre_trust(), [fixed(1, 1), Fr::zero()]);
+		assert_eq!(network.scores(), &scores[1..]);
+		// The peers which trusted the removed one renormalize their opinions.
+		let local_trust = network.local_trust();
+		assert_eq!(local_trust.get(0, 1), fixed(1, 1));
+		assert_eq!(local_trust.get(1, 0), fixed(1, 1));
+		assert_eq!(local_trust.num_opinions(), 2);
+	}
+
+	#[test]
+	fn test_network_update_opinion() {
+		let mut network = setup();
+		network.update_opinion(1, 0, fixed(3, 4));
+		network.update_opinion(1, 2, Fr::zero());
+		network.update_opinion(0, 2, Fr::zero());
+		assert_eq!(network.local_trust().get(1, 0), fixed(1, 1));
+		assert_eq!(network.local_trust().get(0, 1), fixed(1, 1));
+		assert_eq!(network.local_trust().num_opinions(), 4);
+
+		// Adding an opinion of the last peer about itself.
+		network.update_opinion(2, 2, fixed(1, 1));
+		assert_eq!(network.local_trust().get(2, 2), fixed(1, 2));
+		assert_eq!(network.local_trust().get(2, 1), fixed(1, 4));
+	}
+
+	#[test]
+	fn test_network_update_order() {
+		// The rows are normalized from the opinions as set, whatever the order
+		// and the number of the updates.
+		let mut network = setup();
+		let mut other = setup();
+		for _ in 0..10 {
+			network.update_opinion(0, 1, fixed(1, 3));
+		}
+		network.update_opinion(0, 2, fixed(1, 3));
+		other.update_opinion(0, 2, fixed(1, 3));
+		other.update_opinion(0, 1, fixed(1, 3));
+		assert_eq!(network.local_trust(), other.local_trust());
+		assert_eq!(network.opinions().get(0, 1), fixed(1, 3));
+		assert_eq!(network.local_trust().get(0, 1), fixed(1, 2));
+
+		// Setting the opinion back gives the row back, without any drift.
+		for _ in 0..10 {
+			network.update_opinion(1, 0, fixed(1, 7));
+		}
+		network.update_opinion(1, 0, fixed(1, 4));
+		let row = |network: &Network<Fr>| {
+			network.local_trust().row(1).map(|(j, c_ij)| (j, *c_ij)).collect::<Vec<_>>()
+		};
+		assert_eq!(row(&network), row(&setup()));
+	}
+
+	#[test]
+	fn test_network_apply_epoch() {
+		let one = fixed(1, 1);
+		// Only the first epoch trusts the peer 2, every one trusts the peer 1.
+		let mut first = SparseTrustBuilder::new(3);
+		first.set(0, 1, one).set(0, 2, one).set(1, 2, one).set(2, 1, one);
+		let mut next = SparseTrustBuilder::new(3);
+		next.set(0, 1, one).set(1, 0, one).set(2, 1, one);
+
+		let mut network = Network::new(first.build(), vec![fixed(1, 3); 3], fixed(1, 5));
+		network.reconverge(1e-4, 200);
+		let score = |network: &Network<Fr>, i: usize| Score::from_field(network.scores()[i]);
+		let mut last = score(&network, 2);
+		for _ in 0..2 {
+			let report = network.apply_epoch(next.build(), fixed(1, 2), 1e-4, 200);
+			assert!(report.converged());
+			assert!(score(&network, 1) > Score::from_f64(0.4));
+			assert!(score(&network, 2) < last);
+			last = score(&network, 2);
+		}
+		assert!(last < Score::from_f64(0.1));
+	}
+
+	#[test]
+	fn test_network_distrust() {
+		let mut network = setup();
+		let scores = network.scores().to_vec();
+		network.update_distrust(0, 1, fixed(1, 1));
+		network.update_signed_opinion(1, 2, -(1 << FRAC_BITS));
+		// The negative opinions replace the trust opinions.
+		assert_eq!(network.local_trust().row(0).collect::<Vec<_>>(), vec![(
+			2,
+			&fixed(1, 1)
+		)]);
+		assert_eq!(network.local_trust().row(1).collect::<Vec<_>>(), vec![(
+			0,
+			&fixed(1, 1)
+		)]);
+		assert_eq!(network.distrust().num_opinions(), 2);
+
+		// Clamped, the negative opinions don't lower the scores.
+		assert_eq!(network.distrust_scores(), vec![Fr::zero(); 3]);
+		assert_eq!(network.net_scores(), scores);
+
+		// Propagated, the peers spread their scores over the distrusted peers.
+		network.set_config(NetworkConfig { distrust: DistrustMode::Propagate });
+		assert_eq!(network.distrust_scores(), [
+			Fr::zero(),
+			fixed(1, 2),
+			fixed(1, 2)
+		]);
+		assert_eq!(network.net_scores(), [fixed(1, 2), Fr::zero(), Fr::zero()]);
+
+		// A positive opinion replaces the negative one.
+		network.update_signed_opinion(0, 1, 1 << FRAC_BITS);
+		assert_eq!(network.distrust().num_opinions(), 1);
+		assert_eq!(network.distrust_scores(), [
+			Fr::zero(),
+			Fr::zero(),
+			fixed(1, 2)
+		]);
+
+		// Removing the distrusting peer removes its negative opinions.
+		network.remove_peer(1);
+		assert_eq!(network.distrust().num_opinions(), 0);
+	}
+
+	#[test]
+	fn test_network_reconverge() {
+		let delta = 1e-3;
+		let mut network = setup();
+		let cold = network.reconverge(delta, 100);
+		assert!(cold < 100);
+		// The scores settled, so another run stops right away.
+		assert_eq!(network.reconverge(delta, 100), 1);
+
+		// A small change settles faster than starting from scratch.
+		network.update_opinion(1, 0, fixed(1, 3));
+		let warm = network.reconverge(delta, 100);
+		network.reset_scores();
+		assert!(warm <= network.reconverge(delta, 100));
+	}
+
+	#[test]
+	fn test_network_convergence_report() {
+		let mut network = setup();
+		let report = network.converge_with_report(1e-3, 100, true);
+		assert_eq!(report.outcome, ConvergenceOutcome::Converged);
+		assert!(network.is_converged());
+
+		let trajectory = report.trajectory.as_ref().unwrap();
+		assert_eq!(trajectory.len(), report.num_iterations());
+		assert_eq!(trajectory.last().unwrap(), network.scores());
+		let last = report.iterations.last().unwrap();
+		assert!(last.max_change <= 1e-3 && last.max_change <= last.residual);
+		assert!(report.iterations[0].max_change > 1e-3);
+
+		// Without enough iterations, the scores don't settle.
+		network.reset_scores();
+		let report = network.converge_with_report(1e-3, 1, false);
+		assert_eq!(report.outcome, ConvergenceOutcome::MaxIterationsReached);
+		assert!(report.trajectory.is_none());
+		assert_eq!(report.num_iterations(), 1);
+		assert!(!network.is_converged());
+	}
+
+	#[test]
+	fn test_network_converged_within() {
+		let mut network = setup();
+		assert!(!network.converged_within(1.0));
+		network.reconverge(1e-3, 100);
+		assert!(network.is_converged());
+		assert!(network.converged_within(1e-3));
+		assert!(network.converged_within(1e-2));
+
+		// The first iteration changes the scores by more than 1e-3.
+		network.reset_scores();
+		network.converge(1);
+		assert!(!network.converged_within(1e-3));
+		assert!(network.converged_within(1.0));
+
+		// The network changed since the last run.
+		network.update_opinion(1, 0, fixed(1, 3));
+		assert!(!network.converged_within(1.0));
+	}
+
+	#[test]
+	fn test_network_oscillation() {
+		// Two peers trusting only each other, without pre-trust to damp the
+		// updates, swap their scores forever.
+		let mut builder = SparseTrustBuilder::new(2);
+		builder.set(0, 1, fixed(1, 1)).set(1, 0, fixed(1, 1));
+		let pre_trust = vec![fixed(1, 1), Fr::zero()];
+		let mut network = Network::new(builder.build(), pre_trust.clone(), Fr::zero());
+
+		let report = network.converge_with_report(1e-3, 100, true);
+		assert_eq!(report.outcome, ConvergenceOutcome::Oscillating);
+		assert!(!report.converged() && !network.is_converged());
+		assert_eq!(report.num_iterations(), 2);
+		assert_eq!(report.trajectory.unwrap(), vec![
+			vec![Fr::zero(), fixed(1, 1)],
+			pre_trust
+		]);
+	}
+
+	#[test]
+	fn test_network_schedule() {
+		let mut network = setup();
+		let mut expected = setup();
+		let alpha = fixed(1, 5);
+		let report = network.converge_with_schedule(1e-3, 100, |_| alpha);
+		let expected_report = expected.converge_with_report(1e-3, 100, false);
+		assert_eq!(network.scores(), expected.scores());
+		assert_eq!(report.num_iterations(), expected_report.num_iterations());
+		assert!(network.is_converged());
+
+		// Two peers trusting only each other, with little pre-trust, swap most
+		// of their scores at every iteration. Anchoring the first iterations
+		// to the pre-trust settles them sooner.
+		let mut builder = SparseTrustBuilder::new(2);
+		builder.set(0, 1, fixed(1, 1)).set(1, 0, fixed(1, 1));
+		let pre_trust = vec![fixed(1, 1), Fr::zero()];
+		let mut network = Network::new(builder.build(), pre_trust, fixed(1, 16));
+		let constant = network.converge_with_report(1e-3, 1000, false);
+		network.reset_scores();
+		let schedule = |i: usize| if i == 0 { fixed(1, 2) } else { fixed(1, 4) };
+		let decaying = network.converge_with_schedule(1e-3, 1000, schedule);
+		assert!(decaying.converged());
+		assert!(decaying.num_iterations() < constant.num_iterations());
+	}
+
+	#[test]
+	fn test_aitken() {
+		// A geometric sequence, converging to 100.
+		let f = |x: u64| Fr::from(x);
+		assert_eq!(aitken(f(36), f(68), f(84)), f(100));
+		// An arithmetic sequence has no limit.
+		assert_eq!(aitken(f(1), f(2), f(3)), f(3));
+		// The extrapolation would be negative.
+		assert_eq!(aitken(f(0), f(10), f(21)), f(21));
+	}
+
+	#[test]
+	fn test_network_strategies() {
+		let delta = 1e-4;
+		let mut network = setup();
+		assert!(network.converge_with_report(delta, 100, false).converged());
+		let expected = network.scores().to_vec();
+
+		for strategy in [ConvergenceStrategy::GaussSeidel, ConvergenceStrategy::Aitken] {
+			network.reset_scores();
+			network.set_strategy(strategy);
+			let report = network.converge_with_report(delta, 100, false);
+			assert!(report.converged());
+			for (score, expected) in network.fixed_scores().into_iter().zip(&expected) {
+				assert!(score.abs_diff(Score::from_field(*expected)) < Score::from_f64(1e-2));
+			}
+		}
+	}
+
+	#[test]
+	fn test_personalized_trust() {
+		let mut builder = SparseTrustBuilder::new(4);
+		let one = fixed(1, 1);
+		// Two pairs of peers, trusting only each other.
+		builder.set(0, 1, one).set(1, 0, one).set(2, 3, one).set(3, 2, one);
+		let network = Network::new(builder.build(), vec![fixed(1, 4); 4], fixed(1, 2));
+
+		let mut views = PersonalizedTrust::new(&network, 1e-4, 100);
+		let scores = views.scores(0).to_vec();
+		assert_ne!(scores[1], Fr::zero());
+		assert_eq!(scores[2], Fr::zero());
+		assert_eq!(scores[3], Fr::zero());
+		assert_eq!(views.trust(2, 3), views.trust(0, 1));
+		assert_eq!(views.trust(2, 0), Fr::zero());
+		assert_eq!(views.num_views(), 2);
+
+		// A view is the global trust with all the pre-trust in the peer.
+		let network = setup();
+		let view = PersonalizedTrust::new(&network, 1e-4, 100).scores(0).to_vec();
+		let pre_trust = vec![fixed(1, 1), Fr::zero(), Fr::zero()];
+		let mut personal = Network::new(network.local_trust().clone(), pre_trust, fixed(1, 5));
+		personal.reconverge(1e-4, 100);
+		assert_eq!(personal.scores(), view);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_network_to_bytes() {
+		let mut network = setup();
+		network.set_strategy(ConvergenceStrategy::GaussSeidel);
+		network.reconverge(1e-3, 100);
+		assert!(network.is_converged());
+
+		let bytes = network.to_bytes();
+		let res = Network::<Fr>::from_bytes(&bytes).unwrap();
+		assert_eq!(res.local_trust(), network.local_trust());
+		assert_eq!(res.pre_trust(), network.pre_trust());
+		assert_eq!(res.scores(), network.scores());
+		assert_eq!(res.strategy(), ConvergenceStrategy::GaussSeidel);
+		assert!(res.is_converged());
+		assert!(res.converged_within(1e-3));
+		assert_eq!(res.to_bytes(), bytes);
+
+		// Truncated, or of another version.
+		assert!(Network::<Fr>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+		let mut other_version = bytes.clone();
+		other_version[..4].copy_from_slice(&2u32.to_le_bytes());
+		assert!(Network::<Fr>::from_bytes(&other_version).is_err());
+
+		// The same network in JSON.
+		let json = network.to_json();
+		let res = Network::<Fr>::from_json(&json).unwrap();
+		assert_eq!(res.local_trust(), network.local_trust());
+		assert_eq!(res.scores(), network.scores());
+		assert!(res.is_converged());
+		let other_version = json.replace("\"version\":1", "\"version\":2");
+		assert!(Network::<Fr>::from_json(&other_version).is_err());
+
+		network.update_distrust(0, 1, fixed(1, 1));
+		let res = Network::<Fr>::from_bytes(&network.to_bytes()).unwrap();
+		assert_eq!(res.distrust(), network.distrust());
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_network_config_round_trip() {
+		let mut network = setup();
+		network.set_config(NetworkConfig { distrust: DistrustMode::Propagate });
+		network.set_strategy(ConvergenceStrategy::Aitken);
+		network.update_distrust(2, 1, fixed(1, 1));
+		network.reconverge(1e-3, 100);
+		assert_ne!(network.net_scores(), network.scores());
+
+		let res = Network::<Fr>::from_bytes(&network.to_bytes()).unwrap();
+		assert_eq!(res.config(), network.config());
+		assert_eq!(res.strategy(), ConvergenceStrategy::Aitken);
+		assert_eq!(res.net_scores(), network.net_scores());
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_network_serde_malformed() {
+		let json = serde_json::to_value(setup()).unwrap();
+		let with = |key: &str, value: serde_json::Value| {
+			let mut json = json.clone();
+			json[key] = value;
+			serde_json::from_value::<Network<Fr>>(json)
+		};
+		assert!(with("scores", serde_json::json!([])).is_err());
+		assert!(with(
+			"opinions",
+			serde_json::json!([[0, 3, to_hex(&fixed(1, 2))]])
+		)
+		.is_err());
+		assert!(with("alpha", serde_json::json!(hex::encode([0xff; 32]))).is_err());
+		assert!(with("strategy", serde_json::json!("Newton")).is_err());
+	}
+}
@@ -0,0 +1,300 @@
+//! Attack simulations on EigenTrust networks, following the threat models of
+//! the EigenTrust paper. Every round, each peer downloads files from random
+//! peers and rates the downloads according to its `PeerStrategy`. The local
+//! trust of a peer in another is the number of its satisfactory downloads
+//! from it minus the unsatisfactory ones, a distrust if negative.
+
+use super::{
+	native::SparseTrustBuilder,
+	network::{Network, PersonalizedTrust},
+	FRAC_BITS,
+};
+use crate::gadgets::fixed_point::from_fixed;
+use halo2wrong::halo2::arithmetic::FieldExt;
+use rand::Rng;
+use std::collections::BTreeMap;
+
+/// The behavior of a peer in a simulation.
+pub trait PeerStrategy {
+	/// Returns the probability that the peer serves an authentic file.
+	fn authenticity(&self) -> f64;
+
+	/// Returns whether the peer is malicious. The members of a malicious
+	/// collective know the other malicious peers.
+	fn is_malicious(&self) -> bool;
+
+	/// Returns the rating of a download from a peer, `1` for a satisfactory
+	/// one and `-1` for an unsatisfactory one.
+	fn rate(&self, authentic: bool, source_malicious: bool) -> i64;
+}
+
+/// Rates the downloads by their authenticity, and always serves authentic
+/// files.
+pub struct Honest;
+
+impl PeerStrategy for Honest {
+	fn authenticity(&self) -> f64 {
+		1.0
+	}
+
+	fn is_malicious(&self) -> bool {
+		false
+	}
+
+	fn rate(&self, authentic: bool, _: bool) -> i64 {
+		if authentic {
+			1
+		} else {
+			-1
+		}
+	}
+}
+
+/// Always serves inauthentic files, and rates the inauthentic downloads as
+/// satisfactory. Threat model A of the paper.
+pub struct MaliciousIndividual;
+
+impl PeerStrategy for MaliciousIndividual {
+	fn authenticity(&self) -> f64 {
+		0.0
+	}
+
+	fn is_malicious(&self) -> bool {
+		true
+	}
+
+	fn rate(&self, authentic: bool, _: bool) -> i64 {
+		if authentic {
+			-1
+		} else {
+			1
+		}
+	}
+}
+
+/// Always serves inauthentic files, and only trusts the other malicious
+/// peers. Threat model B of the paper.
+pub struct MaliciousCollective;
+
+impl PeerStrategy for MaliciousCollective {
+	fn authenticity(&self) -> f64 {
+		0.0
+	}
+
+	fn is_malicious(&self) -> bool {
+		true
+	}
+
+	fn rate(&self, _: bool, source_malicious: bool) -> i64 {
+		i64::from(source_malicious)
+	}
+}
+
+/// Member of the malicious collective serving authentic files with the
+/// given probability, to earn the trust of the honest peers. Threat model C
+/// of the paper.
+pub struct Camouflaged(pub f64);
+
+impl PeerStrategy for Camouflaged {
+	fn authenticity(&self) -> f64 {
+		self.0
+	}
+
+	fn is_malicious(&self) -> bool {
+		true
+	}
+
+	fn rate(&self, _: bool, source_malicious: bool) -> i64 {
+		i64::from(source_malicious)
+	}
+}
+
+/// Member of the malicious collective always serving authentic files, to
+/// earn the trust of the honest peers and pass it on to the other members.
+/// Threat model D of the paper.
+pub struct Spy;
+
+impl PeerStrategy for Spy {
+	fn authenticity(&self) -> f64 {
+		1.0
+	}
+
+	fn is_malicious(&self) -> bool {
+		true
+	}
+
+	fn rate(&self, _: bool, source_malicious: bool) -> i64 {
+		i64::from(source_malicious)
+	}
+}
+
+/// Runs the rounds of a simulation, and builds the `Network` of the local
+/// trust they result in.
+pub struct Simulation<R: Rng> {
+	/// The strategies of the peers.
+	peers: Vec<Box<dyn PeerStrategy>>,
+	/// The pre-trusted peers.
+	pre_trusted: Vec<usize>,
+	/// Number of downloads of every peer in a round.
+	downloads: usize,
+	/// Sum of the ratings of the downloads of every peer, by the source.
+	ratings: Vec<BTreeMap<usize, i64>>,
+	/// Source of the randomness of the downloads.
+	rng: R,
+}
+
+impl<R: Rng> Simulation<R> {
+	/// Create a new simulation of the `peers`, each making `downloads`
+	/// downloads in a round.
+	pub fn new(
+		peers: Vec<Box<dyn PeerStrategy>>, pre_trusted: Vec<usize>, downloads: usize, rng: R,
+	) -> Self {
+		assert!(peers.len() > 1 && pre_trusted.iter().all(|i| *i < peers.len()));
+		let ratings = vec![BTreeMap::new(); peers.len()];
+		Self { peers, pre_trusted, downloads, ratings, rng }
+	}
+
+	/// Runs a round of downloads, each from a random other peer.
+	pub fn run_round(&mut self) {
+		let num_peers = self.peers.len();
+		for i in 0..num_peers {
+			for _ in 0..self.downloads {
+				let source = (i + self.rng.gen_range(1..num_peers)) % num_peers;
+				let authentic = self.rng.gen_bool(self.peers[source].authenticity());
+				let rating = self.peers[i].rate(authentic, self.peers[source].is_malicious());
+				*self.ratings[i].entry(source).or_insert(0) += rating;
+			}
+		}
+	}
+
+	/// Runs `rounds` rounds of downloads.
+	pub fn run(&mut self, rounds: usize) {
+		for _ in 0..rounds {
+			self.run_round();
+		}
+	}
+
+	/// Returns the network of the local trust and the distrust built by the
+	/// rounds so far, with the pre-trust split evenly among the pre-trusted
+	/// peers, and the pre-trust weight `alpha`.
+	pub fn network<F: FieldExt>(&self, alpha: F) -> Network<F> {
+		let num_peers = self.peers.len();
+		let mut builder = SparseTrustBuilder::new(num_peers);
+		for (i, ratings) in self.ratings.iter().enumerate() {
+			for (j, rating) in ratings {
+				let rating = u64::try_from(*rating).unwrap_or(0);
+				builder.set(i, *j, F::from(rating));
+			}
+		}
+		let local_trust = builder.build();
+
+		let mut pre_trust = vec![F::zero(); num_peers];
+		let share = (1u128 << FRAC_BITS) / self.pre_trusted.len() as u128;
+		for i in &self.pre_trusted {
+			pre_trust[*i] = F::from_u128(share);
+		}
+		let mut network = Network::new(local_trust, pre_trust, alpha);
+		for (i, ratings) in self.ratings.iter().enumerate() {
+			for (j, rating) in ratings.iter().filter(|(_, rating)| **rating < 0) {
+				network.update_distrust(i, *j, F::from(rating.unsigned_abs()));
+			}
+		}
+		network
+	}
+
+	/// Returns the share of the global trust held by the malicious peers, for
+	/// the `scores` of the `network`.
+	pub fn malicious_share<F: FieldExt>(&self, scores: &[F]) -> f64 {
+		let mut total = 0.0;
+		let mut malicious = 0.0;
+		for (peer, score) in self.peers.iter().zip(scores) {
+			let score = from_fixed::<_, FRAC_BITS>(*score);
+			total += score;
+			if peer.is_malicious() {
+				malicious += score;
+			}
+		}
+		if total == 0.0 {
+			0.0
+		} else {
+			malicious / total
+		}
+	}
+
+	/// Returns the share of the trust of the `peer` in the malicious peers,
+	/// from its personalized view.
+	pub fn personalized_malicious_share<F: FieldExt>(
+		&self, views: &mut PersonalizedTrust<F>, peer: usize,
+	) -> f64 {
+		self.malicious_share(views.scores(peer))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use halo2wrong::curves::bn256::Fr;
+	use rand::SeedableRng;
+	use rand_chacha::ChaCha20Rng;
+
+	const NUM_HONEST: usize = 20;
+	const NUM_MALICIOUS: usize = 10;
+
+	fn simulation(malicious: impl Fn() -> Box<dyn PeerStrategy>) -> Simulation<ChaCha20Rng> {
+		let mut peers: Vec<Box<dyn PeerStrategy>> = Vec::new();
+		peers.extend((0..NUM_HONEST).map(|_| Box::new(Honest) as Box<dyn PeerStrategy>));
+		peers.extend((0..NUM_MALICIOUS).map(|_| malicious()));
+		let rng = ChaCha20Rng::seed_from_u64(7);
+		let mut simulation = Simulation::new(peers, vec![0, 1, 2], 10, rng);
+		simulation.run(3);
+		simulation
+	}
+
+	fn malicious_share(simulation: &Simulation<ChaCha20Rng>) -> f64 {
+		let alpha = Fr::from(1 << (FRAC_BITS - 3));
+		let mut network = simulation.network(alpha);
+		network.reconverge(1e-4, 200);
+		simulation.malicious_share(network.scores())
+	}
+
+	#[test]
+	fn test_simulation_network() {
+		let simulation = simulation(|| Box::new(MaliciousCollective));
+		let network = simulation.network(Fr::zero());
+		assert_eq!(network.size(), NUM_HONEST + NUM_MALICIOUS);
+		// The honest peers never trust the malicious ones.
+		for i in 0..NUM_HONEST {
+			assert!(network.local_trust().row(i).all(|(j, _)| j < NUM_HONEST));
+		}
+		// Their bad downloads are kept as distrust in the malicious peers.
+		assert!(network.distrust().num_opinions() > 0);
+		for i in 0..NUM_HONEST {
+			assert!(network.distrust().row(i).all(|(j, _)| j >= NUM_HONEST));
+		}
+		let third = Fr::from((1 << FRAC_BITS) / 3);
+		assert_eq!(network.pre_trust()[..4], [third, third, third, Fr::zero()]);
+	}
+
+	#[test]
+	fn test_simulation_threat_models() {
+		// The honest peers keep most of the trust, as long as the malicious
+		// peers don't serve authentic files.
+		assert!(malicious_share(&simulation(|| Box::new(MaliciousIndividual))) < 0.05);
+		assert!(malicious_share(&simulation(|| Box::new(MaliciousCollective))) < 0.05);
+		// Camouflage earns the malicious peers some of the trust.
+		let camouflaged = malicious_share(&simulation(|| Box::new(Camouflaged(0.5))));
+		assert!(camouflaged > 0.05);
+	}
+
+	#[test]
+	fn test_simulation_personalized() {
+		let simulation = simulation(|| Box::new(Spy));
+		let mut network = simulation.network(Fr::from(1 << (FRAC_BITS - 3)));
+		network.reconverge(1e-4, 200);
+		let mut views = PersonalizedTrust::new(&network, 1e-4, 200);
+		// A spy trusts the collective more than an honest peer does.
+		let honest = simulation.personalized_malicious_share(&mut views, 0);
+		let spy = simulation.personalized_malicious_share(&mut views, NUM_HONEST);
+		assert!(spy > honest);
+	}
+}
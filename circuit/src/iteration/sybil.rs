@@ -0,0 +1,212 @@
+//! Diagnostics of the trust graph beyond the convergence of the scores:
+//! colluding peers trusting each other, while getting little trust from the
+//! rest of the network, form communities with a low conductance.
+
+use super::{native::SparseTrust, network::Network, FRAC_BITS};
+use crate::gadgets::fixed_point::from_fixed;
+use halo2wrong::halo2::arithmetic::FieldExt;
+use std::collections::BTreeMap;
+
+/// Maximum number of passes of the label propagation.
+const MAX_PASSES: usize = 32;
+
+/// A tightly interconnected cluster of peers with little trust from the rest
+/// of the network, a candidate Sybil set.
+#[derive(Clone, Debug)]
+pub struct SybilCluster {
+	/// The peers of the cluster, in ascending order.
+	pub peers: Vec<usize>,
+	/// The trust of the peers of the cluster in each other.
+	pub internal_trust: f64,
+	/// The trust of the other peers in the peers of the cluster.
+	pub inbound_trust: f64,
+	/// The trust of the peers of the cluster in the other peers.
+	pub outbound_trust: f64,
+	/// The trust crossing the border of the cluster, divided by the trust
+	/// of the smaller side. Low for a well separated cluster.
+	pub conductance: f64,
+	/// How suspicious the cluster is, from `0` to `1`: the share of the trust
+	/// of the cluster kept inside it, times the share of the trust in the
+	/// cluster coming from the inside.
+	pub score: f64,
+}
+
+/// Returns the trust graph as undirected, with the weight of an edge being
+/// the sum of the trust in both directions.
+fn undirected<F: FieldExt>(local_trust: &SparseTrust<F>) -> Vec<BTreeMap<usize, f64>> {
+	let mut edges = vec![BTreeMap::new(); local_trust.size()];
+	for i in 0..local_trust.size() {
+		for (j, c_ij) in local_trust.row(i).filter(|(j, _)| *j != i) {
+			let weight = from_fixed::<_, FRAC_BITS>(*c_ij);
+			*edges[i].entry(j).or_insert(0.0) += weight;
+			*edges[j].entry(i).or_insert(0.0) += weight;
+		}
+	}
+	edges
+}
+
+/// Splits the peers into communities of the undirected trust graph, with
+/// label propagation: every peer takes the label with the highest weight
+/// among its neighbors, the lowest one on a tie, until the labels settle.
+/// The peers are visited in order, so the result is deterministic.
+pub fn communities<F: FieldExt>(local_trust: &SparseTrust<F>) -> Vec<Vec<usize>> {
+	let edges = undirected(local_trust);
+	let mut labels: Vec<usize> = (0..local_trust.size()).collect();
+	for _ in 0..MAX_PASSES {
+		let mut changed = false;
+		for (i, neighbors) in edges.iter().enumerate() {
+			let mut weights = BTreeMap::new();
+			for (j, weight) in neighbors {
+				*weights.entry(labels[*j]).or_insert(0.0) += weight;
+			}
+			// The labels are in ascending order, so only a strictly higher
+			// weight replaces the best one.
+			let mut best: Option<(usize, f64)> = None;
+			for (label, weight) in weights {
+				if !matches!(best, Some((_, best_weight)) if best_weight >= weight) {
+					best = Some((label, weight));
+				}
+			}
+			if let Some((label, _)) = best {
+				changed |= labels[i] != label;
+				labels[i] = label;
+			}
+		}
+		if !changed {
+			break;
+		}
+	}
+
+	let mut communities = BTreeMap::new();
+	for (i, label) in labels.into_iter().enumerate() {
+		communities.entry(label).or_insert_with(Vec::new).push(i);
+	}
+	communities.into_values().collect()
+}
+
+/// Returns the communities of at least `min_size` peers of the network as
+/// candidate Sybil sets, from the most suspicious one. The communities with
+/// pre-trusted peers are assumed honest, and left out.
+pub fn sybil_clusters<F: FieldExt>(network: &Network<F>, min_size: usize) -> Vec<SybilCluster> {
+	let local_trust = network.local_trust();
+	let mut community_of = vec![0; local_trust.size()];
+	let communities = communities(local_trust);
+	for (c, peers) in communities.iter().enumerate() {
+		for i in peers {
+			community_of[*i] = c;
+		}
+	}
+
+	// The internal, inbound and outbound trust of every community.
+	let mut flows = vec![(0.0, 0.0, 0.0); communities.len()];
+	let mut total = 0.0;
+	for i in 0..local_trust.size() {
+		for (j, c_ij) in local_trust.row(i) {
+			let weight = from_fixed::<_, FRAC_BITS>(*c_ij);
+			let (from, to) = (community_of[i], community_of[j]);
+			if from == to {
+				flows[from].0 += weight;
+			} else {
+				flows[to].1 += weight;
+				flows[from].2 += weight;
+			}
+			total += 2.0 * weight;
+		}
+	}
+
+	let mut clusters: Vec<SybilCluster> = communities
+		.into_iter()
+		.zip(flows)
+		.filter(|(peers, _)| {
+			peers.len() >= min_size && peers.iter().all(|i| network.pre_trust()[*i] == F::zero())
+		})
+		.map(|(peers, (internal_trust, inbound_trust, outbound_trust))| {
+			let share = |part: f64, whole: f64| if whole == 0.0 { 0.0 } else { part / whole };
+			let cut = inbound_trust + outbound_trust;
+			let volume = 2.0 * internal_trust + cut;
+			let conductance = share(cut, f64::min(volume, total - volume));
+			let kept = share(internal_trust, internal_trust + outbound_trust);
+			let from_inside = share(internal_trust, internal_trust + inbound_trust);
+			SybilCluster {
+				peers,
+				internal_trust,
+				inbound_trust,
+				outbound_trust,
+				conductance,
+				score: kept * from_inside,
+			}
+		})
+		.collect();
+	clusters.sort_by(|a, b| b.score.total_cmp(&a.score));
+	clusters
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::iteration::{
+		native::SparseTrustBuilder,
+		simulation::{Camouflaged, Honest, MaliciousCollective, PeerStrategy, Simulation},
+	};
+	use halo2wrong::curves::bn256::Fr;
+	use rand::SeedableRng;
+	use rand_chacha::ChaCha20Rng;
+
+	fn fixed(num: u64, den: u64) -> Fr {
+		Fr::from((num << FRAC_BITS) / den)
+	}
+
+	#[test]
+	fn test_communities() {
+		// Two triangles, joined by a weak edge.
+		let mut builder = SparseTrustBuilder::new(6);
+		for (i, j) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)] {
+			builder.set(i, j, fixed(1, 1));
+		}
+		builder.set(2, 0, fixed(7, 8)).set(2, 3, fixed(1, 8));
+		let local_trust = builder.build();
+		assert_eq!(communities(&local_trust), vec![vec![0, 1, 2], vec![
+			3, 4, 5
+		]]);
+
+		let network = Network::new(
+			local_trust,
+			[vec![fixed(1, 1)], vec![Fr::zero(); 5]].concat(),
+			Fr::zero(),
+		);
+		let clusters = sybil_clusters(&network, 2);
+		assert_eq!(clusters.len(), 1);
+		assert_eq!(clusters[0].peers, vec![3, 4, 5]);
+		assert_eq!(clusters[0].internal_trust, 3.0);
+		assert_eq!(clusters[0].inbound_trust, 0.125);
+		assert_eq!(clusters[0].outbound_trust, 0.0);
+		assert_eq!(clusters[0].score, 3.0 / 3.125);
+	}
+
+	fn simulation(malicious: impl Fn() -> Box<dyn PeerStrategy>) -> Network<Fr> {
+		let mut peers: Vec<Box<dyn PeerStrategy>> = Vec::new();
+		peers.extend((0..20).map(|_| Box::new(Honest) as Box<dyn PeerStrategy>));
+		peers.extend((0..10).map(|_| malicious()));
+		let rng = ChaCha20Rng::seed_from_u64(7);
+		let mut simulation = Simulation::new(peers, vec![0, 1, 2], 30, rng);
+		simulation.run(3);
+		simulation.network(Fr::zero())
+	}
+
+	#[test]
+	fn test_sybil_clusters_collective() {
+		let clusters = sybil_clusters(&simulation(|| Box::new(MaliciousCollective)), 3);
+		assert_eq!(clusters[0].peers, (20..30).collect::<Vec<_>>());
+		assert_eq!(clusters[0].score, 1.0);
+		assert_eq!(clusters[0].conductance, 0.0);
+	}
+
+	#[test]
+	fn test_sybil_clusters_camouflaged() {
+		let clusters = sybil_clusters(&simulation(|| Box::new(Camouflaged(0.5))), 3);
+		let cluster = &clusters[0];
+		assert!(cluster.peers.iter().all(|i| *i >= 20));
+		assert!(cluster.inbound_trust > 0.0 && cluster.outbound_trust == 0.0);
+		assert!(cluster.score > 0.5);
+	}
+}
@@ -0,0 +1,464 @@
+/// Native version of Keccak256
+pub mod native;
+
+use crate::gadgets::bits::{BitsChip, BitsConfig, Endianness};
+use halo2wrong::halo2::{
+	arithmetic::FieldExt,
+	circuit::{AssignedCell, Layouter, Region, Value},
+	plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Selector},
+	poly::Rotation,
+};
+use native::{NUM_ROUNDS, RATE, ROTATIONS, ROUND_CONSTANTS};
+
+/// Number of bits of the Keccak-f[1600] state.
+const STATE_BITS: usize = 1600;
+/// Number of bits of a lane of the state.
+const LANE_BITS: usize = 64;
+
+/// Index of the bit `z` of the lane at `x` and `y` in the state.
+fn bit_index(x: usize, y: usize, z: usize) -> usize {
+	(x + 5 * y) * LANE_BITS + z
+}
+
+#[derive(Clone, Debug)]
+/// Configuration elements for the circuit are defined here.
+pub struct KeccakConfig {
+	/// Constructs the bits circuit elements, used for the bytes.
+	bits: BitsConfig,
+	/// Configures columns for the input bits of an operation.
+	inputs: [Column<Advice>; 5],
+	/// Configures a column for the output bit, the parity of the sum.
+	parity: Column<Advice>,
+	/// Configures a column for the sum divided by two.
+	half: Column<Advice>,
+	/// Configures a fixed column for the bits of the round constants.
+	round_constant: Column<Fixed>,
+	/// Configures fixed boolean values for each row of the circuit.
+	selectors: [Selector; 2],
+}
+
+/// Keccak256 over bytes, with every bit of the state in a cell. Each row
+/// either XORs up to five bits, or applies the chi step to a bit, together
+/// with the iota step.
+pub struct KeccakChip;
+
+impl KeccakChip {
+	/// Make the circuit config.
+	pub fn configure<F: FieldExt>(meta: &mut ConstraintSystem<F>) -> KeccakConfig {
+		let bits = BitsChip::configure(meta);
+		let inputs = [(); 5].map(|_| meta.advice_column());
+		let parity = meta.advice_column();
+		let half = meta.advice_column();
+		let round_constant = meta.fixed_column();
+		let fixed = meta.fixed_column();
+		let selectors = [meta.selector(), meta.selector()];
+
+		inputs.map(|c| meta.enable_equality(c));
+		meta.enable_equality(parity);
+		meta.enable_constant(fixed);
+
+		// The sum is split into its parity and half, with both in range. The
+		// parity of the sum of bits is their XOR.
+		let split = |sum: Expression<F>, parity: Expression<F>, half: Expression<F>| {
+			let one = Expression::Constant(F::one());
+			let two = Expression::Constant(F::from_u128(2));
+			vec![
+				sum - (half.clone() * two.clone() + parity.clone()),
+				parity.clone() * (one.clone() - parity),
+				half.clone() * (half.clone() - one) * (half - two),
+			]
+		};
+
+		meta.create_gate("keccak_xor", |v_cells| {
+			let s_exp = v_cells.query_selector(selectors[0]);
+			let sum = inputs
+				.map(|c| v_cells.query_advice(c, Rotation::cur()))
+				.into_iter()
+				.reduce(|acc, input| acc + input)
+				.unwrap();
+			let parity_exp = v_cells.query_advice(parity, Rotation::cur());
+			let half_exp = v_cells.query_advice(half, Rotation::cur());
+
+			split(sum, parity_exp, half_exp).into_iter().map(|c| s_exp.clone() * c).collect()
+		});
+
+		meta.create_gate("keccak_chi", |v_cells| {
+			let s_exp = v_cells.query_selector(selectors[1]);
+			let one = Expression::Constant(F::one());
+			let a = v_cells.query_advice(inputs[0], Rotation::cur());
+			let b = v_cells.query_advice(inputs[1], Rotation::cur());
+			let c = v_cells.query_advice(inputs[2], Rotation::cur());
+			let rc = v_cells.query_fixed(round_constant, Rotation::cur());
+			let parity_exp = v_cells.query_advice(parity, Rotation::cur());
+			let half_exp = v_cells.query_advice(half, Rotation::cur());
+
+			// a ^ (!b & c) ^ rc
+			let sum = a + (one - b) * c + rc;
+			split(sum, parity_exp, half_exp).into_iter().map(|c| s_exp.clone() * c).collect()
+		});
+
+		KeccakConfig { bits, inputs, parity, half, round_constant, selectors }
+	}
+
+	/// Synthesize the Keccak256 hash of the bytes. Every byte is constrained
+	/// to be below 256. Returns the 32 bytes of the hash.
+	pub fn hash<F: FieldExt>(
+		bytes: &[AssignedCell<F, F>], config: &KeccakConfig, mut layouter: impl Layouter<F>,
+	) -> Result<Vec<AssignedCell<F, F>>, Error> {
+		let bits = Self::digest_bits(bytes, config, layouter.namespace(|| "digest"))?;
+		bits.chunks(8)
+			.map(|byte| {
+				BitsChip::from_bits(
+					byte,
+					Endianness::Little,
+					config.bits.clone(),
+					layouter.namespace(|| "byte"),
+				)
+			})
+			.collect()
+	}
+
+	/// Synthesize the Ethereum address of the secp256k1 public key, given by
+	/// the 64 bytes of its uncompressed encoding without the prefix. Returns
+	/// the last 20 bytes of the Keccak256 hash as a big-endian number.
+	pub fn eth_address<F: FieldExt>(
+		pubkey: &[AssignedCell<F, F>; 64], config: &KeccakConfig, mut layouter: impl Layouter<F>,
+	) -> Result<AssignedCell<F, F>, Error> {
+		let bits = Self::digest_bits(pubkey, config, layouter.namespace(|| "digest"))?;
+		// The bytes are in order, but their bits start from the least
+		// significant one.
+		let address_bits: Vec<AssignedCell<F, F>> =
+			bits[96..].chunks(8).flat_map(|byte| byte.iter().rev().cloned()).collect();
+		BitsChip::from_bits(
+			&address_bits,
+			Endianness::Big,
+			config.bits.clone(),
+			layouter.namespace(|| "address"),
+		)
+	}
+
+	/// Synthesize the 256 bits of the Keccak256 hash of the bytes, with the
+	/// bits of each byte from the least significant one.
+	fn digest_bits<F: FieldExt>(
+		bytes: &[AssignedCell<F, F>], config: &KeccakConfig, mut layouter: impl Layouter<F>,
+	) -> Result<Vec<AssignedCell<F, F>>, Error> {
+		let (zero, one) = layouter.assign_region(
+			|| "keccak_constants",
+			|mut region: Region<'_, F>| {
+				let column = config.inputs[0];
+				let zero = region.assign_advice_from_constant(|| "zero", column, 0, F::zero())?;
+				let one = region.assign_advice_from_constant(|| "one", column, 1, F::one())?;
+				Ok((zero, one))
+			},
+		)?;
+
+		let mut message = Vec::with_capacity((bytes.len() / RATE + 1) * RATE * 8);
+		for byte in bytes {
+			message.extend(BitsChip::to_bits(
+				byte.clone(),
+				8,
+				Endianness::Little,
+				config.bits.clone(),
+				layouter.namespace(|| "byte_bits"),
+			)?);
+		}
+
+		// Pad with a one, zeros, and a one as the last bit of the block.
+		message.push(one.clone());
+		while message.len() % (RATE * 8) != RATE * 8 - 1 {
+			message.push(zero.clone());
+		}
+		message.push(one);
+
+		let mut state: Vec<AssignedCell<F, F>> = Vec::new();
+		for block in message.chunks(RATE * 8) {
+			// The state is all zeros before the first block.
+			state = if state.is_empty() {
+				let mut state = block.to_vec();
+				state.resize(STATE_BITS, zero.clone());
+				state
+			} else {
+				let mut absorbed =
+					Self::xor(&state[..RATE * 8], block, &zero, config, &mut layouter)?;
+				absorbed.extend_from_slice(&state[RATE * 8..]);
+				absorbed
+			};
+			for round in 0..NUM_ROUNDS {
+				state = Self::round(&state, round, &zero, config, &mut layouter)?;
+			}
+		}
+
+		state.truncate(256);
+		Ok(state)
+	}
+
+	/// XOR the bits of the rate part of the state with the block, leaving the
+	/// capacity part out.
+	fn xor<F: FieldExt>(
+		state: &[AssignedCell<F, F>], block: &[AssignedCell<F, F>], zero: &AssignedCell<F, F>,
+		config: &KeccakConfig, layouter: &mut impl Layouter<F>,
+	) -> Result<Vec<AssignedCell<F, F>>, Error> {
+		layouter.assign_region(
+			|| "keccak_absorb",
+			|mut region: Region<'_, F>| {
+				let mut offset = 0;
+				state
+					.iter()
+					.zip(block)
+					.map(|(s, b)| Self::assign_xor(&mut region, &mut offset, &[s, b], zero, config))
+					.collect()
+			},
+		)
+	}
+
+	/// Synthesize a round of the Keccak-f[1600] permutation.
+	fn round<F: FieldExt>(
+		state: &[AssignedCell<F, F>], round: usize, zero: &AssignedCell<F, F>,
+		config: &KeccakConfig, layouter: &mut impl Layouter<F>,
+	) -> Result<Vec<AssignedCell<F, F>>, Error> {
+		layouter.assign_region(
+			|| "keccak_round",
+			|mut region: Region<'_, F>| {
+				let mut offset = 0;
+
+				// Theta, with the parities of the columns first.
+				let mut c = Vec::with_capacity(5 * LANE_BITS);
+				for x in 0..5 {
+					for z in 0..LANE_BITS {
+						let column: Vec<_> = (0..5).map(|y| &state[bit_index(x, y, z)]).collect();
+						c.push(Self::assign_xor(
+							&mut region, &mut offset, &column, zero, config,
+						)?);
+					}
+				}
+				let mut theta = Vec::with_capacity(STATE_BITS);
+				for y in 0..5 {
+					for x in 0..5 {
+						for z in 0..LANE_BITS {
+							let left = &c[(x + 4) % 5 * LANE_BITS + z];
+							let right =
+								&c[(x + 1) % 5 * LANE_BITS + (z + LANE_BITS - 1) % LANE_BITS];
+							let inputs = [&state[bit_index(x, y, z)], left, right];
+							theta.push(Self::assign_xor(
+								&mut region, &mut offset, &inputs, zero, config,
+							)?);
+						}
+					}
+				}
+
+				// Rho and pi only move the bits.
+				let mut b = vec![None; STATE_BITS];
+				for x in 0..5 {
+					for y in 0..5 {
+						let rotation = ROTATIONS[x][y] as usize;
+						for z in 0..LANE_BITS {
+							let to = bit_index(y, (2 * x + 3 * y) % 5, (z + rotation) % LANE_BITS);
+							b[to] = Some(theta[bit_index(x, y, z)].clone());
+						}
+					}
+				}
+				let b: Vec<AssignedCell<F, F>> = b.into_iter().map(Option::unwrap).collect();
+
+				// Chi, with iota on the first lane.
+				let mut next = Vec::with_capacity(STATE_BITS);
+				for y in 0..5 {
+					for x in 0..5 {
+						for z in 0..LANE_BITS {
+							let rc = x == 0 && y == 0 && (ROUND_CONSTANTS[round] >> z) & 1 == 1;
+							let inputs = [
+								&b[bit_index(x, y, z)],
+								&b[bit_index((x + 1) % 5, y, z)],
+								&b[bit_index((x + 2) % 5, y, z)],
+							];
+							next.push(Self::assign_chi(
+								&mut region, &mut offset, inputs, rc, config,
+							)?);
+						}
+					}
+				}
+				Ok(next)
+			},
+		)
+	}
+
+	/// Assign a row XORing up to five bits, padded with zeros.
+	fn assign_xor<F: FieldExt>(
+		region: &mut Region<'_, F>, offset: &mut usize, bits: &[&AssignedCell<F, F>],
+		zero: &AssignedCell<F, F>, config: &KeccakConfig,
+	) -> Result<AssignedCell<F, F>, Error> {
+		config.selectors[0].enable(region, *offset)?;
+		let mut count = Value::known(0);
+		for (i, column) in config.inputs.into_iter().enumerate() {
+			let bit = bits.get(i).copied().unwrap_or(zero);
+			let bit = bit.copy_advice(|| "bit", region, column, *offset)?;
+			count = count.zip(Self::bit_value(&bit)).map(|(count, bit)| count + u64::from(bit));
+		}
+		let parity = Self::assign_split(region, *offset, count, config)?;
+		*offset += 1;
+		Ok(parity)
+	}
+
+	/// Assign a row of the chi step for the bits `a`, `b` and `c`, XORing the
+	/// result with the bit of the round constant `rc`.
+	fn assign_chi<F: FieldExt>(
+		region: &mut Region<'_, F>, offset: &mut usize, bits: [&AssignedCell<F, F>; 3], rc: bool,
+		config: &KeccakConfig,
+	) -> Result<AssignedCell<F, F>, Error> {
+		config.selectors[1].enable(region, *offset)?;
+		let [a, b, c] = bits;
+		let a = a.copy_advice(|| "a", region, config.inputs[0], *offset)?;
+		let b = b.copy_advice(|| "b", region, config.inputs[1], *offset)?;
+		let c = c.copy_advice(|| "c", region, config.inputs[2], *offset)?;
+		region.assign_fixed(
+			|| "round_constant",
+			config.round_constant,
+			*offset,
+			|| Value::known(F::from(rc)),
+		)?;
+
+		let count = Self::bit_value(&a)
+			.zip(Self::bit_value(&b))
+			.zip(Self::bit_value(&c))
+			.map(|((a, b), c)| u64::from(a) + u64::from(!b & c) + u64::from(rc));
+		let parity = Self::assign_split(region, *offset, count, config)?;
+		*offset += 1;
+		Ok(parity)
+	}
+
+	/// Assign the parity and the half of the sum `count`. Returns the parity.
+	fn assign_split<F: FieldExt>(
+		region: &mut Region<'_, F>, offset: usize, count: Value<u64>, config: &KeccakConfig,
+	) -> Result<AssignedCell<F, F>, Error> {
+		let half = count.map(|count| F::from(count / 2));
+		region.assign_advice(|| "half", config.half, offset, || half)?;
+		let parity = count.map(|count| F::from(count % 2));
+		region.assign_advice(|| "parity", config.parity, offset, || parity)
+	}
+
+	/// Returns whether the bit in the cell is set.
+	fn bit_value<F: FieldExt>(bit: &AssignedCell<F, F>) -> Value<bool> {
+		bit.value().map(|bit| *bit == F::one())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{native::keccak256, *};
+	use halo2wrong::{
+		curves::bn256::Fr,
+		halo2::{
+			circuit::SimpleFloorPlanner,
+			dev::MockProver,
+			plonk::{Circuit, Instance},
+		},
+	};
+
+	#[derive(Clone)]
+	struct TestConfig {
+		keccak: KeccakConfig,
+		temp: Column<Advice>,
+		pub_ins: Column<Instance>,
+	}
+
+	#[derive(Clone)]
+	struct TestCircuit<const N: usize> {
+		bytes: [u8; N],
+	}
+
+	impl<const N: usize> Circuit<Fr> for TestCircuit<N> {
+		type Config = TestConfig;
+		type FloorPlanner = SimpleFloorPlanner;
+
+		fn without_witnesses(&self) -> Self {
+			self.clone()
+		}
+
+		fn configure(meta: &mut ConstraintSystem<Fr>) -> TestConfig {
+			let keccak = KeccakChip::configure(meta);
+			let temp = meta.advice_column();
+			let pub_ins = meta.instance_column();
+
+			meta.enable_equality(temp);
+			meta.enable_equality(pub_ins);
+
+			TestConfig { keccak, temp, pub_ins }
+		}
+
+		fn synthesize(
+			&self, config: TestConfig, mut layouter: impl Layouter<Fr>,
+		) -> Result<(), Error> {
+			let bytes = layouter.assign_region(
+				|| "temp",
+				|mut region: Region<'_, Fr>| {
+					self.bytes
+						.iter()
+						.enumerate()
+						.map(|(i, byte)| {
+							let byte = Value::known(Fr::from(u64::from(*byte)));
+							region.assign_advice(|| "byte", config.temp, i, || byte)
+						})
+						.collect::<Result<Vec<_>, Error>>()
+				},
+			)?;
+
+			if N == 64 {
+				let pubkey = bytes.try_into().unwrap();
+				let address = KeccakChip::eth_address(
+					&pubkey,
+					&config.keccak,
+					layouter.namespace(|| "eth_address"),
+				)?;
+				layouter.constrain_instance(address.cell(), config.pub_ins, 0)?;
+			} else {
+				let hash =
+					KeccakChip::hash(&bytes, &config.keccak, layouter.namespace(|| "keccak"))?;
+				for (i, byte) in hash.iter().enumerate() {
+					layouter.constrain_instance(byte.cell(), config.pub_ins, i)?;
+				}
+			}
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn test_keccak() {
+		let bytes = *b"abc";
+		let pub_ins = keccak256(&bytes).map(|byte| Fr::from(u64::from(byte))).to_vec();
+		let circuit = TestCircuit { bytes };
+		let prover = MockProver::run(17, &circuit, vec![pub_ins]).unwrap();
+		assert_eq!(prover.verify(), Ok(()));
+	}
+
+	#[test]
+	fn test_keccak_wrong_hash() {
+		let bytes = *b"abc";
+		let pub_ins = keccak256(b"abd").map(|byte| Fr::from(u64::from(byte))).to_vec();
+		let circuit = TestCircuit { bytes };
+		let prover = MockProver::run(17, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	#[ignore = "hashes two blocks, and takes minutes"]
+	fn test_keccak_two_blocks() {
+		let bytes = [7; 140];
+		let pub_ins = keccak256(&bytes).map(|byte| Fr::from(u64::from(byte))).to_vec();
+		let circuit = TestCircuit { bytes };
+		let prover = MockProver::run(18, &circuit, vec![pub_ins]).unwrap();
+		assert_eq!(prover.verify(), Ok(()));
+	}
+
+	#[test]
+	fn test_eth_address() {
+		let pubkey: [u8; 64] = std::array::from_fn(|i| i as u8);
+		let address = native::eth_address(&pubkey);
+		let mut address_bytes = [0; 32];
+		address_bytes[12..].copy_from_slice(&address);
+		address_bytes.reverse();
+		let pub_ins = vec![Fr::from_bytes(&address_bytes).unwrap()];
+
+		let circuit = TestCircuit { bytes: pubkey };
+		let prover = MockProver::run(17, &circuit, vec![pub_ins]).unwrap();
+		assert_eq!(prover.verify(), Ok(()));
+	}
+}
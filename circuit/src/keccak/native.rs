@@ -0,0 +1,124 @@
+/// Number of rounds of the Keccak-f[1600] permutation.
+pub const NUM_ROUNDS: usize = 24;
+/// Number of bytes absorbed by each permutation of Keccak256.
+pub const RATE: usize = 136;
+
+/// Round constants of the iota step.
+pub const ROUND_CONSTANTS: [u64; NUM_ROUNDS] = [
+	0x0000000000000001, 0x0000000000008082, 0x800000000000808A, 0x8000000080008000,
+	0x000000000000808B, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+	0x000000000000008A, 0x0000000000000088, 0x0000000080008009, 0x000000008000000A,
+	0x000000008000808B, 0x800000000000008B, 0x8000000000008089, 0x8000000000008003,
+	0x8000000000008002, 0x8000000000000080, 0x000000000000800A, 0x800000008000000A,
+	0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+];
+
+/// Rotation offsets of the rho step, indexed by the x and y of the lane.
+pub const ROTATIONS: [[u32; 5]; 5] =
+	[[0, 36, 3, 41, 18], [1, 44, 10, 45, 2], [62, 6, 43, 15, 61], [28, 55, 25, 21, 56], [
+		27, 20, 39, 8, 14,
+	]];
+
+/// Applies the Keccak-f[1600] permutation to the state, with the lane at x
+/// and y at the index `x + 5 * y`.
+pub fn keccak_f(state: &mut [u64; 25]) {
+	for rc in ROUND_CONSTANTS {
+		// Theta
+		let c: [u64; 5] = std::array::from_fn(|x| (0..5).fold(0, |acc, y| acc ^ state[x + 5 * y]));
+		for x in 0..5 {
+			let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+			for y in 0..5 {
+				state[x + 5 * y] ^= d;
+			}
+		}
+
+		// Rho and pi
+		let mut b = [0; 25];
+		for x in 0..5 {
+			for y in 0..5 {
+				b[y + 5 * ((2 * x + 3 * y) % 5)] = state[x + 5 * y].rotate_left(ROTATIONS[x][y]);
+			}
+		}
+
+		// Chi
+		for x in 0..5 {
+			for y in 0..5 {
+				state[x + 5 * y] =
+					b[x + 5 * y] ^ (!b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y]);
+			}
+		}
+
+		// Iota
+		state[0] ^= rc;
+	}
+}
+
+/// Returns the Keccak256 hash of the data, as used by Ethereum.
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+	let mut padded = data.to_vec();
+	padded.push(0x01);
+	padded.resize((padded.len() + RATE - 1) / RATE * RATE, 0);
+	*padded.last_mut().unwrap() |= 0x80;
+
+	let mut state = [0u64; 25];
+	for block in padded.chunks(RATE) {
+		for (lane, bytes) in state.iter_mut().zip(block.chunks(8)) {
+			let mut word = [0; 8];
+			word.copy_from_slice(bytes);
+			*lane ^= u64::from_le_bytes(word);
+		}
+		keccak_f(&mut state);
+	}
+
+	let mut hash = [0; 32];
+	for (bytes, lane) in hash.chunks_mut(8).zip(state) {
+		bytes.copy_from_slice(&lane.to_le_bytes());
+	}
+	hash
+}
+
+/// Returns the Ethereum address of the secp256k1 public key, given by its 64
+/// byte uncompressed encoding without the prefix: the last 20 bytes of its
+/// Keccak256 hash.
+pub fn eth_address(pubkey: &[u8; 64]) -> [u8; 20] {
+	let mut address = [0; 20];
+	address.copy_from_slice(&keccak256(pubkey)[12..]);
+	address
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_keccak256() {
+		assert_eq!(
+			hex::encode(keccak256(b"")),
+			"c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+		);
+		assert_eq!(
+			hex::encode(keccak256(b"abc")),
+			"4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+		);
+		// Two blocks, with the padding in the second one.
+		assert_eq!(
+			hex::encode(keccak256(&[0; 136])),
+			"3a5912a7c5faa06ee4fe906253e339467a9ce87d533c65be3c15cb231cdb25f9"
+		);
+	}
+
+	#[test]
+	fn test_eth_address() {
+		// The public key of the secret key 1, i.e. the generator of secp256k1.
+		let pubkey = hex::decode(
+			"79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\
+			 483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+		)
+		.unwrap();
+		let address = eth_address(&pubkey.try_into().unwrap());
+		assert_eq!(
+			hex::encode(address),
+			"7e5f4552091a69125d5dfcb7b8c2659029395bdf"
+		);
+	}
+}
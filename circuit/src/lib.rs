@@ -10,22 +10,51 @@
 #![warn(trivial_casts)]
 #![forbid(unsafe_code)]
 
+/// Aggregation of many proofs into a single proof
+pub mod aggregator;
+/// Reporting of the cost of the circuits
+pub mod cost;
+/// Helpers for debugging circuits
+pub mod dev;
 /// EDDSA signature scheme gadgets + native version
 pub mod eddsa;
+/// Self-describing envelope of the proofs
+pub mod envelope;
+/// Errors of loading the parameters, proving and verifying
+pub mod error;
 /// Common gadgets used across circuits
 pub mod gadgets;
+/// Iterations of the EigenTrust algorithm over fixed-point scores
+pub mod iteration;
+/// Keccak256 hash function gadgets + native version, for Ethereum addresses
+pub mod keccak;
+/// Poseidon Merkle tree for committing to trust values
+pub mod merkle;
+/// Aggregation of the signed opinions into a row of the trust matrix
+pub mod opinion;
 /// A module for defining round parameters and MDS matrix for hash
 /// permutations
 pub mod params;
 /// Poseidon hash function gadgets + native version
 pub mod poseidon;
+/// Poseidon2 hash function gadgets + native version
+pub mod poseidon2;
+/// Prover owning the params and the proving keys of the circuits
+pub mod prover;
+/// Builders of the public inputs of the circuits
+pub mod public_inputs;
 /// Rescue Prime hash function gadgets + native version
 pub mod rescue_prime;
+/// Loading the KZG parameters from the powers of tau ceremonies
+pub mod srs;
+/// Proof that a committed trust score is above a threshold
+pub mod threshold;
 /// Utilities for proving and verifying
 pub mod utils;
 
 use gadgets::{
 	common::{CommonChip, CommonConfig},
+	instances::{InstancesChip, InstancesConfig},
 	set::{FixedSetChip, FixedSetConfig},
 	sum::{SumChip, SumConfig},
 };
@@ -38,6 +67,10 @@ use halo2wrong::halo2::{
 use params::RoundParams;
 use poseidon::{PoseidonChip, PoseidonConfig};
 use std::marker::PhantomData;
+/// Initializes the pool of web workers, which the prover uses for its
+/// parallelism in the browser.
+#[cfg(all(target_arch = "wasm32", feature = "wasm-parallel"))]
+pub use wasm_bindgen_rayon::init_thread_pool;
 
 /// The halo2 columns config for the main circuit.
 #[derive(Clone, Debug)]
@@ -47,12 +80,15 @@ pub struct EigenTrustConfig {
 	common: CommonConfig,
 	poseidon: PoseidonConfig<5>,
 	sum: SumConfig,
+	instances: InstancesConfig,
 	// EigenTrust columns
 	temp: Column<Advice>,
 	pub_ins: Column<Instance>,
 }
 
-/// The EigenTrust main circuit.
+/// The EigenTrust main circuit. Its only public input is the commitment to
+/// the hash of the message sent to the peer v, see
+/// `gadgets::instances::commit_instances`.
 #[derive(Clone)]
 pub struct EigenTrustCircuit<
 	F: FieldExt,
@@ -144,6 +180,7 @@ impl<F: FieldExt, const S: usize, const B: usize, P: RoundParams<F, 5>> Circuit<
 		let common = CommonChip::configure(meta);
 		let poseidon = PoseidonChip::<_, 5, P>::configure(meta);
 		let sum = SumChip::<_, S>::configure(meta);
+		let instances = InstancesChip::<_, P>::configure(meta);
 
 		let temp = meta.advice_column();
 		let fixed = meta.fixed_column();
@@ -153,15 +190,15 @@ impl<F: FieldExt, const S: usize, const B: usize, P: RoundParams<F, 5>> Circuit<
 		meta.enable_constant(fixed);
 		meta.enable_equality(pub_ins);
 
-		EigenTrustConfig { set, common, poseidon, sum, temp, pub_ins }
+		EigenTrustConfig { set, common, poseidon, sum, instances, temp, pub_ins }
 	}
 
 	/// Synthesize the circuit.
 	fn synthesize(
 		&self, config: Self::Config, mut layouter: impl Layouter<F>,
 	) -> Result<(), Error> {
-		let (zero, ops, c_v, sk, epoch, iteration, bootstrap_score, pubkey_v, out_m_hash) =
-			layouter.assign_region(
+		let (zero, ops, c_v, sk, epoch, iteration, bootstrap_score, pubkey_v) = layouter
+			.assign_region(
 				|| "temp",
 				|mut region: Region<'_, F>| {
 					let mut offset = 0;
@@ -198,16 +235,8 @@ impl<F: FieldExt, const S: usize, const B: usize, P: RoundParams<F, 5>> Circuit<
 						Self::assign_temp(config.temp, "ops", &mut region, &mut offset, op)
 					})?;
 
-					let out_m_hash = region.assign_advice_from_instance(
-						|| "m_hash",
-						config.pub_ins,
-						0,
-						config.temp,
-						offset,
-					)?;
-
 					Ok((
-						zero, ops, c_v, sk, epoch, iteration, bootstrap_score, pubkey_v, out_m_hash,
+						zero, ops, c_v, sk, epoch, iteration, bootstrap_score, pubkey_v,
 					))
 				},
 			)?;
@@ -257,6 +286,14 @@ impl<F: FieldExt, const S: usize, const B: usize, P: RoundParams<F, 5>> Circuit<
 		let res = poseidon_m_hash
 			.synthesize(config.poseidon, layouter.namespace(|| "poseidon_m_hash"))?;
 		let m_hash = res[0].clone();
+		// The message of a zero opinion is not checked, so the prover can
+		// output any hash. An honest one outputs the same hash.
+		let out_m_hash = layouter.assign_region(
+			|| "out_m_hash",
+			|mut region: Region<'_, F>| {
+				region.assign_advice(|| "m_hash", config.temp, 0, || m_hash.value().cloned())
+			},
+		)?;
 
 		let is_zero_opinion = CommonChip::is_zero(
 			op_v,
@@ -272,15 +309,20 @@ impl<F: FieldExt, const S: usize, const B: usize, P: RoundParams<F, 5>> Circuit<
 			layouter.namespace(|| "m_hash_select"),
 		)?;
 
-		layouter.constrain_instance(final_m_hash.cell(), config.pub_ins, 0)?;
-
-		Ok(())
+		InstancesChip::<_, P>::expose(
+			&[final_m_hash],
+			config.pub_ins,
+			0,
+			&config.instances,
+			layouter.namespace(|| "instances"),
+		)
 	}
 }
 
 #[cfg(test)]
 mod test {
 	use super::*;
+	use gadgets::instances::commit_instances;
 	use halo2wrong::{
 		curves::bn256::{Bn256, Fr},
 		halo2::{arithmetic::Field, dev::MockProver},
@@ -321,9 +363,9 @@ mod test {
 		let opv = Fr::from(256);
 		let inputs = [epoch, iter, opv, pubkey_v, pubkey_i];
 		let m_hash_poseidon = Poseidon::<_, 5, Params>::new(inputs).permute()[0];
-		// let m_hash_poseidon = Fr::one();
+		let pub_in = commit_instances::<_, Params>(&[m_hash_poseidon]);
 
-		let prover = match MockProver::<Fr>::run(k, &eigen_trust, vec![vec![m_hash_poseidon]]) {
+		let prover = match MockProver::<Fr>::run(k, &eigen_trust, vec![vec![pub_in]]) {
 			Ok(prover) => prover,
 			Err(e) => panic!("{}", e),
 		};
@@ -357,11 +399,11 @@ mod test {
 		let opv = Fr::from(256);
 		let inputs = [epoch, iter, opv, pubkey_v, pubkey_i];
 		let m_hash_poseidon = Poseidon::<_, 5, Params>::new(inputs).permute()[0];
+		let pub_in = commit_instances::<_, Params>(&[m_hash_poseidon]);
 
 		let params = generate_params(k);
 		let res =
-			prove_and_verify::<Bn256, _, _>(params, eigen_trust, &[&[m_hash_poseidon]], &mut rng)
-				.unwrap();
+			prove_and_verify::<Bn256, _, _>(params, eigen_trust, &[&[pub_in]], &mut rng).unwrap();
 		assert!(res);
 	}
 }
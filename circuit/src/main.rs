@@ -12,5 +12,5 @@ fn generate_params_and_write(k: u32) {
 	let params = generate_params::<Bn256>(k);
 	let current_path = current_dir().unwrap();
 	let path = format!("{}/../data/params-{}.bin", current_path.display(), k);
-	write_params(&params, &path);
+	write_params(&params, &path).unwrap();
 }
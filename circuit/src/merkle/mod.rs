@@ -0,0 +1,276 @@
+/// Native version of the Poseidon Merkle tree
+pub mod native;
+
+use crate::{
+	gadgets::common::{CommonChip, CommonConfig},
+	params::poseidon_bn254_3x5::Params,
+	poseidon::{
+		native::sponge::{MERKLE_LEAF_DOMAIN, MERKLE_NODE_DOMAIN},
+		PoseidonChip, PoseidonConfig,
+	},
+};
+use halo2wrong::{
+	curves::bn256::Fr,
+	halo2::{
+		arithmetic::Field,
+		circuit::{AssignedCell, Layouter, Region},
+		plonk::{Advice, Column, ConstraintSystem, Error},
+	},
+};
+
+#[derive(Clone, Debug)]
+/// Configuration elements for the circuit are defined here.
+pub struct MerkleConfig {
+	/// Configures the common gadgets, used for ordering the nodes.
+	common: CommonConfig,
+	/// Configures the Poseidon chip, used for hashing the nodes.
+	poseidon: PoseidonConfig<3>,
+	/// Configures a column for the constants.
+	temp: Column<Advice>,
+}
+
+/// Constructs a chip that computes the Merkle root from a leaf and its path,
+/// hashing the leaf and the nodes the same way as
+/// `native::PoseidonMerkleTree`.
+pub struct MerkleChip<const DEPTH: usize> {
+	/// Assigns a cell for the leaf.
+	leaf: AssignedCell<Fr, Fr>,
+	/// Assigns cells for the bits of the leaf index, from the least
+	/// significant one. A set bit means the node is the right child.
+	path_bits: [AssignedCell<Fr, Fr>; DEPTH],
+	/// Assigns cells for the siblings on the path from the leaf to the root.
+	siblings: [AssignedCell<Fr, Fr>; DEPTH],
+}
+
+impl<const DEPTH: usize> MerkleChip<DEPTH> {
+	/// Create a new chip.
+	pub fn new(
+		leaf: AssignedCell<Fr, Fr>, path_bits: [AssignedCell<Fr, Fr>; DEPTH],
+		siblings: [AssignedCell<Fr, Fr>; DEPTH],
+	) -> Self {
+		MerkleChip { leaf, path_bits, siblings }
+	}
+
+	/// Make the circuit config.
+	pub fn configure(meta: &mut ConstraintSystem<Fr>) -> MerkleConfig {
+		let common = CommonChip::configure(meta);
+		let poseidon = PoseidonChip::<_, 3, Params>::configure(meta);
+		let temp = meta.advice_column();
+		let fixed = meta.fixed_column();
+
+		meta.enable_equality(temp);
+		meta.enable_constant(fixed);
+
+		MerkleConfig { common, poseidon, temp }
+	}
+
+	/// Synthesize the circuit, returning the computed root. Constrain it to
+	/// the committed root to prove that the leaf is in the tree.
+	pub fn synthesize(
+		&self, config: MerkleConfig, mut layouter: impl Layouter<Fr>,
+	) -> Result<AssignedCell<Fr, Fr>, Error> {
+		let (zero, leaf_domain, node_domain) = layouter.assign_region(
+			|| "assign_constants",
+			|mut region: Region<'_, Fr>| {
+				let zero =
+					region.assign_advice_from_constant(|| "zero", config.temp, 0, Fr::zero())?;
+				let leaf_domain = region.assign_advice_from_constant(
+					|| "leaf_domain",
+					config.temp,
+					1,
+					Fr::from(MERKLE_LEAF_DOMAIN),
+				)?;
+				let node_domain = region.assign_advice_from_constant(
+					|| "node_domain",
+					config.temp,
+					2,
+					Fr::from(MERKLE_NODE_DOMAIN),
+				)?;
+				Ok((zero, leaf_domain, node_domain))
+			},
+		)?;
+
+		let inputs = [leaf_domain, self.leaf.clone(), zero.clone()];
+		let hasher = PoseidonChip::<_, 3, Params>::new(inputs);
+		let res = hasher.synthesize(config.poseidon.clone(), layouter.namespace(|| "leaf"))?;
+		let mut node = res[0].clone();
+		for (bit, sibling) in self.path_bits.iter().zip(&self.siblings) {
+			// Swap the node and the sibling if the node is the right child.
+			let left = CommonChip::select(
+				bit.clone(),
+				sibling.clone(),
+				node.clone(),
+				config.common,
+				layouter.namespace(|| "left"),
+			)?;
+			let right = CommonChip::select(
+				bit.clone(),
+				node,
+				sibling.clone(),
+				config.common,
+				layouter.namespace(|| "right"),
+			)?;
+
+			let inputs = [node_domain.clone(), left, right];
+			let hasher = PoseidonChip::<_, 3, Params>::new(inputs);
+			let res = hasher.synthesize(config.poseidon.clone(), layouter.namespace(|| "hash"))?;
+			node = res[0].clone();
+		}
+
+		Ok(node)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{native::PoseidonMerkleTree, *};
+	use crate::utils::{generate_params, prove_and_verify};
+	use halo2wrong::{
+		curves::bn256::Bn256,
+		halo2::{
+			circuit::{SimpleFloorPlanner, Value},
+			dev::MockProver,
+			plonk::{Circuit, Instance},
+		},
+	};
+	use rand::thread_rng;
+
+	#[derive(Clone)]
+	struct TestConfig {
+		merkle: MerkleConfig,
+		temp: Column<Advice>,
+		pub_ins: Column<Instance>,
+	}
+
+	#[derive(Clone)]
+	struct TestCircuit {
+		leaf: Fr,
+		path_bits: [Fr; 3],
+		siblings: [Fr; 3],
+	}
+
+	impl TestCircuit {
+		fn new(leaf: Fr, index: usize, siblings: [Fr; 3]) -> Self {
+			let path_bits = [0, 1, 2].map(|i| Fr::from(((index >> i) & 1) as u64));
+			Self { leaf, path_bits, siblings }
+		}
+	}
+
+	impl Circuit<Fr> for TestCircuit {
+		type Config = TestConfig;
+		type FloorPlanner = SimpleFloorPlanner;
+
+		fn without_witnesses(&self) -> Self {
+			self.clone()
+		}
+
+		fn configure(meta: &mut ConstraintSystem<Fr>) -> TestConfig {
+			let merkle = MerkleChip::<3>::configure(meta);
+			let temp = meta.advice_column();
+			let pub_ins = meta.instance_column();
+
+			meta.enable_equality(temp);
+			meta.enable_equality(pub_ins);
+
+			TestConfig { merkle, temp, pub_ins }
+		}
+
+		fn synthesize(
+			&self, config: TestConfig, mut layouter: impl Layouter<Fr>,
+		) -> Result<(), Error> {
+			let (leaf, path_bits, siblings) = layouter.assign_region(
+				|| "temp",
+				|mut region: Region<'_, Fr>| {
+					let leaf = region.assign_advice(
+						|| "leaf",
+						config.temp,
+						0,
+						|| Value::known(self.leaf),
+					)?;
+					let mut path_bits = Vec::new();
+					let mut siblings = Vec::new();
+					for i in 0..3 {
+						path_bits.push(region.assign_advice(
+							|| "path_bit",
+							config.temp,
+							1 + i,
+							|| Value::known(self.path_bits[i]),
+						)?);
+						siblings.push(region.assign_advice(
+							|| "sibling",
+							config.temp,
+							4 + i,
+							|| Value::known(self.siblings[i]),
+						)?);
+					}
+					Ok((leaf, path_bits, siblings))
+				},
+			)?;
+
+			let merkle = MerkleChip::<3>::new(
+				leaf,
+				path_bits.try_into().unwrap(),
+				siblings.try_into().unwrap(),
+			);
+			let root = merkle.synthesize(config.merkle, layouter.namespace(|| "merkle"))?;
+			layouter.constrain_instance(root.cell(), config.pub_ins, 0)?;
+			Ok(())
+		}
+	}
+
+	fn tree() -> (Vec<Fr>, PoseidonMerkleTree) {
+		let leaves: Vec<Fr> = (0u64..8).map(Fr::from).collect();
+		let tree = PoseidonMerkleTree::new(leaves.clone());
+		(leaves, tree)
+	}
+
+	#[test]
+	fn test_merkle_path() {
+		let (leaves, tree) = tree();
+		for index in [0, 5, 7] {
+			let proof = tree.proof(index);
+			let circuit =
+				TestCircuit::new(leaves[index], index, proof.siblings.try_into().unwrap());
+
+			let k = 11;
+			let prover = MockProver::run(k, &circuit, vec![vec![tree.root()]]).unwrap();
+			assert_eq!(prover.verify(), Ok(()));
+		}
+	}
+
+	#[test]
+	fn test_merkle_path_wrong_leaf() {
+		let (leaves, tree) = tree();
+		let proof = tree.proof(5);
+		let circuit = TestCircuit::new(leaves[6], 5, proof.siblings.try_into().unwrap());
+
+		let k = 11;
+		let prover = MockProver::run(k, &circuit, vec![vec![tree.root()]]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_merkle_path_wrong_index() {
+		let (leaves, tree) = tree();
+		let proof = tree.proof(5);
+		let circuit = TestCircuit::new(leaves[5], 4, proof.siblings.try_into().unwrap());
+
+		let k = 11;
+		let prover = MockProver::run(k, &circuit, vec![vec![tree.root()]]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_merkle_path_production() {
+		let (leaves, tree) = tree();
+		let proof = tree.proof(3);
+		let circuit = TestCircuit::new(leaves[3], 3, proof.siblings.try_into().unwrap());
+
+		let k = 11;
+		let rng = &mut thread_rng();
+		let params = generate_params(k);
+		let res = prove_and_verify::<Bn256, _, _>(params, circuit, &[&[tree.root()]], rng).unwrap();
+
+		assert!(res);
+	}
+}
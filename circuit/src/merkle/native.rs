@@ -0,0 +1,267 @@
+use crate::{
+	params::poseidon_bn254_3x5::Params,
+	poseidon::native::{
+		sponge::{MERKLE_LEAF_DOMAIN, MERKLE_NODE_DOMAIN},
+		Poseidon,
+	},
+};
+use halo2wrong::{curves::bn256::Fr, halo2::arithmetic::Field};
+use std::collections::HashMap;
+
+type Hasher = Poseidon<Fr, 3, Params>;
+
+/// Hashes a leaf into the bottom node of the tree, with the leaf domain tag,
+/// so a leaf can never be mistaken for an inner node.
+pub fn hash_leaf(leaf: Fr) -> Fr {
+	let domain = Fr::from(MERKLE_LEAF_DOMAIN);
+	Hasher::new([domain, leaf, Fr::zero()]).permute()[0]
+}
+
+/// Hashes two nodes of the tree into their parent, with the node domain tag.
+pub fn hash_two(left: Fr, right: Fr) -> Fr {
+	let domain = Fr::from(MERKLE_NODE_DOMAIN);
+	Hasher::new([domain, left, right]).permute()[0]
+}
+
+/// Inclusion proof of a leaf in the Merkle tree.
+#[derive(Clone, Debug)]
+pub struct MerkleProof {
+	/// Index of the leaf.
+	pub index: usize,
+	/// Siblings on the path from the leaf to the root.
+	pub siblings: Vec<Fr>,
+}
+
+/// Merkle tree over field elements, hashing the leaves and the nodes with
+/// Poseidon of width 3, under different domain tags in the capacity element.
+pub struct PoseidonMerkleTree {
+	/// Nodes of each level, from the hashes of the leaves up to the root.
+	levels: Vec<Vec<Fr>>,
+}
+
+impl PoseidonMerkleTree {
+	/// Build the tree from the leaves. The leaves are padded with zeros up to
+	/// the next power of two.
+	pub fn new(mut leaves: Vec<Fr>) -> Self {
+		let size = leaves.len().max(1).next_power_of_two();
+		leaves.resize(size, Fr::zero());
+
+		let mut levels = vec![leaves.into_iter().map(hash_leaf).collect::<Vec<_>>()];
+		while levels[levels.len() - 1].len() > 1 {
+			let level = &levels[levels.len() - 1];
+			let next = level.chunks(2).map(|pair| hash_two(pair[0], pair[1])).collect();
+			levels.push(next);
+		}
+
+		Self { levels }
+	}
+
+	/// Returns the root of the tree.
+	pub fn root(&self) -> Fr {
+		self.levels[self.levels.len() - 1][0]
+	}
+
+	/// Returns the inclusion proof of the leaf at `index`.
+	/// Panics if the index is out of range.
+	pub fn proof(&self, index: usize) -> MerkleProof {
+		assert!(index < self.levels[0].len());
+		let siblings = self.levels[..self.levels.len() - 1]
+			.iter()
+			.enumerate()
+			.map(|(level, nodes)| nodes[(index >> level) ^ 1])
+			.collect();
+
+		MerkleProof { index, siblings }
+	}
+}
+
+/// Sparse Merkle tree of a fixed depth, where every leaf that was not set
+/// holds the default leaf. Only the nodes above the set leaves are stored, so
+/// the depth can be large, like one leaf per possible peer id.
+pub struct SparseMerkleTree {
+	/// Number of levels above the leaves.
+	depth: usize,
+	/// The leaf of every index that was not set.
+	default_leaf: Fr,
+	/// Root of an empty subtree at each level, starting from the hash of the
+	/// default leaf.
+	defaults: Vec<Fr>,
+	/// The leaves that were set, keyed by their index.
+	leaves: HashMap<usize, Fr>,
+	/// Nodes that differ from the default, keyed by their level and index.
+	nodes: HashMap<(usize, usize), Fr>,
+}
+
+impl SparseMerkleTree {
+	/// Create a tree of `2^depth` default leaves.
+	pub fn new(depth: usize, default_leaf: Fr) -> Self {
+		assert!(depth < usize::BITS as usize);
+		let mut defaults = vec![hash_leaf(default_leaf)];
+		for level in 0..depth {
+			defaults.push(hash_two(defaults[level], defaults[level]));
+		}
+		Self { depth, default_leaf, defaults, leaves: HashMap::new(), nodes: HashMap::new() }
+	}
+
+	/// Returns the node at `level` and `index`.
+	fn node(&self, level: usize, index: usize) -> Fr {
+		self.nodes.get(&(level, index)).copied().unwrap_or(self.defaults[level])
+	}
+
+	/// Sets the leaf at `index`, updating the nodes on its path to the root.
+	/// Panics if the index is out of range.
+	pub fn insert(&mut self, index: usize, leaf: Fr) {
+		assert!(index < 1 << self.depth);
+		self.leaves.insert(index, leaf);
+		let mut node = hash_leaf(leaf);
+		for level in 0..self.depth {
+			self.nodes.insert((level, index >> level), node);
+			let sibling = self.node(level, (index >> level) ^ 1);
+			node = if (index >> level) & 1 == 0 {
+				hash_two(node, sibling)
+			} else {
+				hash_two(sibling, node)
+			};
+		}
+		self.nodes.insert((self.depth, 0), node);
+	}
+
+	/// Returns the leaf at `index`.
+	pub fn leaf(&self, index: usize) -> Fr {
+		self.leaves.get(&index).copied().unwrap_or(self.default_leaf)
+	}
+
+	/// Returns the root of the tree.
+	pub fn root(&self) -> Fr {
+		self.node(self.depth, 0)
+	}
+
+	/// Returns the inclusion proof of the leaf at `index`, which is also a
+	/// proof that an unset leaf holds the default leaf.
+	/// Panics if the index is out of range.
+	pub fn proof(&self, index: usize) -> MerkleProof {
+		assert!(index < 1 << self.depth);
+		let siblings =
+			(0..self.depth).map(|level| self.node(level, (index >> level) ^ 1)).collect();
+
+		MerkleProof { index, siblings }
+	}
+}
+
+/// Checks that the `leaf` is included in the tree with the given `root`.
+/// Rejects the proof if its index doesn't fit in the depth of the tree, given
+/// by the number of siblings, so every leaf has a single valid index.
+pub fn verify_proof(root: Fr, leaf: Fr, proof: &MerkleProof) -> bool {
+	let depth = proof.siblings.len();
+	if depth < usize::BITS as usize && proof.index >> depth != 0 {
+		return false;
+	}
+	let mut node = hash_leaf(leaf);
+	for (level, sibling) in proof.siblings.iter().enumerate() {
+		node = if (proof.index >> level) & 1 == 0 {
+			hash_two(node, *sibling)
+		} else {
+			hash_two(*sibling, node)
+		};
+	}
+	node == root
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn should_build_tree_with_power_of_two_leaves() {
+		let leaves: Vec<Fr> = (0u64..4).map(Fr::from).collect();
+		let tree = PoseidonMerkleTree::new(leaves.clone());
+
+		let [a, b, c, d] = [0, 1, 2, 3].map(|i| hash_leaf(leaves[i]));
+		assert_eq!(tree.root(), hash_two(hash_two(a, b), hash_two(c, d)));
+	}
+
+	#[test]
+	fn should_separate_leaves_from_nodes() {
+		let leaves: Vec<Fr> = (0u64..4).map(Fr::from).collect();
+		let tree = PoseidonMerkleTree::new(leaves);
+		let proof = tree.proof(0);
+
+		// The inner node above the first two leaves, proven as a leaf of the
+		// tree of depth one.
+		let inner = hash_two(hash_leaf(Fr::from(0)), hash_leaf(Fr::from(1)));
+		let forged = MerkleProof { index: 0, siblings: proof.siblings[1..].to_vec() };
+		assert!(!verify_proof(tree.root(), inner, &forged));
+		assert_ne!(hash_leaf(Fr::zero()), hash_two(Fr::zero(), Fr::zero()));
+	}
+
+	#[test]
+	fn should_verify_inclusion_proof() {
+		let leaves: Vec<Fr> = (0u64..8).map(Fr::from).collect();
+		let tree = PoseidonMerkleTree::new(leaves.clone());
+
+		for (i, leaf) in leaves.iter().enumerate() {
+			let proof = tree.proof(i);
+			assert_eq!(proof.siblings.len(), 3);
+			assert!(verify_proof(tree.root(), *leaf, &proof));
+		}
+	}
+
+	#[test]
+	fn should_reject_tampered_proof() {
+		let leaves: Vec<Fr> = (0u64..8).map(Fr::from).collect();
+		let tree = PoseidonMerkleTree::new(leaves.clone());
+
+		let mut proof = tree.proof(5);
+		proof.siblings[1] += Fr::one();
+		assert!(!verify_proof(tree.root(), leaves[5], &proof));
+
+		let mut proof = tree.proof(5);
+		proof.index = 4;
+		assert!(!verify_proof(tree.root(), leaves[5], &proof));
+
+		// The same path bits, with a bit above the depth of the tree.
+		let mut proof = tree.proof(5);
+		proof.index = 5 + 8;
+		assert!(!verify_proof(tree.root(), leaves[5], &proof));
+
+		let proof = tree.proof(5);
+		assert!(!verify_proof(tree.root(), leaves[6], &proof));
+	}
+
+	#[test]
+	fn should_match_dense_tree() {
+		let leaves: Vec<Fr> = (0u64..5).map(Fr::from).collect();
+		let dense = PoseidonMerkleTree::new(leaves.clone());
+
+		let mut sparse = SparseMerkleTree::new(3, Fr::zero());
+		for (i, leaf) in leaves.iter().enumerate() {
+			sparse.insert(i, *leaf);
+		}
+		assert_eq!(sparse.root(), dense.root());
+		assert_eq!(sparse.proof(6).siblings, dense.proof(6).siblings);
+	}
+
+	#[test]
+	fn should_prove_sparse_leaves() {
+		let default_leaf = Fr::from(42);
+		let mut tree = SparseMerkleTree::new(32, default_leaf);
+		let empty_root = tree.root();
+
+		tree.insert(7, Fr::from(1));
+		tree.insert(1 << 31, Fr::from(2));
+		assert_ne!(tree.root(), empty_root);
+		assert_eq!(tree.leaf(7), Fr::from(1));
+		assert_eq!(tree.leaf(8), default_leaf);
+
+		let proof = tree.proof(1 << 31);
+		assert_eq!(proof.siblings.len(), 32);
+		assert!(verify_proof(tree.root(), Fr::from(2), &proof));
+		assert!(verify_proof(tree.root(), default_leaf, &tree.proof(12345)));
+		assert!(!verify_proof(tree.root(), default_leaf, &tree.proof(7)));
+
+		// Setting the leaves back to the default gives the empty root.
+		tree.insert(7, default_leaf);
+		tree.insert(1 << 31, default_leaf);
+		assert_eq!(tree.root(), empty_root);
+	}
+}
@@ -0,0 +1,515 @@
+//! Circuit aggregating the signed opinions of a peer into a row of the local
+//! trust matrix. The signatures of the opinions are verified as a batch,
+//! their scores are normalized to sum to one, and the row is committed to with
+//! Poseidon.
+//! Every opinion has a nullifier for the epoch, so the same opinion can't be
+//! counted twice. The normalized scores are also published encrypted to a
+//! recipient, with a key derived in the circuit from the Diffie-Hellman
+//! secret of the peer and the recipient, so only the recipient can read them.
+
+use crate::{
+	eddsa::{
+		batch::{AssignedSignature, BatchEddsaChip, BatchEddsaConfig},
+		eddsa_gadgets::{EddsaGadgetsChip, EddsaGadgetsConfig},
+		native::{dh::kdf_info, PublicKey, SecretKey, Signature},
+	},
+	gadgets::{
+		bits2num::to_bits,
+		fixed_point::{div_fixed, FixedPointChip, FixedPointConfig},
+		nullifier::{NullifierChip, NullifierConfig},
+	},
+	iteration::FRAC_BITS,
+	params::poseidon_bn254_5x5::Params,
+	poseidon::{
+		cipher::{PoseidonCipherChip, PoseidonCipherConfig},
+		native::sponge::{PoseidonSponge, KDF_DOMAIN, OPINION_HASH_DOMAIN},
+		sponge::{PoseidonSpongeChip, PoseidonSpongeConfig},
+		PoseidonChip, PoseidonConfig,
+	},
+};
+use halo2wrong::{
+	curves::bn256::Fr,
+	halo2::{
+		arithmetic::Field,
+		circuit::{AssignedCell, Layouter, Region, SimpleFloorPlanner, Value},
+		plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance},
+	},
+};
+
+/// The `info` of the key encrypting the scores, see `SharedSecret::derive_key`.
+pub const OPINION_KEY_INFO: &[u8] = b"opinion";
+
+/// Returns the key encrypting the scores of the holder of `sk` for the
+/// `recipient`, or `None` if the key of the recipient is invalid. The
+/// recipient gets the same key from its own secret key and the key of the
+/// peer.
+pub fn opinion_key(sk: &SecretKey, recipient: &PublicKey) -> Option<Fr> {
+	Some(sk.diffie_hellman(recipient)?.derive_key(OPINION_KEY_INFO))
+}
+
+/// Returns the message signed for the opinion with the `score` about the
+/// `neighbor`.
+pub fn opinion_message(neighbor: Fr, score: Fr) -> Fr {
+	let mut sponge =
+		PoseidonSponge::<Fr, 5, Params>::new_with_domain(Fr::from(OPINION_HASH_DOMAIN));
+	sponge.update(&[neighbor, score]);
+	sponge.squeeze()
+}
+
+/// Normalizes the scores to sum to one, as fixed-point numbers with
+/// `FRAC_BITS` fractional bits rounded down, like the `OpinionCircuit`.
+pub fn normalize(scores: &[Fr]) -> Vec<Fr> {
+	let sum = scores.iter().fold(Fr::zero(), |acc, score| acc + score);
+	scores.iter().map(|score| div_fixed::<_, FRAC_BITS>(*score, sum)).collect()
+}
+
+/// Returns the commitment to the row of the local trust matrix, holding the
+/// normalized `scores` of the `neighbors`.
+pub fn row_commitment(neighbors: &[Fr], scores: &[Fr]) -> Fr {
+	let inputs: Vec<Fr> = neighbors.iter().zip(scores).flat_map(|(n, s)| [*n, *s]).collect();
+	let mut sponge = PoseidonSponge::<Fr, 5, Params>::new_legacy();
+	sponge.update(&inputs);
+	sponge.squeeze()
+}
+
+/// The halo2 columns config for the opinion circuit.
+#[derive(Clone)]
+pub struct OpinionConfig {
+	eddsa: BatchEddsaConfig,
+	poseidon: PoseidonConfig<5>,
+	sponge: PoseidonSpongeConfig<5>,
+	fixed_point: FixedPointConfig,
+	nullifier: NullifierConfig,
+	cipher: PoseidonCipherConfig,
+	eddsa_gadgets: EddsaGadgetsConfig,
+	temp: Column<Advice>,
+	pub_ins: Column<Instance>,
+}
+
+/// Circuit aggregating `N` opinions of a peer, each with a score about a
+/// neighbor and signed by the peer. The public inputs are the x and y of the
+/// public key of the peer, the `row_commitment` to the neighbors and their
+/// normalized scores, the epoch, the `nullifier` of every opinion, the x and
+/// y of the public key of the recipient, the nonce, and the normalized scores
+/// encrypted with the `opinion_key` for the recipient and the nonce. The
+/// nonce must be fresh for every encryption, since the key is the same for
+/// all the rows sent to the recipient. The scores are fixed-point numbers
+/// below `2^NUM_BITS` and can't all be zero.
+#[derive(Clone)]
+pub struct OpinionCircuit<const N: usize> {
+	neighbors: [Fr; N],
+	scores: [Fr; N],
+	signatures: [Signature; N],
+	sk: Fr,
+	epoch: Fr,
+}
+
+impl<const N: usize> OpinionCircuit<N> {
+	/// Create a new circuit from the opinions in the `epoch` and their
+	/// signatures by the holder of `sk`.
+	pub fn new(
+		neighbors: [Fr; N], scores: [Fr; N], signatures: [Signature; N], sk: &SecretKey, epoch: Fr,
+	) -> Self {
+		Self { neighbors, scores, signatures, sk: sk.scalar(), epoch }
+	}
+}
+
+impl<const N: usize> Circuit<Fr> for OpinionCircuit<N> {
+	type Config = OpinionConfig;
+	type FloorPlanner = SimpleFloorPlanner;
+
+	fn without_witnesses(&self) -> Self {
+		self.clone()
+	}
+
+	fn configure(meta: &mut ConstraintSystem<Fr>) -> OpinionConfig {
+		let eddsa = BatchEddsaChip::configure(meta);
+		let poseidon = PoseidonChip::<_, 5, Params>::configure(meta);
+		let sponge = PoseidonSpongeChip::<_, 5, Params>::configure(meta);
+		let fixed_point = FixedPointChip::<_, FRAC_BITS>::configure(meta);
+		let nullifier = NullifierChip::configure(meta);
+		let cipher = PoseidonCipherChip::configure(meta);
+		let eddsa_gadgets = EddsaGadgetsChip::configure(meta);
+		let temp = meta.advice_column();
+		let fixed = meta.fixed_column();
+		let pub_ins = meta.instance_column();
+
+		meta.enable_equality(temp);
+		meta.enable_constant(fixed);
+		meta.enable_equality(pub_ins);
+
+		OpinionConfig {
+			eddsa,
+			poseidon,
+			sponge,
+			fixed_point,
+			nullifier,
+			cipher,
+			eddsa_gadgets,
+			temp,
+			pub_ins,
+		}
+	}
+
+	fn synthesize(
+		&self, config: OpinionConfig, mut layouter: impl Layouter<Fr>,
+	) -> Result<(), Error> {
+		let (constants, pk_x, pk_y, epoch, sk, neighbors, scores) = layouter.assign_region(
+			|| "temp",
+			|mut region: Region<'_, Fr>| {
+				let pk_x = region.assign_advice_from_instance(
+					|| "pk_x",
+					config.pub_ins,
+					0,
+					config.temp,
+					0,
+				)?;
+				let pk_y = region.assign_advice_from_instance(
+					|| "pk_y",
+					config.pub_ins,
+					1,
+					config.temp,
+					1,
+				)?;
+				let epoch = region.assign_advice_from_instance(
+					|| "epoch",
+					config.pub_ins,
+					3,
+					config.temp,
+					2,
+				)?;
+
+				let mut offset = 3;
+				let info = kdf_info(OPINION_KEY_INFO);
+				let constants =
+					[Fr::from(OPINION_HASH_DOMAIN), Fr::one(), Fr::zero(), Fr::from(2), info]
+						.try_map::<_, Result<AssignedCell<Fr, Fr>, Error>>(|value| {
+						let cell = region.assign_advice_from_constant(
+							|| "const",
+							config.temp,
+							offset,
+							value,
+						);
+						offset += 1;
+						cell
+					})?;
+
+				let mut assign = |region: &mut Region<'_, Fr>, name: &str, value: Fr| {
+					let cell =
+						region.assign_advice(|| name, config.temp, offset, || Value::known(value));
+					offset += 1;
+					cell
+				};
+				let sk = assign(&mut region, "sk", self.sk)?;
+				let neighbors = self.neighbors.try_map::<_, Result<AssignedCell<Fr, Fr>, Error>>(
+					|neighbor| assign(&mut region, "neighbor", neighbor),
+				)?;
+				let scores =
+					self.scores.try_map::<_, Result<AssignedCell<Fr, Fr>, Error>>(|score| {
+						assign(&mut region, "score", score)
+					})?;
+
+				Ok((constants, pk_x, pk_y, epoch, sk, neighbors, scores))
+			},
+		)?;
+		let (recipient_x, recipient_y, nonce) = layouter.assign_region(
+			|| "recipient",
+			|mut region: Region<'_, Fr>| {
+				let mut assign = |offset, name: &str| {
+					let row = 4 + N + offset;
+					region.assign_advice_from_instance(
+						|| name,
+						config.pub_ins,
+						row,
+						config.temp,
+						offset,
+					)
+				};
+				Ok((
+					assign(0, "recipient_x")?,
+					assign(1, "recipient_y")?,
+					assign(2, "nonce")?,
+				))
+			},
+		)?;
+		let [domain, one, zero, len, info] = constants;
+
+		FixedPointChip::<_, FRAC_BITS>::load_table(
+			&config.fixed_point,
+			layouter.namespace(|| "range_table"),
+		)?;
+		BatchEddsaChip::load_table(&config.eddsa, layouter.namespace(|| "eddsa_range_table"))?;
+		NullifierChip::load_table(&config.nullifier, layouter.namespace(|| "nullifier_table"))?;
+
+		// The nullifiers are derived from the key of the peer
+		let sk_bits = to_bits(self.sk.to_bytes()).map(Fr::from);
+		NullifierChip::check_key(
+			sk.clone(),
+			sk_bits,
+			pk_x.clone(),
+			pk_y.clone(),
+			&config.nullifier,
+			layouter.namespace(|| "check_key"),
+		)?;
+		for (i, neighbor) in neighbors.iter().enumerate() {
+			let nullifier = NullifierChip::nullifier(
+				sk.clone(),
+				epoch.clone(),
+				neighbor.clone(),
+				&config.nullifier,
+				layouter.namespace(|| "nullifier"),
+			)?;
+			layouter.constrain_instance(nullifier.cell(), config.pub_ins, 4 + i)?;
+		}
+
+		// Every opinion is signed by the peer
+		let mut signatures = Vec::with_capacity(N);
+		for ((neighbor, score), sig) in neighbors.iter().zip(&scores).zip(&self.signatures) {
+			let (big_r_x, big_r_y, s) = layouter.assign_region(
+				|| "signature",
+				|mut region: Region<'_, Fr>| {
+					let mut assign = |offset, name: &str, value: Fr| {
+						region.assign_advice(|| name, config.temp, offset, || Value::known(value))
+					};
+					Ok((
+						assign(0, "big_r_x", sig.big_r.x)?,
+						assign(1, "big_r_y", sig.big_r.y)?,
+						assign(2, "s", sig.s)?,
+					))
+				},
+			)?;
+
+			// The capacity element holds the domain, followed by the padded inputs.
+			let inputs =
+				[domain.clone(), neighbor.clone(), score.clone(), one.clone(), zero.clone()];
+			let hasher = PoseidonChip::<_, 5, Params>::new(inputs);
+			let m = hasher.synthesize(config.poseidon.clone(), layouter.namespace(|| "message"))?;
+			signatures.push(AssignedSignature { big_r_x, big_r_y, s, m: m[1].clone() });
+		}
+		let eddsa = BatchEddsaChip::new(signatures, pk_x, pk_y);
+		eddsa.synthesize(config.eddsa.clone(), layouter.namespace(|| "batch_eddsa"))?;
+
+		// Normalize the scores by their sum
+		let mut sum = zero;
+		for score in &scores {
+			FixedPointChip::<_, FRAC_BITS>::check_range(
+				score.clone(),
+				config.fixed_point.clone(),
+				layouter.namespace(|| "score_range"),
+			)?;
+			sum = FixedPointChip::<_, FRAC_BITS>::add(
+				sum,
+				score.clone(),
+				config.fixed_point.clone(),
+				layouter.namespace(|| "sum"),
+			)?;
+		}
+		let mut inputs = Vec::with_capacity(2 * N);
+		let mut plaintext = Vec::with_capacity(N);
+		for (neighbor, score) in neighbors.into_iter().zip(scores) {
+			let normalized = FixedPointChip::<_, FRAC_BITS>::div(
+				score,
+				sum.clone(),
+				config.fixed_point.clone(),
+				layouter.namespace(|| "normalize"),
+			)?;
+			inputs.push(neighbor);
+			inputs.push(normalized.clone());
+			plaintext.push(normalized);
+		}
+
+		// Commit to the row
+		let mut sponge = PoseidonSpongeChip::<_, 5, Params>::new();
+		sponge.update(&inputs);
+		let commitment = sponge.squeeze(&config.sponge, layouter.namespace(|| "commitment"))?;
+		layouter.constrain_instance(commitment.cell(), config.pub_ins, 2)?;
+
+		// Derive the key from the shared secret `sk * PK` with the recipient, see
+		// `opinion_key`
+		let (x, y, z) = EddsaGadgetsChip::scalar_mul(
+			recipient_x,
+			recipient_y,
+			one,
+			sk,
+			sk_bits,
+			config.eddsa_gadgets.clone(),
+			layouter.namespace(|| "shared_secret"),
+		)?;
+		let (x, y) = EddsaGadgetsChip::into_affine(
+			x,
+			y,
+			z,
+			config.eddsa_gadgets.clone(),
+			layouter.namespace(|| "shared_secret_affine"),
+		)?;
+		let mut kdf = PoseidonSpongeChip::<_, 5, Params>::new_with_domain(Fr::from(KDF_DOMAIN));
+		kdf.update(&[len, x, y, info]);
+		let key = kdf.squeeze(&config.sponge, layouter.namespace(|| "kdf"))?;
+
+		// Encrypt the normalized scores, with the nonce
+		let ciphertext = PoseidonCipherChip::encrypt(
+			key,
+			nonce,
+			&plaintext,
+			&config.cipher,
+			layouter.namespace(|| "encrypt"),
+		)?;
+		for (i, cipher) in ciphertext.iter().enumerate() {
+			layouter.constrain_instance(cipher.cell(), config.pub_ins, 7 + N + i)?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		eddsa::native::{ed_on_bn254::Point, sign},
+		gadgets::nullifier::nullifier,
+		poseidon::native::cipher::{decrypt, encrypt},
+		public_inputs::OpinionPublicInputs,
+		utils::{generate_params, prove_and_verify},
+	};
+	use halo2wrong::{curves::bn256::Bn256, halo2::dev::MockProver};
+	use rand::thread_rng;
+
+	const N: usize = 2;
+
+	/// Make a circuit for the opinions signed by the peer, and its public
+	/// inputs, encrypted for the `recipient`.
+	fn setup(scores: [u64; N], recipient: &SecretKey) -> (OpinionCircuit<N>, Vec<Fr>) {
+		let epoch = Fr::from(5);
+		let rng = &mut thread_rng();
+		let sk = SecretKey::random(rng);
+		let pk = sk.public();
+		let neighbors = [(); N].map(|_| Fr::random(&mut *rng));
+		let scores = scores.map(Fr::from);
+		let signatures =
+			std::array::from_fn(|i| sign(&sk, &pk, opinion_message(neighbors[i], scores[i])));
+
+		let recipient = recipient.public();
+		let key = opinion_key(&sk, &recipient).unwrap();
+		let nonce = Fr::random(&mut *rng);
+
+		let normalized = normalize(&scores);
+		let commitment = row_commitment(&neighbors, &normalized);
+		let pub_ins = OpinionPublicInputs::new()
+			.with_pk(pk)
+			.with_row_commitment(commitment)
+			.with_epoch(epoch)
+			.with_nullifiers(neighbors.map(|neighbor| nullifier(&sk, epoch, neighbor)))
+			.with_recipient(recipient)
+			.with_nonce(nonce)
+			.with_ciphertext(encrypt::<_, 5, Params>(key, nonce, &normalized))
+			.build()
+			.unwrap();
+		let pub_ins = pub_ins.columns()[0].clone();
+		(
+			OpinionCircuit::new(neighbors, scores, signatures, &sk, epoch),
+			pub_ins,
+		)
+	}
+
+	/// Make a circuit encrypting for a random recipient.
+	fn setup_random(scores: [u64; N]) -> (OpinionCircuit<N>, Vec<Fr>) {
+		setup(scores, &SecretKey::random(&mut thread_rng()))
+	}
+
+	#[test]
+	fn test_normalize() {
+		let scores = [1, 3].map(Fr::from);
+		let one = 1 << FRAC_BITS;
+		assert_eq!(normalize(&scores), vec![
+			Fr::from(one / 4),
+			Fr::from(3 * one / 4)
+		]);
+	}
+
+	#[test]
+	fn test_opinion() {
+		let (circuit, pub_ins) = setup_random([1, 3]);
+		let prover = MockProver::run(12, &circuit, vec![pub_ins]).unwrap();
+		assert_eq!(prover.verify(), Ok(()));
+	}
+
+	#[test]
+	fn test_opinion_wrong_commitment() {
+		let (circuit, mut pub_ins) = setup_random([1, 3]);
+		pub_ins[2] = row_commitment(&circuit.neighbors, &[Fr::from(1), Fr::from(3)]);
+		let prover = MockProver::run(12, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_opinion_wrong_signer() {
+		let (circuit, mut pub_ins) = setup_random([1, 3]);
+		let other = SecretKey::random(&mut thread_rng()).public();
+		pub_ins[0] = other.0.x;
+		pub_ins[1] = other.0.y;
+		let prover = MockProver::run(12, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_opinion_wrong_epoch() {
+		let (circuit, mut pub_ins) = setup_random([1, 3]);
+		pub_ins[3] = Fr::from(6);
+		let prover = MockProver::run(12, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_opinion_decrypt() {
+		let recipient = SecretKey::random(&mut thread_rng());
+		let (circuit, pub_ins) = setup([1, 3], &recipient);
+		let pk = PublicKey(Point { x: pub_ins[0], y: pub_ins[1] });
+		let key = opinion_key(&recipient, &pk).unwrap();
+		let scores = decrypt::<_, 5, Params>(key, pub_ins[6 + N], &pub_ins[7 + N..]);
+		assert_eq!(scores, normalize(&circuit.scores));
+	}
+
+	#[test]
+	fn test_opinion_wrong_ciphertext() {
+		let (circuit, mut pub_ins) = setup_random([1, 3]);
+		let key = Fr::random(&mut thread_rng());
+		let other = encrypt::<_, 5, Params>(key, pub_ins[6 + N], &normalize(&circuit.scores));
+		pub_ins[7 + N..].copy_from_slice(&other);
+		let prover = MockProver::run(12, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_opinion_wrong_recipient_or_nonce() {
+		let (circuit, pub_ins) = setup_random([1, 3]);
+		let other = SecretKey::random(&mut thread_rng()).public();
+		let mut wrong_recipient = pub_ins.clone();
+		wrong_recipient[4 + N] = other.0.x;
+		wrong_recipient[5 + N] = other.0.y;
+		let prover = MockProver::run(12, &circuit, vec![wrong_recipient]).unwrap();
+		assert!(prover.verify().is_err());
+
+		let mut wrong_nonce = pub_ins;
+		wrong_nonce[6 + N] += Fr::one();
+		let prover = MockProver::run(12, &circuit, vec![wrong_nonce]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_opinion_tampered_score() {
+		let (mut circuit, pub_ins) = setup_random([1, 3]);
+		circuit.scores[0] = Fr::from(2);
+		let prover = MockProver::run(12, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_opinion_production() {
+		let (circuit, pub_ins) = setup_random([5, 7]);
+		let params = generate_params(12);
+		let res = prove_and_verify::<Bn256, _, _>(params, circuit, &[&pub_ins], &mut thread_rng())
+			.unwrap();
+		assert!(res);
+	}
+}
@@ -0,0 +1,140 @@
+use crate::utils::to_wide;
+use halo2wrong::halo2::arithmetic::FieldExt;
+use num_bigint::BigUint;
+use std::collections::VecDeque;
+
+/// Grain LFSR in self-shrinking mode, as used by the reference Poseidon
+/// implementation to generate the round constants and the MDS matrix.
+struct Grain {
+	/// The 80 bit state of the LFSR.
+	bits: VecDeque<bool>,
+}
+
+impl Grain {
+	/// Initializes the LFSR with the parameters of a Poseidon instance over a
+	/// prime field with the x^alpha S-box, and discards the first 160 bits.
+	fn new(field_size: usize, width: usize, full_rounds: usize, partial_rounds: usize) -> Self {
+		let mut bits = VecDeque::with_capacity(80);
+		let mut push = |value: usize, len: usize| {
+			for i in (0..len).rev() {
+				bits.push_back((value >> i) & 1 == 1);
+			}
+		};
+		// Prime field.
+		push(1, 2);
+		// x^alpha S-box.
+		push(0, 4);
+		push(field_size, 12);
+		push(width, 12);
+		push(full_rounds, 10);
+		push(partial_rounds, 10);
+		push((1 << 30) - 1, 30);
+
+		let mut grain = Grain { bits };
+		for _ in 0..160 {
+			grain.step();
+		}
+		grain
+	}
+
+	/// Clocks the LFSR once, returning the new bit.
+	fn step(&mut self) -> bool {
+		let b = &self.bits;
+		let bit = b[62] ^ b[51] ^ b[38] ^ b[23] ^ b[13] ^ b[0];
+		self.bits.pop_front();
+		self.bits.push_back(bit);
+		bit
+	}
+
+	/// Returns the next output bit: bits are read in pairs, and the second
+	/// one is output only if the first one is set.
+	fn next_bit(&mut self) -> bool {
+		loop {
+			let first = self.step();
+			let second = self.step();
+			if first {
+				return second;
+			}
+		}
+	}
+
+	/// Returns the next `len` output bits as a big-endian integer.
+	fn next_uint(&mut self, len: usize) -> BigUint {
+		(0..len).fold(BigUint::from(0u64), |acc, _| {
+			(acc << 1) + BigUint::from(u8::from(self.next_bit()))
+		})
+	}
+}
+
+/// Converts the integer into a field element, reducing it by the modulus.
+fn to_field<F: FieldExt>(x: &BigUint) -> F {
+	F::from_bytes_wide(&to_wide(&x.to_bytes_le()))
+}
+
+/// Generates the round constants and the MDS matrix of a Poseidon instance
+/// with the x^5 S-box, the same way as the reference implementation. The
+/// round constants are sampled by rejection, and the MDS matrix is a Cauchy
+/// matrix `1 / (x_i + y_j)` of distinct sampled elements.
+pub fn generate<F: FieldExt, const WIDTH: usize>(
+	full_rounds: usize, partial_rounds: usize,
+) -> (Vec<F>, [[F; WIDTH]; WIDTH]) {
+	let field_size = F::NUM_BITS as usize;
+	let modulus = BigUint::parse_bytes(F::MODULUS[2..].as_bytes(), 16).expect("Invalid modulus");
+	let mut grain = Grain::new(field_size, WIDTH, full_rounds, partial_rounds);
+
+	let round_constants = (0..(full_rounds + partial_rounds) * WIDTH)
+		.map(|_| loop {
+			let x = grain.next_uint(field_size);
+			if x < modulus {
+				return to_field(&x);
+			}
+		})
+		.collect();
+
+	loop {
+		let mut sample = || [(); WIDTH].map(|_| to_field::<F>(&grain.next_uint(field_size)));
+		let mut xs = sample();
+		let mut ys = sample();
+		while !all_distinct(&xs, &ys) {
+			xs = sample();
+			ys = sample();
+		}
+		if xs.iter().any(|x| ys.iter().any(|y| bool::from((*x + y).is_zero()))) {
+			continue;
+		}
+
+		let mds = xs.map(|x| ys.map(|y| (x + y).invert().unwrap()));
+		return (round_constants, mds);
+	}
+}
+
+/// Returns true if all the elements of both arrays are distinct.
+fn all_distinct<F: FieldExt>(xs: &[F], ys: &[F]) -> bool {
+	let all: Vec<&F> = xs.iter().chain(ys).collect();
+	all.iter().enumerate().all(|(i, a)| all[i + 1..].iter().all(|b| a != b))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::params::{
+		poseidon_bn254_12x5, poseidon_bn254_3x5, poseidon_bn254_5x5, poseidon_bn254_6x5,
+		poseidon_bn254_9x5, RoundParams,
+	};
+	use halo2wrong::curves::bn256::Fr;
+
+	fn assert_generated<const WIDTH: usize, P: RoundParams<Fr, WIDTH>>() {
+		let (round_constants, mds) = generate::<Fr, WIDTH>(P::full_rounds(), P::partial_rounds());
+		assert_eq!(round_constants, P::round_constants());
+		assert_eq!(mds, P::mds());
+	}
+
+	#[test]
+	fn should_match_shipped_params() {
+		assert_generated::<3, poseidon_bn254_3x5::Params>();
+		assert_generated::<5, poseidon_bn254_5x5::Params>();
+		assert_generated::<6, poseidon_bn254_6x5::Params>();
+		assert_generated::<9, poseidon_bn254_9x5::Params>();
+		assert_generated::<12, poseidon_bn254_12x5::Params>();
+	}
+}
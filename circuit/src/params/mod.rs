@@ -1,11 +1,26 @@
+/// Grain LFSR generation of Poseidon round constants and MDS matrices
+pub mod grain;
+/// Poseidon2 Bn254 with WIDTH = 3 and EXPONENTIATION = 5
+pub mod poseidon2_bn254_3x5;
 /// Poseidon Bn254 with WIDTH = 10 and EXPONENTIATION = 5
 pub mod poseidon_bn254_10x5;
+/// Poseidon Bn254 with WIDTH = 12 and EXPONENTIATION = 5
+pub mod poseidon_bn254_12x5;
+/// Poseidon Bn254 with WIDTH = 3 and EXPONENTIATION = 5
+pub mod poseidon_bn254_3x5;
 /// Poseidon Bn254 with WIDTH = 5 and EXPONENTIATION = 5
 pub mod poseidon_bn254_5x5;
+/// Poseidon Bn254 with WIDTH = 6 and EXPONENTIATION = 5
+pub mod poseidon_bn254_6x5;
+/// Poseidon Bn254 with WIDTH = 9 and EXPONENTIATION = 5
+pub mod poseidon_bn254_9x5;
 /// Rescue Prime Bn254 with WIDTH = 5 and EXPONENTIATION = 5
 pub mod rescue_prime_bn254_5x5;
+/// Validation of the security of Poseidon parameters
+pub mod validate;
 
 use halo2wrong::halo2::{arithmetic::FieldExt, plonk::Expression};
+pub use validate::{validate, ValidationError};
 
 /// Trait definition of Round parameters of Poseidon
 pub trait RoundParams<F: FieldExt, const WIDTH: usize>: Sbox {
@@ -44,6 +59,56 @@ pub trait RoundParams<F: FieldExt, const WIDTH: usize>: Sbox {
 		mds_raw.map(|row| row.map(|item| hex_to_field(item)))
 	}
 
+	/// Returns the parameters for computing the partial rounds with sparse
+	/// matrices. The constants of each partial round are moved into the
+	/// previous rounds, except for their first element, and the MDS matrix of
+	/// each partial round is split into a sparse matrix and a matrix that
+	/// commutes with the S-box, which is merged into the previous round.
+	/// https://eprint.iacr.org/2019/458.pdf#page=21
+	fn sparse_rounds() -> SparseRounds<F, WIDTH> {
+		let half_full_rounds = Self::full_rounds() / 2;
+		let partial_rounds = Self::partial_rounds();
+		let round_consts = Self::round_constants();
+		let mds = to_vecs(&Self::mds());
+		let mds_inv = invert_matrix(&mds).expect("Invalid params");
+		let mds_inv_00_inv: F = Option::from(mds_inv[0][0].invert()).expect("Invalid params");
+
+		let mut round_constants: Vec<[F; WIDTH]> = (0..partial_rounds)
+			.map(|round| Self::load_round_constants(half_full_rounds + round, &round_consts))
+			.collect();
+		for round in (1..partial_rounds).rev() {
+			// Keep the part of the constants that can't be moved through the
+			// S-box of the previous round.
+			let first = mat_vec(&mds_inv, &round_constants[round])[0] * mds_inv_00_inv;
+			let mut rest = round_constants[round];
+			rest[0] -= first;
+			let moved = mat_vec(&mds_inv, &rest);
+			for i in 0..WIDTH {
+				round_constants[round - 1][i] += moved[i];
+			}
+			round_constants[round] = [F::zero(); WIDTH];
+			round_constants[round][0] = first;
+		}
+
+		let mut sparse_mds = vec![[[F::zero(); WIDTH]; WIDTH]; partial_rounds];
+		let mut acc = mds.clone();
+		let mut m_prime = identity_matrix(WIDTH);
+		for round in (0..partial_rounds).rev() {
+			// Split the matrix into a sparse one, and one that keeps the bottom
+			// right block of it.
+			m_prime = identity_matrix(WIDTH);
+			for i in 1..WIDTH {
+				m_prime[i][1..].copy_from_slice(&acc[i][1..]);
+			}
+			let m_prime_inv = invert_matrix(&m_prime).expect("Invalid params");
+			sparse_mds[round] = to_array(&mat_mul(&acc, &m_prime_inv));
+			acc = mat_mul(&m_prime, &mds);
+		}
+		round_constants[0] = to_array_vec(&mat_vec(&m_prime, &round_constants[0]));
+
+		SparseRounds { pre_sparse_mds: to_array(&acc), round_constants, sparse_mds }
+	}
+
 	/// Returns round constants in its hex string form.
 	fn round_constants_raw() -> Vec<&'static str>;
 	/// Returns MDS martrix in its hex string form.
@@ -74,6 +139,95 @@ pub trait RoundParams<F: FieldExt, const WIDTH: usize>: Sbox {
 	}
 }
 
+/// Parameters of the Poseidon partial rounds with sparse matrices.
+#[derive(Clone, Debug)]
+pub struct SparseRounds<F: FieldExt, const WIDTH: usize> {
+	/// MDS matrix of the last full round before the partial rounds.
+	pub pre_sparse_mds: [[F; WIDTH]; WIDTH],
+	/// Round constants of each partial round. Only the first round has
+	/// constants beyond the first element.
+	pub round_constants: Vec<[F; WIDTH]>,
+	/// Sparse matrices of each partial round, with the identity matrix below
+	/// the first row and to the right of the first column.
+	pub sparse_mds: Vec<[[F; WIDTH]; WIDTH]>,
+}
+
+/// Trait definition of Round parameters of Poseidon2
+pub trait Poseidon2Params<F: FieldExt, const WIDTH: usize>: Sbox {
+	/// Returns a number of full (external) rounds.
+	fn full_rounds() -> usize;
+	/// Returns a number of partial (internal) rounds.
+	fn partial_rounds() -> usize;
+
+	/// Returns round constants of each round, with the constants of the
+	/// partial rounds in the first element, and zeros in the others.
+	fn round_constants() -> Vec<[F; WIDTH]> {
+		let half_full_rounds = Self::full_rounds() / 2;
+		let partial_rounds = Self::partial_rounds();
+		let mut raw = Self::round_constants_raw().into_iter().map(|x| hex_to_field(x));
+		let rounds: Vec<[F; WIDTH]> = (0..Self::full_rounds() + partial_rounds)
+			.map(|round| {
+				let mut consts = [F::zero(); WIDTH];
+				let is_full =
+					round < half_full_rounds || round >= half_full_rounds + partial_rounds;
+				let count = if is_full { WIDTH } else { 1 };
+				for c in consts.iter_mut().take(count) {
+					*c = raw.next().expect("Invalid params");
+				}
+				consts
+			})
+			.collect();
+		assert!(raw.next().is_none());
+		rounds
+	}
+
+	/// Returns the external matrix: circ(2, 1, ..) for WIDTH 2 and 3, and
+	/// circ(2 * M4, M4, ..) for multiples of 4.
+	fn mat_external() -> [[F; WIDTH]; WIDTH] {
+		assert!(WIDTH == 2 || WIDTH == 3 || WIDTH % 4 == 0);
+		let m4 = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]];
+		let mut mat = [[F::zero(); WIDTH]; WIDTH];
+		for i in 0..WIDTH {
+			for j in 0..WIDTH {
+				mat[i][j] = if WIDTH < 4 {
+					F::from(1 + u64::from(i == j))
+				} else {
+					F::from(m4[i % 4][j % 4] * (1 + u64::from(i / 4 == j / 4)))
+				};
+			}
+		}
+		mat
+	}
+
+	/// Returns the internal matrix: ones everywhere, plus the diagonal.
+	fn mat_internal() -> [[F; WIDTH]; WIDTH] {
+		let diag = Self::mat_internal_diag_m_1_raw().map(|x| hex_to_field::<F>(x));
+		let mut mat = [[F::one(); WIDTH]; WIDTH];
+		for i in 0..WIDTH {
+			mat[i][i] += diag[i];
+		}
+		mat
+	}
+
+	/// Returns round constants in their hex string form: WIDTH for each full
+	/// round and one for each partial round, in the order of the rounds.
+	fn round_constants_raw() -> Vec<&'static str>;
+	/// Returns the diagonal of the internal matrix minus one, in its hex
+	/// string form.
+	fn mat_internal_diag_m_1_raw() -> [&'static str; WIDTH];
+
+	/// Multiply the state by the matrix.
+	fn apply_matrix(state: &[F; WIDTH], mat: &[[F; WIDTH]; WIDTH]) -> [F; WIDTH] {
+		let mut new_state = [F::zero(); WIDTH];
+		for i in 0..WIDTH {
+			for j in 0..WIDTH {
+				new_state[i] += state[j] * mat[i][j];
+			}
+		}
+		new_state
+	}
+}
+
 /// Trait definition for Sbox operation of Poseidon
 pub trait Sbox {
 	/// Returns the S-box exponentiation for the expression.
@@ -84,6 +238,83 @@ pub trait Sbox {
 	fn sbox_inv_f<F: FieldExt>(f: F) -> F;
 }
 
+/// Returns the inverse of the square matrix, or `None` if it is singular.
+pub fn invert_matrix<F: FieldExt>(mat: &[Vec<F>]) -> Option<Vec<Vec<F>>> {
+	let n = mat.len();
+	let mut mat = mat.to_vec();
+	let mut inv = identity_matrix(n);
+	for col in 0..n {
+		let pivot = (col..n).find(|&row| mat[row][col] != F::zero())?;
+		mat.swap(col, pivot);
+		inv.swap(col, pivot);
+
+		let pivot_inv: F = Option::from(mat[col][col].invert())?;
+		for j in 0..n {
+			mat[col][j] *= pivot_inv;
+			inv[col][j] *= pivot_inv;
+		}
+
+		for row in 0..n {
+			let factor = mat[row][col];
+			if row == col || factor == F::zero() {
+				continue;
+			}
+			for j in 0..n {
+				let (mat_col_j, inv_col_j) = (mat[col][j], inv[col][j]);
+				mat[row][j] -= factor * mat_col_j;
+				inv[row][j] -= factor * inv_col_j;
+			}
+		}
+	}
+	Some(inv)
+}
+
+/// Returns the identity matrix of the given size.
+fn identity_matrix<F: FieldExt>(n: usize) -> Vec<Vec<F>> {
+	(0..n)
+		.map(|i| (0..n).map(|j| if i == j { F::one() } else { F::zero() }).collect())
+		.collect()
+}
+
+/// Multiplies the two matrices.
+fn mat_mul<F: FieldExt>(a: &[Vec<F>], b: &[Vec<F>]) -> Vec<Vec<F>> {
+	a.iter()
+		.map(|row| {
+			(0..b[0].len())
+				.map(|j| row.iter().zip(b).fold(F::zero(), |acc, (x, b_row)| acc + *x * b_row[j]))
+				.collect()
+		})
+		.collect()
+}
+
+/// Multiplies the matrix by the vector.
+fn mat_vec<F: FieldExt>(mat: &[Vec<F>], v: &[F]) -> Vec<F> {
+	mat.iter()
+		.map(|row| row.iter().zip(v).fold(F::zero(), |acc, (x, y)| acc + *x * y))
+		.collect()
+}
+
+/// Converts the matrix into vectors.
+fn to_vecs<F: FieldExt, const WIDTH: usize>(mat: &[[F; WIDTH]; WIDTH]) -> Vec<Vec<F>> {
+	mat.iter().map(|row| row.to_vec()).collect()
+}
+
+/// Converts the vectors into a matrix.
+fn to_array<F: FieldExt, const WIDTH: usize>(mat: &[Vec<F>]) -> [[F; WIDTH]; WIDTH] {
+	let mut res = [[F::zero(); WIDTH]; WIDTH];
+	for i in 0..WIDTH {
+		res[i] = to_array_vec(&mat[i]);
+	}
+	res
+}
+
+/// Converts the vector into an array.
+fn to_array_vec<F: FieldExt, const WIDTH: usize>(v: &[F]) -> [F; WIDTH] {
+	let mut res = [F::zero(); WIDTH];
+	res.copy_from_slice(v);
+	res
+}
+
 /// Returns congruent field element for the given hex string.
 pub fn hex_to_field<F: FieldExt>(s: &str) -> F {
 	let s = &s[2..];
@@ -0,0 +1,264 @@
+use super::{invert_matrix, mat_mul, to_vecs, RoundParams, Sbox};
+use halo2wrong::halo2::arithmetic::FieldExt;
+use num_bigint::BigUint;
+
+/// Security level in bits that the number of rounds should reach.
+pub const SECURITY_LEVEL: usize = 128;
+/// Largest S-box exponent looked for.
+const MAX_SBOX_EXPONENT: u64 = 255;
+
+/// The reason why a Poseidon parameter set is rejected.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError {
+	/// The number of round constants doesn't match the number of rounds.
+	RoundConstantsCount,
+	/// The MDS matrix is not invertible.
+	MdsNotInvertible,
+	/// Some subspace of the states keeps the S-box of the partial rounds
+	/// inactive for all the rounds, under the given power of the MDS matrix.
+	InvariantSubspace(usize),
+	/// The S-box is not `x^alpha`, or it is not a permutation.
+	InvalidSbox,
+	/// The number of rounds doesn't reach the security level.
+	InsufficientRounds,
+}
+
+/// Checks the Poseidon parameters, like the reference script does when
+/// generating them: the MDS matrix has to be invertible, with no infinitely
+/// long subspace trails through the partial rounds, and the number of rounds
+/// has to resist the statistical, interpolation and Groebner basis attacks
+/// at the `SECURITY_LEVEL`, without the security margin.
+/// https://extgit.iaik.tugraz.at/krypto/hadeshash
+pub fn validate<F: FieldExt, const WIDTH: usize, P>() -> Result<(), ValidationError>
+where
+	P: RoundParams<F, WIDTH>,
+{
+	let full_rounds = P::full_rounds();
+	let partial_rounds = P::partial_rounds();
+	if P::round_constants_raw().len() != P::round_constants_count() {
+		return Err(ValidationError::RoundConstantsCount);
+	}
+
+	let mds = P::mds();
+	if invert_matrix(&to_vecs(&mds)).is_none() {
+		return Err(ValidationError::MdsNotInvertible);
+	}
+	if let Some(power) = subspace_trail_power(&mds) {
+		return Err(ValidationError::InvariantSubspace(power));
+	}
+
+	let modulus = BigUint::parse_bytes(F::MODULUS[2..].as_bytes(), 16).expect("Invalid modulus");
+	let alpha = sbox_exponent::<F, P>(&modulus).ok_or(ValidationError::InvalidSbox)?;
+	if full_rounds % 2 != 0
+		|| !are_rounds_secure(WIDTH, full_rounds, partial_rounds, alpha, log2(&modulus))
+	{
+		return Err(ValidationError::InsufficientRounds);
+	}
+
+	Ok(())
+}
+
+/// Returns the smallest power of the MDS matrix, up to 2 * WIDTH like the
+/// reference script, under which some subspace of the states keeps the
+/// S-box of the partial rounds inactive for all the rounds. The S-box stays
+/// inactive for the states in the kernel of all the rows `e_0 * M^k`, which
+/// is trivial only if the first WIDTH of them have full rank.
+fn subspace_trail_power<F: FieldExt, const WIDTH: usize>(
+	mds: &[[F; WIDTH]; WIDTH],
+) -> Option<usize> {
+	let mds = to_vecs(mds);
+	let mut power = mds.clone();
+	for i in 1..=2 * WIDTH {
+		let mut row: Vec<F> =
+			(0..WIDTH).map(|j| if j == 0 { F::one() } else { F::zero() }).collect();
+		let mut rows = Vec::with_capacity(WIDTH);
+		for _ in 0..WIDTH {
+			let next_row = (0..WIDTH)
+				.map(|j| {
+					row.iter().zip(&power).fold(F::zero(), |acc, (x, p_row)| acc + *x * p_row[j])
+				})
+				.collect();
+			rows.push(row);
+			row = next_row;
+		}
+		if rank(rows) < WIDTH {
+			return Some(i);
+		}
+		power = mat_mul(&power, &mds);
+	}
+	None
+}
+
+/// Returns the rank of the matrix.
+fn rank<F: FieldExt>(mut rows: Vec<Vec<F>>) -> usize {
+	let mut rank = 0;
+	for col in 0..rows.first().map_or(0, |row| row.len()) {
+		let pivot = match (rank..rows.len()).find(|&i| rows[i][col] != F::zero()) {
+			Some(pivot) => pivot,
+			None => continue,
+		};
+		rows.swap(rank, pivot);
+		let pivot_inv = rows[rank][col].invert().unwrap();
+		for i in rank + 1..rows.len() {
+			let factor = rows[i][col] * pivot_inv;
+			for j in col..rows[i].len() {
+				let pivot_j = rows[rank][j];
+				rows[i][j] -= factor * pivot_j;
+			}
+		}
+		rank += 1;
+	}
+	rank
+}
+
+/// Returns the exponent of the S-box, if it is `x^alpha` and a permutation,
+/// i.e. `alpha` is coprime to `p - 1`.
+fn sbox_exponent<F: FieldExt, P: Sbox>(modulus: &BigUint) -> Option<u64> {
+	let two = F::from(2);
+	let alpha =
+		(3..=MAX_SBOX_EXPONENT).find(|alpha| two.pow(&[*alpha, 0, 0, 0]) == P::sbox_f(two))?;
+
+	let rem = (modulus - BigUint::from(1u64)) % BigUint::from(alpha);
+	let rem = rem.to_bytes_le().iter().rev().fold(0u64, |acc, b| (acc << 8) | u64::from(*b));
+	let (mut a, mut b) = (alpha, rem);
+	while b != 0 {
+		(a, b) = (b, a % b);
+	}
+	if a == 1 {
+		Some(alpha)
+	} else {
+		None
+	}
+}
+
+/// Returns the base 2 logarithm of the integer.
+fn log2(x: &BigUint) -> f64 {
+	let bytes = x.to_bytes_be();
+	let top = bytes.iter().take(8).fold(0u64, |acc, b| (acc << 8) | u64::from(*b));
+	let rest = bytes.len().saturating_sub(8);
+	(top as f64).log2() + (rest * 8) as f64
+}
+
+/// Checks the number of rounds against the attacks considered by the
+/// reference script, in `sat_inequiv_alpha`.
+fn are_rounds_secure(
+	width: usize, full_rounds: usize, partial_rounds: usize, alpha: u64, log2_p: f64,
+) -> bool {
+	let (t, rp, m) = (width as f64, partial_rounds as f64, SECURITY_LEVEL as f64);
+	let alpha_f = alpha as f64;
+	let log_alpha = |x: f64| x.ln() / alpha_f.ln();
+	let n = log2_p.ceil();
+
+	// Statistical attacks.
+	let r_1 = if m <= (log2_p - (alpha_f - 1.0) / 2.0).floor() * (t + 1.0) {
+		6.0
+	} else {
+		10.0
+	};
+	// Interpolation attack.
+	let r_2 = 1.0 + (log_alpha(2.0) * m.min(n)).ceil() + log_alpha(t).ceil() - rp;
+	// Groebner basis attacks.
+	let r_3 = log_alpha(2.0) * m.min(log2_p) - rp;
+	let r_4 = t - 1.0 + log_alpha(2.0) * (m / (t + 1.0)).min(log2_p / 2.0) - rp;
+	let r_5 = (t - 2.0 + m / (2.0 * alpha_f.log2()) - rp) / (t - 1.0);
+	let min_full_rounds =
+		[r_1, r_2, r_3, r_4, r_5].into_iter().map(f64::ceil).fold(f64::MIN, f64::max);
+	if (full_rounds as f64) < min_full_rounds {
+		return false;
+	}
+
+	// Groebner basis attack of https://eprint.iacr.org/2023/537.pdf
+	let r = width / 3;
+	let under = r * full_rounds / 2 + partial_rounds + alpha as usize;
+	let over = (full_rounds - 1) * width + r + partial_rounds + under;
+	let log2_binomial: f64 =
+		(1..=under).map(|i| ((over - under + i) as f64 / i as f64).log2()).sum();
+	(2.0 * log2_binomial).ceil() >= m
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::params::{
+		poseidon_bn254_10x5::Params as Params10, poseidon_bn254_12x5::Params as Params12,
+		poseidon_bn254_3x5::Params as Params3, poseidon_bn254_5x5::Params as Params5,
+		poseidon_bn254_6x5::Params as Params6, poseidon_bn254_9x5::Params as Params9,
+	};
+	use halo2wrong::{curves::bn256::Fr, halo2::plonk::Expression};
+
+	/// Parameters with 3 elements, the given number of partial rounds, and
+	/// the MDS matrix of `Params3` (0), a circulant one (1), or a singular one
+	/// (2).
+	struct TestParams<const PARTIAL_ROUNDS: usize, const MDS: u8>;
+
+	impl<const PARTIAL_ROUNDS: usize, const MDS: u8> Sbox for TestParams<PARTIAL_ROUNDS, MDS> {
+		fn sbox_expr<F: FieldExt>(exp: Expression<F>) -> Expression<F> {
+			Params3::sbox_expr(exp)
+		}
+
+		fn sbox_f<F: FieldExt>(f: F) -> F {
+			Params3::sbox_f(f)
+		}
+
+		fn sbox_inv_f<F: FieldExt>(f: F) -> F {
+			Params3::sbox_inv_f(f)
+		}
+	}
+
+	impl<const PARTIAL_ROUNDS: usize, const MDS: u8> RoundParams<Fr, 3>
+		for TestParams<PARTIAL_ROUNDS, MDS>
+	{
+		fn partial_rounds() -> usize {
+			PARTIAL_ROUNDS
+		}
+
+		fn full_rounds() -> usize {
+			8
+		}
+
+		fn round_constants_raw() -> Vec<&'static str> {
+			vec!["0x01"; (8 + PARTIAL_ROUNDS) * 3]
+		}
+
+		fn mds_raw() -> [[&'static str; 3]; 3] {
+			match MDS {
+				0 => <Params3 as RoundParams<Fr, 3>>::mds_raw(),
+				1 => [["0x02", "0x01", "0x01"], ["0x01", "0x02", "0x01"], ["0x01", "0x01", "0x02"]],
+				_ => [["0x01"; 3]; 3],
+			}
+		}
+	}
+
+	#[test]
+	fn should_accept_params() {
+		assert_eq!(validate::<Fr, 3, Params3>(), Ok(()));
+		assert_eq!(validate::<Fr, 5, Params5>(), Ok(()));
+		assert_eq!(validate::<Fr, 6, Params6>(), Ok(()));
+		assert_eq!(validate::<Fr, 9, Params9>(), Ok(()));
+		assert_eq!(validate::<Fr, 10, Params10>(), Ok(()));
+		assert_eq!(validate::<Fr, 12, Params12>(), Ok(()));
+		assert_eq!(validate::<Fr, 3, TestParams<57, 0>>(), Ok(()));
+	}
+
+	#[test]
+	fn should_reject_weak_mds() {
+		assert_eq!(
+			validate::<Fr, 3, TestParams<57, 2>>(),
+			Err(ValidationError::MdsNotInvertible)
+		);
+		// Vectors with a zero first element, summing to zero, are mapped to
+		// each other.
+		assert_eq!(
+			validate::<Fr, 3, TestParams<57, 1>>(),
+			Err(ValidationError::InvariantSubspace(1))
+		);
+	}
+
+	#[test]
+	fn should_reject_few_rounds() {
+		assert_eq!(
+			validate::<Fr, 3, TestParams<10, 0>>(),
+			Err(ValidationError::InsufficientRounds)
+		);
+	}
+}
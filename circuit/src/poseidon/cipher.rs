@@ -0,0 +1,293 @@
+use crate::{
+	params::poseidon_bn254_5x5::Params,
+	poseidon::{native::sponge::PRF_DOMAIN, PoseidonChip, PoseidonConfig},
+};
+use halo2wrong::{
+	curves::bn256::Fr,
+	halo2::{
+		arithmetic::Field,
+		circuit::{AssignedCell, Layouter, Region},
+		plonk::{Advice, Column, ConstraintSystem, Error, Selector},
+		poly::Rotation,
+	},
+};
+
+#[derive(Clone, Debug)]
+/// Configuration elements for the circuit are defined here.
+pub struct PoseidonCipherConfig {
+	/// Constructs the Poseidon circuit elements, used for the keystream.
+	poseidon: PoseidonConfig<5>,
+	/// Configures columns for the plaintext, keystream and ciphertext.
+	advice: [Column<Advice>; 3],
+	/// Configures a fixed boolean value for each row of the circuit.
+	selector: Selector,
+}
+
+/// Encrypts values with the Poseidon stream cipher, matching
+/// `poseidon::native::cipher`.
+pub struct PoseidonCipherChip;
+
+impl PoseidonCipherChip {
+	/// Make the circuit config.
+	pub fn configure(meta: &mut ConstraintSystem<Fr>) -> PoseidonCipherConfig {
+		let poseidon = PoseidonChip::<_, 5, Params>::configure(meta);
+		let advice = [meta.advice_column(), meta.advice_column(), meta.advice_column()];
+		let fixed = meta.fixed_column();
+		let s = meta.selector();
+
+		advice.map(|c| meta.enable_equality(c));
+		meta.enable_constant(fixed);
+
+		meta.create_gate("cipher", |v_cells| {
+			let plain_exp = v_cells.query_advice(advice[0], Rotation::cur());
+			let stream_exp = v_cells.query_advice(advice[1], Rotation::cur());
+			let cipher_exp = v_cells.query_advice(advice[2], Rotation::cur());
+			let s_exp = v_cells.query_selector(s);
+
+			vec![
+				// plaintext + keystream == ciphertext
+				s_exp * (plain_exp + stream_exp - cipher_exp),
+			]
+		});
+
+		PoseidonCipherConfig { poseidon, advice, selector: s }
+	}
+
+	/// Synthesize the commitment to the key. See
+	/// `poseidon::native::cipher::key_commitment`.
+	pub fn commit_key(
+		key: AssignedCell<Fr, Fr>, config: &PoseidonCipherConfig, mut layouter: impl Layouter<Fr>,
+	) -> Result<AssignedCell<Fr, Fr>, Error> {
+		let (domain, one, zero) = layouter.assign_region(
+			|| "key_commitment_constants",
+			|mut region: Region<'_, Fr>| {
+				let domain = Fr::from(PRF_DOMAIN);
+				let domain =
+					region.assign_advice_from_constant(|| "domain", config.advice[0], 0, domain)?;
+				let one =
+					region.assign_advice_from_constant(|| "one", config.advice[1], 0, Fr::one())?;
+				let zero = region.assign_advice_from_constant(
+					|| "zero",
+					config.advice[2],
+					0,
+					Fr::zero(),
+				)?;
+				Ok((domain, one, zero))
+			},
+		)?;
+
+		// The key is followed by the padding.
+		let hasher = PoseidonChip::<_, 5, Params>::new([domain, key, one, zero.clone(), zero]);
+		let res =
+			hasher.synthesize(config.poseidon.clone(), layouter.namespace(|| "commit_key"))?;
+		Ok(res[1].clone())
+	}
+
+	/// Synthesize the encryption of the plaintext with the `key` and the
+	/// `nonce`. See `poseidon::native::cipher::encrypt`.
+	pub fn encrypt(
+		key: AssignedCell<Fr, Fr>, nonce: AssignedCell<Fr, Fr>, plaintext: &[AssignedCell<Fr, Fr>],
+		config: &PoseidonCipherConfig, mut layouter: impl Layouter<Fr>,
+	) -> Result<Vec<AssignedCell<Fr, Fr>>, Error> {
+		let (domain, one, indices) = layouter.assign_region(
+			|| "keystream_constants",
+			|mut region: Region<'_, Fr>| {
+				let domain = Fr::from(PRF_DOMAIN);
+				let domain =
+					region.assign_advice_from_constant(|| "domain", config.advice[0], 0, domain)?;
+				let one =
+					region.assign_advice_from_constant(|| "one", config.advice[1], 0, Fr::one())?;
+				let indices = (0..plaintext.len())
+					.map(|i| {
+						let index = Fr::from(i as u64);
+						region.assign_advice_from_constant(|| "index", config.advice[2], i, index)
+					})
+					.collect::<Result<Vec<_>, Error>>()?;
+				Ok((domain, one, indices))
+			},
+		)?;
+
+		let mut ciphertext = Vec::new();
+		for (plain, index) in plaintext.iter().zip(indices) {
+			// The capacity element holds the domain, followed by the padded
+			// `key || nonce || index`.
+			let hasher = PoseidonChip::<_, 5, Params>::new([
+				domain.clone(),
+				key.clone(),
+				nonce.clone(),
+				index,
+				one.clone(),
+			]);
+			let res =
+				hasher.synthesize(config.poseidon.clone(), layouter.namespace(|| "keystream"))?;
+
+			let cipher = layouter.assign_region(
+				|| "encrypt",
+				|mut region: Region<'_, Fr>| {
+					config.selector.enable(&mut region, 0)?;
+					let plain = plain.copy_advice(|| "plain", &mut region, config.advice[0], 0)?;
+					let stream =
+						res[1].copy_advice(|| "stream", &mut region, config.advice[1], 0)?;
+
+					let cipher = plain.value().cloned() + stream.value();
+					region.assign_advice(|| "cipher", config.advice[2], 0, || cipher)
+				},
+			)?;
+			ciphertext.push(cipher);
+		}
+
+		Ok(ciphertext)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		poseidon::native::cipher::{encrypt, key_commitment},
+		utils::{generate_params, prove_and_verify},
+	};
+	use halo2wrong::{
+		curves::bn256::Bn256,
+		halo2::{
+			circuit::{SimpleFloorPlanner, Value},
+			dev::MockProver,
+			plonk::{Circuit, Instance},
+		},
+	};
+	use rand::thread_rng;
+
+	#[derive(Clone)]
+	struct TestConfig {
+		cipher: PoseidonCipherConfig,
+		temp: Column<Advice>,
+		pub_ins: Column<Instance>,
+	}
+
+	#[derive(Clone)]
+	struct TestCircuit {
+		key: Fr,
+		nonce: Fr,
+		plaintext: [Fr; 3],
+	}
+
+	impl Circuit<Fr> for TestCircuit {
+		type Config = TestConfig;
+		type FloorPlanner = SimpleFloorPlanner;
+
+		fn without_witnesses(&self) -> Self {
+			self.clone()
+		}
+
+		fn configure(meta: &mut ConstraintSystem<Fr>) -> TestConfig {
+			let cipher = PoseidonCipherChip::configure(meta);
+			let temp = meta.advice_column();
+			let pub_ins = meta.instance_column();
+
+			meta.enable_equality(temp);
+			meta.enable_equality(pub_ins);
+
+			TestConfig { cipher, temp, pub_ins }
+		}
+
+		fn synthesize(
+			&self, config: TestConfig, mut layouter: impl Layouter<Fr>,
+		) -> Result<(), Error> {
+			let (key, nonce, plaintext) = layouter.assign_region(
+				|| "temp",
+				|mut region: Region<'_, Fr>| {
+					let key = region.assign_advice(
+						|| "key",
+						config.temp,
+						0,
+						|| Value::known(self.key),
+					)?;
+					let nonce = region.assign_advice(
+						|| "nonce",
+						config.temp,
+						1,
+						|| Value::known(self.nonce),
+					)?;
+					let plaintext = self
+						.plaintext
+						.iter()
+						.enumerate()
+						.map(|(i, p)| {
+							region.assign_advice(
+								|| "plain",
+								config.temp,
+								i + 2,
+								|| Value::known(*p),
+							)
+						})
+						.collect::<Result<Vec<_>, Error>>()?;
+					Ok((key, nonce, plaintext))
+				},
+			)?;
+
+			let commitment = PoseidonCipherChip::commit_key(
+				key.clone(),
+				&config.cipher,
+				layouter.namespace(|| "commit_key"),
+			)?;
+			layouter.constrain_instance(commitment.cell(), config.pub_ins, 0)?;
+
+			let ciphertext = PoseidonCipherChip::encrypt(
+				key,
+				nonce,
+				&plaintext,
+				&config.cipher,
+				layouter.namespace(|| "encrypt"),
+			)?;
+			for (i, cipher) in ciphertext.iter().enumerate() {
+				layouter.constrain_instance(cipher.cell(), config.pub_ins, i + 1)?;
+			}
+			Ok(())
+		}
+	}
+
+	fn setup() -> (TestCircuit, Vec<Fr>) {
+		let rng = &mut thread_rng();
+		let key = Fr::random(&mut *rng);
+		let nonce = Fr::from(2);
+		let plaintext = [Fr::from(10), Fr::from(0), Fr::random(rng)];
+
+		let mut pub_ins = vec![key_commitment::<_, 5, Params>(key)];
+		pub_ins.extend(encrypt::<_, 5, Params>(key, nonce, &plaintext));
+		(TestCircuit { key, nonce, plaintext }, pub_ins)
+	}
+
+	#[test]
+	fn test_cipher() {
+		let (circuit, pub_ins) = setup();
+		let prover = MockProver::run(9, &circuit, vec![pub_ins]).unwrap();
+		assert_eq!(prover.verify(), Ok(()));
+	}
+
+	#[test]
+	fn test_cipher_wrong_plaintext() {
+		let (mut circuit, pub_ins) = setup();
+		circuit.plaintext[1] = Fr::from(1);
+		let prover = MockProver::run(9, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_cipher_wrong_key() {
+		let (mut circuit, mut pub_ins) = setup();
+		circuit.key += Fr::one();
+		// The ciphertext can't be made to match, even with the right commitment.
+		pub_ins[0] = key_commitment::<_, 5, Params>(circuit.key);
+		let prover = MockProver::run(9, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_cipher_production() {
+		let (circuit, pub_ins) = setup();
+		let params = generate_params(9);
+		let res = prove_and_verify::<Bn256, _, _>(params, circuit, &[&pub_ins], &mut thread_rng())
+			.unwrap();
+		assert!(res);
+	}
+}
@@ -1,3 +1,5 @@
+/// Poseidon stream cipher chip for confidential values
+pub mod cipher;
 /// Native version of Poseidon
 pub mod native;
 /// Implementation of a Poseidon sponge
@@ -94,6 +96,22 @@ where
 		Ok(mds_values)
 	}
 
+	/// Assign the first row and column of the sparse MDS matrix to the circuit,
+	/// since the rest of it is the identity.
+	fn load_sparse_mds(
+		config: &PoseidonConfig<WIDTH>, region: &mut Region<'_, F>, round: usize,
+		sparse_mds: &[[F; WIDTH]; WIDTH],
+	) -> Result<[[Value<F>; WIDTH]; WIDTH], Error> {
+		let mds_values = sparse_mds.map(|row| row.map(Value::known));
+		for i in 0..WIDTH {
+			region.assign_fixed(|| "mds", config.mds[0][i], round, || mds_values[0][i])?;
+			if i > 0 {
+				region.assign_fixed(|| "mds", config.mds[i][0], round, || mds_values[i][0])?;
+			}
+		}
+		Ok(mds_values)
+	}
+
 	/// Add round constants to the state values
 	/// for the AddRoundConstants operation.
 	fn apply_round_constants(
@@ -155,21 +173,41 @@ where
 		new_exprs
 	}
 
-	/// Configures full_round.
+	/// Compute the sparse MDS matrix for MixLayer operation in the circuit.
+	/// Only the first row and column of it are stored, the rest being the
+	/// identity.
+	fn apply_sparse_mds_expr(
+		v_cells: &mut VirtualCells<F>, exprs: &[Expression<F>; WIDTH],
+		mds: &[[Column<Fixed>; WIDTH]; WIDTH],
+	) -> [Expression<F>; WIDTH] {
+		let mut new_exprs = exprs.clone();
+		new_exprs[0] = Expression::Constant(F::zero());
+		for i in 0..WIDTH {
+			let mds_0i = v_cells.query_fixed(mds[0][i], Rotation::cur());
+			new_exprs[0] = new_exprs[0].clone() + (exprs[i].clone() * mds_0i);
+			if i > 0 {
+				let mds_i0 = v_cells.query_fixed(mds[i][0], Rotation::cur());
+				new_exprs[i] = new_exprs[i].clone() + (exprs[0].clone() * mds_i0);
+			}
+		}
+		new_exprs
+	}
+
+	/// Configures full_round, with an MDS matrix for each round.
 	fn full_round(
-		config: &PoseidonConfig<WIDTH>, region: &mut Region<'_, F>, num_rounds: usize,
-		round_constants: &[F], mds: &[[F; WIDTH]; WIDTH], prev_state: &[AssignedCell<F, F>; WIDTH],
+		config: &PoseidonConfig<WIDTH>, region: &mut Region<'_, F>, round_constants: &[F],
+		mds: &[[[F; WIDTH]; WIDTH]], prev_state: &[AssignedCell<F, F>; WIDTH],
 	) -> Result<[AssignedCell<F, F>; WIDTH], Error> {
 		// Assign initial state
 		let mut state_cells = Self::copy_state(config, region, 0, prev_state)?;
-		for round in 0..num_rounds {
+		for round in 0..mds.len() {
 			config.full_round_selector.enable(region, round)?;
 
 			// Assign round constants
 			let round_const_values =
 				Self::load_round_constants(config, region, round, round_constants)?;
 			// Assign mds matrix
-			let mds_values = Self::load_mds(config, region, round, mds)?;
+			let mds_values = Self::load_mds(config, region, round, &mds[round])?;
 
 			// 1. step for the TRF.
 			// AddRoundConstants step.
@@ -197,20 +235,20 @@ where
 		Ok(state_cells)
 	}
 
-	/// Configures partial_round.
+	/// Configures partial_round, with a sparse MDS matrix for each round.
 	fn partial_round(
-		config: &PoseidonConfig<WIDTH>, region: &mut Region<'_, F>, num_rounds: usize,
-		round_constants: &[F], mds: &[[F; WIDTH]; WIDTH], prev_state: &[AssignedCell<F, F>; WIDTH],
+		config: &PoseidonConfig<WIDTH>, region: &mut Region<'_, F>, round_constants: &[F],
+		sparse_mds: &[[[F; WIDTH]; WIDTH]], prev_state: &[AssignedCell<F, F>; WIDTH],
 	) -> Result<[AssignedCell<F, F>; WIDTH], Error> {
 		let mut state_cells = Self::copy_state(config, region, 0, prev_state)?;
-		for round in 0..num_rounds {
+		for round in 0..sparse_mds.len() {
 			config.partial_round_selector.enable(region, round)?;
 
 			// Assign round constants
 			let round_const_cells =
 				Self::load_round_constants(config, region, round, round_constants)?;
-			// Assign mds matrix
-			let mds_cells = Self::load_mds(config, region, round, mds)?;
+			// Assign sparse mds matrix
+			let mds_cells = Self::load_sparse_mds(config, region, round, &sparse_mds[round])?;
 
 			// 1. step for the TRF.
 			// AddRoundConstants step.
@@ -286,8 +324,8 @@ where
 			exprs[0] = P::sbox_expr(exprs[0].clone());
 
 			// 3. step for the TRF.
-			// MixLayer step.
-			exprs = Self::apply_mds_expr(v_cells, &exprs, &mds);
+			// MixLayer step, with the sparse matrix.
+			exprs = Self::apply_sparse_mds_expr(v_cells, &exprs, &mds);
 
 			let s_cells = v_cells.query_selector(partial_round_selector);
 			// It should be equal to the state in next row
@@ -312,14 +350,21 @@ where
 		let mds = P::mds();
 		let round_constants = P::round_constants();
 		let total_count = P::round_constants_count();
+		let sparse_rounds = P::sparse_rounds();
 
 		let first_round_end = half_full_rounds * WIDTH;
 		let first_round_constants = &round_constants[0..first_round_end];
+		// The last full round before the partial rounds takes over a part of
+		// the MDS matrix of the first partial round.
+		let mut first_round_mds = vec![mds; half_full_rounds];
+		first_round_mds[half_full_rounds - 1] = sparse_rounds.pre_sparse_mds;
 
 		let second_round_end = first_round_end + partial_rounds * WIDTH;
-		let second_round_constants = &round_constants[first_round_end..second_round_end];
+		let second_round_constants: Vec<F> =
+			sparse_rounds.round_constants.iter().flatten().copied().collect();
 
 		let third_round_constants = &round_constants[second_round_end..total_count];
+		let third_round_mds = vec![mds; half_full_rounds];
 
 		// The Hades Design Strategy for Hashing.
 		// Mixing rounds with half-full S-box layers and
@@ -331,17 +376,20 @@ where
 			|| "full_rounds_1",
 			|mut region: Region<'_, F>| {
 				Self::full_round(
-					&config, &mut region, half_full_rounds, first_round_constants, &mds,
-					&self.inputs,
+					&config, &mut region, first_round_constants, &first_round_mds, &self.inputs,
 				)
 			},
 		)?;
 
+		// The partial rounds use the sparse MDS matrices, with a single round
+		// constant after the first one.
+		// https://eprint.iacr.org/2019/458.pdf#page=21
 		let state2 = layouter.assign_region(
 			|| "partial_rounds",
 			|mut region: Region<'_, F>| {
 				Self::partial_round(
-					&config, &mut region, partial_rounds, second_round_constants, &mds, &state1,
+					&config, &mut region, &second_round_constants, &sparse_rounds.sparse_mds,
+					&state1,
 				)
 			},
 		)?;
@@ -350,7 +398,7 @@ where
 			|| "full_rounds_2",
 			|mut region: Region<'_, F>| {
 				Self::full_round(
-					&config, &mut region, half_full_rounds, third_round_constants, &mds, &state2,
+					&config, &mut region, third_round_constants, &third_round_mds, &state2,
 				)
 			},
 		)?;
@@ -0,0 +1,31 @@
+use super::sponge::{PoseidonSponge, BYTES_DOMAIN};
+use crate::{params::poseidon_bn254_5x5::Params, utils::bytes_to_fields};
+use halo2wrong::curves::bn256::Fr;
+
+type Sponge = PoseidonSponge<Fr, 5, Params>;
+
+/// Hashes the bytes into a field element, with the Poseidon sponge over
+/// `utils::bytes_to_fields(bytes)` and the `BYTES_DOMAIN` tag. Use it for
+/// binding Ethereum addresses, peer IDs and other metadata into Poseidon
+/// hashes.
+pub fn hash_bytes_to_field(bytes: &[u8]) -> Fr {
+	let mut sponge = Sponge::new_with_domain(Fr::from(BYTES_DOMAIN));
+	sponge.update(&bytes_to_fields(bytes));
+	sponge.squeeze()
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn should_hash_bytes_to_field() {
+		let address = hex::decode("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap();
+		let hash = hash_bytes_to_field(&address);
+		assert_eq!(hash, hash_bytes_to_field(&address));
+		assert_ne!(hash, hash_bytes_to_field(&address[1..]));
+		// Trailing zeros change the length.
+		assert_ne!(hash_bytes_to_field(&[1]), hash_bytes_to_field(&[1, 0]));
+		assert_ne!(hash_bytes_to_field(&[]), hash_bytes_to_field(&[0]));
+	}
+}
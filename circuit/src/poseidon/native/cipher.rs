@@ -0,0 +1,87 @@
+use super::prf::prf;
+use crate::params::RoundParams;
+use halo2wrong::halo2::arithmetic::FieldExt;
+
+/// Returns the commitment to the encryption key, so the holder of the key can
+/// check that a ciphertext was made with it: the PRF keyed by `key` over no
+/// input.
+pub fn key_commitment<F: FieldExt, const WIDTH: usize, P>(key: F) -> F
+where
+	P: RoundParams<F, WIDTH>,
+{
+	prf::<F, WIDTH, P>(key, &[])
+}
+
+/// Returns `n` elements of the keystream of `key` for the `nonce`. The
+/// element `i` is the PRF keyed by `key` over `nonce || i`.
+pub fn keystream<F: FieldExt, const WIDTH: usize, P>(key: F, nonce: F, n: usize) -> Vec<F>
+where
+	P: RoundParams<F, WIDTH>,
+{
+	(0..n).map(|i| prf::<F, WIDTH, P>(key, &[nonce, F::from(i as u64)])).collect()
+}
+
+/// Encrypts the plaintext by adding the keystream of `key` for the `nonce`
+/// to it. A nonce must never be used twice with the same key, and the
+/// ciphertext is malleable, so it needs to be bound to a proof or signature.
+pub fn encrypt<F: FieldExt, const WIDTH: usize, P>(key: F, nonce: F, plaintext: &[F]) -> Vec<F>
+where
+	P: RoundParams<F, WIDTH>,
+{
+	let stream = keystream::<F, WIDTH, P>(key, nonce, plaintext.len());
+	plaintext.iter().zip(stream).map(|(p, k)| *p + k).collect()
+}
+
+/// Decrypts the ciphertext made by `encrypt` with the same `key` and `nonce`.
+pub fn decrypt<F: FieldExt, const WIDTH: usize, P>(key: F, nonce: F, ciphertext: &[F]) -> Vec<F>
+where
+	P: RoundParams<F, WIDTH>,
+{
+	let stream = keystream::<F, WIDTH, P>(key, nonce, ciphertext.len());
+	ciphertext.iter().zip(stream).map(|(c, k)| *c - k).collect()
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{eddsa::native::SecretKey, params::poseidon_bn254_5x5::Params};
+	use halo2wrong::curves::bn256::Fr;
+	use rand::thread_rng;
+
+	#[test]
+	fn should_decrypt_with_shared_key() {
+		let mut rng = thread_rng();
+		let sk_a = SecretKey::random(&mut rng);
+		let sk_b = SecretKey::random(&mut rng);
+		let key_a = sk_a.diffie_hellman(&sk_b.public()).unwrap().derive_key(b"opinion");
+		let key_b = sk_b.diffie_hellman(&sk_a.public()).unwrap().derive_key(b"opinion");
+
+		let nonce = Fr::from(3);
+		let plaintext = [5u64, 0, 7].map(Fr::from);
+		let ciphertext = encrypt::<_, 5, Params>(key_a, nonce, &plaintext);
+		assert_ne!(ciphertext, plaintext);
+		assert_eq!(
+			decrypt::<_, 5, Params>(key_b, nonce, &ciphertext),
+			plaintext
+		);
+		assert_eq!(
+			key_commitment::<_, 5, Params>(key_a),
+			key_commitment::<_, 5, Params>(key_b)
+		);
+	}
+
+	#[test]
+	fn should_not_reuse_keystream() {
+		let key = Fr::from(11);
+		let plaintext = [Fr::from(1); 2];
+		let c1 = encrypt::<_, 5, Params>(key, Fr::from(1), &plaintext);
+		let c2 = encrypt::<_, 5, Params>(key, Fr::from(2), &plaintext);
+		// Different nonces and positions give different keystream elements.
+		assert_ne!(c1, c2);
+		assert_ne!(c1[0], c1[1]);
+		assert_ne!(
+			decrypt::<_, 5, Params>(Fr::from(12), Fr::from(1), &c1),
+			plaintext
+		);
+	}
+}
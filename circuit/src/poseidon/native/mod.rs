@@ -1,8 +1,18 @@
+/// Hashing of byte arrays into field elements
+pub mod bytes;
+/// Poseidon stream cipher for confidential values
+pub mod cipher;
+/// Poseidon based PRF and KDF
+pub mod prf;
 /// Native sponge implementation
 pub mod sponge;
+/// Fiat-Shamir transcript over a duplex sponge
+pub mod transcript;
 
 use crate::params::RoundParams;
 use halo2wrong::halo2::arithmetic::FieldExt;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use std::marker::PhantomData;
 
 /// Constructs objects.
@@ -32,6 +42,39 @@ where
 	/// The Round Function (TRF) and Hades:
 	/// https://eprint.iacr.org/2019/458.pdf#page=5
 	pub fn permute(&self) -> [F; WIDTH] {
+		self.permute_inner(None)
+	}
+
+	/// Same as `permute`, but also returns the state after each round, for
+	/// debugging and generating test vectors.
+	pub fn permute_with_trace(&self) -> (Vec<[F; WIDTH]>, [F; WIDTH]) {
+		let mut trace = Vec::new();
+		let state = self.permute_inner(Some(&mut trace));
+		(trace, state)
+	}
+
+	/// Hashes each of the independent inputs, returning the first element of
+	/// each permuted state in the same order. With the `parallel` feature, the
+	/// inputs are hashed across threads.
+	pub fn hash_many(inputs: &[[F; WIDTH]]) -> Vec<F> {
+		#[cfg(feature = "parallel")]
+		let states = Self::par_permute(inputs);
+		#[cfg(not(feature = "parallel"))]
+		let states: Vec<[F; WIDTH]> = inputs.iter().map(|x| Self::new(*x).permute()).collect();
+
+		states.iter().map(|state| state[0]).collect()
+	}
+
+	/// Permutes each of the independent inputs across threads, returning the
+	/// states in the same order.
+	#[cfg(feature = "parallel")]
+	pub fn par_permute(inputs: &[[F; WIDTH]]) -> Vec<[F; WIDTH]> {
+		inputs.par_iter().map(|x| Self::new(*x).permute()).collect()
+	}
+
+	/// Runs the permutation, pushing the state after each round into `trace`
+	/// if it is given.
+	fn permute_inner(&self, mut trace: Option<&mut Vec<[F; WIDTH]>>) -> [F; WIDTH] {
 		let full_rounds = P::full_rounds();
 		let half_full_rounds = full_rounds / 2;
 		let partial_rounds = P::partial_rounds();
@@ -62,6 +105,9 @@ where
 			// 3. step for the TRF.
 			// MixLayer step.
 			state = P::apply_mds(&state, &mds);
+			if let Some(trace) = trace.as_mut() {
+				trace.push(state);
+			}
 		}
 
 		for round in 0..partial_rounds {
@@ -76,6 +122,9 @@ where
 			// 3. step for the TRF.
 			// MixLayer step.
 			state = P::apply_mds(&state, &mds);
+			if let Some(trace) = trace.as_mut() {
+				trace.push(state);
+			}
 		}
 
 		for round in 0..half_full_rounds {
@@ -92,6 +141,9 @@ where
 			// 3. step for the TRF.
 			// MixLayer step.
 			state = P::apply_mds(&state, &mds);
+			if let Some(trace) = trace.as_mut() {
+				trace.push(state);
+			}
 		}
 
 		state
@@ -101,7 +153,11 @@ where
 #[cfg(test)]
 mod test {
 	use super::*;
-	use crate::params::{hex_to_field, poseidon_bn254_5x5::Params};
+	use crate::params::{
+		hex_to_field, poseidon_bn254_12x5::Params as Params12,
+		poseidon_bn254_3x5::Params as Params3, poseidon_bn254_5x5::Params,
+		poseidon_bn254_6x5::Params as Params6, poseidon_bn254_9x5::Params as Params9,
+	};
 	use halo2wrong::curves::bn256::Fr;
 
 	type TestPoseidon = Poseidon<Fr, 5, Params>;
@@ -133,4 +189,228 @@ mod test {
 
 		assert_eq!(out, outputs);
 	}
+
+	#[test]
+	fn test_native_poseidon_6x5() {
+		// Testing 6x5 input, against circomlib's Poseidon with 5 inputs.
+		let inputs: [Fr; 6] = [
+			"0x0000000000000000000000000000000000000000000000000000000000000000",
+			"0x0000000000000000000000000000000000000000000000000000000000000001",
+			"0x0000000000000000000000000000000000000000000000000000000000000002",
+			"0x0000000000000000000000000000000000000000000000000000000000000003",
+			"0x0000000000000000000000000000000000000000000000000000000000000004",
+			"0x0000000000000000000000000000000000000000000000000000000000000005",
+		]
+		.map(|n| hex_to_field(n));
+
+		let outputs: [Fr; 6] = [
+			"0x0dab9449e4a1398a15224c0b15a49d598b2174d305a316c918125f8feeb123c0",
+			"0x05cf88c465d1f87a273fed9f778b64367d3b32e45f274c03b4f441940f6d2db9",
+			"0x2c6c70449c91e046669977a3bf21021976db909b35843833babfbcba4937a2b3",
+			"0x2cabceb63c5f917cc45d3fcbad6733b8d42e2a2a12e1aeb4e3eb8fb6f3d2ca40",
+			"0x21ea1279294dbc9a32bc13827d052ce939d5397b4cb7b19e2ead087f41cfdfd7",
+			"0x208adf8d7f4ac061f00db710aef42f3b2f13176de26674b0a5f4436b883db6bc",
+		]
+		.map(|n| hex_to_field(n));
+
+		let poseidon = Poseidon::<Fr, 6, Params6>::new(inputs);
+
+		let out = poseidon.permute();
+
+		assert_eq!(out, outputs);
+	}
+
+	#[test]
+	fn test_native_poseidon_3x5() {
+		// Testing 3x5 input, against circomlib's Poseidon with 2 inputs.
+		let inputs: [Fr; 3] = [
+			"0x0000000000000000000000000000000000000000000000000000000000000000",
+			"0x0000000000000000000000000000000000000000000000000000000000000001",
+			"0x0000000000000000000000000000000000000000000000000000000000000002",
+		]
+		.map(|n| hex_to_field(n));
+
+		let outputs: [Fr; 3] = [
+			"0x115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a",
+			"0x0fca49b798923ab0239de1c9e7a4a9a2210312b6a2f616d18b5a87f9b628ae29",
+			"0x0e7ae82e40091e63cbd4f16a6d16310b3729d4b6e138fcf54110e2867045a30c",
+		]
+		.map(|n| hex_to_field(n));
+
+		let poseidon = Poseidon::<Fr, 3, Params3>::new(inputs);
+
+		let out = poseidon.permute();
+
+		assert_eq!(out, outputs);
+	}
+
+	#[test]
+	fn test_native_poseidon_9x5() {
+		// Testing 9x5 input, against circomlib's Poseidon with 8 inputs.
+		let inputs: [Fr; 9] = [
+			"0x0000000000000000000000000000000000000000000000000000000000000000",
+			"0x0000000000000000000000000000000000000000000000000000000000000001",
+			"0x0000000000000000000000000000000000000000000000000000000000000002",
+			"0x0000000000000000000000000000000000000000000000000000000000000003",
+			"0x0000000000000000000000000000000000000000000000000000000000000004",
+			"0x0000000000000000000000000000000000000000000000000000000000000005",
+			"0x0000000000000000000000000000000000000000000000000000000000000006",
+			"0x0000000000000000000000000000000000000000000000000000000000000007",
+			"0x0000000000000000000000000000000000000000000000000000000000000008",
+		]
+		.map(|n| hex_to_field(n));
+
+		let outputs: [Fr; 9] = [
+			"0x2921ab9bd0140cbc98e40395c0fefb40337a4d54fbbecd9a4d43b3d8d0c4d8d1",
+			"0x0f4bef710c430ccf4b066245ebda76ec4c571816b5766bffbe64dfcef83ad9ee",
+			"0x29ae93298f7f5ac359eed2a1b4fe0b8605e6caf86a2952ddc353edee612f431b",
+			"0x0100596375fcd85a397fabfef5af0a64caac9fa4206e3825651c96c00221ad89",
+			"0x0f007579146e6d18785d8edd07bfc2ff49ff194bb40da0bfad0fd77239d41104",
+			"0x21b31b3be4a08e10a24e2d327ea64077fb18dc9428fa04e30faf543a5cad6c41",
+			"0x032589fca1f1eb8f5c617c7256ae25221ed6cb7272b9ce1ff0f4cfb89050e601",
+			"0x1e51f0950c8b317a62bb43b082347bdb2b83deb856dabc1cdbbb7569c0e81955",
+			"0x2c8e23a3569963447e55619f1d1462f63ea2e40d3d405c18bbf394f13c253749",
+		]
+		.map(|n| hex_to_field(n));
+
+		let poseidon = Poseidon::<Fr, 9, Params9>::new(inputs);
+
+		let out = poseidon.permute();
+
+		assert_eq!(out, outputs);
+	}
+
+	#[test]
+	fn test_native_poseidon_12x5() {
+		// Testing 12x5 input, against circomlib's Poseidon with 11 inputs.
+		let inputs: [Fr; 12] = [
+			"0x0000000000000000000000000000000000000000000000000000000000000000",
+			"0x0000000000000000000000000000000000000000000000000000000000000001",
+			"0x0000000000000000000000000000000000000000000000000000000000000002",
+			"0x0000000000000000000000000000000000000000000000000000000000000003",
+			"0x0000000000000000000000000000000000000000000000000000000000000004",
+			"0x0000000000000000000000000000000000000000000000000000000000000005",
+			"0x0000000000000000000000000000000000000000000000000000000000000006",
+			"0x0000000000000000000000000000000000000000000000000000000000000007",
+			"0x0000000000000000000000000000000000000000000000000000000000000008",
+			"0x0000000000000000000000000000000000000000000000000000000000000009",
+			"0x000000000000000000000000000000000000000000000000000000000000000a",
+			"0x000000000000000000000000000000000000000000000000000000000000000b",
+		]
+		.map(|n| hex_to_field(n));
+
+		let outputs: [Fr; 12] = [
+			"0x07e5b070aa2dba008f30a6b785b6c5ae2429e211f71cacdbdae0e07fc05b47a8",
+			"0x2c36e7328f048c2ba25f79fcfafacc769122f2dde41a818b11241d17ae867707",
+			"0x016f55aae328c5b04cc62b781df8419375d1d7df12df6bfb4b86b6060442695c",
+			"0x05d3cebacb3945fbc76165b2276d1e4d0aacabca36c8a50bc4214d30efb26a3d",
+			"0x0d20bf02f4fbea024bf6d32e25afc15cc7dbda6325274037d719d77d93ae5c8d",
+			"0x069c738536077c296d11592c86f9dd891cfa968f696cd27d6e15d9829997c5a3",
+			"0x2e269dd408c488a724c1ff7437f7bf32dd694225f6d4433effb63352d4eb80bf",
+			"0x2bd25629cc03fcfda9887ac607a1549511209a3ccf032bc4cd752d80feec95d4",
+			"0x245e5169cc6cca781b75854e94b2ee179cd62b5e6221b557fdb7cbe11bc7cd09",
+			"0x05037d55a0b53cea251b26bf61b745d1cf3312332d3c99263dd4b4f544dcc6f2",
+			"0x0bce3d39a2b8601c6ef886ee266c1b0ff95b2a59334c30890164d7e1e03334c4",
+			"0x1941a33364c6d1904c0e540b5170c73567d31cb038d5d6b83cd769412139321a",
+		]
+		.map(|n| hex_to_field(n));
+
+		let poseidon = Poseidon::<Fr, 12, Params12>::new(inputs);
+
+		let out = poseidon.permute();
+
+		assert_eq!(out, outputs);
+	}
+
+	#[test]
+	fn test_native_poseidon_hash_many() {
+		let inputs: Vec<[Fr; 5]> =
+			(0u64..16).map(|i| [i, i + 1, i + 2, i + 3, i + 4].map(Fr::from)).collect();
+
+		let hashes = TestPoseidon::hash_many(&inputs);
+		assert_eq!(hashes.len(), inputs.len());
+		for (input, hash) in inputs.iter().zip(&hashes) {
+			assert_eq!(*hash, TestPoseidon::new(*input).permute()[0]);
+		}
+
+		#[cfg(feature = "parallel")]
+		for (input, state) in inputs.iter().zip(TestPoseidon::par_permute(&inputs)) {
+			assert_eq!(state, TestPoseidon::new(*input).permute());
+		}
+	}
+
+	#[test]
+	fn test_native_poseidon_5x5_trace() {
+		let inputs: [Fr; 5] = [0u64, 1, 2, 3, 4].map(Fr::from);
+		let poseidon = TestPoseidon::new(inputs);
+
+		let (trace, out) = poseidon.permute_with_trace();
+
+		// 8 full rounds + 60 partial rounds.
+		assert_eq!(trace.len(), 68);
+		assert_eq!(trace.last(), Some(&out));
+		assert_eq!(out, poseidon.permute());
+	}
+
+	/// Permutes with the partial rounds computed like in the chip, with the
+	/// sparse MDS matrices.
+	fn permute_sparse<const WIDTH: usize, P: RoundParams<Fr, WIDTH>>(
+		inputs: [Fr; WIDTH],
+	) -> [Fr; WIDTH] {
+		let half_full_rounds = P::full_rounds() / 2;
+		let partial_rounds = P::partial_rounds();
+		let mds = P::mds();
+		let round_constants = P::round_constants();
+		let sparse_rounds = P::sparse_rounds();
+
+		let mut state = inputs;
+		for round in 0..P::full_rounds() + partial_rounds {
+			let is_full = round < half_full_rounds || round >= half_full_rounds + partial_rounds;
+			if is_full {
+				let round_consts = P::load_round_constants(round, &round_constants);
+				state = P::apply_round_constants(&state, &round_consts);
+				state = state.map(P::sbox_f);
+				let mds =
+					if round == half_full_rounds - 1 { sparse_rounds.pre_sparse_mds } else { mds };
+				state = P::apply_mds(&state, &mds);
+			} else {
+				let partial_round = round - half_full_rounds;
+				let round_consts = sparse_rounds.round_constants[partial_round];
+				if partial_round > 0 {
+					assert!(round_consts[1..].iter().all(|c| *c == Fr::from(0)));
+				}
+				state = P::apply_round_constants(&state, &round_consts);
+				state[0] = P::sbox_f(state[0]);
+				let sparse_mds = sparse_rounds.sparse_mds[partial_round];
+				for i in 1..WIDTH {
+					for j in 1..WIDTH {
+						assert_eq!(sparse_mds[i][j], Fr::from(u64::from(i == j)));
+					}
+				}
+				state = P::apply_mds(&state, &sparse_mds);
+			}
+		}
+		state
+	}
+
+	#[test]
+	fn test_native_poseidon_sparse_rounds() {
+		let inputs: [Fr; 5] = [0u64, 1, 2, 3, 4].map(Fr::from);
+		assert_eq!(
+			permute_sparse::<5, Params>(inputs),
+			TestPoseidon::new(inputs).permute()
+		);
+
+		let inputs: [Fr; 3] = [0u64, 1, 2].map(Fr::from);
+		assert_eq!(
+			permute_sparse::<3, Params3>(inputs),
+			Poseidon::<Fr, 3, Params3>::new(inputs).permute()
+		);
+
+		let inputs: [Fr; 9] = [0u64, 1, 2, 3, 4, 5, 6, 7, 8].map(Fr::from);
+		assert_eq!(
+			permute_sparse::<9, Params9>(inputs),
+			Poseidon::<Fr, 9, Params9>::new(inputs).permute()
+		);
+	}
 }
@@ -0,0 +1,73 @@
+use super::sponge::{PoseidonSponge, KDF_DOMAIN, PRF_DOMAIN};
+use crate::params::RoundParams;
+use halo2wrong::halo2::arithmetic::FieldExt;
+
+/// Pseudorandom function keyed by `key`, evaluated on `input`, as the
+/// Poseidon sponge over `key || input` with the PRF domain tag.
+pub fn prf<F: FieldExt, const WIDTH: usize, P>(key: F, input: &[F]) -> F
+where
+	P: RoundParams<F, WIDTH>,
+{
+	let mut sponge = PoseidonSponge::<F, WIDTH, P>::new_with_domain(F::from(PRF_DOMAIN));
+	sponge.update(&[key]);
+	sponge.update(input);
+	sponge.squeeze()
+}
+
+/// Derives `n` keys from the shared secret for the purpose described by
+/// `info`, as the Poseidon sponge over `len(shared_secret) || shared_secret ||
+/// info` with the KDF domain tag. Different `info` give independent keys.
+pub fn kdf<F: FieldExt, const WIDTH: usize, P>(shared_secret: &[F], info: &[F], n: usize) -> Vec<F>
+where
+	P: RoundParams<F, WIDTH>,
+{
+	let mut sponge = PoseidonSponge::<F, WIDTH, P>::new_with_domain(F::from(KDF_DOMAIN));
+	sponge.update(&[F::from(shared_secret.len() as u64)]);
+	sponge.update(shared_secret);
+	sponge.update(info);
+	sponge.squeeze_n(n)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::params::poseidon_bn254_5x5::Params;
+	use halo2wrong::curves::bn256::Fr;
+
+	#[test]
+	fn should_separate_prf_keys_and_inputs() {
+		let prf = prf::<Fr, 5, Params>;
+		let (key1, key2) = (Fr::from(1), Fr::from(2));
+		let input = [3u64, 4].map(Fr::from);
+
+		assert_eq!(prf(key1, &input), prf(key1, &input));
+		assert_ne!(prf(key1, &input), prf(key2, &input));
+		assert_ne!(prf(key1, &input), prf(key1, &input[..1]));
+		// Differs from the plain sponge over the same elements.
+		let mut sponge = PoseidonSponge::<Fr, 5, Params>::new();
+		sponge.update(&[key1, input[0], input[1]]);
+		assert_ne!(prf(key1, &input), sponge.squeeze());
+	}
+
+	#[test]
+	fn should_derive_independent_keys() {
+		let kdf = kdf::<Fr, 5, Params>;
+		let secret = [5u64, 6].map(Fr::from);
+		let info = [Fr::from(7)];
+
+		let keys = kdf(&secret, &info, 3);
+		assert_eq!(keys.len(), 3);
+		assert_ne!(keys[0], keys[1]);
+		assert_ne!(keys[1], keys[2]);
+		// Shorter outputs are prefixes of the longer ones.
+		assert_eq!(kdf(&secret, &info, 1), keys[..1]);
+		assert_ne!(kdf(&secret, &[Fr::from(8)], 1), keys[..1]);
+		// The length of the secret separates it from the info.
+		assert_ne!(kdf(&secret[..1], &[secret[1], info[0]], 1), keys[..1]);
+		// And the domain tag from the PRF.
+		assert_ne!(
+			prf::<Fr, 5, Params>(secret[0], &[secret[1], info[0]]),
+			keys[0]
+		);
+	}
+}
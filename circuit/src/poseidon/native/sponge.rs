@@ -2,13 +2,45 @@ use crate::poseidon::{native::Poseidon, RoundParams};
 use halo2wrong::halo2::arithmetic::FieldExt;
 use std::marker::PhantomData;
 
+/// Domain tag of the EdDSA nonce derivation.
+pub const EDDSA_NONCE_DOMAIN: u64 = 1;
+/// Domain tag of the EdDSA challenge.
+pub const EDDSA_CHALLENGE_DOMAIN: u64 = 2;
+/// Domain tag of the Merkle tree nodes.
+pub const MERKLE_NODE_DOMAIN: u64 = 3;
+/// Domain tag of the opinion hashes.
+pub const OPINION_HASH_DOMAIN: u64 = 4;
+/// Domain tag of the PRF.
+pub const PRF_DOMAIN: u64 = 5;
+/// Domain tag of the KDF.
+pub const KDF_DOMAIN: u64 = 6;
+/// Domain tag of the byte array hashes.
+pub const BYTES_DOMAIN: u64 = 7;
+/// Domain tag of hashing to the curve.
+pub const HASH_TO_CURVE_DOMAIN: u64 = 8;
+/// Domain tag of the commitments to the public inputs.
+pub const INSTANCES_DOMAIN: u64 = 9;
+/// Domain tag of the nullifiers of the opinions.
+pub const NULLIFIER_DOMAIN: u64 = 10;
+/// Domain tag of the coefficients of the batch EdDSA verification.
+pub const EDDSA_BATCH_DOMAIN: u64 = 11;
+/// Domain tag of the Merkle tree leaves.
+pub const MERKLE_LEAF_DOMAIN: u64 = 12;
+
 /// Constructs objects.
 pub struct PoseidonSponge<F: FieldExt, const WIDTH: usize, P>
 where
 	P: RoundParams<F, WIDTH>,
 {
-	/// Constructs a vector for the inputs.
-	inputs: Vec<F>,
+	/// State with all the complete chunks absorbed.
+	state: [F; WIDTH],
+	/// Inputs of the chunk that is not absorbed yet.
+	buffer: [F; WIDTH],
+	/// Number of the inputs in the buffer.
+	buffered: usize,
+	/// Whether to absorb across the full WIDTH without padding, like the
+	/// sponge chip.
+	legacy: bool,
 	/// Constructs a phantom data for the parameters.
 	_params: PhantomData<P>,
 }
@@ -17,14 +49,56 @@ impl<F: FieldExt, const WIDTH: usize, P> PoseidonSponge<F, WIDTH, P>
 where
 	P: RoundParams<F, WIDTH>,
 {
-	/// Create objects.
+	/// Create a sponge with the rate of WIDTH - 1, keeping the first element
+	/// of the state as the capacity. The inputs are padded with a one followed
+	/// by zeros up to a multiple of the rate.
 	pub fn new() -> Self {
-		Self { inputs: Vec::new(), _params: PhantomData }
+		Self::new_with_domain(F::zero())
 	}
 
-	/// Clones and appends all elements from a slice to the vec.
+	/// Create a sponge like `new`, with the capacity element initialized to
+	/// the domain tag, so sponges with different tags never give the same
+	/// output. See the `*_DOMAIN` constants for the predefined tags.
+	pub fn new_with_domain(domain: F) -> Self {
+		let mut state = [F::zero(); WIDTH];
+		state[0] = domain;
+		Self {
+			state,
+			buffer: [F::zero(); WIDTH],
+			buffered: 0,
+			legacy: false,
+			_params: PhantomData,
+		}
+	}
+
+	/// Create a sponge that absorbs the inputs across the full WIDTH, with no
+	/// capacity element and no padding. Only kept for the hashes that already
+	/// depend on it, like the ones of the sponge chip.
+	pub fn new_legacy() -> Self {
+		Self {
+			state: [F::zero(); WIDTH],
+			buffer: [F::zero(); WIDTH],
+			buffered: 0,
+			legacy: true,
+			_params: PhantomData,
+		}
+	}
+
+	/// Absorbs the inputs, permuting the state each time a chunk is complete,
+	/// so only a single chunk is kept in memory. Can be called after a
+	/// squeeze, in which case the following squeezes cover all the inputs.
 	pub fn update(&mut self, inputs: &[F]) {
-		self.inputs.extend_from_slice(inputs);
+		let rate = self.rate();
+		for input in inputs {
+			// A complete chunk is absorbed only once more inputs arrive, since
+			// the last one is handled by the padding.
+			if self.buffered == rate {
+				self.state = self.absorb_chunk(self.state, &self.buffer[..rate]);
+				self.buffered = 0;
+			}
+			self.buffer[self.buffered] = *input;
+			self.buffered += 1;
+		}
 	}
 
 	/// Absorb the data in and split it into
@@ -36,21 +110,196 @@ where
 		fixed_chunk
 	}
 
+	/// Returns the number of inputs absorbed by each permutation.
+	fn rate(&self) -> usize {
+		WIDTH - self.output_index()
+	}
+
+	/// Adds the chunk to the rate part of the state, and permutes it.
+	fn absorb_chunk(&self, mut state: [F; WIDTH], chunk: &[F]) -> [F; WIDTH] {
+		for (s, input) in state[self.output_index()..].iter_mut().zip(chunk) {
+			*s += input;
+		}
+		Poseidon::<_, WIDTH, P>::new(state).permute()
+	}
+
+	/// Absorbs the buffered inputs together with the padding, and returns the
+	/// final state. The sponge itself is left unchanged.
+	fn absorb(&self) -> [F; WIDTH] {
+		if self.legacy {
+			assert!(self.buffered > 0);
+			return self.absorb_chunk(self.state, &self.buffer[..self.buffered]);
+		}
+
+		let rate = self.rate();
+		let mut state = self.state;
+		let mut padded = [F::zero(); WIDTH];
+		let mut len = self.buffered;
+		if len == rate {
+			// The chunk is complete, so the padding gets a chunk of its own.
+			state = self.absorb_chunk(state, &self.buffer[..rate]);
+			len = 0;
+		} else {
+			padded[..len].copy_from_slice(&self.buffer[..len]);
+		}
+		padded[len] = F::one();
+
+		self.absorb_chunk(state, &padded[..rate])
+	}
+
+	/// Returns the index of the state element that is output: the first rate
+	/// element, or the first element for the legacy sponge.
+	fn output_index(&self) -> usize {
+		usize::from(!self.legacy)
+	}
+
 	/// Squeeze the data out by
 	/// permuting until no more chunks are left.
 	pub fn squeeze(&mut self) -> F {
-		assert!(!self.inputs.is_empty());
+		self.absorb()[self.output_index()]
+	}
 
-		let mut state = [F::zero(); WIDTH];
+	/// Squeeze `n` elements out of the absorbed data. The first one is the
+	/// output of `squeeze`, and the state is permuted again before each of
+	/// the following ones.
+	pub fn squeeze_n(&mut self, n: usize) -> Vec<F> {
+		let mut state = self.absorb();
+		let mut outputs = Vec::with_capacity(n);
+		for i in 0..n {
+			if i > 0 {
+				state = Poseidon::<_, WIDTH, P>::new(state).permute();
+			}
+			outputs.push(state[self.output_index()]);
+		}
+		outputs
+	}
+}
+
+/// Commit to the public inputs, as the sponge with the `INSTANCES_DOMAIN` over
+/// the number of columns, and the length and the values of each column.
+pub fn hash_instances<F: FieldExt, P: RoundParams<F, 5>>(pub_inps: &[&[F]]) -> F {
+	let mut sponge = PoseidonSponge::<F, 5, P>::new_with_domain(F::from(INSTANCES_DOMAIN));
+	sponge.update(&[F::from(pub_inps.len() as u64)]);
+	for column in pub_inps {
+		sponge.update(&[F::from(column.len() as u64)]);
+		sponge.update(column);
+	}
+	sponge.squeeze()
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::params::poseidon_bn254_5x5::Params;
+	use halo2wrong::{curves::bn256::Fr, halo2::arithmetic::Field};
+
+	type Sponge = PoseidonSponge<Fr, 5, Params>;
+
+	#[test]
+	fn should_squeeze_n() {
+		let inputs = [1u64, 2, 3, 4, 5, 6, 7].map(Fr::from);
+		let mut sponge = Sponge::new();
+		sponge.update(&inputs);
+
+		let outputs = sponge.squeeze_n(3);
+		assert_eq!(outputs.len(), 3);
+		assert_eq!(outputs[0], sponge.squeeze());
+		assert_ne!(outputs[0], outputs[1]);
+		assert_ne!(outputs[1], outputs[2]);
+		// Shorter squeezes are prefixes of the longer ones.
+		assert_eq!(sponge.squeeze_n(2), outputs[..2]);
+		assert!(sponge.squeeze_n(0).is_empty());
+	}
+
+	#[test]
+	fn should_pad_inputs() {
+		let hash = |inputs: &[Fr]| {
+			let mut sponge = Sponge::new();
+			sponge.update(inputs);
+			sponge.squeeze()
+		};
+		let hash_legacy = |inputs: &[Fr]| {
+			let mut sponge = Sponge::new_legacy();
+			sponge.update(inputs);
+			sponge.squeeze()
+		};
+
+		let one = Fr::from(1);
+		// Trailing zeros are absorbed by the padding.
+		assert_ne!(hash(&[one]), hash(&[one, Fr::zero()]));
+		assert_ne!(hash(&[]), hash(&[Fr::zero()]));
+		// Inputs that fill the rate exactly get a block of padding.
+		assert_ne!(hash(&[one; 4]), hash(&[one; 3]));
+		// Unlike the legacy sponge.
+		assert_eq!(hash_legacy(&[one]), hash_legacy(&[one, Fr::zero()]));
+		assert_ne!(hash(&[one]), hash_legacy(&[one]));
+	}
+
+	#[test]
+	fn should_separate_domains() {
+		let inputs = [1u64, 2, 3].map(Fr::from);
+		let hash = |domain: u64| {
+			let mut sponge = Sponge::new_with_domain(Fr::from(domain));
+			sponge.update(&inputs);
+			sponge.squeeze()
+		};
+
+		let mut sponge = Sponge::new();
+		sponge.update(&inputs);
+		assert_eq!(hash(0), sponge.squeeze());
+		assert_ne!(hash(EDDSA_NONCE_DOMAIN), hash(EDDSA_CHALLENGE_DOMAIN));
+		assert_ne!(hash(MERKLE_NODE_DOMAIN), hash(OPINION_HASH_DOMAIN));
+		assert_ne!(hash(0), hash(MERKLE_NODE_DOMAIN));
+	}
+
+	#[test]
+	fn should_absorb_in_chunks() {
+		let inputs: Vec<Fr> = (0..23u64).map(Fr::from).collect();
+		for legacy in [false, true] {
+			let new_sponge = || if legacy { Sponge::new_legacy() } else { Sponge::new() };
 
-		for chunk in self.inputs.chunks(WIDTH) {
-			let loaded_state = Self::load_state(chunk);
-			let input = loaded_state.zip(state).map(|(lhs, rhs)| lhs + rhs);
+			let mut sponge = new_sponge();
+			sponge.update(&inputs);
+			let hash = sponge.squeeze();
 
-			let pos = Poseidon::<_, WIDTH, P>::new(input);
-			state = pos.permute();
+			for chunk_size in [1, 3, 4, 5, 7] {
+				let mut chunked = new_sponge();
+				for chunk in inputs.chunks(chunk_size) {
+					chunked.update(chunk);
+				}
+				assert_eq!(chunked.squeeze(), hash);
+			}
 		}
+	}
 
-		state[0]
+	#[test]
+	fn should_update_after_squeeze() {
+		let inputs = [1u64, 2, 3, 4, 5, 6, 7, 8].map(Fr::from);
+
+		let mut sponge = Sponge::new();
+		sponge.update(&inputs);
+		let hash = sponge.squeeze();
+
+		let mut streamed = Sponge::new();
+		streamed.update(&inputs[..4]);
+		let partial = streamed.squeeze();
+		streamed.update(&inputs[4..]);
+		assert_eq!(streamed.squeeze(), hash);
+		assert_ne!(partial, hash);
+
+		let mut sponge = Sponge::new();
+		sponge.update(&inputs[..4]);
+		assert_eq!(sponge.squeeze(), partial);
+	}
+
+	#[test]
+	fn test_hash_instances() {
+		fn hash(pub_inps: &[&[Fr]]) -> Fr {
+			hash_instances::<_, Params>(pub_inps)
+		}
+		let (a, b) = (Fr::from(1), Fr::from(2));
+		assert_ne!(hash(&[&[a, b]]), hash(&[&[a], &[b]]));
+		assert_ne!(hash(&[&[a, b]]), hash(&[&[b, a]]));
+		assert_ne!(hash(&[]), hash(&[&[]]));
 	}
 }
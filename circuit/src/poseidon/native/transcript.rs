@@ -0,0 +1,111 @@
+use crate::poseidon::{native::Poseidon, RoundParams};
+use halo2wrong::halo2::arithmetic::FieldExt;
+use std::marker::PhantomData;
+
+/// Fiat-Shamir transcript over a duplex Poseidon sponge. Inputs can be
+/// absorbed and challenges squeezed in any order, and each challenge depends
+/// on everything absorbed before it, including the earlier challenges.
+pub struct PoseidonTranscript<F: FieldExt, const WIDTH: usize, P>
+where
+	P: RoundParams<F, WIDTH>,
+{
+	/// State of the sponge, with the capacity element first.
+	state: [F; WIDTH],
+	/// Inputs absorbed since the last challenge.
+	pending: Vec<F>,
+	/// Constructs a phantom data for the parameters.
+	_params: PhantomData<P>,
+}
+
+impl<F: FieldExt, const WIDTH: usize, P> PoseidonTranscript<F, WIDTH, P>
+where
+	P: RoundParams<F, WIDTH>,
+{
+	/// Create a transcript with the capacity element initialized to the
+	/// domain tag, so transcripts of different protocols never agree.
+	pub fn new(domain: F) -> Self {
+		let mut state = [F::zero(); WIDTH];
+		state[0] = domain;
+		Self { state, pending: Vec::new(), _params: PhantomData }
+	}
+
+	/// Absorbs the inputs into the transcript.
+	pub fn absorb(&mut self, inputs: &[F]) {
+		self.pending.extend_from_slice(inputs);
+	}
+
+	/// Squeezes a challenge out of the transcript. The pending inputs are
+	/// padded with a one followed by zeros up to a multiple of the rate
+	/// (WIDTH - 1), and added to the rate elements of the state, permuting
+	/// after each block.
+	pub fn squeeze_challenge(&mut self) -> F {
+		let rate = WIDTH - 1;
+		self.pending.push(F::one());
+		let len = self.pending.len();
+		self.pending.resize(len + (rate - len % rate) % rate, F::zero());
+
+		for chunk in self.pending.chunks(rate) {
+			for (s, input) in self.state[1..].iter_mut().zip(chunk) {
+				*s += input;
+			}
+			self.state = Poseidon::<_, WIDTH, P>::new(self.state).permute();
+		}
+		self.pending.clear();
+
+		self.state[1]
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{params::poseidon_bn254_5x5::Params, poseidon::native::sponge::PoseidonSponge};
+	use halo2wrong::{curves::bn256::Fr, halo2::arithmetic::Field};
+
+	type Transcript = PoseidonTranscript<Fr, 5, Params>;
+
+	#[test]
+	fn should_match_sponge_for_first_challenge() {
+		let inputs = [1u64, 2, 3, 4, 5, 6].map(Fr::from);
+		let domain = Fr::from(7);
+
+		let mut transcript = Transcript::new(domain);
+		transcript.absorb(&inputs[..2]);
+		transcript.absorb(&inputs[2..]);
+
+		let mut sponge = PoseidonSponge::<Fr, 5, Params>::new_with_domain(domain);
+		sponge.update(&inputs);
+		assert_eq!(transcript.squeeze_challenge(), sponge.squeeze());
+	}
+
+	#[test]
+	fn should_bind_challenges_to_history() {
+		let one = Fr::one();
+
+		let mut t1 = Transcript::new(Fr::zero());
+		t1.absorb(&[one]);
+		let c1 = t1.squeeze_challenge();
+		// Squeezing again without absorbing gives a fresh challenge.
+		let c2 = t1.squeeze_challenge();
+		assert_ne!(c1, c2);
+		t1.absorb(&[one]);
+		let c3 = t1.squeeze_challenge();
+
+		// Same inputs, without the intermediate challenges.
+		let mut t2 = Transcript::new(Fr::zero());
+		t2.absorb(&[one, one]);
+		assert_ne!(t2.squeeze_challenge(), c3);
+
+		// Same history gives the same challenges.
+		let mut t3 = Transcript::new(Fr::zero());
+		t3.absorb(&[one]);
+		assert_eq!(t3.squeeze_challenge(), c1);
+		assert_eq!(t3.squeeze_challenge(), c2);
+		t3.absorb(&[one]);
+		assert_eq!(t3.squeeze_challenge(), c3);
+
+		let mut t4 = Transcript::new(Fr::one());
+		t4.absorb(&[one]);
+		assert_ne!(t4.squeeze_challenge(), c1);
+	}
+}
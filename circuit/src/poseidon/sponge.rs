@@ -13,7 +13,7 @@ use halo2wrong::halo2::{
 
 #[derive(Clone)]
 /// Configuration elements for the circuit are defined here.
-struct PoseidonSpongeConfig<const WIDTH: usize> {
+pub struct PoseidonSpongeConfig<const WIDTH: usize> {
 	/// Constructs poseidon circuit elements.
 	poseidon_config: PoseidonConfig<WIDTH>,
 	/// Configures columns for the state.
@@ -23,27 +23,44 @@ struct PoseidonSpongeConfig<const WIDTH: usize> {
 }
 
 /// Constructs a chip structure for the circuit.
-struct PoseidonSpongeChip<F: FieldExt, const WIDTH: usize, P>
+pub struct PoseidonSpongeChip<F: FieldExt, const WIDTH: usize, P>
 where
 	P: RoundParams<F, WIDTH>,
 {
 	/// Constructs a cell vector for the inputs.
 	inputs: Vec<AssignedCell<F, F>>,
+	/// Domain tag of the capacity element, or `None` for the legacy sponge.
+	domain: Option<F>,
 	/// Constructs a phantom data for the parameters.
 	_params: PhantomData<P>,
 }
 
+impl<F: FieldExt, const WIDTH: usize, P> Default for PoseidonSpongeChip<F, WIDTH, P>
+where
+	P: RoundParams<F, WIDTH>,
+{
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 impl<F: FieldExt, const WIDTH: usize, P> PoseidonSpongeChip<F, WIDTH, P>
 where
 	P: RoundParams<F, WIDTH>,
 {
-	/// Create a new chip.
-	fn new() -> Self {
-		Self { inputs: Vec::new(), _params: PhantomData }
+	/// Create a new chip, matching the legacy native sponge.
+	pub fn new() -> Self {
+		Self { inputs: Vec::new(), domain: None, _params: PhantomData }
+	}
+
+	/// Create a new chip matching the native sponge with the domain tag, with
+	/// the rate of WIDTH - 1 and the padded inputs.
+	pub fn new_with_domain(domain: F) -> Self {
+		Self { inputs: Vec::new(), domain: Some(domain), _params: PhantomData }
 	}
 
 	/// Make the circuit config.
-	fn configure(meta: &mut ConstraintSystem<F>) -> PoseidonSpongeConfig<WIDTH> {
+	pub fn configure(meta: &mut ConstraintSystem<F>) -> PoseidonSpongeConfig<WIDTH> {
 		let poseidon_config = PoseidonChip::<_, WIDTH, P>::configure(meta);
 		let state = [(); WIDTH].map(|_| {
 			let column = meta.advice_column();
@@ -51,6 +68,8 @@ where
 			column
 		});
 		let absorb_selector = meta.selector();
+		let fixed = meta.fixed_column();
+		meta.enable_constant(fixed);
 
 		meta.create_gate("absorb", |v_cells| {
 			let mut exprs = [(); WIDTH].map(|_| Expression::Constant(F::zero()));
@@ -93,7 +112,7 @@ where
 	}
 
 	/// Clones and appends all elements from a slice to the vec.
-	fn update(&mut self, inputs: &[AssignedCell<F, F>]) {
+	pub fn update(&mut self, inputs: &[AssignedCell<F, F>]) {
 		self.inputs.extend_from_slice(inputs);
 	}
 
@@ -102,14 +121,20 @@ where
 	pub fn squeeze(
 		&self, config: &PoseidonSpongeConfig<WIDTH>, mut layouter: impl Layouter<F>,
 	) -> Result<AssignedCell<F, F>, Error> {
-		assert!(!self.inputs.is_empty());
-
-		let mut state = layouter.assign_region(
-			|| "load_chunks",
-			|mut region: Region<'_, F>| Self::load_state(config.state, &mut region, 0, &[]),
-		)?;
-
-		for (i, chunk) in self.inputs.chunks(WIDTH).enumerate() {
+		let (mut state, chunks) = match self.domain {
+			Some(domain) => self.pad(domain, config, layouter.namespace(|| "padding"))?,
+			None => {
+				assert!(!self.inputs.is_empty());
+				let state = layouter.assign_region(
+					|| "load_chunks",
+					|mut region: Region<'_, F>| Self::load_state(config.state, &mut region, 0, &[]),
+				)?;
+				let chunks = self.inputs.chunks(WIDTH).map(|chunk| chunk.to_vec()).collect();
+				(state, chunks)
+			},
+		};
+
+		for (i, chunk) in chunks.iter().enumerate() {
 			let inputs = layouter.assign_region(
 				|| format!("absorb_{}", i),
 				|mut region: Region<'_, F>| {
@@ -140,7 +165,45 @@ where
 			)?;
 		}
 
-		Ok(state[0].clone())
+		let output_index = usize::from(self.domain.is_some());
+		Ok(state[output_index].clone())
+	}
+
+	/// Returns the initial state with the domain tag, and the inputs padded
+	/// with a one followed by zeros, split into chunks of the rate with a
+	/// zero added to the capacity element.
+	fn pad(
+		&self, domain: F, config: &PoseidonSpongeConfig<WIDTH>, mut layouter: impl Layouter<F>,
+	) -> Result<([AssignedCell<F, F>; WIDTH], Vec<Vec<AssignedCell<F, F>>>), Error> {
+		let (domain, one, zero) = layouter.assign_region(
+			|| "constants",
+			|mut region: Region<'_, F>| {
+				let domain =
+					region.assign_advice_from_constant(|| "domain", config.state[0], 0, domain)?;
+				let one =
+					region.assign_advice_from_constant(|| "one", config.state[1], 0, F::one())?;
+				let zero =
+					region.assign_advice_from_constant(|| "zero", config.state[2], 0, F::zero())?;
+				Ok((domain, one, zero))
+			},
+		)?;
+
+		let mut state = [(); WIDTH].map(|_| zero.clone());
+		state[0] = domain;
+
+		let rate = WIDTH - 1;
+		let mut padded = self.inputs.clone();
+		padded.push(one);
+		padded.resize((padded.len() + rate - 1) / rate * rate, zero.clone());
+		let chunks = padded
+			.chunks(rate)
+			.map(|chunk| {
+				let mut chunk = chunk.to_vec();
+				chunk.insert(0, zero.clone());
+				chunk
+			})
+			.collect();
+		Ok((state, chunks))
 	}
 }
 
@@ -154,6 +217,7 @@ mod test {
 	use halo2wrong::{
 		curves::bn256::Fr,
 		halo2::{
+			arithmetic::Field,
 			circuit::{AssignedCell, Layouter, Region, SimpleFloorPlanner, Value},
 			dev::MockProver,
 			plonk::{Circuit, Column, ConstraintSystem, Error, Instance},
@@ -172,13 +236,15 @@ mod test {
 	struct PoseidonTester {
 		inputs1: [Value<Fr>; 5],
 		inputs2: [Value<Fr>; 5],
+		domain: Option<Fr>,
 	}
 
 	impl PoseidonTester {
-		fn new(inputs1: [Fr; 5], inputs2: [Fr; 5]) -> Self {
+		fn new(inputs1: [Fr; 5], inputs2: [Fr; 5], domain: Option<Fr>) -> Self {
 			Self {
 				inputs1: inputs1.map(|item| Value::known(item)),
 				inputs2: inputs2.map(|item| Value::known(item)),
+				domain,
 			}
 		}
 
@@ -204,7 +270,11 @@ mod test {
 		type FloorPlanner = SimpleFloorPlanner;
 
 		fn without_witnesses(&self) -> Self {
-			Self { inputs1: [Value::unknown(); 5], inputs2: [Value::unknown(); 5] }
+			Self {
+				inputs1: [Value::unknown(); 5],
+				inputs2: [Value::unknown(); 5],
+				domain: self.domain,
+			}
 		}
 
 		fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
@@ -233,7 +303,10 @@ mod test {
 				},
 			)?;
 
-			let mut poseidon_sponge = TestPoseidonSpongeChip::new();
+			let mut poseidon_sponge = match self.domain {
+				Some(domain) => TestPoseidonSpongeChip::new_with_domain(domain),
+				None => TestPoseidonSpongeChip::new(),
+			};
 			poseidon_sponge.update(&inputs1);
 			poseidon_sponge.update(&inputs2);
 			let result_state = poseidon_sponge
@@ -265,16 +338,38 @@ mod test {
 		]
 		.map(|n| hex_to_field(n));
 
-		let mut sponge = TestPoseidonSponge::new();
+		let mut sponge = TestPoseidonSponge::new_legacy();
 		sponge.update(&inputs1);
 		sponge.update(&inputs2);
 
 		let native_result = sponge.squeeze();
 
-		let poseidon_sponge = PoseidonTester::new(inputs1, inputs2);
+		let poseidon_sponge = PoseidonTester::new(inputs1, inputs2, None);
+
+		let k = 12;
+		let prover = MockProver::run(k, &poseidon_sponge, vec![vec![native_result]]).unwrap();
+		assert_eq!(prover.verify(), Ok(()));
+	}
+
+	#[test]
+	fn should_match_native_sponge_with_domain() {
+		let inputs1 = [0u64, 1, 2, 3, 4].map(Fr::from);
+		let inputs2 = [5u64, 6, 7, 8, 9].map(Fr::from);
+		let domain = Fr::from(9);
 
+		// Ten inputs and the padding make three chunks of the rate.
+		let mut sponge = TestPoseidonSponge::new_with_domain(domain);
+		sponge.update(&inputs1);
+		sponge.update(&inputs2);
+		let native_result = sponge.squeeze();
+
+		let poseidon_sponge = PoseidonTester::new(inputs1, inputs2, Some(domain));
 		let k = 12;
 		let prover = MockProver::run(k, &poseidon_sponge, vec![vec![native_result]]).unwrap();
 		assert_eq!(prover.verify(), Ok(()));
+
+		let prover =
+			MockProver::run(k, &poseidon_sponge, vec![vec![native_result + Fr::one()]]).unwrap();
+		assert!(prover.verify().is_err());
 	}
 }
@@ -0,0 +1,329 @@
+/// Native version of Poseidon2
+pub mod native;
+
+use crate::params::Poseidon2Params;
+use halo2wrong::halo2::{
+	arithmetic::FieldExt,
+	circuit::{AssignedCell, Layouter, Region, Value},
+	plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Selector, VirtualCells},
+	poly::Rotation,
+};
+use std::marker::PhantomData;
+
+#[derive(Clone, Debug)]
+/// Configuration elements for the circuit are defined here.
+pub struct Poseidon2Config<const WIDTH: usize> {
+	/// Configures columns for the state.
+	state: [Column<Advice>; WIDTH],
+	/// Configures columns for the round constants.
+	round_constants: [Column<Fixed>; WIDTH],
+	/// Configures a fixed boolean value for the initial external matrix.
+	linear_selector: Selector,
+	/// Configures a fixed boolean value for each row of the full rounds.
+	full_round_selector: Selector,
+	/// Configures a fixed boolean value for each row of the partial rounds.
+	partial_round_selector: Selector,
+}
+
+/// Constructs a chip structure for the circuit.
+pub struct Poseidon2Chip<F: FieldExt, const WIDTH: usize, P>
+where
+	P: Poseidon2Params<F, WIDTH>,
+{
+	/// Constructs a cell array for the inputs.
+	inputs: [AssignedCell<F, F>; WIDTH],
+	/// Constructs a phantom data for the parameters.
+	_params: PhantomData<P>,
+}
+
+impl<F: FieldExt, const WIDTH: usize, P> Poseidon2Chip<F, WIDTH, P>
+where
+	P: Poseidon2Params<F, WIDTH>,
+{
+	/// Create a new chip.
+	pub fn new(inputs: [AssignedCell<F, F>; WIDTH]) -> Self {
+		Poseidon2Chip { inputs, _params: PhantomData }
+	}
+
+	/// Multiply the expressions by the constant matrix.
+	fn apply_matrix_expr(
+		exprs: &[Expression<F>; WIDTH], mat: &[[F; WIDTH]; WIDTH],
+	) -> [Expression<F>; WIDTH] {
+		let mut new_exprs = [(); WIDTH].map(|_| Expression::Constant(F::zero()));
+		for i in 0..WIDTH {
+			for j in 0..WIDTH {
+				new_exprs[i] = new_exprs[i].clone() + exprs[j].clone() * mat[i][j];
+			}
+		}
+		new_exprs
+	}
+
+	/// Constrains the next row of the state to be equal to the expressions.
+	fn next_state_constraints(
+		v_cells: &mut VirtualCells<F>, state: &[Column<Advice>; WIDTH], selector: Selector,
+		exprs: [Expression<F>; WIDTH],
+	) -> [Expression<F>; WIDTH] {
+		let s = v_cells.query_selector(selector);
+		let mut constraints = exprs;
+		for i in 0..WIDTH {
+			let next_state = v_cells.query_advice(state[i], Rotation::next());
+			constraints[i] = s.clone() * (constraints[i].clone() - next_state);
+		}
+		constraints
+	}
+
+	/// Make the circuit config.
+	pub fn configure(meta: &mut ConstraintSystem<F>) -> Poseidon2Config<WIDTH> {
+		let state = [(); WIDTH].map(|_| meta.advice_column());
+		let round_constants = [(); WIDTH].map(|_| meta.fixed_column());
+		let linear_selector = meta.selector();
+		let full_round_selector = meta.selector();
+		let partial_round_selector = meta.selector();
+
+		state.map(|c| meta.enable_equality(c));
+
+		let mat_external = P::mat_external();
+		let mat_internal = P::mat_internal();
+
+		meta.create_gate("linear", |v_cells| {
+			let exprs = state.map(|c| v_cells.query_advice(c, Rotation::cur()));
+			let exprs = Self::apply_matrix_expr(&exprs, &mat_external);
+			Self::next_state_constraints(v_cells, &state, linear_selector, exprs)
+		});
+
+		meta.create_gate("full_round", |v_cells| {
+			let mut exprs = [(); WIDTH].map(|_| Expression::Constant(F::zero()));
+			for i in 0..WIDTH {
+				let curr_state = v_cells.query_advice(state[i], Rotation::cur());
+				let round_constant = v_cells.query_fixed(round_constants[i], Rotation::cur());
+				exprs[i] = P::sbox_expr(curr_state + round_constant);
+			}
+			let exprs = Self::apply_matrix_expr(&exprs, &mat_external);
+			Self::next_state_constraints(v_cells, &state, full_round_selector, exprs)
+		});
+
+		meta.create_gate("partial_round", |v_cells| {
+			let mut exprs = state.map(|c| v_cells.query_advice(c, Rotation::cur()));
+			let round_constant = v_cells.query_fixed(round_constants[0], Rotation::cur());
+			exprs[0] = P::sbox_expr(exprs[0].clone() + round_constant);
+			let exprs = Self::apply_matrix_expr(&exprs, &mat_internal);
+			Self::next_state_constraints(v_cells, &state, partial_round_selector, exprs)
+		});
+
+		Poseidon2Config {
+			state,
+			round_constants,
+			linear_selector,
+			full_round_selector,
+			partial_round_selector,
+		}
+	}
+
+	/// Assign the state values to the given row.
+	fn assign_state(
+		config: &Poseidon2Config<WIDTH>, region: &mut Region<'_, F>, row: usize,
+		state: Value<[F; WIDTH]>,
+	) -> Result<[AssignedCell<F, F>; WIDTH], Error> {
+		let mut cells: [Option<AssignedCell<F, F>>; WIDTH] = [(); WIDTH].map(|_| None);
+		for i in 0..WIDTH {
+			cells[i] = Some(region.assign_advice(
+				|| "state",
+				config.state[i],
+				row,
+				|| state.map(|s| s[i]),
+			)?);
+		}
+		Ok(cells.map(|item| item.unwrap()))
+	}
+
+	/// Synthesize the circuit.
+	pub fn synthesize(
+		&self, config: Poseidon2Config<WIDTH>, mut layouter: impl Layouter<F>,
+	) -> Result<[AssignedCell<F, F>; WIDTH], Error> {
+		let half_full_rounds = P::full_rounds() / 2;
+		let partial_rounds = P::partial_rounds();
+		let round_constants = P::round_constants();
+		let mat_external = P::mat_external();
+		let mat_internal = P::mat_internal();
+
+		layouter.assign_region(
+			|| "poseidon2",
+			|mut region: Region<'_, F>| {
+				// Copy the inputs, and apply the external matrix to them.
+				let mut state = Value::known([F::zero(); WIDTH]);
+				for i in 0..WIDTH {
+					let cell =
+						self.inputs[i].copy_advice(|| "state", &mut region, config.state[i], 0)?;
+					state = state.zip(cell.value().cloned()).map(|(mut s, v)| {
+						s[i] = v;
+						s
+					});
+				}
+				config.linear_selector.enable(&mut region, 0)?;
+				state = state.map(|s| P::apply_matrix(&s, &mat_external));
+				let mut state_cells = Self::assign_state(&config, &mut region, 1, state)?;
+
+				for (round, consts) in round_constants.iter().enumerate() {
+					let row = round + 1;
+					for i in 0..WIDTH {
+						region.assign_fixed(
+							|| "round_constant",
+							config.round_constants[i],
+							row,
+							|| Value::known(consts[i]),
+						)?;
+					}
+
+					if round < half_full_rounds || round >= half_full_rounds + partial_rounds {
+						config.full_round_selector.enable(&mut region, row)?;
+						state = state.map(|mut s| {
+							for i in 0..WIDTH {
+								s[i] = P::sbox_f(s[i] + consts[i]);
+							}
+							P::apply_matrix(&s, &mat_external)
+						});
+					} else {
+						config.partial_round_selector.enable(&mut region, row)?;
+						state = state.map(|mut s| {
+							s[0] = P::sbox_f(s[0] + consts[0]);
+							P::apply_matrix(&s, &mat_internal)
+						});
+					}
+					state_cells = Self::assign_state(&config, &mut region, row + 1, state)?;
+				}
+
+				Ok(state_cells)
+			},
+		)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		params::{hex_to_field, poseidon2_bn254_3x5::Params},
+		utils::{generate_params, prove_and_verify},
+	};
+	use halo2wrong::{
+		curves::bn256::{Bn256, Fr},
+		halo2::{
+			circuit::SimpleFloorPlanner,
+			dev::MockProver,
+			plonk::{Circuit, Instance},
+		},
+	};
+
+	type TestPoseidon2Chip = Poseidon2Chip<Fr, 3, Params>;
+
+	#[derive(Clone)]
+	struct Poseidon2TesterConfig {
+		poseidon2_config: Poseidon2Config<3>,
+		temp: Column<Advice>,
+		results: Column<Instance>,
+	}
+
+	struct Poseidon2Tester {
+		inputs: [Value<Fr>; 3],
+	}
+
+	impl Poseidon2Tester {
+		fn new(inputs: [Value<Fr>; 3]) -> Self {
+			Self { inputs }
+		}
+	}
+
+	impl Circuit<Fr> for Poseidon2Tester {
+		type Config = Poseidon2TesterConfig;
+		type FloorPlanner = SimpleFloorPlanner;
+
+		fn without_witnesses(&self) -> Self {
+			Self { inputs: [Value::unknown(); 3] }
+		}
+
+		fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+			let poseidon2_config = TestPoseidon2Chip::configure(meta);
+			let temp = meta.advice_column();
+			let results = meta.instance_column();
+
+			meta.enable_equality(temp);
+			meta.enable_equality(results);
+
+			Self::Config { poseidon2_config, temp, results }
+		}
+
+		fn synthesize(
+			&self, config: Self::Config, mut layouter: impl Layouter<Fr>,
+		) -> Result<(), Error> {
+			let init_state = layouter.assign_region(
+				|| "load_state",
+				|mut region: Region<'_, Fr>| {
+					let mut state: [Option<AssignedCell<Fr, Fr>>; 3] = [(); 3].map(|_| None);
+					for i in 0..3 {
+						state[i] = Some(region.assign_advice(
+							|| "state",
+							config.temp,
+							i,
+							|| self.inputs[i],
+						)?);
+					}
+					Ok(state.map(|item| item.unwrap()))
+				},
+			)?;
+
+			let poseidon2 = TestPoseidon2Chip::new(init_state);
+			let result_state = poseidon2
+				.synthesize(config.poseidon2_config, layouter.namespace(|| "poseidon2"))?;
+			for i in 0..3 {
+				layouter.constrain_instance(result_state[i].cell(), config.results, i)?;
+			}
+			Ok(())
+		}
+	}
+
+	fn test_vector() -> ([Value<Fr>; 3], [Fr; 3]) {
+		let inputs: [Value<Fr>; 3] = [
+			"0x0000000000000000000000000000000000000000000000000000000000000000",
+			"0x0000000000000000000000000000000000000000000000000000000000000001",
+			"0x0000000000000000000000000000000000000000000000000000000000000002",
+		]
+		.map(|n| Value::known(hex_to_field(n)));
+
+		let outputs: [Fr; 3] = [
+			"0x0bb61d24daca55eebcb1929a82650f328134334da98ea4f847f760054f4a3033",
+			"0x303b6f7c86d043bfcbcc80214f26a30277a15d3f74ca654992defe7ff8d03570",
+			"0x1ed25194542b12eef8617361c3ba7c52e660b145994427cc86296242cf766ec8",
+		]
+		.map(|n| hex_to_field(n));
+
+		(inputs, outputs)
+	}
+
+	#[test]
+	fn test_poseidon2_x5_3() {
+		let (inputs, outputs) = test_vector();
+		let poseidon2_tester = Poseidon2Tester::new(inputs);
+
+		let k = 7;
+		let prover = MockProver::run(k, &poseidon2_tester, vec![outputs.to_vec()]).unwrap();
+		assert_eq!(prover.verify(), Ok(()));
+
+		let mut wrong_outputs = outputs;
+		wrong_outputs[0] += Fr::one();
+		let prover = MockProver::run(k, &poseidon2_tester, vec![wrong_outputs.to_vec()]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_poseidon2_x5_3_production() {
+		let (inputs, outputs) = test_vector();
+		let poseidon2_tester = Poseidon2Tester::new(inputs);
+
+		let k = 7;
+		let rng = &mut rand::thread_rng();
+		let params = generate_params(k);
+		let res =
+			prove_and_verify::<Bn256, _, _>(params, poseidon2_tester, &[&outputs], rng).unwrap();
+		assert!(res);
+	}
+}
@@ -0,0 +1,84 @@
+use crate::params::Poseidon2Params;
+use halo2wrong::halo2::arithmetic::FieldExt;
+use std::marker::PhantomData;
+
+/// Constructs objects.
+pub struct Poseidon2<F: FieldExt, const WIDTH: usize, P>
+where
+	P: Poseidon2Params<F, WIDTH>,
+{
+	/// Constructs an array for the inputs.
+	inputs: [F; WIDTH],
+	/// Constructs a phantom data for the parameters.
+	_params: PhantomData<P>,
+}
+
+impl<F: FieldExt, const WIDTH: usize, P> Poseidon2<F, WIDTH, P>
+where
+	P: Poseidon2Params<F, WIDTH>,
+{
+	/// Create the objects.
+	pub fn new(inputs: [F; WIDTH]) -> Self {
+		Poseidon2 { inputs, _params: PhantomData }
+	}
+
+	/// Poseidon2 permutation: the external matrix is applied to the inputs,
+	/// followed by the full rounds with the external matrix, and the partial
+	/// rounds with the cheaper internal matrix.
+	/// https://eprint.iacr.org/2023/323.pdf
+	pub fn permute(&self) -> [F; WIDTH] {
+		let half_full_rounds = P::full_rounds() / 2;
+		let partial_rounds = P::partial_rounds();
+		let round_constants = P::round_constants();
+		let mat_external = P::mat_external();
+		let mat_internal = P::mat_internal();
+
+		let mut state = P::apply_matrix(&self.inputs, &mat_external);
+		for (round, consts) in round_constants.iter().enumerate() {
+			if round < half_full_rounds || round >= half_full_rounds + partial_rounds {
+				// Full round: S-boxes on the whole state.
+				for i in 0..WIDTH {
+					state[i] = P::sbox_f(state[i] + consts[i]);
+				}
+				state = P::apply_matrix(&state, &mat_external);
+			} else {
+				// Partial round: single S-box on the first element.
+				state[0] = P::sbox_f(state[0] + consts[0]);
+				state = P::apply_matrix(&state, &mat_internal);
+			}
+		}
+
+		state
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::params::{hex_to_field, poseidon2_bn254_3x5::Params};
+	use halo2wrong::curves::bn256::Fr;
+
+	#[test]
+	fn test_native_poseidon2_3x5() {
+		// Testing 3x5 input, against the reference implementation.
+		let inputs: [Fr; 3] = [
+			"0x0000000000000000000000000000000000000000000000000000000000000000",
+			"0x0000000000000000000000000000000000000000000000000000000000000001",
+			"0x0000000000000000000000000000000000000000000000000000000000000002",
+		]
+		.map(|n| hex_to_field(n));
+
+		let outputs: [Fr; 3] = [
+			"0x0bb61d24daca55eebcb1929a82650f328134334da98ea4f847f760054f4a3033",
+			"0x303b6f7c86d043bfcbcc80214f26a30277a15d3f74ca654992defe7ff8d03570",
+			"0x1ed25194542b12eef8617361c3ba7c52e660b145994427cc86296242cf766ec8",
+		]
+		.map(|n| hex_to_field(n));
+
+		let poseidon2 = Poseidon2::<Fr, 3, Params>::new(inputs);
+
+		let out = poseidon2.permute();
+
+		assert_eq!(out, outputs);
+	}
+}
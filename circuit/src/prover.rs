@@ -0,0 +1,108 @@
+//! Prover owning the params and caching the proving key of every circuit
+//! type, so the keys are generated once and don't have to be threaded around.
+
+use crate::{
+	error::Error,
+	utils::{keygen, prove, verify},
+};
+use halo2wrong::{
+	curves::bn256::{Bn256, Fr, G1Affine},
+	halo2::{
+		plonk::{Circuit, ProvingKey},
+		poly::kzg::commitment::ParamsKZG,
+	},
+};
+use rand::thread_rng;
+use std::{any::TypeId, collections::HashMap};
+
+/// Proves and verifies the circuits with the shared params. The proving keys
+/// are cached by the type of the circuit, so all the circuits of one type must
+/// have the same shape and the same fixed values.
+pub struct Prover {
+	params: ParamsKZG<Bn256>,
+	pks: HashMap<TypeId, ProvingKey<G1Affine>>,
+	num_keygens: usize,
+}
+
+impl Prover {
+	/// Create a prover with the `params`.
+	pub fn new(params: ParamsKZG<Bn256>) -> Self {
+		Self { params, pks: HashMap::new(), num_keygens: 0 }
+	}
+
+	/// The params of the prover.
+	pub fn params(&self) -> &ParamsKZG<Bn256> {
+		&self.params
+	}
+
+	/// Add the proving key of the circuit type `C`, e.g. read from the disk.
+	pub fn insert_pk<C: Circuit<Fr> + 'static>(&mut self, pk: ProvingKey<G1Affine>) {
+		self.pks.insert(TypeId::of::<C>(), pk);
+	}
+
+	/// Returns the proving key of the circuit type `C`, if it is cached.
+	pub fn get_pk<C: Circuit<Fr> + 'static>(&self) -> Option<&ProvingKey<G1Affine>> {
+		self.pks.get(&TypeId::of::<C>())
+	}
+
+	/// Returns the proving key of the circuit type `C`, generating it from
+	/// `circuit` if it is not cached yet.
+	pub fn get_or_keygen<C: Circuit<Fr> + 'static>(
+		&mut self, circuit: &C,
+	) -> Result<&ProvingKey<G1Affine>, Error> {
+		let type_id = TypeId::of::<C>();
+		if !self.pks.contains_key(&type_id) {
+			let pk = keygen(&self.params, &circuit.without_witnesses())?;
+			self.num_keygens += 1;
+			self.pks.insert(type_id, pk);
+		}
+		Ok(&self.pks[&type_id])
+	}
+
+	/// Make a proof of `circuit` with the public inputs `pub_inps`.
+	pub fn prove<C: Circuit<Fr> + 'static>(
+		&mut self, circuit: C, pub_inps: &[&[Fr]],
+	) -> Result<Vec<u8>, Error> {
+		self.get_or_keygen(&circuit)?;
+		let pk = &self.pks[&TypeId::of::<C>()];
+		prove(&self.params, circuit, pub_inps, pk, &mut thread_rng())
+	}
+
+	/// Verify a proof of the circuit type `C`, whose proving key is cached.
+	pub fn verify<C: Circuit<Fr> + 'static>(
+		&self, pub_inps: &[&[Fr]], proof: &[u8],
+	) -> Result<bool, Error> {
+		let pk = self.get_pk::<C>().ok_or(Error::KeyNotFound)?;
+		verify(&self.params, pub_inps, proof, pk.get_vk())
+	}
+
+	/// Returns the number of proving keys generated.
+	pub fn num_keygens(&self) -> usize {
+		self.num_keygens
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::utils::{
+		generate_params,
+		test::{valid_circuit, EtCircuit},
+	};
+
+	#[test]
+	fn test_prover() {
+		let mut prover = Prover::new(generate_params(9));
+		assert!(matches!(
+			prover.verify::<EtCircuit>(&[], &[]),
+			Err(Error::KeyNotFound)
+		));
+
+		for _ in 0..2 {
+			let (circuit, m_hash) = valid_circuit(&mut thread_rng());
+			let proof = prover.prove(circuit, &[&[m_hash]]).unwrap();
+			assert!(prover.verify::<EtCircuit>(&[&[m_hash]], &proof).unwrap());
+		}
+		assert_eq!(prover.num_keygens(), 1);
+	}
+}
@@ -0,0 +1,254 @@
+//! Builders of the public inputs of the circuits, which know the layout of
+//! their instance columns.
+
+use crate::{
+	eddsa::native::PublicKey, error::Error, gadgets::instances::commit_instances,
+	params::RoundParams,
+};
+use halo2wrong::{curves::bn256::Fr, halo2::arithmetic::FieldExt};
+
+/// The public inputs of a circuit, one vector per instance column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Instances<F: FieldExt> {
+	columns: Vec<Vec<F>>,
+}
+
+impl<F: FieldExt> Instances<F> {
+	/// The public inputs of each instance column.
+	pub fn columns(&self) -> &[Vec<F>] {
+		&self.columns
+	}
+
+	/// The public inputs in the shape expected by `prove` and `verify`.
+	pub fn as_slices(&self) -> Vec<&[F]> {
+		self.columns.iter().map(Vec::as_slice).collect()
+	}
+
+	/// The public inputs in the shape expected by the `MockProver`.
+	pub fn to_vecs(&self) -> Vec<Vec<F>> {
+		self.columns.clone()
+	}
+}
+
+/// Builder of the public inputs of the `EigenTrustCircuit`: a single
+/// instance column holding the commitment to the hash of the message sent to
+/// the peer v.
+#[derive(Clone, Debug, Default)]
+pub struct EigenTrustPublicInputs<F: FieldExt> {
+	m_hash: Option<F>,
+}
+
+impl<F: FieldExt> EigenTrustPublicInputs<F> {
+	/// Start building the public inputs.
+	pub fn new() -> Self {
+		Self { m_hash: None }
+	}
+
+	/// Set the hash of the message: the Poseidon hash of the epoch, the
+	/// iteration, the opinion, the pubkey of the peer v and the pubkey of the
+	/// prover.
+	pub fn with_m_hash(mut self, m_hash: F) -> Self {
+		self.m_hash = Some(m_hash);
+		self
+	}
+
+	/// Lay out the public inputs in the instance columns, committing to them
+	/// with the Poseidon params `P` of the circuit.
+	pub fn build<P: RoundParams<F, 5>>(self) -> Result<Instances<F>, Error> {
+		let m_hash = self.m_hash.ok_or(Error::MissingPublicInput("m_hash"))?;
+		let commitment = commit_instances::<F, P>(&[m_hash]);
+		Ok(Instances { columns: vec![vec![commitment]] })
+	}
+}
+
+/// Builder of the public inputs of the `EigenTrustIterationCircuit` over `N`
+/// peers: a single instance column holding the commitment to the pre-trust
+/// scores followed by the global trust scores.
+#[derive(Clone, Debug, Default)]
+pub struct IterationPublicInputs<F: FieldExt, const N: usize> {
+	pre_trust: Option<[F; N]>,
+	scores: Option<[F; N]>,
+}
+
+impl<F: FieldExt, const N: usize> IterationPublicInputs<F, N> {
+	/// Start building the public inputs.
+	pub fn new() -> Self {
+		Self { pre_trust: None, scores: None }
+	}
+
+	/// Set the pre-trust scores the iterations start from.
+	pub fn with_pre_trust(mut self, pre_trust: [F; N]) -> Self {
+		self.pre_trust = Some(pre_trust);
+		self
+	}
+
+	/// Set the global trust scores resulting from the iterations.
+	pub fn with_scores(mut self, scores: [F; N]) -> Self {
+		self.scores = Some(scores);
+		self
+	}
+
+	/// Lay out the public inputs in the instance columns, committing to them
+	/// with the Poseidon params `P` of the circuit.
+	pub fn build<P: RoundParams<F, 5>>(self) -> Result<Instances<F>, Error> {
+		let pre_trust = self.pre_trust.ok_or(Error::MissingPublicInput("pre_trust"))?;
+		let scores = self.scores.ok_or(Error::MissingPublicInput("scores"))?;
+		let commitment = commit_instances::<F, P>(&[pre_trust, scores].concat());
+		Ok(Instances { columns: vec![vec![commitment]] })
+	}
+}
+
+/// Builder of the public inputs of the `OpinionCircuit` with `N` opinions: a
+/// single instance column holding the public key of the peer, the row
+/// commitment, the epoch, the nullifiers of the opinions, the public key of
+/// the recipient, the nonce and the encrypted scores.
+#[derive(Clone, Debug, Default)]
+pub struct OpinionPublicInputs<const N: usize> {
+	pk: Option<PublicKey>,
+	row_commitment: Option<Fr>,
+	epoch: Option<Fr>,
+	nullifiers: Option<[Fr; N]>,
+	recipient: Option<PublicKey>,
+	nonce: Option<Fr>,
+	ciphertext: Option<Vec<Fr>>,
+}
+
+impl<const N: usize> OpinionPublicInputs<N> {
+	/// Start building the public inputs.
+	pub fn new() -> Self {
+		Self {
+			pk: None,
+			row_commitment: None,
+			epoch: None,
+			nullifiers: None,
+			recipient: None,
+			nonce: None,
+			ciphertext: None,
+		}
+	}
+
+	/// Set the public key of the peer signing the opinions.
+	pub fn with_pk(mut self, pk: PublicKey) -> Self {
+		self.pk = Some(pk);
+		self
+	}
+
+	/// Set the commitment to the row, see `opinion::row_commitment`.
+	pub fn with_row_commitment(mut self, row_commitment: Fr) -> Self {
+		self.row_commitment = Some(row_commitment);
+		self
+	}
+
+	/// Set the epoch of the opinions.
+	pub fn with_epoch(mut self, epoch: Fr) -> Self {
+		self.epoch = Some(epoch);
+		self
+	}
+
+	/// Set the nullifiers of the opinions, in the order of the neighbors.
+	pub fn with_nullifiers(mut self, nullifiers: [Fr; N]) -> Self {
+		self.nullifiers = Some(nullifiers);
+		self
+	}
+
+	/// Set the public key of the recipient of the encrypted scores.
+	pub fn with_recipient(mut self, recipient: PublicKey) -> Self {
+		self.recipient = Some(recipient);
+		self
+	}
+
+	/// Set the nonce of the encryption.
+	pub fn with_nonce(mut self, nonce: Fr) -> Self {
+		self.nonce = Some(nonce);
+		self
+	}
+
+	/// Set the normalized scores encrypted for the recipient.
+	pub fn with_ciphertext(mut self, ciphertext: Vec<Fr>) -> Self {
+		self.ciphertext = Some(ciphertext);
+		self
+	}
+
+	/// Lay out the public inputs in the instance columns.
+	pub fn build(self) -> Result<Instances<Fr>, Error> {
+		let pk = self.pk.ok_or(Error::MissingPublicInput("pk"))?;
+		let row_commitment =
+			self.row_commitment.ok_or(Error::MissingPublicInput("row_commitment"))?;
+		let epoch = self.epoch.ok_or(Error::MissingPublicInput("epoch"))?;
+		let nullifiers = self.nullifiers.ok_or(Error::MissingPublicInput("nullifiers"))?;
+		let recipient = self.recipient.ok_or(Error::MissingPublicInput("recipient"))?;
+		let nonce = self.nonce.ok_or(Error::MissingPublicInput("nonce"))?;
+		let ciphertext = self.ciphertext.ok_or(Error::MissingPublicInput("ciphertext"))?;
+
+		let mut column = vec![pk.0.x, pk.0.y, row_commitment, epoch];
+		column.extend(nullifiers);
+		column.extend([recipient.0.x, recipient.0.y, nonce]);
+		column.extend(ciphertext);
+		Ok(Instances { columns: vec![column] })
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{eddsa::native::SecretKey, params::poseidon_bn254_5x5::Params};
+	use rand::thread_rng;
+
+	#[test]
+	fn test_eigen_trust_public_inputs() {
+		let m_hash = Fr::from(123);
+		let instances =
+			EigenTrustPublicInputs::new().with_m_hash(m_hash).build::<Params>().unwrap();
+		let commitment = commit_instances::<_, Params>(&[m_hash]);
+		assert_eq!(instances.as_slices(), vec![&[commitment][..]]);
+		assert_eq!(instances.to_vecs(), vec![vec![commitment]]);
+	}
+
+	#[test]
+	fn test_missing_public_input() {
+		let res = EigenTrustPublicInputs::<Fr>::new().build::<Params>();
+		assert!(matches!(res, Err(Error::MissingPublicInput("m_hash"))));
+	}
+
+	#[test]
+	fn test_iteration_public_inputs() {
+		let pre_trust = [Fr::from(1), Fr::from(2)];
+		let scores = [Fr::from(3), Fr::from(4)];
+		let instances = IterationPublicInputs::new()
+			.with_pre_trust(pre_trust)
+			.with_scores(scores)
+			.build::<Params>()
+			.unwrap();
+		let commitment = commit_instances::<_, Params>(&[pre_trust, scores].concat());
+		assert_eq!(instances.to_vecs(), vec![vec![commitment]]);
+
+		let res = IterationPublicInputs::new().with_pre_trust(pre_trust).build::<Params>();
+		assert!(matches!(res, Err(Error::MissingPublicInput("scores"))));
+	}
+
+	#[test]
+	fn test_opinion_public_inputs() {
+		let rng = &mut thread_rng();
+		let pk = SecretKey::random(rng).public();
+		let recipient = SecretKey::random(rng).public();
+		let nullifiers = [Fr::from(3), Fr::from(4)];
+		let ciphertext = vec![Fr::from(6), Fr::from(7), Fr::from(8)];
+		let builder = OpinionPublicInputs::new()
+			.with_pk(pk.clone())
+			.with_row_commitment(Fr::from(1))
+			.with_epoch(Fr::from(2))
+			.with_nullifiers(nullifiers)
+			.with_recipient(recipient.clone());
+
+		let res = builder.clone().build();
+		assert!(matches!(res, Err(Error::MissingPublicInput("nonce"))));
+
+		let instances =
+			builder.with_nonce(Fr::from(5)).with_ciphertext(ciphertext.clone()).build().unwrap();
+		let mut expected = vec![pk.0.x, pk.0.y, Fr::from(1), Fr::from(2)];
+		expected.extend(nullifiers);
+		expected.extend([recipient.0.x, recipient.0.y, Fr::from(5)]);
+		expected.extend(ciphertext);
+		assert_eq!(instances.to_vecs(), vec![expected]);
+	}
+}
@@ -1,2 +1,329 @@
 /// Native implementation
 pub mod native;
+
+use crate::params::RoundParams;
+use halo2wrong::halo2::{
+	arithmetic::FieldExt,
+	circuit::{AssignedCell, Layouter, Region, Value},
+	plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Selector, VirtualCells},
+	poly::Rotation,
+};
+use std::marker::PhantomData;
+
+#[derive(Clone, Debug)]
+/// Configuration elements for the circuit are defined here.
+pub struct RescuePrimeConfig<const WIDTH: usize> {
+	/// Configures columns for the state.
+	state: [Column<Advice>; WIDTH],
+	/// Configures columns for the inverse S-box of the state.
+	inverse: [Column<Advice>; WIDTH],
+	/// Configures columns for the round constants.
+	round_constants: [Column<Fixed>; WIDTH],
+	/// Configures a fixed boolean value for the S-box half of each round.
+	sbox_selector: Selector,
+	/// Configures a fixed boolean value for the inverse S-box half of each
+	/// round.
+	sbox_inv_selector: Selector,
+}
+
+/// Constructs a chip structure for the circuit.
+pub struct RescuePrimeChip<F: FieldExt, const WIDTH: usize, P>
+where
+	P: RoundParams<F, WIDTH>,
+{
+	/// Constructs a cell array for the inputs.
+	inputs: [AssignedCell<F, F>; WIDTH],
+	/// Constructs a phantom data for the parameters.
+	_params: PhantomData<P>,
+}
+
+impl<F: FieldExt, const WIDTH: usize, P> RescuePrimeChip<F, WIDTH, P>
+where
+	P: RoundParams<F, WIDTH>,
+{
+	/// Create a new chip.
+	pub fn new(inputs: [AssignedCell<F, F>; WIDTH]) -> Self {
+		RescuePrimeChip { inputs, _params: PhantomData }
+	}
+
+	/// Multiply the expressions by the MDS matrix, and add the round
+	/// constants of the current row.
+	fn apply_mds_expr(
+		v_cells: &mut VirtualCells<F>, exprs: &[Expression<F>; WIDTH], mds: &[[F; WIDTH]; WIDTH],
+		round_constants: &[Column<Fixed>; WIDTH],
+	) -> [Expression<F>; WIDTH] {
+		let mut new_exprs = [(); WIDTH].map(|_| Expression::Constant(F::zero()));
+		for i in 0..WIDTH {
+			new_exprs[i] = v_cells.query_fixed(round_constants[i], Rotation::cur());
+			for j in 0..WIDTH {
+				new_exprs[i] = new_exprs[i].clone() + exprs[j].clone() * mds[i][j];
+			}
+		}
+		new_exprs
+	}
+
+	/// Constrains the next row of the state to be equal to the expressions.
+	fn next_state_constraints(
+		v_cells: &mut VirtualCells<F>, state: &[Column<Advice>; WIDTH], s: Expression<F>,
+		exprs: [Expression<F>; WIDTH],
+	) -> Vec<Expression<F>> {
+		let mut constraints = Vec::with_capacity(WIDTH);
+		for i in 0..WIDTH {
+			let next_state = v_cells.query_advice(state[i], Rotation::next());
+			constraints.push(s.clone() * (exprs[i].clone() - next_state));
+		}
+		constraints
+	}
+
+	/// Make the circuit config.
+	pub fn configure(meta: &mut ConstraintSystem<F>) -> RescuePrimeConfig<WIDTH> {
+		let state = [(); WIDTH].map(|_| meta.advice_column());
+		let inverse = [(); WIDTH].map(|_| meta.advice_column());
+		let round_constants = [(); WIDTH].map(|_| meta.fixed_column());
+		let sbox_selector = meta.selector();
+		let sbox_inv_selector = meta.selector();
+
+		state.map(|c| meta.enable_equality(c));
+
+		let mds = P::mds();
+
+		meta.create_gate("sbox", |v_cells| {
+			let exprs = state.map(|c| P::sbox_expr(v_cells.query_advice(c, Rotation::cur())));
+			let exprs = Self::apply_mds_expr(v_cells, &exprs, &mds, &round_constants);
+			let s = v_cells.query_selector(sbox_selector);
+			Self::next_state_constraints(v_cells, &state, s, exprs)
+		});
+
+		meta.create_gate("sbox_inv", |v_cells| {
+			// The inverse S-box is given by the prover, and checked with the
+			// S-box, which is a permutation of the field.
+			let s = v_cells.query_selector(sbox_inv_selector);
+			let mut constraints = Vec::with_capacity(2 * WIDTH);
+			for i in 0..WIDTH {
+				let curr_state = v_cells.query_advice(state[i], Rotation::cur());
+				let inv = v_cells.query_advice(inverse[i], Rotation::cur());
+				constraints.push(s.clone() * (P::sbox_expr(inv) - curr_state));
+			}
+
+			let exprs = inverse.map(|c| v_cells.query_advice(c, Rotation::cur()));
+			let exprs = Self::apply_mds_expr(v_cells, &exprs, &mds, &round_constants);
+			constraints.extend(Self::next_state_constraints(v_cells, &state, s, exprs));
+			constraints
+		});
+
+		RescuePrimeConfig { state, inverse, round_constants, sbox_selector, sbox_inv_selector }
+	}
+
+	/// Assign the values to the columns in the given row.
+	fn assign_row(
+		columns: &[Column<Advice>; WIDTH], region: &mut Region<'_, F>, row: usize,
+		values: Value<[F; WIDTH]>,
+	) -> Result<[AssignedCell<F, F>; WIDTH], Error> {
+		let mut cells: [Option<AssignedCell<F, F>>; WIDTH] = [(); WIDTH].map(|_| None);
+		for i in 0..WIDTH {
+			cells[i] =
+				Some(region.assign_advice(|| "state", columns[i], row, || values.map(|s| s[i]))?);
+		}
+		Ok(cells.map(|item| item.unwrap()))
+	}
+
+	/// Assign the round constants to the given row.
+	fn assign_round_constants(
+		config: &RescuePrimeConfig<WIDTH>, region: &mut Region<'_, F>, row: usize,
+		consts: &[F; WIDTH],
+	) -> Result<(), Error> {
+		for i in 0..WIDTH {
+			region.assign_fixed(
+				|| "round_constant",
+				config.round_constants[i],
+				row,
+				|| Value::known(consts[i]),
+			)?;
+		}
+		Ok(())
+	}
+
+	/// Synthesize the circuit. Each round takes two rows, one for each of its
+	/// halves, like in `RescuePrime::permute`.
+	pub fn synthesize(
+		&self, config: RescuePrimeConfig<WIDTH>, mut layouter: impl Layouter<F>,
+	) -> Result<[AssignedCell<F, F>; WIDTH], Error> {
+		let full_rounds = P::full_rounds();
+		let mds = P::mds();
+		let round_constants = P::round_constants();
+
+		layouter.assign_region(
+			|| "rescue_prime",
+			|mut region: Region<'_, F>| {
+				let mut state = Value::known([F::zero(); WIDTH]);
+				let mut state_cells = self.inputs.clone();
+				for i in 0..WIDTH {
+					let cell =
+						self.inputs[i].copy_advice(|| "state", &mut region, config.state[i], 0)?;
+					state = state.zip(cell.value().cloned()).map(|(mut s, v)| {
+						s[i] = v;
+						s
+					});
+				}
+
+				for round in 0..full_rounds - 1 {
+					let row = 2 * round;
+
+					config.sbox_selector.enable(&mut region, row)?;
+					let consts = P::load_round_constants(round, &round_constants);
+					Self::assign_round_constants(&config, &mut region, row, &consts)?;
+					state = state.map(|s| {
+						let s = P::apply_mds(&s.map(P::sbox_f), &mds);
+						P::apply_round_constants(&s, &consts)
+					});
+					Self::assign_row(&config.state, &mut region, row + 1, state)?;
+
+					config.sbox_inv_selector.enable(&mut region, row + 1)?;
+					let consts = P::load_round_constants(round + 1, &round_constants);
+					Self::assign_round_constants(&config, &mut region, row + 1, &consts)?;
+					let inverse = state.map(|s| s.map(P::sbox_inv_f));
+					Self::assign_row(&config.inverse, &mut region, row + 1, inverse)?;
+					state = inverse.map(|s| {
+						let s = P::apply_mds(&s, &mds);
+						P::apply_round_constants(&s, &consts)
+					});
+					state_cells = Self::assign_row(&config.state, &mut region, row + 2, state)?;
+				}
+
+				Ok(state_cells)
+			},
+		)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		params::{hex_to_field, rescue_prime_bn254_5x5::Params},
+		rescue_prime::native::RescuePrime,
+		utils::{generate_params, prove_and_verify},
+	};
+	use halo2wrong::{
+		curves::bn256::{Bn256, Fr},
+		halo2::{
+			arithmetic::Field,
+			circuit::SimpleFloorPlanner,
+			dev::MockProver,
+			plonk::{Circuit, Instance},
+		},
+	};
+
+	type TestRescuePrimeChip = RescuePrimeChip<Fr, 5, Params>;
+
+	#[derive(Clone)]
+	struct RescuePrimeTesterConfig {
+		rescue_prime_config: RescuePrimeConfig<5>,
+		temp: Column<Advice>,
+		results: Column<Instance>,
+	}
+
+	struct RescuePrimeTester {
+		inputs: [Value<Fr>; 5],
+	}
+
+	impl RescuePrimeTester {
+		fn new(inputs: [Value<Fr>; 5]) -> Self {
+			Self { inputs }
+		}
+	}
+
+	impl Circuit<Fr> for RescuePrimeTester {
+		type Config = RescuePrimeTesterConfig;
+		type FloorPlanner = SimpleFloorPlanner;
+
+		fn without_witnesses(&self) -> Self {
+			Self { inputs: [Value::unknown(); 5] }
+		}
+
+		fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+			let rescue_prime_config = TestRescuePrimeChip::configure(meta);
+			let temp = meta.advice_column();
+			let results = meta.instance_column();
+
+			meta.enable_equality(temp);
+			meta.enable_equality(results);
+
+			Self::Config { rescue_prime_config, temp, results }
+		}
+
+		fn synthesize(
+			&self, config: Self::Config, mut layouter: impl Layouter<Fr>,
+		) -> Result<(), Error> {
+			let init_state = layouter.assign_region(
+				|| "load_state",
+				|mut region: Region<'_, Fr>| {
+					let mut state: [Option<AssignedCell<Fr, Fr>>; 5] = [(); 5].map(|_| None);
+					for i in 0..5 {
+						state[i] = Some(region.assign_advice(
+							|| "state",
+							config.temp,
+							i,
+							|| self.inputs[i],
+						)?);
+					}
+					Ok(state.map(|item| item.unwrap()))
+				},
+			)?;
+
+			let rescue_prime = TestRescuePrimeChip::new(init_state);
+			let result_state = rescue_prime.synthesize(
+				config.rescue_prime_config,
+				layouter.namespace(|| "rescue_prime"),
+			)?;
+			for i in 0..5 {
+				layouter.constrain_instance(result_state[i].cell(), config.results, i)?;
+			}
+			Ok(())
+		}
+	}
+
+	fn test_vector() -> ([Value<Fr>; 5], [Fr; 5]) {
+		let inputs: [Fr; 5] = [
+			"0x0000000000000000000000000000000000000000000000000000000000000000",
+			"0x0000000000000000000000000000000000000000000000000000000000000001",
+			"0x0000000000000000000000000000000000000000000000000000000000000002",
+			"0x0000000000000000000000000000000000000000000000000000000000000003",
+			"0x0000000000000000000000000000000000000000000000000000000000000004",
+		]
+		.map(|n| hex_to_field(n));
+
+		let outputs = RescuePrime::<Fr, 5, Params>::new(inputs).permute();
+
+		(inputs.map(Value::known), outputs)
+	}
+
+	#[test]
+	fn test_rescue_prime_x5_5() {
+		let (inputs, outputs) = test_vector();
+		let rescue_prime_tester = RescuePrimeTester::new(inputs);
+
+		let k = 6;
+		let prover = MockProver::run(k, &rescue_prime_tester, vec![outputs.to_vec()]).unwrap();
+		assert_eq!(prover.verify(), Ok(()));
+
+		let mut wrong_outputs = outputs;
+		wrong_outputs[0] += Fr::one();
+		let prover =
+			MockProver::run(k, &rescue_prime_tester, vec![wrong_outputs.to_vec()]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_rescue_prime_x5_5_production() {
+		let (inputs, outputs) = test_vector();
+		let rescue_prime_tester = RescuePrimeTester::new(inputs);
+
+		let k = 6;
+		let rng = &mut rand::thread_rng();
+		let params = generate_params(k);
+		let res =
+			prove_and_verify::<Bn256, _, _>(params, rescue_prime_tester, &[&outputs], rng).unwrap();
+		assert!(res);
+	}
+}
@@ -0,0 +1,432 @@
+//! Loading of the KZG parameters from the transcripts of the powers of tau
+//! ceremonies, instead of generating them with a known toxic waste.
+
+use crate::error::Error;
+use halo2wrong::{
+	curves::{
+		bn256::{Bn256, Fq, Fq2, G1Affine, G2Affine},
+		group::{prime::PrimeCurveAffine, GroupEncoding},
+		pairing::Engine,
+		CurveAffine,
+	},
+	halo2::{
+		arithmetic::{g_to_lagrange, Field},
+		poly::{commitment::Params, kzg::commitment::ParamsKZG},
+	},
+};
+use std::io::{self, Read};
+#[cfg(feature = "fs")]
+use std::{fs::File, io::BufReader};
+
+/// Number of bytes of an `Fq` element.
+const FQ_BYTES: usize = 32;
+
+/// Reads the parameters for `2^k` rows from a snarkjs `.ptau` file, e.g. one
+/// of the Perpetual Powers of Tau ceremony. The file has to contain at least
+/// `2^k` powers of tau.
+#[cfg(feature = "fs")]
+pub fn read_ptau(path: &str, k: u32) -> Result<ParamsKZG<Bn256>, Error> {
+	let file = BufReader::new(File::open(path)?);
+	ptau_from_reader(file, k).map_err(Error::ParamsDecode)
+}
+
+/// Reads the parameters for `2^k` rows from the bytes of a `.ptau` file.
+pub fn ptau_from_bytes(bytes: &[u8], k: u32) -> Result<ParamsKZG<Bn256>, Error> {
+	ptau_from_reader(bytes, k).map_err(Error::ParamsDecode)
+}
+
+/// Reads the parameters for `2^k` rows from the first transcript
+/// (`transcript00.dat`) of the Aztec Ignition ceremony, which holds enough
+/// powers of tau for `k <= 22`.
+#[cfg(feature = "fs")]
+pub fn read_ignition(path: &str, k: u32) -> Result<ParamsKZG<Bn256>, Error> {
+	let file = BufReader::new(File::open(path)?);
+	ignition_from_reader(file, k).map_err(Error::ParamsDecode)
+}
+
+/// Reads the parameters for `2^k` rows from the bytes of an Ignition
+/// transcript.
+pub fn ignition_from_bytes(bytes: &[u8], k: u32) -> Result<ParamsKZG<Bn256>, Error> {
+	ignition_from_reader(bytes, k).map_err(Error::ParamsDecode)
+}
+
+/// Reads the `.ptau` format: the "ptau" magic, the version and the number of
+/// sections, followed by the sections, each with a type and a size. The
+/// header section holds the field size, the modulus and the power, and the
+/// tau G1 and tau G2 sections hold the powers of tau, as little-endian
+/// coordinates in Montgomery form.
+fn ptau_from_reader<R: Read>(mut reader: R, k: u32) -> io::Result<ParamsKZG<Bn256>> {
+	let mut magic = [0u8; 4];
+	reader.read_exact(&mut magic)?;
+	if &magic != b"ptau" {
+		return Err(invalid_data("Not a ptau file"));
+	}
+	let _version = read_u32_le(&mut reader)?;
+	let num_sections = read_u32_le(&mut reader)?;
+
+	let n = 1usize << k;
+	let mut power = None;
+	let mut g = None;
+	let mut s_g2 = None;
+	for _ in 0..num_sections {
+		let section_type = read_u32_le(&mut reader)?;
+		let size = read_u64_le(&mut reader)?;
+		let mut section = (&mut reader).take(size);
+		match section_type {
+			// Header
+			1 => {
+				let n8 = read_u32_le(&mut section)?;
+				let mut modulus = vec![0u8; n8 as usize];
+				section.read_exact(&mut modulus)?;
+				if modulus != modulus_le_bytes() {
+					return Err(invalid_data("The ptau file is not over Bn256"));
+				}
+				let file_power = read_u32_le(&mut section)?;
+				if file_power < k {
+					return Err(invalid_data("The ptau file has too few powers of tau"));
+				}
+				power = Some(file_power);
+			},
+			// Powers of tau in G1
+			2 => {
+				if power.is_none() {
+					return Err(invalid_data("The ptau header is missing"));
+				}
+				let points = (0..n)
+					.map(|_| read_g1(&mut section, read_fq_montgomery))
+					.collect::<Result<_, _>>()?;
+				g = Some(points);
+			},
+			// Powers of tau in G2
+			3 => {
+				let _g2 = read_g2(&mut section, read_fq_montgomery)?;
+				s_g2 = Some(read_g2(&mut section, read_fq_montgomery)?);
+			},
+			_ => {},
+		}
+		// Skip the rest of the section.
+		io::copy(&mut section, &mut io::sink())?;
+	}
+
+	match (g, s_g2) {
+		(Some(g), Some(s_g2)) => build_params(k, g, s_g2),
+		_ => Err(invalid_data("The ptau file is missing the powers of tau")),
+	}
+}
+
+/// Reads the Ignition transcript format: a manifest of big-endian integers,
+/// followed by the G1 points `[x^i]_1` from `i = 1`, and the G2 point
+/// `[x]_2`. The coordinates are 4 big-endian limbs, the least significant
+/// first.
+fn ignition_from_reader<R: Read>(mut reader: R, k: u32) -> io::Result<ParamsKZG<Bn256>> {
+	let _transcript_number = read_u32_be(&mut reader)?;
+	let _total_transcripts = read_u32_be(&mut reader)?;
+	let _total_g1_points = read_u32_be(&mut reader)?;
+	let _total_g2_points = read_u32_be(&mut reader)?;
+	let num_g1_points = read_u32_be(&mut reader)? as usize;
+	let num_g2_points = read_u32_be(&mut reader)?;
+	let _start_from = read_u32_be(&mut reader)?;
+
+	let n = 1usize << k;
+	if num_g1_points < n - 1 || num_g2_points < 1 {
+		return Err(invalid_data("The transcript has too few powers of tau"));
+	}
+
+	// The generator is not included in the transcript.
+	let mut g = Vec::with_capacity(n);
+	g.push(G1Affine::generator());
+	for _ in 1..n {
+		g.push(read_g1(&mut reader, read_fq_limbs)?);
+	}
+	// Skip the rest of the G1 points.
+	let rest = ((num_g1_points - (n - 1)) * 2 * FQ_BYTES) as u64;
+	io::copy(&mut (&mut reader).take(rest), &mut io::sink())?;
+
+	let s_g2 = read_g2(&mut reader, read_fq_limbs)?;
+	build_params(k, g, s_g2)
+}
+
+/// Makes the parameters from the powers of tau in G1 and tau in G2, after
+/// checking that they are consistent. The parameters are serialized in the
+/// halo2 format and read back, since they can't be built from their parts.
+fn build_params(k: u32, g: Vec<G1Affine>, s_g2: G2Affine) -> io::Result<ParamsKZG<Bn256>> {
+	// e([tau]_1, [1]_2) = e([1]_1, [tau]_2)
+	let consistent = g[0] == G1Affine::generator()
+		&& g.get(1).map_or(true, |tau_g1| {
+			Bn256::pairing(tau_g1, &G2Affine::generator())
+				== Bn256::pairing(&G1Affine::generator(), &s_g2)
+		});
+	if !consistent {
+		return Err(invalid_data("The powers of tau are inconsistent"));
+	}
+
+	let g_lagrange = g_to_lagrange(g.iter().map(|p| p.to_curve()).collect(), k);
+
+	let mut buffer = Vec::new();
+	buffer.extend_from_slice(&k.to_le_bytes());
+	for p in g.iter().chain(g_lagrange.iter()) {
+		buffer.extend_from_slice(p.to_bytes().as_ref());
+	}
+	buffer.extend_from_slice(G2Affine::generator().to_bytes().as_ref());
+	buffer.extend_from_slice(s_g2.to_bytes().as_ref());
+	ParamsKZG::read(&mut &buffer[..])
+}
+
+/// Reads a G1 point as its x and y coordinates.
+fn read_g1<R: Read>(reader: &mut R, read_fq: fn(&mut R) -> io::Result<Fq>) -> io::Result<G1Affine> {
+	let x = read_fq(reader)?;
+	let y = read_fq(reader)?;
+	Option::from(G1Affine::from_xy(x, y)).ok_or_else(|| invalid_data("Invalid G1 point"))
+}
+
+/// Reads a G2 point as its x and y coordinates, each as `c0` and `c1`.
+fn read_g2<R: Read>(reader: &mut R, read_fq: fn(&mut R) -> io::Result<Fq>) -> io::Result<G2Affine> {
+	let x = Fq2 { c0: read_fq(reader)?, c1: read_fq(reader)? };
+	let y = Fq2 { c0: read_fq(reader)?, c1: read_fq(reader)? };
+	Option::from(G2Affine::from_xy(x, y)).ok_or_else(|| invalid_data("Invalid G2 point"))
+}
+
+/// Reads an `Fq` element in little-endian Montgomery form, as in `.ptau`.
+fn read_fq_montgomery<R: Read>(reader: &mut R) -> io::Result<Fq> {
+	let mut bytes = [0u8; FQ_BYTES];
+	reader.read_exact(&mut bytes)?;
+	let x: Fq = Option::from(Fq::from_bytes(&bytes)).ok_or_else(|| invalid_data("Invalid Fq"))?;
+	Ok(x * montgomery_r().invert().unwrap())
+}
+
+/// Reads an `Fq` element as 4 big-endian limbs, the least significant first,
+/// as in the Ignition transcripts.
+fn read_fq_limbs<R: Read>(reader: &mut R) -> io::Result<Fq> {
+	let mut bytes = [0u8; FQ_BYTES];
+	for limb in bytes.chunks_mut(8) {
+		let mut limb_bytes = [0u8; 8];
+		reader.read_exact(&mut limb_bytes)?;
+		limb.copy_from_slice(&u64::from_be_bytes(limb_bytes).to_le_bytes());
+	}
+	Option::from(Fq::from_bytes(&bytes)).ok_or_else(|| invalid_data("Invalid Fq"))
+}
+
+/// Returns the Montgomery constant `R = 2^256 mod q`.
+fn montgomery_r() -> Fq {
+	Fq::from(2).pow_vartime(&[256])
+}
+
+/// Returns the modulus of `Fq` as little-endian bytes.
+fn modulus_le_bytes() -> Vec<u8> {
+	let mut bytes =
+		hex::decode(&<Fq as halo2wrong::curves::FieldExt>::MODULUS[2..]).expect("Invalid modulus");
+	bytes.reverse();
+	bytes
+}
+
+/// Reads a little-endian `u32`.
+fn read_u32_le<R: Read>(reader: &mut R) -> io::Result<u32> {
+	let mut bytes = [0u8; 4];
+	reader.read_exact(&mut bytes)?;
+	Ok(u32::from_le_bytes(bytes))
+}
+
+/// Reads a big-endian `u32`.
+fn read_u32_be<R: Read>(reader: &mut R) -> io::Result<u32> {
+	let mut bytes = [0u8; 4];
+	reader.read_exact(&mut bytes)?;
+	Ok(u32::from_be_bytes(bytes))
+}
+
+/// Reads a little-endian `u64`.
+fn read_u64_le<R: Read>(reader: &mut R) -> io::Result<u64> {
+	let mut bytes = [0u8; 8];
+	reader.read_exact(&mut bytes)?;
+	Ok(u64::from_le_bytes(bytes))
+}
+
+/// Returns an error for a malformed file.
+fn invalid_data(msg: &str) -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use halo2wrong::curves::{
+		bn256::{Fr, G2},
+		group::ff::PrimeField,
+	};
+
+	/// Writes an `Fq` element in little-endian Montgomery form.
+	fn write_fq_montgomery(buffer: &mut Vec<u8>, x: Fq) {
+		buffer.extend_from_slice(&(x * montgomery_r()).to_bytes());
+	}
+
+	/// Writes an `Fq` element as 4 big-endian limbs.
+	fn write_fq_limbs(buffer: &mut Vec<u8>, x: Fq) {
+		for limb in x.to_bytes().chunks(8) {
+			let mut limb_bytes = [0u8; 8];
+			limb_bytes.copy_from_slice(limb);
+			buffer.extend_from_slice(&u64::from_le_bytes(limb_bytes).to_be_bytes());
+		}
+	}
+
+	fn write_g1(buffer: &mut Vec<u8>, p: G1Affine, write_fq: fn(&mut Vec<u8>, Fq)) {
+		let coords = p.coordinates().unwrap();
+		write_fq(buffer, *coords.x());
+		write_fq(buffer, *coords.y());
+	}
+
+	fn write_g2(buffer: &mut Vec<u8>, p: G2Affine, write_fq: fn(&mut Vec<u8>, Fq)) {
+		let coords = p.coordinates().unwrap();
+		for c in [coords.x().c0, coords.x().c1, coords.y().c0, coords.y().c1] {
+			write_fq(buffer, c);
+		}
+	}
+
+	fn powers_of_tau(tau: Fr, n: usize) -> Vec<Fr> {
+		(0..n)
+			.scan(Fr::one(), |acc, _| {
+				let power = *acc;
+				*acc *= tau;
+				Some(power)
+			})
+			.collect()
+	}
+
+	/// Makes a `.ptau` file with the given `power`, holding only the first two
+	/// powers of tau in G2.
+	fn make_ptau(tau: Fr, power: u32) -> Vec<u8> {
+		let mut header = Vec::new();
+		header.extend_from_slice(&(FQ_BYTES as u32).to_le_bytes());
+		header.extend_from_slice(&modulus_le_bytes());
+		header.extend_from_slice(&power.to_le_bytes());
+		header.extend_from_slice(&power.to_le_bytes());
+
+		let mut tau_g1 = Vec::new();
+		for x in powers_of_tau(tau, (1 << (power + 1)) - 1) {
+			write_g1(
+				&mut tau_g1,
+				(G1Affine::generator() * x).into(),
+				write_fq_montgomery,
+			);
+		}
+
+		let mut tau_g2 = Vec::new();
+		for x in powers_of_tau(tau, 2) {
+			write_g2(
+				&mut tau_g2,
+				(G2::generator() * x).into(),
+				write_fq_montgomery,
+			);
+		}
+
+		let mut file = b"ptau".to_vec();
+		file.extend_from_slice(&1u32.to_le_bytes());
+		file.extend_from_slice(&3u32.to_le_bytes());
+		for (section_type, section) in [(1u32, header), (2, tau_g1), (3, tau_g2)] {
+			file.extend_from_slice(&section_type.to_le_bytes());
+			file.extend_from_slice(&(section.len() as u64).to_le_bytes());
+			file.extend_from_slice(&section);
+		}
+		file
+	}
+
+	/// Makes an Ignition transcript with `num_g1_points` powers of tau.
+	fn make_ignition(tau: Fr, num_g1_points: u32) -> Vec<u8> {
+		let mut file = Vec::new();
+		for x in [0, 1, num_g1_points, 1, num_g1_points, 1, 0] {
+			file.extend_from_slice(&u32::to_be_bytes(x));
+		}
+		for x in &powers_of_tau(tau, num_g1_points as usize + 1)[1..] {
+			write_g1(
+				&mut file,
+				(G1Affine::generator() * x).into(),
+				write_fq_limbs,
+			);
+		}
+		write_g2(&mut file, (G2::generator() * tau).into(), write_fq_limbs);
+		file
+	}
+
+	/// Checks the powers of tau and their Lagrange basis against the ones
+	/// computed from `tau`, through the serialized parameters.
+	fn assert_params(params: &ParamsKZG<Bn256>, tau: Fr, k: u32) {
+		let n = 1usize << k;
+		assert_eq!(params.k(), k);
+		assert_eq!(params.s_g2(), (G2::generator() * tau).into());
+
+		let mut omega = Fr::root_of_unity();
+		for _ in k..Fr::S {
+			omega = omega.square();
+		}
+		let n_inv = Fr::from(n as u64).invert().unwrap();
+		let vanishing = tau.pow_vartime(&[n as u64]) - Fr::one();
+
+		let mut expected = Vec::new();
+		expected.extend_from_slice(&k.to_le_bytes());
+		for x in powers_of_tau(tau, n) {
+			expected
+				.extend_from_slice(G1Affine::from(G1Affine::generator() * x).to_bytes().as_ref());
+		}
+		// L_i(tau) = omega^i (tau^n - 1) / (n (tau - omega^i))
+		for omega_i in powers_of_tau(omega, n) {
+			let l_i = omega_i * vanishing * n_inv * (tau - omega_i).invert().unwrap();
+			expected
+				.extend_from_slice(G1Affine::from(G1Affine::generator() * l_i).to_bytes().as_ref());
+		}
+
+		let mut buffer = Vec::new();
+		params.write(&mut buffer).unwrap();
+		assert_eq!(buffer[..expected.len()], expected[..]);
+	}
+
+	#[test]
+	fn test_read_ptau() {
+		let tau = Fr::from(7);
+		let params = ptau_from_reader(&make_ptau(tau, 3)[..], 3).unwrap();
+		assert_params(&params, tau, 3);
+	}
+
+	#[test]
+	fn test_read_ptau_truncated() {
+		let tau = Fr::from(11);
+		let params = ptau_from_reader(&make_ptau(tau, 4)[..], 2).unwrap();
+		assert_params(&params, tau, 2);
+	}
+
+	#[test]
+	fn test_read_ptau_errors() {
+		let tau = Fr::from(7);
+		let file = make_ptau(tau, 2);
+		// Not enough powers of tau.
+		assert!(ptau_from_reader(&file[..], 3).is_err());
+		// Truncated file.
+		assert!(ptau_from_reader(&file[..file.len() - 1], 2).is_err());
+		// Wrong magic.
+		let mut wrong_magic = file.clone();
+		wrong_magic[0] = b'q';
+		assert!(ptau_from_reader(&wrong_magic[..], 2).is_err());
+		// Missing file.
+		#[cfg(feature = "fs")]
+		assert!(matches!(read_ptau("missing.ptau", 2), Err(Error::Io(_))));
+	}
+
+	#[test]
+	fn test_read_ignition() {
+		let tau = Fr::from(13);
+		let params = ignition_from_reader(&make_ignition(tau, 10)[..], 3).unwrap();
+		assert_params(&params, tau, 3);
+		assert!(ignition_from_reader(&make_ignition(tau, 6)[..], 3).is_err());
+	}
+
+	#[test]
+	fn test_inconsistent_powers() {
+		let mut file = make_ignition(Fr::from(13), 3);
+		// Replace [x]_2 with [x + 1]_2.
+		let g2_start = file.len() - 4 * FQ_BYTES;
+		file.truncate(g2_start);
+		write_g2(
+			&mut file,
+			(G2::generator() * Fr::from(14)).into(),
+			write_fq_limbs,
+		);
+		assert!(ignition_from_reader(&file[..], 2).is_err());
+	}
+}
@@ -0,0 +1,278 @@
+//! Circuit proving that the global trust score of a peer is at least a
+//! threshold, without revealing the score. The score is a leaf of a Merkle
+//! tree, whose root is signed by the attester.
+
+use crate::{
+	eddsa::{
+		native::{PublicKey, Signature},
+		EddsaChip, EddsaConfig, SignatureBits,
+	},
+	gadgets::fixed_point::{FixedPointChip, FixedPointConfig},
+	iteration::FRAC_BITS,
+	merkle::{native::MerkleProof, MerkleChip, MerkleConfig},
+	params::poseidon_bn254_5x5::Params,
+	poseidon::{native::Poseidon, PoseidonChip, PoseidonConfig},
+};
+use halo2wrong::{
+	curves::bn256::Fr,
+	halo2::{
+		arithmetic::Field,
+		circuit::{AssignedCell, Layouter, Region, SimpleFloorPlanner, Value},
+		plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance},
+	},
+};
+
+/// Returns the leaf of the score tree holding the `score` of the `peer`.
+pub fn score_leaf(peer: Fr, score: Fr) -> Fr {
+	Poseidon::<_, 5, Params>::new([peer, score, Fr::zero(), Fr::zero(), Fr::zero()]).permute()[0]
+}
+
+/// The halo2 columns config for the threshold circuit.
+#[derive(Clone)]
+pub struct ThresholdConfig {
+	eddsa: EddsaConfig,
+	merkle: MerkleConfig,
+	poseidon: PoseidonConfig<5>,
+	fixed_point: FixedPointConfig,
+	temp: Column<Advice>,
+	pub_ins: Column<Instance>,
+}
+
+/// Circuit proving that the peer has a score of at least the threshold in the
+/// score tree of depth `DEPTH`, whose root is signed by the attester. The
+/// public inputs are the peer, the threshold, and the x and y of the public
+/// key of the attester. The score is a fixed-point number with `FRAC_BITS`
+/// fractional bits, and the threshold must be below `2^NUM_BITS`.
+#[derive(Clone)]
+pub struct ThresholdCircuit<const DEPTH: usize> {
+	score: Fr,
+	path_bits: [Fr; DEPTH],
+	siblings: [Fr; DEPTH],
+	root: Fr,
+	signature: Signature,
+	attester: PublicKey,
+}
+
+impl<const DEPTH: usize> ThresholdCircuit<DEPTH> {
+	/// Create a new circuit from the score, its inclusion proof in the tree
+	/// with the `root` and the `signature` of the root by the `attester`.
+	/// Panics if the proof is not of the depth `DEPTH`.
+	pub fn new(
+		score: Fr, proof: &MerkleProof, root: Fr, signature: Signature, attester: PublicKey,
+	) -> Self {
+		assert_eq!(proof.siblings.len(), DEPTH);
+		let path_bits = std::array::from_fn(|i| Fr::from(((proof.index >> i) & 1) as u64));
+		let siblings = std::array::from_fn(|i| proof.siblings[i]);
+		Self { score, path_bits, siblings, root, signature, attester }
+	}
+}
+
+impl<const DEPTH: usize> Circuit<Fr> for ThresholdCircuit<DEPTH> {
+	type Config = ThresholdConfig;
+	type FloorPlanner = SimpleFloorPlanner;
+
+	fn without_witnesses(&self) -> Self {
+		self.clone()
+	}
+
+	fn configure(meta: &mut ConstraintSystem<Fr>) -> ThresholdConfig {
+		let eddsa = EddsaChip::configure(meta);
+		let merkle = MerkleChip::<DEPTH>::configure(meta);
+		let poseidon = PoseidonChip::<_, 5, Params>::configure(meta);
+		let fixed_point = FixedPointChip::<_, FRAC_BITS>::configure(meta);
+		let temp = meta.advice_column();
+		let fixed = meta.fixed_column();
+		let pub_ins = meta.instance_column();
+
+		meta.enable_equality(temp);
+		meta.enable_constant(fixed);
+		meta.enable_equality(pub_ins);
+
+		ThresholdConfig { eddsa, merkle, poseidon, fixed_point, temp, pub_ins }
+	}
+
+	fn synthesize(
+		&self, config: ThresholdConfig, mut layouter: impl Layouter<Fr>,
+	) -> Result<(), Error> {
+		let (zero, peer, threshold, pk_x, pk_y, score, big_r_x, big_r_y, s, path_bits, siblings) =
+			layouter.assign_region(
+				|| "temp",
+				|mut region: Region<'_, Fr>| {
+					let mut offset = 0;
+					let mut assign_instance = |region: &mut Region<'_, Fr>, name: &str| {
+						let cell = region.assign_advice_from_instance(
+							|| name,
+							config.pub_ins,
+							offset,
+							config.temp,
+							offset,
+						);
+						offset += 1;
+						cell
+					};
+					let peer = assign_instance(&mut region, "peer")?;
+					let threshold = assign_instance(&mut region, "threshold")?;
+					let pk_x = assign_instance(&mut region, "pk_x")?;
+					let pk_y = assign_instance(&mut region, "pk_y")?;
+
+					let mut offset = 4;
+					let mut assign = |region: &mut Region<'_, Fr>, name: &str, value: Fr| {
+						let cell = region.assign_advice(
+							|| name,
+							config.temp,
+							offset,
+							|| Value::known(value),
+						);
+						offset += 1;
+						cell
+					};
+					let score = assign(&mut region, "score", self.score)?;
+					let big_r_x = assign(&mut region, "big_r_x", self.signature.big_r.x)?;
+					let big_r_y = assign(&mut region, "big_r_y", self.signature.big_r.y)?;
+					let s = assign(&mut region, "s", self.signature.s)?;
+					let path_bits =
+						self.path_bits.try_map::<_, Result<AssignedCell<Fr, Fr>, Error>>(
+							|bit| assign(&mut region, "path_bit", bit),
+						)?;
+					let siblings =
+						self.siblings.try_map::<_, Result<AssignedCell<Fr, Fr>, Error>>(
+							|node| assign(&mut region, "sibling", node),
+						)?;
+
+					let zero = region.assign_advice_from_constant(
+						|| "zero",
+						config.temp,
+						offset,
+						Fr::zero(),
+					)?;
+
+					Ok((
+						zero, peer, threshold, pk_x, pk_y, score, big_r_x, big_r_y, s, path_bits,
+						siblings,
+					))
+				},
+			)?;
+
+		FixedPointChip::<_, FRAC_BITS>::load_table(
+			&config.fixed_point,
+			layouter.namespace(|| "range_table"),
+		)?;
+
+		// The score is in the tree
+		let inputs = [peer, score.clone(), zero.clone(), zero.clone(), zero];
+		let hasher = PoseidonChip::<_, 5, Params>::new(inputs);
+		let leaf = hasher.synthesize(config.poseidon, layouter.namespace(|| "leaf"))?[0].clone();
+		let merkle = MerkleChip::new(leaf, path_bits, siblings);
+		let root = merkle.synthesize(config.merkle, layouter.namespace(|| "merkle"))?;
+
+		// The root is signed by the attester
+		let bits = SignatureBits::new(&self.signature, &self.attester, self.root);
+		let eddsa = EddsaChip::from_bits(big_r_x, big_r_y, s, pk_x, pk_y, root, bits);
+		eddsa.synthesize(config.eddsa, layouter.namespace(|| "eddsa"))?;
+
+		// The score is at least the threshold
+		let is_above = FixedPointChip::<_, FRAC_BITS>::less_eq(
+			threshold,
+			score,
+			config.fixed_point,
+			layouter.namespace(|| "less_eq"),
+		)?;
+		layouter.assign_region(
+			|| "enforce_above",
+			|mut region: Region<'_, Fr>| {
+				let is_above = is_above.copy_advice(|| "is_above", &mut region, config.temp, 0)?;
+				region.constrain_constant(is_above.cell(), Fr::one())
+			},
+		)?;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		eddsa::native::{sign, SecretKey},
+		gadgets::fixed_point::to_fixed,
+		merkle::native::PoseidonMerkleTree,
+		utils::{generate_params, prove_and_verify},
+	};
+	use halo2wrong::{curves::bn256::Bn256, halo2::dev::MockProver};
+	use rand::thread_rng;
+
+	const DEPTH: usize = 2;
+
+	/// Make a circuit for the peer at `index` of a tree of four scores, and
+	/// its public inputs for the `threshold`.
+	fn setup(index: usize, threshold: f64) -> (ThresholdCircuit<DEPTH>, Vec<Fr>) {
+		let rng = &mut thread_rng();
+		let peers = [(); 4].map(|_| Fr::random(&mut *rng));
+		let scores = [0.1, 0.2, 0.3, 0.4].map(to_fixed::<Fr, FRAC_BITS>);
+		let leaves = peers.iter().zip(&scores).map(|(peer, score)| score_leaf(*peer, *score));
+		let tree = PoseidonMerkleTree::new(leaves.collect());
+
+		let sk = SecretKey::random(rng);
+		let attester = sk.public();
+		let signature = sign(&sk, &attester, tree.root());
+
+		let pub_ins =
+			vec![peers[index], to_fixed::<_, FRAC_BITS>(threshold), attester.0.x, attester.0.y];
+		let circuit = ThresholdCircuit::new(
+			scores[index],
+			&tree.proof(index),
+			tree.root(),
+			signature,
+			attester,
+		);
+		(circuit, pub_ins)
+	}
+
+	#[test]
+	fn test_threshold() {
+		let (circuit, pub_ins) = setup(2, 0.25);
+		let prover = MockProver::run(11, &circuit, vec![pub_ins]).unwrap();
+		assert_eq!(prover.verify(), Ok(()));
+	}
+
+	#[test]
+	fn test_threshold_equal() {
+		let (circuit, pub_ins) = setup(2, 0.3);
+		let prover = MockProver::run(11, &circuit, vec![pub_ins]).unwrap();
+		assert_eq!(prover.verify(), Ok(()));
+	}
+
+	#[test]
+	fn test_threshold_below() {
+		let (circuit, pub_ins) = setup(1, 0.25);
+		let prover = MockProver::run(11, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_threshold_wrong_peer() {
+		let (circuit, mut pub_ins) = setup(2, 0.25);
+		pub_ins[0] += Fr::one();
+		let prover = MockProver::run(11, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_threshold_wrong_attester() {
+		let (circuit, mut pub_ins) = setup(2, 0.25);
+		let other = SecretKey::random(&mut thread_rng()).public();
+		pub_ins[2] = other.0.x;
+		pub_ins[3] = other.0.y;
+		let prover = MockProver::run(11, &circuit, vec![pub_ins]).unwrap();
+		assert!(prover.verify().is_err());
+	}
+
+	#[test]
+	fn test_threshold_production() {
+		let (circuit, pub_ins) = setup(3, 0.25);
+		let params = generate_params(11);
+		let res = prove_and_verify::<Bn256, _, _>(params, circuit, &[&pub_ins], &mut thread_rng())
+			.unwrap();
+		assert!(res);
+	}
+}
@@ -1,20 +1,24 @@
 //! Helper functions for generating params, pk/vk pairs, creating and verifying
 //! proofs, etc.
 
-use crate::{params::RoundParams, EigenTrustCircuit};
+use crate::{error::Error, params::RoundParams, EigenTrustCircuit};
 use halo2wrong::{
-	curves::pairing::{Engine, MultiMillerLoop},
+	curves::{
+		bn256::{Bn256, Fq, Fr, G1Affine},
+		group::ff::PrimeField,
+		pairing::{Engine, MultiMillerLoop},
+		FieldExt,
+	},
 	halo2::{
 		arithmetic::Field,
 		plonk::{
-			create_proof, keygen_pk, keygen_vk, verify_proof, Circuit, Error, ProvingKey,
-			VerifyingKey,
+			create_proof, keygen_pk, keygen_vk, verify_proof, Circuit, ProvingKey, VerifyingKey,
 		},
 		poly::{
 			commitment::{CommitmentScheme, Params, ParamsProver},
 			kzg::{
 				commitment::{KZGCommitmentScheme, ParamsKZG},
-				multiopen::{ProverSHPLONK, VerifierSHPLONK},
+				multiopen::{ProverGWC, ProverSHPLONK, VerifierGWC, VerifierSHPLONK},
 				strategy::AccumulatorStrategy,
 			},
 			VerificationStrategy,
@@ -22,10 +26,37 @@ use halo2wrong::{
 		transcript::{
 			Blake2bRead, Blake2bWrite, Challenge255, TranscriptReadBuffer, TranscriptWriterBuffer,
 		},
+		SerdeFormat,
 	},
 };
-use rand::Rng;
-use std::{fmt::Debug, fs::write, io::Read, time::Instant};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use snark_verifier::{
+	loader::{evm::EvmLoader, native::NativeLoader},
+	pcs::kzg::{Bdfg21, Kzg},
+	system::halo2::{compile, transcript::evm::EvmTranscript, Config},
+	verifier::{self, PlonkVerifier},
+};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+use std::{collections::HashMap, fmt::Debug, io::Read, rc::Rc};
+#[cfg(feature = "fs")]
+use std::{
+	env,
+	fs::{create_dir_all, read, write},
+	path::PathBuf,
+};
+
+/// Magic bytes at the start of the key files.
+const KEY_MAGIC: [u8; 4] = *b"ETKY";
+/// Version of the key file format. Bump it whenever the circuits or the
+/// serialization change in a way that makes the stored keys invalid.
+pub const KEY_FORMAT_VERSION: u32 = 1;
+
+/// Plonk verifier over KZG with the SHPLONK (BDFG21) multiopen scheme.
+type Plonk = verifier::Plonk<Kzg<Bn256, Bdfg21>>;
 
 /// Convert bytes array to a wide representation of 64 bytes
 pub fn to_wide(b: &[u8]) -> [u8; 64] {
@@ -34,24 +65,295 @@ pub fn to_wide(b: &[u8]) -> [u8; 64] {
 	bytes
 }
 
+/// Encodes the bytes into field elements: the number of bytes, followed by
+/// the bytes split into chunks of 31, each read as a little-endian integer.
+/// Chunks of 31 bytes are always below the modulus, so the encoding is
+/// injective, and circuits can recompute it from the same field elements.
+pub fn bytes_to_fields(bytes: &[u8]) -> Vec<Fr> {
+	let mut fields = Vec::with_capacity(1 + (bytes.len() + 30) / 31);
+	fields.push(Fr::from(bytes.len() as u64));
+	fields.extend(bytes.chunks(31).map(|chunk| Fr::from_bytes_wide(&to_wide(chunk))));
+	fields
+}
+
 /// Generate parameters with polynomial degere = `k`.
 pub fn generate_params<E: MultiMillerLoop + Debug>(k: u32) -> ParamsKZG<E> {
 	ParamsKZG::<E>::new(k)
 }
 
-/// Write parameters to a file.
-pub fn write_params<E: MultiMillerLoop + Debug>(params: &ParamsKZG<E>, path: &str) {
+/// Truncate the parameters to `2^new_k` rows, e.g. to use a large ceremony
+/// SRS for a smaller circuit.
+pub fn downsize_params<E: MultiMillerLoop + Debug>(
+	params: &ParamsKZG<E>, new_k: u32,
+) -> Result<ParamsKZG<E>, Error> {
+	if new_k > params.k() {
+		return Err(Error::ParamsTooSmall);
+	}
+	let mut params = params.clone();
+	params.downsize(new_k);
+	Ok(params)
+}
+
+/// Serialize the parameters into bytes.
+pub fn params_to_bytes<E: MultiMillerLoop + Debug>(params: &ParamsKZG<E>) -> Vec<u8> {
 	let mut buffer: Vec<u8> = Vec::new();
-	params.write(&mut buffer).unwrap();
-	write(path, buffer).unwrap();
+	params.write(&mut buffer).expect("Writing to a Vec can't fail");
+	buffer
+}
+
+/// Deserialize the parameters from bytes, e.g. fetched by a browser.
+pub fn params_from_bytes<E: MultiMillerLoop + Debug>(bytes: &[u8]) -> Result<ParamsKZG<E>, Error> {
+	ParamsKZG::<E>::read(&mut &bytes[..]).map_err(Error::ParamsDecode)
+}
+
+/// Write parameters to a file.
+#[cfg(feature = "fs")]
+pub fn write_params<E: MultiMillerLoop + Debug>(
+	params: &ParamsKZG<E>, path: &str,
+) -> Result<(), Error> {
+	write(path, params_to_bytes(params))?;
+	Ok(())
 }
 
 /// Read parameters from a file.
-pub fn read_params<E: MultiMillerLoop + Debug>(path: &str) -> ParamsKZG<E> {
-	let mut buffer: Vec<u8> = Vec::new();
-	let mut file = std::fs::File::open(path).unwrap();
-	file.read_to_end(&mut buffer).unwrap();
-	ParamsKZG::<E>::read(&mut &buffer[..]).unwrap()
+#[cfg(feature = "fs")]
+pub fn read_params<E: MultiMillerLoop + Debug>(path: &str) -> Result<ParamsKZG<E>, Error> {
+	params_from_bytes(&read(path)?)
+}
+
+/// Kind of the key stored in a key file.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum KeyKind {
+	Verifying = 0,
+	Proving = 1,
+}
+
+/// Write the header of a key file: the magic bytes, the format version, the
+/// kind of the key, the circuit name and `k`.
+fn write_key_header(buffer: &mut Vec<u8>, kind: KeyKind, name: &str, k: u32) {
+	buffer.extend_from_slice(&KEY_MAGIC);
+	buffer.extend_from_slice(&KEY_FORMAT_VERSION.to_le_bytes());
+	buffer.push(kind as u8);
+	buffer.extend_from_slice(&(name.len() as u32).to_le_bytes());
+	buffer.extend_from_slice(name.as_bytes());
+	buffer.extend_from_slice(&k.to_le_bytes());
+}
+
+/// Read the header of a key file, and check that it matches the expected
+/// kind, circuit name, `k` and the current format version.
+fn read_key_header<Rd: Read>(
+	reader: &mut Rd, kind: KeyKind, name: &str, k: u32,
+) -> Result<(), Error> {
+	let mut magic = [0u8; 4];
+	reader.read_exact(&mut magic).map_err(Error::KeyDecode)?;
+	let version = read_key_u32(reader)?;
+	let mut file_kind = [0u8; 1];
+	reader.read_exact(&mut file_kind).map_err(Error::KeyDecode)?;
+	let name_len = read_key_u32(reader)?;
+	let mut file_name = Vec::new();
+	(&mut *reader)
+		.take(u64::from(name_len))
+		.read_to_end(&mut file_name)
+		.map_err(Error::KeyDecode)?;
+	let file_k = read_key_u32(reader)?;
+
+	if magic != KEY_MAGIC
+		|| version != KEY_FORMAT_VERSION
+		|| file_kind[0] != kind as u8
+		|| file_name != name.as_bytes()
+		|| file_k != k
+	{
+		return Err(Error::KeyMismatch);
+	}
+	Ok(())
+}
+
+/// Read a little-endian `u32` from the header of a key file.
+fn read_key_u32<Rd: Read>(reader: &mut Rd) -> Result<u32, Error> {
+	let mut bytes = [0u8; 4];
+	reader.read_exact(&mut bytes).map_err(Error::KeyDecode)?;
+	Ok(u32::from_le_bytes(bytes))
+}
+
+/// Serialize the proving key of the circuit `name`, with the key header.
+pub fn pk_to_bytes(pk: &ProvingKey<G1Affine>, name: &str) -> Vec<u8> {
+	let mut buffer = Vec::new();
+	write_key_header(
+		&mut buffer,
+		KeyKind::Proving,
+		name,
+		pk.get_vk().get_domain().k(),
+	);
+	pk.write(&mut buffer, SerdeFormat::RawBytes).expect("Writing to a Vec can't fail");
+	buffer
+}
+
+/// Deserialize the proving key of the circuit `name` with degree `k`.
+pub fn pk_from_bytes<C: Circuit<Fr>>(
+	bytes: &[u8], name: &str, k: u32,
+) -> Result<ProvingKey<G1Affine>, Error> {
+	let mut reader = bytes;
+	read_key_header(&mut reader, KeyKind::Proving, name, k)?;
+	ProvingKey::read::<_, C>(&mut reader, SerdeFormat::RawBytes).map_err(Error::KeyDecode)
+}
+
+/// Serialize the verifying key of the circuit `name`, with the key header.
+pub fn vk_to_bytes(vk: &VerifyingKey<G1Affine>, name: &str) -> Vec<u8> {
+	let mut buffer = Vec::new();
+	write_key_header(&mut buffer, KeyKind::Verifying, name, vk.get_domain().k());
+	vk.write(&mut buffer, SerdeFormat::RawBytes).expect("Writing to a Vec can't fail");
+	buffer
+}
+
+/// Deserialize the verifying key of the circuit `name` with degree `k`.
+pub fn vk_from_bytes<C: Circuit<Fr>>(
+	bytes: &[u8], name: &str, k: u32,
+) -> Result<VerifyingKey<G1Affine>, Error> {
+	let mut reader = bytes;
+	read_key_header(&mut reader, KeyKind::Verifying, name, k)?;
+	VerifyingKey::read::<_, C>(&mut reader, SerdeFormat::RawBytes).map_err(Error::KeyDecode)
+}
+
+/// Write the proving key of the circuit `name` to a file.
+#[cfg(feature = "fs")]
+pub fn write_pk(pk: &ProvingKey<G1Affine>, name: &str, path: &str) -> Result<(), Error> {
+	write(path, pk_to_bytes(pk, name))?;
+	Ok(())
+}
+
+/// Read the proving key of the circuit `name` with degree `k` from a file.
+#[cfg(feature = "fs")]
+pub fn read_pk<C: Circuit<Fr>>(
+	path: &str, name: &str, k: u32,
+) -> Result<ProvingKey<G1Affine>, Error> {
+	pk_from_bytes::<C>(&read(path)?, name, k)
+}
+
+/// Write the verifying key of the circuit `name` to a file.
+#[cfg(feature = "fs")]
+pub fn write_vk(vk: &VerifyingKey<G1Affine>, name: &str, path: &str) -> Result<(), Error> {
+	write(path, vk_to_bytes(vk, name))?;
+	Ok(())
+}
+
+/// Read the verifying key of the circuit `name` with degree `k` from a file.
+#[cfg(feature = "fs")]
+pub fn read_vk<C: Circuit<Fr>>(
+	path: &str, name: &str, k: u32,
+) -> Result<VerifyingKey<G1Affine>, Error> {
+	vk_from_bytes::<C>(&read(path)?, name, k)
+}
+
+/// In-memory cache of the parameters, keyed by the polynomial degree `k`.
+/// Optionally persists the parameters to a directory, so they are reused
+/// across restarts.
+pub struct ParamsCache<E: MultiMillerLoop + Debug> {
+	params: HashMap<u32, ParamsKZG<E>>,
+	#[cfg(feature = "fs")]
+	dir: Option<PathBuf>,
+	num_generated: usize,
+}
+
+impl<E: MultiMillerLoop + Debug> ParamsCache<E> {
+	/// Create a cache that keeps the parameters only in memory.
+	pub fn new() -> Self {
+		Self {
+			params: HashMap::new(),
+			#[cfg(feature = "fs")]
+			dir: None,
+			num_generated: 0,
+		}
+	}
+
+	/// Create a cache that also persists the parameters in `dir`.
+	#[cfg(feature = "fs")]
+	pub fn with_dir(dir: impl Into<PathBuf>) -> Self {
+		Self { params: HashMap::new(), dir: Some(dir.into()), num_generated: 0 }
+	}
+
+	/// The default directory for persisting the parameters:
+	/// `~/.eigen-trust`.
+	#[cfg(feature = "fs")]
+	pub fn default_dir() -> Option<PathBuf> {
+		env::var_os("HOME").map(|home| PathBuf::from(home).join(".eigen-trust"))
+	}
+
+	/// Returns the parameters for `k`, reading them from the disk or
+	/// generating them, if they are not cached yet.
+	pub fn get_or_generate(&mut self, k: u32) -> Result<&ParamsKZG<E>, Error> {
+		if !self.params.contains_key(&k) {
+			let params = match self.read_persisted(k)? {
+				Some(params) => params,
+				None => {
+					let params = generate_params(k);
+					self.num_generated += 1;
+					self.persist(k, &params)?;
+					params
+				},
+			};
+			self.params.insert(k, params);
+		}
+		Ok(&self.params[&k])
+	}
+
+	/// The path of the persisted parameters for `k`.
+	#[cfg(feature = "fs")]
+	fn path(&self, k: u32) -> Option<PathBuf> {
+		self.dir.as_ref().map(|dir| dir.join(format!("srs-k{}.bin", k)))
+	}
+
+	/// Read the persisted parameters for `k`, if there are any.
+	#[cfg(feature = "fs")]
+	fn read_persisted(&self, k: u32) -> Result<Option<ParamsKZG<E>>, Error> {
+		match self.path(k) {
+			Some(path) if path.exists() => read_params(&path.to_string_lossy()).map(Some),
+			_ => Ok(None),
+		}
+	}
+
+	/// Without the filesystem, the parameters are never persisted.
+	#[cfg(not(feature = "fs"))]
+	fn read_persisted(&self, _: u32) -> Result<Option<ParamsKZG<E>>, Error> {
+		Ok(None)
+	}
+
+	/// Persist the parameters for `k`, if the cache has a directory.
+	#[cfg(feature = "fs")]
+	fn persist(&self, k: u32, params: &ParamsKZG<E>) -> Result<(), Error> {
+		if let Some(path) = self.path(k) {
+			if let Some(dir) = path.parent() {
+				create_dir_all(dir)?;
+			}
+			write_params(params, &path.to_string_lossy())?;
+		}
+		Ok(())
+	}
+
+	/// Without the filesystem, the parameters are never persisted.
+	#[cfg(not(feature = "fs"))]
+	fn persist(&self, _: u32, _: &ParamsKZG<E>) -> Result<(), Error> {
+		Ok(())
+	}
+
+	/// Returns the number of times the parameters were generated from
+	/// scratch.
+	pub fn num_generated(&self) -> usize {
+		self.num_generated
+	}
+}
+
+impl<E: MultiMillerLoop + Debug> Default for ParamsCache<E> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Fingerprint of the circuit with the verifying key `vk`: the Blake2b hash
+/// of its pinned representation (the domain, the constraint system, the fixed
+/// commitments and the permutation), which seeds the transcript of its
+/// proofs. Circuits with the same hash accept the same proofs.
+pub fn vk_hash(vk: &VerifyingKey<G1Affine>) -> [u8; 32] {
+	vk.transcript_repr().to_repr()
 }
 
 /// Make a new circuit with the inputs being random values.
@@ -84,8 +386,10 @@ pub fn random_circuit<
 pub fn keygen<E: MultiMillerLoop + Debug, C: Circuit<E::Scalar>>(
 	params: &ParamsKZG<E>, circuit: &C,
 ) -> Result<ProvingKey<<E as Engine>::G1Affine>, Error> {
-	let vk = keygen_vk::<<E as Engine>::G1Affine, ParamsKZG<E>, _>(params, circuit)?;
-	let pk = keygen_pk::<<E as Engine>::G1Affine, ParamsKZG<E>, _>(params, vk, circuit)?;
+	let vk = keygen_vk::<<E as Engine>::G1Affine, ParamsKZG<E>, _>(params, circuit)
+		.map_err(Error::Keygen)?;
+	let pk = keygen_pk::<<E as Engine>::G1Affine, ParamsKZG<E>, _>(params, vk, circuit)
+		.map_err(Error::Keygen)?;
 
 	Ok(pk)
 }
@@ -102,40 +406,209 @@ pub fn finalize_verify<
 	v.finalize()
 }
 
+/// The multiopen scheme used to open the polynomial commitments of a proof.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MultiopenScheme {
+	/// The SHPLONK (BDFG21) scheme, with smaller proofs.
+	#[default]
+	Shplonk,
+	/// The GWC19 scheme.
+	Gwc,
+}
+
 /// Make a proof for generic circuit.
 pub fn prove<E: MultiMillerLoop + Debug, C: Circuit<E::Scalar>, R: Rng + Clone>(
 	params: &ParamsKZG<E>, circuit: C,
 	pub_inps: &[&[<KZGCommitmentScheme<E> as CommitmentScheme>::Scalar]],
 	pk: &ProvingKey<E::G1Affine>, rng: &mut R,
+) -> Result<Vec<u8>, Error> {
+	prove_with_scheme(params, circuit, pub_inps, pk, MultiopenScheme::Shplonk, rng)
+}
+
+/// Make a proof for generic circuit, opening the commitments with `scheme`.
+pub fn prove_with_scheme<E: MultiMillerLoop + Debug, C: Circuit<E::Scalar>, R: Rng + Clone>(
+	params: &ParamsKZG<E>, circuit: C,
+	pub_inps: &[&[<KZGCommitmentScheme<E> as CommitmentScheme>::Scalar]],
+	pk: &ProvingKey<E::G1Affine>, scheme: MultiopenScheme, rng: &mut R,
 ) -> Result<Vec<u8>, Error> {
 	let mut transcript = Blake2bWrite::<_, E::G1Affine, Challenge255<_>>::init(vec![]);
-	create_proof::<KZGCommitmentScheme<E>, ProverSHPLONK<_>, _, _, _, _>(
+	match scheme {
+		MultiopenScheme::Shplonk => {
+			create_proof::<KZGCommitmentScheme<E>, ProverSHPLONK<_>, _, _, _, _>(
+				params,
+				pk,
+				&[circuit],
+				&[pub_inps],
+				rng.clone(),
+				&mut transcript,
+			)
+		},
+		MultiopenScheme::Gwc => create_proof::<KZGCommitmentScheme<E>, ProverGWC<_>, _, _, _, _>(
+			params,
+			pk,
+			&[circuit],
+			&[pub_inps],
+			rng.clone(),
+			&mut transcript,
+		),
+	}
+	.map_err(|e| Error::from_plonk(e, Error::Proving))?;
+
+	let proof = transcript.finalize();
+	Ok(proof)
+}
+
+/// Make a proof with the ChaCha20 rng seeded with `seed`, so the same inputs
+/// give byte-identical proofs across runs and platforms.
+pub fn prove_deterministic<E: MultiMillerLoop + Debug, C: Circuit<E::Scalar>>(
+	params: &ParamsKZG<E>, circuit: C,
+	pub_inps: &[&[<KZGCommitmentScheme<E> as CommitmentScheme>::Scalar]],
+	pk: &ProvingKey<E::G1Affine>, seed: u64,
+) -> Result<Vec<u8>, Error> {
+	let mut rng = ChaCha20Rng::seed_from_u64(seed);
+	prove(params, circuit, pub_inps, pk, &mut rng)
+}
+
+/// Make proofs for a batch of circuits in parallel, sharing the params and
+/// the proving key. The proof at `index` is made deterministically with the
+/// seed `seed + index`, wrapping around, so the batch is reproducible.
+/// Panics if there are not as many public inputs as circuits.
+#[cfg(feature = "parallel")]
+pub fn prove_batch_parallel<C: Circuit<Fr> + Send>(
+	params: &ParamsKZG<Bn256>, circuits: Vec<C>, pub_inps: Vec<Vec<Vec<Fr>>>,
+	pk: &ProvingKey<G1Affine>, seed: u64,
+) -> Vec<Result<Vec<u8>, Error>> {
+	assert_eq!(circuits.len(), pub_inps.len());
+	circuits
+		.into_par_iter()
+		.zip(pub_inps.into_par_iter())
+		.enumerate()
+		.map(|(i, (circuit, inps))| {
+			let inps: Vec<&[Fr]> = inps.iter().map(|column| column.as_slice()).collect();
+			prove_deterministic(params, circuit, &inps, pk, seed.wrapping_add(i as u64))
+		})
+		.collect()
+}
+
+/// Verify a proof for generic circuit.
+pub fn verify<E: MultiMillerLoop + Debug>(
+	params: &ParamsKZG<E>, pub_inps: &[&[<KZGCommitmentScheme<E> as CommitmentScheme>::Scalar]],
+	proof: &[u8], vk: &VerifyingKey<E::G1Affine>,
+) -> Result<bool, Error> {
+	verify_from_reader(params, pub_inps, proof, vk)
+}
+
+/// Verify a proof for generic circuit, reading the proof directly from
+/// `reader` instead of a buffer.
+pub fn verify_from_reader<E: MultiMillerLoop + Debug, Rd: Read>(
+	params: &ParamsKZG<E>, pub_inps: &[&[<KZGCommitmentScheme<E> as CommitmentScheme>::Scalar]],
+	reader: Rd, vk: &VerifyingKey<E::G1Affine>,
+) -> Result<bool, Error> {
+	verify_from_reader_with_scheme(params, pub_inps, reader, vk, MultiopenScheme::Shplonk)
+}
+
+/// Verify a proof for generic circuit, made with the multiopen `scheme`.
+pub fn verify_with_scheme<E: MultiMillerLoop + Debug>(
+	params: &ParamsKZG<E>, pub_inps: &[&[<KZGCommitmentScheme<E> as CommitmentScheme>::Scalar]],
+	proof: &[u8], vk: &VerifyingKey<E::G1Affine>, scheme: MultiopenScheme,
+) -> Result<bool, Error> {
+	verify_from_reader_with_scheme(params, pub_inps, proof, vk, scheme)
+}
+
+/// Verify a proof for generic circuit, made with the multiopen `scheme`,
+/// reading the proof directly from `reader`.
+pub fn verify_from_reader_with_scheme<E: MultiMillerLoop + Debug, Rd: Read>(
+	params: &ParamsKZG<E>, pub_inps: &[&[<KZGCommitmentScheme<E> as CommitmentScheme>::Scalar]],
+	reader: Rd, vk: &VerifyingKey<E::G1Affine>, scheme: MultiopenScheme,
+) -> Result<bool, Error> {
+	let strategy = AccumulatorStrategy::<E>::new(params);
+	let mut transcript = Blake2bRead::<_, E::G1Affine, Challenge255<_>>::init(reader);
+	let res = match scheme {
+		MultiopenScheme::Shplonk => verify_proof::<
+			KZGCommitmentScheme<E>,
+			VerifierSHPLONK<E>,
+			_,
+			_,
+			_,
+		>(params, vk, strategy, &[pub_inps], &mut transcript)
+		.map(finalize_verify),
+		MultiopenScheme::Gwc => verify_proof::<KZGCommitmentScheme<E>, VerifierGWC<E>, _, _, _>(
+			params,
+			vk,
+			strategy,
+			&[pub_inps],
+			&mut transcript,
+		)
+		.map(VerificationStrategy::<'_, _, VerifierGWC<E>>::finalize),
+	};
+
+	res.map_err(|e| Error::from_plonk(e, Error::Verification))
+}
+
+/// A proof to verify in a batch, with its public inputs and verifying key.
+pub type BatchItem<'a, E> = (
+	&'a [u8],
+	&'a [&'a [<E as Engine>::Scalar]],
+	&'a VerifyingKey<<E as Engine>::G1Affine>,
+);
+
+/// Verify a batch of independent proofs, each with its public inputs and
+/// verifying key, with a single final pairing check. Returns false if any of
+/// the proofs is invalid.
+pub fn verify_batch<E: MultiMillerLoop + Debug>(
+	params: &ParamsKZG<E>, items: &[BatchItem<E>],
+) -> Result<bool, Error> {
+	let mut strategy = AccumulatorStrategy::<E>::new(params);
+	for (proof, pub_inps, vk) in items {
+		let mut transcript = Blake2bRead::<_, E::G1Affine, Challenge255<_>>::init(*proof);
+		strategy = verify_proof::<KZGCommitmentScheme<E>, VerifierSHPLONK<E>, _, _, _>(
+			params,
+			vk,
+			strategy,
+			&[*pub_inps],
+			&mut transcript,
+		)
+		.map_err(|e| Error::from_plonk(e, Error::Verification))?;
+	}
+
+	Ok(finalize_verify(strategy))
+}
+
+/// Make a proof with the Keccak256 (`EvmTranscript`) transcript, which can be
+/// verified by the EVM verifier, see [`gen_evm_verifier`].
+pub fn prove_evm<C: Circuit<Fr>, R: Rng + Clone>(
+	params: &ParamsKZG<Bn256>, circuit: C, pub_inps: &[&[Fr]], pk: &ProvingKey<G1Affine>,
+	rng: &mut R,
+) -> Result<Vec<u8>, Error> {
+	let mut transcript = EvmTranscript::<G1Affine, NativeLoader, _, _>::init(vec![]);
+	create_proof::<KZGCommitmentScheme<Bn256>, ProverSHPLONK<_>, _, _, _, _>(
 		params,
 		pk,
 		&[circuit],
 		&[pub_inps],
 		rng.clone(),
 		&mut transcript,
-	)?;
+	)
+	.map_err(|e| Error::from_plonk(e, Error::Proving))?;
 
 	let proof = transcript.finalize();
 	Ok(proof)
 }
 
-/// Verify a proof for generic circuit.
-pub fn verify<E: MultiMillerLoop + Debug>(
-	params: &ParamsKZG<E>, pub_inps: &[&[<KZGCommitmentScheme<E> as CommitmentScheme>::Scalar]],
-	proof: &[u8], vk: &VerifyingKey<E::G1Affine>,
+/// Verify a proof made with the Keccak256 transcript by [`prove_evm`].
+pub fn verify_evm(
+	params: &ParamsKZG<Bn256>, pub_inps: &[&[Fr]], proof: &[u8], vk: &VerifyingKey<G1Affine>,
 ) -> Result<bool, Error> {
-	let strategy = AccumulatorStrategy::<E>::new(params);
-	let mut transcript = Blake2bRead::<_, E::G1Affine, Challenge255<_>>::init(proof);
-	let output = verify_proof::<KZGCommitmentScheme<E>, VerifierSHPLONK<E>, _, _, _>(
+	let strategy = AccumulatorStrategy::<Bn256>::new(params);
+	let mut transcript = EvmTranscript::<G1Affine, NativeLoader, _, _>::init(proof);
+	let output = verify_proof::<KZGCommitmentScheme<Bn256>, VerifierSHPLONK<Bn256>, _, _, _>(
 		params,
 		vk,
 		strategy,
 		&[pub_inps],
 		&mut transcript,
-	)?;
+	)
+	.map_err(|e| Error::from_plonk(e, Error::Verification))?;
 
 	Ok(finalize_verify(output))
 }
@@ -146,11 +619,447 @@ pub fn prove_and_verify<E: MultiMillerLoop + Debug, C: Circuit<E::Scalar>, R: Rn
 	pub_inps: &[&[<KZGCommitmentScheme<E> as CommitmentScheme>::Scalar]], rng: &mut R,
 ) -> Result<bool, Error> {
 	let pk = keygen(&params, &circuit)?;
+	// The clock is not available on wasm32-unknown-unknown.
+	#[cfg(not(target_arch = "wasm32"))]
 	let start = Instant::now();
 	let proof = prove(&params, circuit, pub_inps, &pk, rng)?;
-	let end = start.elapsed();
-	print!("Proving time: {:?}", end);
+	#[cfg(not(target_arch = "wasm32"))]
+	print!("Proving time: {:?}", start.elapsed());
 	let res = verify(&params, pub_inps, &proof[..], pk.get_vk())?;
 
 	Ok(res)
 }
+
+/// Generate the verifier for the circuit with the verifying key `vk` on the
+/// EVM loader, which records the verifier code. The verifier starts its
+/// transcript from the constant [`vk_hash`] of the circuit, like the native
+/// one, so it only accepts the proofs of that circuit. The constant is checked
+/// against `vk_hash` before generating the code.
+fn evm_verifier_loader(
+	params: &ParamsKZG<Bn256>, vk: &VerifyingKey<G1Affine>, num_instances: Vec<usize>,
+) -> Result<Rc<EvmLoader>, Error> {
+	let svk = params.get_g()[0].into();
+	let dk = (params.g2(), params.s_g2()).into();
+	let protocol = compile(
+		params,
+		vk,
+		Config::kzg().with_num_instance(num_instances.clone()),
+	);
+	let initial_state = protocol.transcript_initial_state.map(|state| state.to_repr());
+	if initial_state != Some(vk_hash(vk)) {
+		return Err(Error::VkHashMismatch);
+	}
+
+	let loader = EvmLoader::new::<Fq, Fr>();
+	let protocol = protocol.loaded(&loader);
+	let mut transcript = EvmTranscript::<_, Rc<EvmLoader>, _, _>::new(&loader);
+
+	let instances = transcript.load_instances(num_instances);
+	let proof = Plonk::read_proof(&svk, &protocol, &instances, &mut transcript)
+		.map_err(Error::EvmVerifier)?;
+	Plonk::verify(&svk, &dk, &protocol, &instances, &proof).map_err(Error::EvmVerifier)?;
+
+	Ok(loader)
+}
+
+/// Generate the deployment bytecode of an EVM verifier contract for the
+/// circuit with the verifying key `vk`. `num_instances` holds the number of
+/// public inputs in each instance column. The verifier expects the proof to be
+/// created with the Keccak256 (`EvmTranscript`) transcript, see [`prove_evm`],
+/// for the circuit with the [`vk_hash`] of `vk`.
+pub fn gen_evm_verifier(
+	params: &ParamsKZG<Bn256>, vk: &VerifyingKey<G1Affine>, num_instances: Vec<usize>,
+) -> Result<Vec<u8>, Error> {
+	let loader = evm_verifier_loader(params, vk, num_instances)?;
+	Ok(loader.deployment_code())
+}
+
+/// Generate the source code of the EVM verifier contract, in Yul, for the
+/// circuit with the verifying key `vk`, e.g. to audit it or to deploy it with
+/// `solc`. The arguments are the same as for [`gen_evm_verifier`]. The code
+/// starts with a comment holding the [`vk_hash`] of the circuit.
+pub fn gen_evm_verifier_code(
+	params: &ParamsKZG<Bn256>, vk: &VerifyingKey<G1Affine>, num_instances: Vec<usize>,
+) -> Result<String, Error> {
+	let loader = evm_verifier_loader(params, vk, num_instances)?;
+	Ok(format!(
+		"// Verifier of the circuit with the vk hash 0x{}\n{}",
+		hex::encode(vk_hash(vk)),
+		loader.yul_code()
+	))
+}
+
+/// Encode the public inputs and the proof into the calldata accepted by the
+/// EVM verifier. Every public input is written as a 32 byte big-endian word,
+/// column by column, followed by the raw proof bytes.
+pub fn encode_calldata(pub_inps: &[&[Fr]], proof: &[u8]) -> Vec<u8> {
+	let mut calldata = Vec::new();
+	for value in pub_inps.iter().flat_map(|column| column.iter()) {
+		let mut bytes = value.to_repr();
+		bytes.reverse();
+		calldata.extend_from_slice(&bytes);
+	}
+	calldata.extend_from_slice(proof);
+	calldata
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+	use super::*;
+	use crate::{
+		gadgets::instances::commit_instances,
+		params::{hex_to_field, poseidon_bn254_5x5::Params},
+		poseidon::native::Poseidon,
+	};
+	use rand::thread_rng;
+	use std::io::Cursor;
+
+	pub(crate) const SIZE: usize = 256;
+	pub(crate) const NUM_BOOTSTRAP: usize = 12;
+	/// The EigenTrust circuit made by `valid_circuit`.
+	pub(crate) type EtCircuit = EigenTrustCircuit<Fr, SIZE, NUM_BOOTSTRAP, Params>;
+
+	/// Make a circuit together with its valid public input. Shared with the
+	/// tests of the prover and the aggregator.
+	pub(crate) fn valid_circuit<R: Rng>(rng: &mut R) -> (EtCircuit, Fr) {
+		let pubkey_v = Fr::random(&mut *rng);
+		let epoch = Fr::one();
+		let iter = Fr::one();
+		let sk = [(); 4].map(|_| Fr::random(&mut *rng));
+		let op_ji = [(); SIZE].map(|_| Fr::one());
+		let c_v = Fr::one();
+		let bootstrap_pubkeys = [(); NUM_BOOTSTRAP].map(|_| Fr::random(&mut *rng));
+		let bootstrap_score = Fr::from(100000000);
+
+		let circuit = EigenTrustCircuit::new(
+			pubkey_v, epoch, iter, sk, op_ji, c_v, bootstrap_pubkeys, bootstrap_score,
+		);
+
+		let inputs_sk = [Fr::zero(), sk[0], sk[1], sk[2], sk[3]];
+		let pubkey_i = Poseidon::<_, 5, Params>::new(inputs_sk).permute()[0];
+		let inputs = [epoch, iter, Fr::from(256), pubkey_v, pubkey_i];
+		let m_hash = Poseidon::<_, 5, Params>::new(inputs).permute()[0];
+
+		(circuit, commit_instances::<_, Params>(&[m_hash]))
+	}
+
+	#[test]
+	fn test_bytes_to_fields() {
+		assert_eq!(bytes_to_fields(&[]), vec![Fr::from(0)]);
+		assert_eq!(bytes_to_fields(&[1, 2]), vec![
+			Fr::from(2),
+			Fr::from(0x0201)
+		]);
+
+		let bytes = [0xffu8; 32];
+		let fields = bytes_to_fields(&bytes);
+		assert_eq!(fields.len(), 3);
+		assert_eq!(fields[0], Fr::from(32));
+		assert_eq!(fields[2], Fr::from(0xff));
+	}
+
+	#[test]
+	fn test_params_cache() {
+		let mut cache = ParamsCache::<Bn256>::new();
+		cache.get_or_generate(10).unwrap();
+		cache.get_or_generate(10).unwrap();
+		assert_eq!(cache.num_generated(), 1);
+
+		cache.get_or_generate(4).unwrap();
+		assert_eq!(cache.num_generated(), 2);
+	}
+
+	#[cfg(feature = "fs")]
+	#[test]
+	fn test_params_cache_dir() {
+		let dir = env::temp_dir().join("eigen-trust-params-cache");
+		let _ = std::fs::remove_dir_all(&dir);
+
+		let mut cache1 = ParamsCache::<Bn256>::with_dir(&dir);
+		let mut bytes1 = Vec::new();
+		cache1.get_or_generate(4).unwrap().write(&mut bytes1).unwrap();
+		assert_eq!(cache1.num_generated(), 1);
+
+		let mut cache2 = ParamsCache::<Bn256>::with_dir(&dir);
+		let mut bytes2 = Vec::new();
+		cache2.get_or_generate(4).unwrap().write(&mut bytes2).unwrap();
+		assert_eq!(cache2.num_generated(), 0);
+
+		assert_eq!(bytes1, bytes2);
+	}
+
+	#[cfg(feature = "fs")]
+	#[test]
+	fn test_read_params_errors() {
+		let dir = env::temp_dir().join("eigen-trust-read-params");
+		create_dir_all(&dir).unwrap();
+
+		let missing = dir.join("missing.bin");
+		let res = read_params::<Bn256>(&missing.to_string_lossy());
+		assert!(matches!(res, Err(Error::Io(_))));
+
+		let corrupt = dir.join("corrupt.bin");
+		write(&corrupt, [1u8, 2]).unwrap();
+		let res = read_params::<Bn256>(&corrupt.to_string_lossy());
+		assert!(matches!(res, Err(Error::ParamsDecode(_))));
+	}
+
+	#[test]
+	fn test_vk_hash() {
+		let rng = &mut thread_rng();
+		let params = generate_params::<Bn256>(9);
+		let (circuit, _) = valid_circuit(rng);
+		let hash = |params: &ParamsKZG<Bn256>| vk_hash(keygen(params, &circuit).unwrap().get_vk());
+		assert_eq!(hash(&params), hash(&params));
+
+		// Another setup gives another circuit.
+		let other_params = generate_params::<Bn256>(9);
+		assert_ne!(hash(&params), hash(&other_params));
+	}
+
+	#[test]
+	fn test_downsize_params() {
+		let params = generate_params::<Bn256>(6);
+		let small = downsize_params(&params, 4).unwrap();
+		assert_eq!(small.k(), 4);
+		assert_eq!(small.get_g(), &params.get_g()[..16]);
+		assert_eq!(small.s_g2(), params.s_g2());
+		assert!(matches!(
+			downsize_params(&params, 7),
+			Err(Error::ParamsTooSmall)
+		));
+	}
+
+	#[test]
+	fn test_instance_mismatch() {
+		let k = 9;
+		let rng = &mut thread_rng();
+		let params = generate_params::<Bn256>(k);
+		let (circuit, m_hash) = valid_circuit(rng);
+		let pk = keygen(&params, &circuit).unwrap();
+		let proof = prove(&params, circuit, &[&[m_hash]], &pk, rng).unwrap();
+
+		let res = verify(&params, &[], &proof, pk.get_vk());
+		assert!(matches!(res, Err(Error::InstanceMismatch)));
+	}
+
+	#[cfg(feature = "fs")]
+	#[test]
+	fn test_write_read_keys() {
+		let k = 9;
+		let rng = &mut thread_rng();
+		let params = generate_params::<Bn256>(k);
+		let (circuit, m_hash) = valid_circuit(rng);
+		let pk = keygen(&params, &circuit).unwrap();
+
+		let dir = env::temp_dir().join("eigen-trust-keys");
+		create_dir_all(&dir).unwrap();
+		let pk_path = dir.join("et.pk").to_string_lossy().to_string();
+		let vk_path = dir.join("et.vk").to_string_lossy().to_string();
+		write_pk(&pk, "eigen-trust", &pk_path).unwrap();
+		write_vk(pk.get_vk(), "eigen-trust", &vk_path).unwrap();
+
+		let read_pk = read_pk::<EtCircuit>(&pk_path, "eigen-trust", k).unwrap();
+		let read_vk = read_vk::<EtCircuit>(&vk_path, "eigen-trust", k).unwrap();
+
+		let proof = prove(&params, circuit, &[&[m_hash]], &read_pk, rng).unwrap();
+		let res = verify(&params, &[&[m_hash]], &proof, &read_vk).unwrap();
+		assert!(res);
+
+		let res = read_pk::<EtCircuit>(&pk_path, "other", k);
+		assert!(matches!(res, Err(Error::KeyMismatch)));
+		let res = read_vk::<EtCircuit>(&vk_path, "eigen-trust", k + 1);
+		assert!(matches!(res, Err(Error::KeyMismatch)));
+		let res = read_pk::<EtCircuit>(&vk_path, "eigen-trust", k);
+		assert!(matches!(res, Err(Error::KeyMismatch)));
+	}
+
+	#[test]
+	fn test_prove_deterministic() {
+		let k = 9;
+		let rng = &mut thread_rng();
+		let params = generate_params::<Bn256>(k);
+		let (circuit, m_hash) = valid_circuit(rng);
+		let pk = keygen(&params, &circuit).unwrap();
+
+		let prove =
+			|seed| prove_deterministic(&params, circuit.clone(), &[&[m_hash]], &pk, seed).unwrap();
+		let proof = prove(1);
+		assert_eq!(proof, prove(1));
+		assert_ne!(proof, prove(2));
+		assert!(verify(&params, &[&[m_hash]], &proof, pk.get_vk()).unwrap());
+	}
+
+	#[test]
+	fn test_verify_batch() {
+		let k = 9;
+		let rng = &mut thread_rng();
+		let params = generate_params::<Bn256>(k);
+		let (circuits, m_hashes): (Vec<_>, Vec<_>) = (0..3).map(|_| valid_circuit(rng)).unzip();
+		let pk = keygen(&params, &circuits[0]).unwrap();
+		let proofs: Vec<Vec<u8>> = circuits
+			.into_iter()
+			.zip(&m_hashes)
+			.map(|(circuit, m_hash)| prove(&params, circuit, &[&[*m_hash]], &pk, rng).unwrap())
+			.collect();
+
+		let pub_inps: Vec<[&[Fr]; 1]> =
+			m_hashes.iter().map(|m_hash| [std::slice::from_ref(m_hash)]).collect();
+		let items: Vec<_> = proofs
+			.iter()
+			.zip(&pub_inps)
+			.map(|(proof, inps)| (proof.as_slice(), &inps[..], pk.get_vk()))
+			.collect();
+		assert!(verify_batch(&params, &items).unwrap());
+		assert!(verify_batch::<Bn256>(&params, &[]).unwrap());
+
+		// Swap the public inputs of two proofs.
+		let mut wrong_items = items.clone();
+		wrong_items[0].1 = &pub_inps[1][..];
+		wrong_items[1].1 = &pub_inps[0][..];
+		assert!(!matches!(verify_batch(&params, &wrong_items), Ok(true)));
+	}
+
+	#[test]
+	fn test_prove_verify_gwc() {
+		let k = 9;
+		let rng = &mut thread_rng();
+		let params = generate_params::<Bn256>(k);
+		let (circuit, m_hash) = valid_circuit(rng);
+		let pk = keygen(&params, &circuit).unwrap();
+		let proof = prove_with_scheme(
+			&params,
+			circuit.clone(),
+			&[&[m_hash]],
+			&pk,
+			MultiopenScheme::Gwc,
+			rng,
+		)
+		.unwrap();
+
+		let res = verify_with_scheme(
+			&params,
+			&[&[m_hash]],
+			&proof,
+			pk.get_vk(),
+			MultiopenScheme::Gwc,
+		)
+		.unwrap();
+		assert!(res);
+
+		// The GWC proof doesn't verify as a SHPLONK proof, and vice versa.
+		let res = verify(&params, &[&[m_hash]], &proof, pk.get_vk());
+		assert!(!matches!(res, Ok(true)));
+		let shplonk_proof = prove(&params, circuit, &[&[m_hash]], &pk, rng).unwrap();
+		let res = verify_with_scheme(
+			&params,
+			&[&[m_hash]],
+			&shplonk_proof,
+			pk.get_vk(),
+			MultiopenScheme::Gwc,
+		);
+		assert!(!matches!(res, Ok(true)));
+	}
+
+	#[test]
+	fn test_prove_verify_evm() {
+		let k = 9;
+		let rng = &mut thread_rng();
+		let params = generate_params::<Bn256>(k);
+		let (circuit, m_hash) = valid_circuit(rng);
+		let pk = keygen(&params, &circuit).unwrap();
+		let proof = prove_evm(&params, circuit, &[&[m_hash]], &pk, rng).unwrap();
+
+		let res = verify_evm(&params, &[&[m_hash]], &proof, pk.get_vk()).unwrap();
+		assert!(res);
+
+		// The proof doesn't verify with the Blake2b transcript.
+		let res = verify(&params, &[&[m_hash]], &proof, pk.get_vk());
+		assert!(!matches!(res, Ok(true)));
+	}
+
+	#[test]
+	fn test_gen_evm_verifier() {
+		let k = 9;
+		let rng = &mut thread_rng();
+		let params = generate_params::<Bn256>(k);
+		let circuit = random_circuit::<Bn256, _, SIZE, NUM_BOOTSTRAP, Params>(rng);
+		let pk = keygen(&params, &circuit).unwrap();
+
+		let bytecode = gen_evm_verifier(&params, pk.get_vk(), vec![1]).unwrap();
+		assert!(!bytecode.is_empty());
+
+		let code = gen_evm_verifier_code(&params, pk.get_vk(), vec![1]).unwrap();
+		assert!(code.contains("object"));
+		assert!(code.contains(&hex::encode(vk_hash(pk.get_vk()))));
+	}
+
+	#[test]
+	fn test_encode_calldata() {
+		let rng = &mut thread_rng();
+		let pub_inps = [Fr::random(rng), Fr::from(256)];
+		let proof = vec![7u8; 96];
+
+		let calldata = encode_calldata(&[&pub_inps], &proof);
+		assert_eq!(calldata.len(), pub_inps.len() * 32 + proof.len());
+
+		// Decode the words back and compare them with the public inputs.
+		for (i, value) in pub_inps.iter().enumerate() {
+			let word = &calldata[i * 32..(i + 1) * 32];
+			let decoded: Fr = hex_to_field(&format!("0x{}", hex::encode(word)));
+			assert_eq!(&decoded, value);
+		}
+		assert_eq!(&calldata[pub_inps.len() * 32..], &proof[..]);
+	}
+
+	#[test]
+	fn test_verify_from_reader() {
+		let k = 9;
+		let rng = &mut thread_rng();
+		let params = generate_params::<Bn256>(k);
+		let (circuit, m_hash) = valid_circuit(rng);
+		let pk = keygen(&params, &circuit).unwrap();
+		let proof = prove(&params, circuit, &[&[m_hash]], &pk, rng).unwrap();
+
+		let res = verify(&params, &[&[m_hash]], &proof, pk.get_vk()).unwrap();
+		let res_reader =
+			verify_from_reader(&params, &[&[m_hash]], Cursor::new(&proof), pk.get_vk()).unwrap();
+		assert!(res);
+		assert_eq!(res, res_reader);
+	}
+
+	#[cfg(feature = "parallel")]
+	#[test]
+	fn test_prove_batch_parallel() {
+		let k = 9;
+		// The seeds of the batch wrap around.
+		let seed = u64::MAX - 1;
+		let rng = &mut thread_rng();
+		let params = generate_params::<Bn256>(k);
+
+		let (circuits, m_hashes): (Vec<_>, Vec<_>) = (0..4).map(|_| valid_circuit(rng)).unzip();
+		let pk = keygen(&params, &circuits[0]).unwrap();
+		let pub_inps = m_hashes.iter().map(|m_hash| vec![vec![*m_hash]]).collect();
+
+		let proofs = prove_batch_parallel(&params, circuits.clone(), pub_inps, &pk, seed);
+		assert_eq!(proofs.len(), 4);
+
+		for (i, (proof, (circuit, m_hash))) in
+			proofs.into_iter().zip(circuits.into_iter().zip(m_hashes)).enumerate()
+		{
+			let proof = proof.unwrap();
+			let res = verify(&params, &[&[m_hash]], &proof, pk.get_vk()).unwrap();
+			assert!(res);
+
+			let seq_proof = prove_deterministic(
+				&params,
+				circuit,
+				&[&[m_hash]],
+				&pk,
+				seed.wrapping_add(i as u64),
+			)
+			.unwrap();
+			assert_eq!(proof, seq_proof);
+		}
+	}
+}
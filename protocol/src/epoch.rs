@@ -3,15 +3,22 @@
 //! - Seconds until next epoch
 //! - Current epoch
 //! - Current timestamp
+//! - Running a network in epochs
 
+use crate::EigenError;
+use eigen_trust_circuit::{
+	halo2wrong::halo2::arithmetic::FieldExt,
+	iteration::{native::SparseTrust, network::Network},
+};
 use std::{
+	collections::BTreeMap,
 	fmt::{Display, Formatter, Result as FmtResult},
 	time::{SystemTime, UNIX_EPOCH},
 };
 
 /// Epoch struct, which is a wrapper around epoch number and timestamp.
 // TODO: add epoch_number and timestamp as private fields
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Epoch(pub u64);
 
 impl Display for Epoch {
@@ -72,9 +79,223 @@ impl Epoch {
 	}
 }
 
+/// Number of finished epochs whose results are kept by default.
+pub const DEFAULT_RETENTION: usize = 64;
+
+/// The local trust matrix and the global trust scores of a finished epoch.
+#[derive(Clone, Debug)]
+pub struct EpochResult<F: FieldExt> {
+	/// The epoch.
+	pub epoch: Epoch,
+	/// Snapshot of the local trust matrix at the end of the epoch.
+	pub local_trust: SparseTrust<F>,
+	/// The global trust scores at the end of the epoch.
+	pub scores: Vec<F>,
+	/// Whether the scores converged within the iteration limit.
+	pub converged: bool,
+}
+
+/// Runs a network in epochs of `interval` seconds. The opinions can change
+/// until the `deadline`, in seconds from the start of the epoch, and are
+/// frozen afterwards. When the epoch ends, the network is converged and its
+/// result is kept, up to the `retention` most recent results.
+pub struct EpochManager<F: FieldExt> {
+	/// The network.
+	network: Network<F>,
+	/// Duration of an epoch, in seconds.
+	interval: u64,
+	/// Time from the start of an epoch until the opinions are frozen, in
+	/// seconds.
+	deadline: u64,
+	/// The epoch in progress.
+	current: Epoch,
+	/// Results of the finished epochs.
+	results: BTreeMap<Epoch, EpochResult<F>>,
+	/// Maximum number of results kept.
+	retention: usize,
+}
+
+impl<F: FieldExt> EpochManager<F> {
+	/// Create a new manager for the `network`, starting with the epoch of the
+	/// `timestamp`. Fails if the `interval` is zero or shorter than the
+	/// `deadline`.
+	pub fn new(
+		network: Network<F>, interval: u64, deadline: u64, timestamp: u64,
+	) -> Result<Self, EigenError> {
+		if interval == 0 || deadline > interval {
+			return Err(EigenError::InvalidEpochInterval);
+		}
+		let current = Epoch(timestamp / interval);
+		Ok(Self {
+			network,
+			interval,
+			deadline,
+			current,
+			results: BTreeMap::new(),
+			retention: DEFAULT_RETENTION,
+		})
+	}
+
+	/// Keeps only the `retention` most recent results, dropping the older ones.
+	pub fn with_retention(mut self, retention: usize) -> Self {
+		self.retention = retention;
+		self.prune();
+		self
+	}
+
+	/// Returns the network.
+	pub fn network(&self) -> &Network<F> {
+		&self.network
+	}
+
+	/// Returns the epoch in progress.
+	pub fn current_epoch(&self) -> Epoch {
+		self.current
+	}
+
+	/// Checks if the opinions are frozen at the `timestamp`, i.e. it is past
+	/// the deadline of the epoch in progress.
+	pub fn is_frozen(&self, timestamp: u64) -> bool {
+		let start = self.current.0.saturating_mul(self.interval);
+		timestamp >= start.saturating_add(self.deadline)
+	}
+
+	/// Sets the trust of the peer `i` in the peer `j` at the `timestamp`. See
+	/// `Network::update_opinion`.
+	pub fn update_opinion(
+		&mut self, timestamp: u64, i: usize, j: usize, c_ij: F,
+	) -> Result<(), EigenError> {
+		if self.is_frozen(timestamp) {
+			return Err(EigenError::OpinionsFrozen);
+		}
+		self.network.update_opinion(i, j, c_ij);
+		Ok(())
+	}
+
+	/// Finishes the epoch in progress if it ended before the `timestamp`,
+	/// converging the network once, see `Network::reconverge`, and moves to
+	/// the epoch of the `timestamp`. The opinions can't change while no epoch
+	/// is in progress, so the epochs skipped in between would have the same
+	/// result, and none is recorded for them. Returns the finished epoch.
+	pub fn advance(&mut self, timestamp: u64, delta: f64, max_iterations: usize) -> Option<Epoch> {
+		let target = Epoch(timestamp / self.interval);
+		if self.current >= target {
+			return None;
+		}
+		self.network.reconverge(delta, max_iterations);
+		let finished = self.current;
+		let result = EpochResult {
+			epoch: finished,
+			local_trust: self.network.local_trust().clone(),
+			scores: self.network.scores().to_vec(),
+			converged: self.network.is_converged(),
+		};
+		self.results.insert(finished, result);
+		self.prune();
+		self.current = target;
+		Some(finished)
+	}
+
+	/// Drops the oldest results beyond the retention.
+	fn prune(&mut self) {
+		while self.results.len() > self.retention {
+			let oldest = *self.results.keys().next().unwrap();
+			self.results.remove(&oldest);
+		}
+	}
+
+	/// Returns the result of the finished `epoch`.
+	pub fn result(&self, epoch: Epoch) -> Option<&EpochResult<F>> {
+		self.results.get(&epoch)
+	}
+
+	/// Returns the results of the finished epochs, from the oldest one.
+	pub fn results(&self) -> impl Iterator<Item = &EpochResult<F>> {
+		self.results.values()
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use eigen_trust_circuit::{
+		halo2wrong::curves::bn256::Fr,
+		iteration::{native::SparseTrustBuilder, FRAC_BITS},
+	};
+
+	fn network() -> Network<Fr> {
+		let one = Fr::from(1 << FRAC_BITS);
+		let mut builder = SparseTrustBuilder::new(2);
+		builder.set(0, 1, one).set(1, 0, one);
+		Network::new(
+			builder.build(),
+			vec![one, Fr::zero()],
+			Fr::from(1 << (FRAC_BITS - 2)),
+		)
+	}
+
+	#[test]
+	fn test_epoch_manager() {
+		let one = Fr::from(1 << FRAC_BITS);
+		let mut manager = EpochManager::new(network(), 10, 8, 25).unwrap();
+		assert_eq!(manager.current_epoch(), Epoch(2));
+		assert_eq!(manager.advance(29, 1e-3, 100), None);
+
+		// Opinions are frozen after the deadline.
+		assert_eq!(manager.update_opinion(27, 1, 1, one), Ok(()));
+		assert_eq!(
+			manager.update_opinion(28, 1, 0, one),
+			Err(EigenError::OpinionsFrozen)
+		);
+		assert_eq!(
+			manager.network().local_trust().get(1, 1),
+			Fr::from(1 << (FRAC_BITS - 1))
+		);
+
+		// The skipped epoch 3 gets no result.
+		assert_eq!(manager.advance(45, 1e-3, 100), Some(Epoch(2)));
+		assert_eq!(manager.current_epoch(), Epoch(4));
+		assert!(!manager.is_frozen(45));
+		let result = manager.result(Epoch(2)).unwrap();
+		assert_eq!(result.epoch, Epoch(2));
+		assert_eq!(result.scores, manager.network().scores());
+		assert!(result.converged);
+		assert_eq!(result.local_trust.num_opinions(), 3);
+		assert_eq!(manager.results().count(), 1);
+		assert!(manager.result(Epoch(3)).is_none());
+		assert!(manager.result(Epoch(4)).is_none());
+	}
+
+	#[test]
+	fn test_epoch_manager_catch_up() {
+		// A long downtime converges the network once, and jumps to the epoch
+		// of the timestamp.
+		let mut manager = EpochManager::new(network(), 10, 8, 0).unwrap();
+		assert_eq!(manager.advance(u64::MAX, 1e-3, 100), Some(Epoch(0)));
+		assert_eq!(manager.current_epoch(), Epoch(u64::MAX / 10));
+		assert!(!manager.is_frozen(u64::MAX - 1));
+		assert_eq!(manager.results().count(), 1);
+
+		// Only the most recent results are kept.
+		let mut manager = EpochManager::new(network(), 10, 8, 0).unwrap().with_retention(2);
+		for timestamp in [10, 20, 30] {
+			manager.advance(timestamp, 1e-3, 100);
+		}
+		let epochs: Vec<Epoch> = manager.results().map(|result| result.epoch).collect();
+		assert_eq!(epochs, vec![Epoch(1), Epoch(2)]);
+	}
+
+	#[test]
+	fn test_epoch_manager_invalid_interval() {
+		assert_eq!(
+			EpochManager::new(network(), 0, 0, 25).err(),
+			Some(EigenError::InvalidEpochInterval)
+		);
+		assert_eq!(
+			EpochManager::new(network(), 10, 11, 25).err(),
+			Some(EigenError::InvalidEpochInterval)
+		);
+	}
 
 	#[test]
 	fn test_display() {
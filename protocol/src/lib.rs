@@ -34,7 +34,7 @@ mod behaviour;
 /// The module for global constants.
 pub mod constants;
 /// The module for epoch-related calculations, like seconds until the next
-/// epoch, current epoch, etc.
+/// epoch, current epoch, etc., and for running a network in epochs.
 mod epoch;
 /// The module for the node setup, running the main loop, and handling network
 /// events.
@@ -49,7 +49,7 @@ mod peer;
 mod utils;
 
 pub use eigen_trust_circuit;
-pub use epoch::Epoch;
+pub use epoch::{Epoch, EpochManager, EpochResult, DEFAULT_RETENTION};
 pub use libp2p::{identity::Keypair, Multiaddr, PeerId};
 pub use log::LevelFilter;
 pub use node::Node;
@@ -98,6 +98,10 @@ pub enum EigenError {
 	PubkeyNotFound,
 	/// Neighbour not found,
 	NeighbourNotFound,
+	/// Opinions are frozen after the deadline of the epoch.
+	OpinionsFrozen,
+	/// The epoch interval is zero, or shorter than the deadline.
+	InvalidEpochInterval,
 	/// Unknown error.
 	Unknown,
 }
@@ -124,6 +128,8 @@ impl From<EigenError> for u8 {
 			EigenError::OpinionNotFound => 16,
 			EigenError::PubkeyNotFound => 17,
 			EigenError::NeighbourNotFound => 18,
+			EigenError::OpinionsFrozen => 19,
+			EigenError::InvalidEpochInterval => 20,
 			EigenError::Unknown => 255,
 		}
 	}
@@ -151,6 +157,8 @@ impl From<u8> for EigenError {
 			16 => EigenError::OpinionNotFound,
 			17 => EigenError::PubkeyNotFound,
 			18 => EigenError::NeighbourNotFound,
+			19 => EigenError::OpinionsFrozen,
+			20 => EigenError::InvalidEpochInterval,
 			_ => EigenError::Unknown,
 		}
 	}
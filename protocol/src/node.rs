@@ -261,6 +261,7 @@ mod tests {
 		utils::keypair_from_sk_bytes,
 	};
 	use eigen_trust_circuit::{
+		eddsa::native::SecretKey,
 		halo2wrong::{
 			curves::bn256::Bn256,
 			halo2::poly::{commitment::ParamsProver, kzg::commitment::ParamsKZG},
@@ -302,8 +303,14 @@ mod tests {
 			random_circuit::<Bn256, _, MAX_NEIGHBORS, NUM_BOOTSTRAP_PEERS, Params>(rng);
 		let pk = keygen(&params, &random_circuit).unwrap();
 
-		let peer1 = Peer::new(local_key1.clone(), params.clone(), pk.clone()).unwrap();
-		let peer2 = Peer::new(local_key2.clone(), params, pk).unwrap();
+		let peer1 = Peer::new(
+			local_key1.clone(),
+			SecretKey::random(rng),
+			params.clone(),
+			pk.clone(),
+		)
+		.unwrap();
+		let peer2 = Peer::new(local_key2.clone(), SecretKey::random(rng), params, pk).unwrap();
 
 		let mut node1 = Node::new(local_key1, local_address1.clone(), peer1).unwrap();
 		let mut node2 = Node::new(local_key2, local_address2.clone(), peer2).unwrap();
@@ -356,8 +363,14 @@ mod tests {
 			random_circuit::<Bn256, _, MAX_NEIGHBORS, NUM_BOOTSTRAP_PEERS, Params>(rng);
 		let pk = keygen(&params, &random_circuit).unwrap();
 
-		let peer1 = Peer::new(local_key1.clone(), params.clone(), pk.clone()).unwrap();
-		let peer2 = Peer::new(local_key2.clone(), params, pk).unwrap();
+		let peer1 = Peer::new(
+			local_key1.clone(),
+			SecretKey::random(rng),
+			params.clone(),
+			pk.clone(),
+		)
+		.unwrap();
+		let peer2 = Peer::new(local_key2.clone(), SecretKey::random(rng), params, pk).unwrap();
 
 		let mut node1 = Node::new(local_key1.clone(), local_address1.clone(), peer1).unwrap();
 		let mut node2 = Node::new(local_key2.clone(), local_address2.clone(), peer2).unwrap();
@@ -416,8 +429,14 @@ mod tests {
 			random_circuit::<Bn256, _, MAX_NEIGHBORS, NUM_BOOTSTRAP_PEERS, Params>(rng);
 		let pk = keygen(&params, &random_circuit).unwrap();
 
-		let peer1 = Peer::new(local_key1.clone(), params.clone(), pk.clone()).unwrap();
-		let peer2 = Peer::new(local_key2.clone(), params, pk).unwrap();
+		let peer1 = Peer::new(
+			local_key1.clone(),
+			SecretKey::random(rng),
+			params.clone(),
+			pk.clone(),
+		)
+		.unwrap();
+		let peer2 = Peer::new(local_key2.clone(), SecretKey::random(rng), params, pk).unwrap();
 
 		let mut node1 = Node::new(local_key1, local_address1, peer1).unwrap();
 		let mut node2 = Node::new(local_key2, local_address2.clone(), peer2).unwrap();
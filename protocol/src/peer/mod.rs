@@ -8,9 +8,12 @@ pub mod opinion;
 pub mod pubkey;
 
 use crate::{constants::MAX_NEIGHBORS, EigenError, Epoch};
-use eigen_trust_circuit::halo2wrong::{
-	curves::bn256::{Bn256, G1Affine},
-	halo2::{plonk::ProvingKey, poly::kzg::commitment::ParamsKZG},
+use eigen_trust_circuit::{
+	eddsa::native::Signer,
+	halo2wrong::{
+		curves::bn256::{Bn256, G1Affine},
+		halo2::{plonk::ProvingKey, poly::kzg::commitment::ParamsKZG},
+	},
 };
 use libp2p::{core::PublicKey, identity::Keypair, PeerId};
 use opinion::Opinion;
@@ -26,15 +29,18 @@ pub struct Peer {
 	pub(crate) cached_neighbor_opinion: HashMap<(PeerId, Epoch, u32), Opinion>,
 	pub(crate) cached_local_opinion: HashMap<(PeerId, Epoch, u32), Opinion>,
 	keypair: Keypair,
+	/// Signs on behalf of the peer.
+	signer: Box<dyn Signer + Send>,
 	pub(crate) pubkey: Pubkey,
 	params: ParamsKZG<Bn256>,
 	proving_key: ProvingKey<G1Affine>,
 }
 
 impl Peer {
-	/// Creates a new peer.
+	/// Creates a new peer, signing on its behalf with the `signer`.
 	pub fn new(
-		keypair: Keypair, params: ParamsKZG<Bn256>, pk: ProvingKey<G1Affine>,
+		keypair: Keypair, signer: impl Signer + Send + 'static, params: ParamsKZG<Bn256>,
+		pk: ProvingKey<G1Affine>,
 	) -> Result<Self, EigenError> {
 		Ok(Peer {
 			neighbors: [None; MAX_NEIGHBORS],
@@ -45,6 +51,7 @@ impl Peer {
 			cached_local_opinion: HashMap::new(),
 			pubkey: Pubkey::from_keypair(&keypair)?,
 			keypair,
+			signer: Box::new(signer),
 			params,
 			proving_key: pk,
 		})
@@ -179,6 +186,11 @@ impl Peer {
 	pub fn get_keypair(&self) -> &Keypair {
 		&self.keypair
 	}
+
+	/// Get the signer for this peer.
+	pub fn get_signer(&self) -> &(dyn Signer + Send) {
+		&*self.signer
+	}
 }
 
 #[cfg(test)]
@@ -186,6 +198,7 @@ mod tests {
 	use super::*;
 	use crate::constants::NUM_BOOTSTRAP_PEERS;
 	use eigen_trust_circuit::{
+		eddsa::native::SecretKey,
 		halo2wrong::halo2::poly::commitment::ParamsProver,
 		params::poseidon_bn254_5x5::Params,
 		utils::{keygen, random_circuit},
@@ -202,9 +215,11 @@ mod tests {
 		let random_circuit =
 			random_circuit::<Bn256, _, MAX_NEIGHBORS, NUM_BOOTSTRAP_PEERS, Params>(rng);
 		let pk = keygen(&params, &random_circuit).unwrap();
-		let peer = Peer::new(kp, params, pk);
+		let sk = SecretKey::random(rng);
+		let public = sk.public();
+		let peer = Peer::new(kp, sk, params, pk).unwrap();
 
-		assert!(peer.is_ok());
+		assert_eq!(peer.get_signer().public(), public);
 	}
 
 	#[test]
@@ -216,7 +231,7 @@ mod tests {
 		let random_circuit =
 			random_circuit::<Bn256, _, MAX_NEIGHBORS, NUM_BOOTSTRAP_PEERS, Params>(rng);
 		let pk = keygen(&params, &random_circuit).unwrap();
-		let mut peer = Peer::new(kp, params.clone(), pk.clone()).unwrap();
+		let mut peer = Peer::new(kp, SecretKey::random(rng), params.clone(), pk.clone()).unwrap();
 
 		let iter = 0;
 		let epoch = Epoch(0);
@@ -252,7 +267,7 @@ mod tests {
 			random_circuit::<Bn256, _, MAX_NEIGHBORS, NUM_BOOTSTRAP_PEERS, Params>(rng);
 		let pk = keygen(&params, &random_circuit).unwrap();
 
-		let mut peer = Peer::new(kp, params, pk).unwrap();
+		let mut peer = Peer::new(kp, SecretKey::random(rng), params, pk).unwrap();
 		let neighbor_id = PeerId::random();
 
 		peer.add_neighbor(neighbor_id).unwrap();
@@ -275,7 +290,13 @@ mod tests {
 			random_circuit::<Bn256, _, MAX_NEIGHBORS, NUM_BOOTSTRAP_PEERS, Params>(rng);
 		let pk = keygen(&params, &random_circuit).unwrap();
 
-		let mut peer = Peer::new(local_keypair.clone(), params.clone(), pk.clone()).unwrap();
+		let mut peer = Peer::new(
+			local_keypair.clone(),
+			SecretKey::random(rng),
+			params.clone(),
+			pk.clone(),
+		)
+		.unwrap();
 
 		let iter = 3;
 		let epoch = Epoch(3);
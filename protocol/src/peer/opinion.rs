@@ -20,6 +20,7 @@ use eigen_trust_circuit::{
 	},
 	params::poseidon_bn254_5x5::Params,
 	poseidon::native::Poseidon,
+	public_inputs::EigenTrustPublicInputs,
 	utils::{prove, verify},
 	EigenTrustCircuit,
 };
@@ -94,16 +95,20 @@ impl Opinion {
 			pk_v, epoch_f, iter_f, sk, op_ji_f, c_v_f, bootstrap_pubkeys, bootstrap_score_f,
 		);
 
-		let pub_ins = vec![m_hash];
+		let pub_ins = EigenTrustPublicInputs::new()
+			.with_m_hash(m_hash)
+			.build::<Params>()
+			.map_err(|_| EigenError::ProvingError)?;
 
-		let proof_bytes = prove(params, circuit, &[&pub_ins], pk, &mut rng)
+		let proof_bytes = prove(params, circuit, &pub_ins.as_slices(), pk, &mut rng)
 			.map_err(|_| EigenError::ProvingError)?;
 
 		// Sanity check
-		let proof_res = verify(params, &[&pub_ins], &proof_bytes, pk.get_vk()).map_err(|e| {
-			println!("{}", e);
-			EigenError::VerificationError
-		})?;
+		let proof_res =
+			verify(params, &pub_ins.as_slices(), &proof_bytes, pk.get_vk()).map_err(|e| {
+				println!("{}", e);
+				EigenError::VerificationError
+			})?;
 		assert!(proof_res);
 
 		Ok(Self { epoch, iter: k, op: op_v_unscaled, proof_bytes, m_hash: m_hash.to_bytes() })
@@ -143,12 +148,16 @@ impl Opinion {
 
 		let final_hash = if op_v_f == Bn256Scalar::zero() { m_hash_passed } else { m_hash };
 
-		let pub_ins = vec![final_hash];
+		let pub_ins = EigenTrustPublicInputs::new()
+			.with_m_hash(final_hash)
+			.build::<Params>()
+			.map_err(|_| EigenError::VerificationError)?;
 
-		let proof_res = verify(params, &[&pub_ins], &self.proof_bytes, vk).map_err(|e| {
-			println!("{}", e);
-			EigenError::VerificationError
-		})?;
+		let proof_res =
+			verify(params, &pub_ins.as_slices(), &self.proof_bytes, vk).map_err(|e| {
+				println!("{}", e);
+				EigenError::VerificationError
+			})?;
 
 		Ok(proof_res)
 	}
@@ -0,0 +1,75 @@
+#!/usr/bin/env python3
+# Independent reference implementation of the native EdDSA signing, used to
+# generate the pinned test vectors in `circuit/src/eddsa/native/mod.rs`.
+# Poseidon constants are read from the params file, the curve arithmetic is
+# done in affine coordinates with plain integers.
+#
+# Usage: python3 scripts/eddsa-test-vectors.py <sk0> <sk1> <m>
+import re
+import sys
+from pathlib import Path
+
+P = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+A = 168700
+D = 168696
+SUBORDER = 2736030358979909402780800718157159386076813972158567259200215660948447373041
+B8 = (
+	5299619240641551281634865583518297030282874472190772894086521144482721001553,
+	16950150798460657717958625567821834550301663161624707787222815936182638968203,
+)
+
+PARAMS = Path(__file__).parent.parent / "circuit/src/params/poseidon_bn254_5x5.rs"
+WIDTH, FULL_ROUNDS, PARTIAL_ROUNDS = 5, 8, 60
+
+
+def load_params():
+	values = [int(h, 16) for h in re.findall(r'"0x([0-9a-f]+)"', PARAMS.read_text())]
+	count = (FULL_ROUNDS + PARTIAL_ROUNDS) * WIDTH
+	consts, mds = values[:count], values[count:]
+	return consts, [mds[i * WIDTH:(i + 1) * WIDTH] for i in range(WIDTH)]
+
+
+def poseidon(inputs):
+	consts, mds = load_params()
+	state = list(inputs)
+	for r in range(FULL_ROUNDS + PARTIAL_ROUNDS):
+		state = [(s + c) % P for s, c in zip(state, consts[r * WIDTH:(r + 1) * WIDTH])]
+		full = r < FULL_ROUNDS // 2 or r >= FULL_ROUNDS // 2 + PARTIAL_ROUNDS
+		state = [pow(s, 5, P) if full or i == 0 else s for i, s in enumerate(state)]
+		state = [sum(m * s for m, s in zip(row, state)) % P for row in mds]
+	return state
+
+
+def add(p, q):
+	(x1, y1), (x2, y2) = p, q
+	t = D * x1 * x2 * y1 * y2 % P
+	x3 = (x1 * y2 + y1 * x2) * pow(1 + t, -1, P) % P
+	y3 = (y1 * y2 - A * x1 * x2) * pow(1 - t, -1, P) % P
+	return (x3, y3)
+
+
+def mul(p, k):
+	r = (0, 1)
+	while k:
+		if k & 1:
+			r = add(r, p)
+		p = add(p, p)
+		k >>= 1
+	return r
+
+
+def sign(sk0, sk1, m):
+	pk = mul(B8, sk0)
+	r = poseidon([0, sk1, m, 0, 0])[0]
+	big_r = mul(B8, r)
+	m_hash = poseidon([big_r[0], big_r[1], pk[0], pk[1], m])[0]
+	s = (r + m_hash * sk0) % SUBORDER
+	return pk, big_r, s
+
+
+if __name__ == "__main__":
+	sk0, sk1, m = (int(a) for a in sys.argv[1:4])
+	pk, big_r, s = sign(sk0, sk1, m)
+	print(f"pk.x    = {pk[0]}\npk.y    = {pk[1]}")
+	print(f"big_r.x = {big_r[0]}\nbig_r.y = {big_r[1]}")
+	print(f"s       = {s}")
@@ -0,0 +1 @@
+cargo test -p eigen-trust-circuit --release --features parallel -- --test-threads=1